@@ -0,0 +1,65 @@
+//! Compares the free-function API, which shares one pooled transport, against
+//! building a new HTTP client per call (the previous behaviour).
+//!
+//! Run with `cargo bench --bench connection_reuse`. The local server speaks
+//! plain HTTP, so the gap shown here is TCP setup only; against
+//! `https://openrouter.ai` every avoided connection also skips a TLS handshake.
+
+use std::{
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+
+use openrouter_rs::{OpenRouterClient, api::credits};
+
+#[path = "../tests/unit/support.rs"]
+#[allow(dead_code)]
+mod support;
+
+use support::spawn_keep_alive_server;
+
+const CALLS: usize = 500;
+
+fn report(label: &str, elapsed: Duration, connections: usize) {
+    println!(
+        "{label:<28} {CALLS} calls in {:>8.2?} ({:>7.1?}/call), {connections} connections",
+        elapsed,
+        elapsed / CALLS as u32
+    );
+}
+
+#[tokio::main]
+async fn main() {
+    let (base_url, connections) = spawn_keep_alive_server().await;
+
+    let start = Instant::now();
+    for _ in 0..CALLS {
+        credits::get_credits(&base_url, "bench-key")
+            .await
+            .expect("credits request should succeed");
+    }
+    report(
+        "shared transport",
+        start.elapsed(),
+        connections.swap(0, Ordering::SeqCst),
+    );
+
+    let start = Instant::now();
+    for _ in 0..CALLS {
+        let client = OpenRouterClient::builder()
+            .base_url(base_url.clone())
+            .api_key("bench-key")
+            .http_client(reqwest::Client::new())
+            .build()
+            .expect("client should build");
+        client
+            .get_credits()
+            .await
+            .expect("credits request should succeed");
+    }
+    report(
+        "new client per call",
+        start.elapsed(),
+        connections.swap(0, Ordering::SeqCst),
+    );
+}
@@ -149,12 +149,21 @@ fn map_sdk_error(error: OpenRouterError) -> (StatusCode, Json<ErrorResponse>) {
             error_response(StatusCode::INTERNAL_SERVER_ERROR, error.to_string())
         }
         OpenRouterError::Api(_) => error_response(StatusCode::BAD_GATEWAY, error.to_string()),
-        OpenRouterError::HttpRequest(_) => {
+        OpenRouterError::HttpRequest(_)
+        | OpenRouterError::StructuredOutput(_)
+        | OpenRouterError::JobFailed(_) => {
             error_response(StatusCode::BAD_GATEWAY, error.to_string())
         }
+        OpenRouterError::Timeout(_) => {
+            error_response(StatusCode::GATEWAY_TIMEOUT, error.to_string())
+        }
+        OpenRouterError::BudgetExceeded(_) => {
+            error_response(StatusCode::PAYMENT_REQUIRED, error.to_string())
+        }
         OpenRouterError::UninitializedFieldError(_)
         | OpenRouterError::Serialization(_)
         | OpenRouterError::Io(_)
+        | OpenRouterError::Cancelled
         | OpenRouterError::Unknown(_) => {
             error_response(StatusCode::INTERNAL_SERVER_ERROR, error.to_string())
         }
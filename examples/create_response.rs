@@ -1,5 +1,7 @@
-use openrouter_rs::{OpenRouterClient, api::responses::ResponsesRequest};
-use serde_json::json;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::responses::{ResponsesInputItem, ResponsesRequest},
+};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -8,15 +10,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let request = ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(json!([{
-            "role": "user",
-            "content": "Say hello in one sentence."
-        }]))
+        .input(vec![ResponsesInputItem::user("Say hello in one sentence.")].into())
         .build()?;
 
     let response = client.responses().create(&request).await?;
     println!("response id: {:?}", response.id);
     println!("status: {:?}", response.status);
+    println!("text: {}", response.output_text().unwrap_or_default());
 
     Ok(())
 }
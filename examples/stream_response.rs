@@ -1,5 +1,8 @@
 use futures_util::StreamExt;
-use openrouter_rs::{OpenRouterClient, api::responses::ResponsesRequest};
+use openrouter_rs::{
+    OpenRouterClient,
+    api::responses::{ResponsesRequest, ResponsesStreamEvent},
+};
 use serde_json::json;
 
 #[tokio::main]
@@ -9,10 +12,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let request = ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(json!([{
-            "role": "user",
-            "content": "Write a short haiku about Rust."
-        }]))
+        .input(
+            json!([{
+                "role": "user",
+                "content": "Write a short haiku about Rust."
+            }])
+            .into(),
+        )
         .build()?;
 
     let stream = client.responses().stream(&request).await?;
@@ -20,7 +26,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     stream
         .filter_map(|event| async { event.ok() })
         .for_each(|event| async move {
-            println!("event: {}", event.event_type);
+            match event {
+                ResponsesStreamEvent::OutputTextDelta { delta, .. } => print!("{delta}"),
+                ResponsesStreamEvent::Completed { response, .. } => {
+                    println!("\nstatus: {:?}", response.status);
+                }
+                other => eprintln!("event: {}", other.event_type()),
+            }
         })
         .await;
 
@@ -0,0 +1,102 @@
+//! # Tool Runner
+//!
+//! Let `ToolRunner` drive the tool-calling loop: register typed handlers,
+//! then stream the run while tools execute between model turns.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! export OPENROUTER_API_KEY=sk-or-v1-...
+//! cargo run --example tool_runner
+//! ```
+
+use std::{env, error::Error};
+
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::chat::{ChatCompletionRequest, Message},
+    tool_runner::{ToolRunEvent, ToolRunner},
+    types::{Role, stream::StreamEvent, typed_tool::TypedTool},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct WeatherParams {
+    /// City name, e.g. "Paris"
+    location: String,
+}
+
+impl TypedTool for WeatherParams {
+    fn name() -> &'static str {
+        "get_weather"
+    }
+
+    fn description() -> &'static str {
+        "Get the current weather for a city"
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+struct ConvertParams {
+    celsius: f64,
+}
+
+impl TypedTool for ConvertParams {
+    fn name() -> &'static str {
+        "celsius_to_fahrenheit"
+    }
+
+    fn description() -> &'static str {
+        "Convert a temperature from Celsius to Fahrenheit"
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let api_key = env::var("OPENROUTER_API_KEY")?;
+    let model =
+        env::var("OPENROUTER_TOOL_MODEL").unwrap_or_else(|_| "openai/gpt-4.1-mini".to_string());
+    let client = OpenRouterClient::builder().api_key(api_key).build()?;
+
+    let runner =
+        ToolRunner::new()
+            .tool(|params: WeatherParams| async move {
+                Ok::<_, String>(
+                    json!({"location": params.location, "celsius": 18.5, "sky": "clear"}),
+                )
+            })
+            .tool(|params: ConvertParams| async move {
+                Ok::<_, String>(params.celsius * 9.0 / 5.0 + 32.0)
+            })
+            .max_iterations(4);
+
+    let request = ChatCompletionRequest::builder()
+        .model(model)
+        .messages(vec![Message::new(
+            Role::User,
+            "What's the weather in Paris and Tokyo, in Fahrenheit?",
+        )])
+        .build()?;
+
+    let mut run = runner.run_streaming(&client, &request);
+    while let Some(event) = run.next().await {
+        match event? {
+            ToolRunEvent::Model(StreamEvent::ContentDelta(delta)) => print!("{delta}"),
+            ToolRunEvent::ToolResult { call, output } => {
+                println!(
+                    "\n[tool] {}({}) -> {}",
+                    call.function.name, call.function.arguments, output.content
+                );
+            }
+            ToolRunEvent::Finished {
+                iterations, stop, ..
+            } => println!("\n\nFinished after {iterations} model call(s): {stop:?}"),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
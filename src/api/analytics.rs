@@ -1,14 +1,13 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::{
     error::OpenRouterError,
     strip_option_vec_setter,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::ApiResponse,
 };
 
@@ -205,7 +204,7 @@ pub async fn get_analytics_meta(
     base_url: &str,
     management_key: &str,
 ) -> Result<AnalyticsMeta, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_analytics_meta_with_client(&http_client, base_url, management_key).await
 }
 
@@ -238,7 +237,7 @@ pub async fn query_analytics(
     management_key: &str,
     request: &AnalyticsQueryRequest,
 ) -> Result<AnalyticsQueryResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     query_analytics_with_client(&http_client, base_url, management_key, request).await
 }
 
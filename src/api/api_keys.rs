@@ -1,9 +1,8 @@
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, PaginationOptions},
 };
 
@@ -116,7 +115,7 @@ pub async fn get_current_api_key(
     base_url: &str,
     api_key: &str,
 ) -> Result<ApiKeyDetails, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_current_api_key_with_client(&http_client, base_url, api_key).await
 }
 
@@ -160,7 +159,7 @@ pub async fn list_api_keys(
     pagination: Option<PaginationOptions>,
     include_disabled: Option<bool>,
 ) -> Result<Vec<ApiKey>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_api_keys_in_workspace_with_client(
         &http_client,
         base_url,
@@ -197,7 +196,7 @@ pub async fn list_api_keys_in_workspace(
     include_disabled: Option<bool>,
     workspace_id: Option<&str>,
 ) -> Result<Vec<ApiKey>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_api_keys_in_workspace_with_client(
         &http_client,
         base_url,
@@ -264,7 +263,7 @@ pub async fn create_api_key(
     name: &str,
     limit: Option<f64>,
 ) -> Result<ApiKey, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_api_key_in_workspace_with_client(
         &http_client,
         base_url,
@@ -301,7 +300,7 @@ pub async fn create_api_key_in_workspace(
     limit: Option<f64>,
     workspace_id: Option<&str>,
 ) -> Result<ApiKey, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_api_key_in_workspace_with_client(
         &http_client,
         base_url,
@@ -362,7 +361,7 @@ pub async fn get_api_key(
     management_key: &str,
     hash: &str,
 ) -> Result<ApiKey, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_api_key_with_client(&http_client, base_url, management_key, hash).await
 }
 
@@ -407,7 +406,7 @@ pub async fn delete_api_key(
     management_key: &str,
     hash: &str,
 ) -> Result<bool, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     delete_api_key_with_client(&http_client, base_url, management_key, hash).await
 }
 
@@ -456,7 +455,7 @@ pub async fn update_api_key(
     disabled: Option<bool>,
     limit: Option<f64>,
 ) -> Result<ApiKey, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     update_api_key_with_client(
         &http_client,
         base_url,
@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::{Client as HttpClient, StatusCode};
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
 };
 
 const OFFICIAL_SPEECH_PATH: &str = "/audio/speech";
@@ -165,7 +165,7 @@ pub async fn create_speech(
     app_categories: &Option<Vec<String>>,
     request: &SpeechRequest,
 ) -> Result<Vec<u8>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_speech_with_client(
         &http_client,
         base_url,
@@ -199,7 +199,9 @@ pub(crate) async fn create_speech_with_client(
     .await?;
 
     if official_response.status().is_success() {
-        return Ok(official_response.bytes().await?.to_vec());
+        return Ok(transport_response::read_bytes(official_response)
+            .await?
+            .to_vec());
     }
 
     let official_error = transport_response::error_from_response(official_response).await;
@@ -218,7 +220,9 @@ pub(crate) async fn create_speech_with_client(
         .await?;
 
         if legacy_response.status().is_success() {
-            return Ok(legacy_response.bytes().await?.to_vec());
+            return Ok(transport_response::read_bytes(legacy_response)
+                .await?
+                .to_vec());
         }
 
         transport_response::handle_error(legacy_response).await?;
@@ -236,7 +240,7 @@ pub async fn create_transcription(
     app_categories: &Option<Vec<String>>,
     request: &TranscriptionRequest,
 ) -> Result<TranscriptionResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_transcription_with_client(
         &http_client,
         base_url,
@@ -287,7 +291,7 @@ async fn send_speech_request(
     endpoint_path: &str,
 ) -> Result<reqwest::Response, OpenRouterError> {
     let url = format!("{base_url}{endpoint_path}");
-    Ok(transport_request::with_client_request_headers(
+    transport_request::with_client_request_headers(
         transport_request::post(http_client, &url),
         api_key,
         request_metadata.0,
@@ -296,7 +300,7 @@ async fn send_speech_request(
     )?
     .json(request)
     .send()
-    .await?)
+    .await
 }
 
 fn should_retry_legacy_tts(error: &OpenRouterError) -> bool {
@@ -1,10 +1,9 @@
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::ApiResponse,
 };
 
@@ -110,7 +109,7 @@ pub async fn exchange_code_for_api_key(
     code_verifier: Option<&str>,
     code_challenge_method: Option<CodeChallengeMethod>,
 ) -> Result<AuthResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     exchange_code_for_api_key_with_client(
         &http_client,
         base_url,
@@ -158,7 +157,7 @@ pub async fn create_auth_code(
     api_key: &str,
     request: &CreateAuthCodeRequest,
 ) -> Result<AuthCodeData, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_auth_code_with_client(&http_client, base_url, api_key, request).await
 }
 
@@ -1,12 +1,11 @@
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
 use urlencoding::encode;
 
 use crate::{
     error::OpenRouterError,
     strip_option_vec_setter,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, PaginationOptions},
 };
 
@@ -219,7 +218,7 @@ pub async fn list_byok_keys(
     workspace_id: Option<&str>,
     provider: Option<&str>,
 ) -> Result<ByokKeyListResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_byok_keys_with_client(
         &http_client,
         base_url,
@@ -274,7 +273,7 @@ pub async fn create_byok_key(
     management_key: &str,
     request: &CreateByokKeyRequest,
 ) -> Result<ByokKey, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_byok_key_with_client(&http_client, base_url, management_key, request).await
 }
 
@@ -309,7 +308,7 @@ pub async fn get_byok_key(
     management_key: &str,
     id: &str,
 ) -> Result<ByokKey, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_byok_key_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -344,7 +343,7 @@ pub async fn update_byok_key(
     id: &str,
     request: &UpdateByokKeyRequest,
 ) -> Result<ByokKey, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     update_byok_key_with_client(&http_client, base_url, management_key, id, request).await
 }
 
@@ -380,7 +379,7 @@ pub async fn delete_byok_key(
     management_key: &str,
     id: &str,
 ) -> Result<bool, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     delete_byok_key_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -2,7 +2,6 @@ use std::collections::HashMap;
 
 use derive_builder::Builder;
 use futures_util::{StreamExt, stream::BoxStream};
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -10,7 +9,8 @@ use crate::{
     error::OpenRouterError,
     strip_option_map_setter, strip_option_vec_setter,
     transport::{
-        request as transport_request, response as transport_response, sse::response_lines,
+        HttpClient, request as transport_request, response as transport_response,
+        sse::response_lines,
     },
     types::{
         OpenRouterExperimentalMetadata, ProviderPreferences, ReasoningConfig, ResponseFormat, Role,
@@ -854,9 +854,54 @@ impl ChatCompletionRequest {
         req
     }
 
+    /// Copy of this request targeting `model`.
+    pub(crate) fn with_model(&self, model: &str) -> Self {
+        let mut req = self.clone();
+        req.model = model.to_string();
+        req
+    }
+
+    /// Copy of this request with the conversation replaced.
+    pub(crate) fn with_messages(&self, messages: Vec<Message>) -> Self {
+        let mut req = self.clone();
+        req.messages = messages;
+        req
+    }
+
+    pub(crate) fn with_response_format(&self, response_format: ResponseFormat) -> Self {
+        let mut req = self.clone();
+        req.response_format = Some(response_format);
+        req
+    }
+
+    /// Copy of this request with any of `tools` not already declared appended.
+    pub(crate) fn with_missing_tools(&self, tools: &[crate::types::Tool]) -> Self {
+        let mut req = self.clone();
+        if tools.is_empty() {
+            return req;
+        }
+        let declared = req.tools.get_or_insert_with(Vec::new);
+        for tool in tools {
+            if !declared
+                .iter()
+                .any(|existing| existing.function.name == tool.function.name)
+            {
+                declared.push(tool.clone());
+            }
+        }
+        req
+    }
+
     pub fn experimental_metadata(&self) -> Option<OpenRouterExperimentalMetadata> {
         self.experimental_metadata
     }
+
+    pub(crate) fn set_experimental_metadata(
+        &mut self,
+        experimental_metadata: Option<OpenRouterExperimentalMetadata>,
+    ) {
+        self.experimental_metadata = experimental_metadata;
+    }
 }
 
 /// Send a chat completion request to a selected model.
@@ -880,7 +925,7 @@ pub async fn send_chat_completion(
     app_categories: &Option<Vec<String>>,
     request: &ChatCompletionRequest,
 ) -> Result<CompletionsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     send_chat_completion_with_client(
         &http_client,
         base_url,
@@ -948,7 +993,7 @@ pub async fn stream_chat_completion(
     app_categories: &Option<Vec<String>>,
     request: &ChatCompletionRequest,
 ) -> Result<BoxStream<'static, Result<CompletionsResponse, OpenRouterError>>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     stream_chat_completion_with_client(
         &http_client,
         base_url,
@@ -986,7 +1031,7 @@ pub(crate) async fn stream_chat_completion_with_client(
         &request.experimental_metadata,
     )
     .json(&request)
-    .send()
+    .send_streaming()
     .await?;
 
     if response.status().is_success() {
@@ -1,12 +1,11 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::ApiResponse,
 };
 
@@ -68,7 +67,7 @@ pub async fn create_coinbase_charge(
     api_key: &str,
     request: &CoinbaseChargeRequest,
 ) -> Result<CoinbaseChargeData, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_coinbase_charge_with_client(&http_client, base_url, api_key, request).await
 }
 
@@ -107,7 +106,7 @@ pub(crate) async fn create_coinbase_charge_with_client(
 ///
 /// * `Result<CreditsData, OpenRouterError>` - The response data containing the total credits and usage.
 pub async fn get_credits(base_url: &str, api_key: &str) -> Result<CreditsData, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_credits_with_client(&http_client, base_url, api_key).await
 }
 
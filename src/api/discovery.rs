@@ -1,14 +1,13 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 use urlencoding::encode;
 
 use crate::{
     api::models::ModelReasoning,
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::ApiResponse,
 };
 
@@ -588,7 +587,7 @@ pub async fn list_providers(
     base_url: &str,
     api_key: &str,
 ) -> Result<Vec<Provider>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_providers_with_client(&http_client, base_url, api_key).await
 }
 
@@ -618,7 +617,7 @@ pub async fn list_models_for_user(
     base_url: &str,
     api_key: &str,
 ) -> Result<Vec<UserModel>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_models_for_user_with_client(&http_client, base_url, api_key).await
 }
 
@@ -648,7 +647,7 @@ pub async fn count_models(
     base_url: &str,
     api_key: &str,
 ) -> Result<ModelsCountData, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     count_models_with_client(&http_client, base_url, api_key).await
 }
 
@@ -680,7 +679,7 @@ pub async fn get_rankings_daily(
     start_date: Option<&str>,
     end_date: Option<&str>,
 ) -> Result<RankingsDailyResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_rankings_daily_with_client(&http_client, base_url, api_key, start_date, end_date).await
 }
 
@@ -726,7 +725,7 @@ pub async fn get_app_rankings(
     api_key: &str,
     params: Option<&AppRankingsParams>,
 ) -> Result<AppRankingsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_app_rankings_with_client(&http_client, base_url, api_key, params).await
 }
 
@@ -758,7 +757,7 @@ pub async fn get_task_classifications(
     api_key: &str,
     window: Option<&str>,
 ) -> Result<TaskClassificationsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_task_classifications_with_client(&http_client, base_url, api_key, window).await
 }
 
@@ -790,7 +789,7 @@ pub async fn get_benchmarks(
     api_key: &str,
     params: &UnifiedBenchmarksParams,
 ) -> Result<UnifiedBenchmarksResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_benchmarks_with_client(&http_client, base_url, api_key, params).await
 }
 
@@ -828,7 +827,7 @@ pub async fn get_benchmarks_artificial_analysis(
     api_key: &str,
     max_results: Option<u32>,
 ) -> Result<BenchmarksAAResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_benchmarks_artificial_analysis_with_client(&http_client, base_url, api_key, max_results)
         .await
 }
@@ -876,7 +875,7 @@ pub async fn get_benchmarks_design_arena(
     category: Option<&str>,
     max_results: Option<u32>,
 ) -> Result<BenchmarksDAResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_benchmarks_design_arena_with_client(
         &http_client,
         base_url,
@@ -924,7 +923,7 @@ pub async fn list_zdr_endpoints(
     base_url: &str,
     api_key: &str,
 ) -> Result<Vec<PublicEndpoint>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_zdr_endpoints_with_client(&http_client, base_url, api_key).await
 }
 
@@ -957,7 +956,7 @@ pub async fn get_activity(
     management_key: &str,
     date: Option<&str>,
 ) -> Result<Vec<ActivityItem>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_activity_with_client(&http_client, base_url, management_key, date).await
 }
 
@@ -1,11 +1,10 @@
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     api::models,
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, ProviderPreferences},
 };
 
@@ -287,7 +286,7 @@ pub async fn create_embedding(
     app_categories: &Option<Vec<String>>,
     request: &EmbeddingRequest,
 ) -> Result<EmbeddingResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_embedding_with_client(
         &http_client,
         base_url,
@@ -337,7 +336,7 @@ pub async fn list_embedding_models(
     base_url: &str,
     api_key: &str,
 ) -> Result<Vec<models::Model>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_embedding_models_with_client(&http_client, base_url, api_key).await
 }
 
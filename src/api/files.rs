@@ -1,13 +1,27 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    io,
+    path::Path,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
 
+use bytes::Bytes;
 use derive_builder::Builder;
-use reqwest::{Client as HttpClient, multipart};
+use futures_util::{Stream, StreamExt, TryStreamExt, stream::BoxStream};
+use reqwest::{Response, multipart};
 use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
 use urlencoding::encode;
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{
+        HttpClient, request as transport_request, response as transport_response,
+        timeout::{StreamIdleTimeout, stream_read_error, with_idle_timeout},
+    },
 };
 
 #[derive(Serialize)]
@@ -85,6 +99,233 @@ impl UploadFileRequest {
     }
 }
 
+/// Bytes moved so far by a streaming upload or download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransferProgress {
+    pub transferred: u64,
+    /// Total size, when known up front.
+    pub total: Option<u64>,
+}
+
+type ProgressCallback = Arc<dyn Fn(TransferProgress) + Send + Sync>;
+
+/// Report progress to `progress` as chunks pass through `bytes`.
+fn with_progress<E: Send + 'static>(
+    bytes: BoxStream<'static, Result<Bytes, E>>,
+    total: Option<u64>,
+    progress: Option<ProgressCallback>,
+) -> BoxStream<'static, Result<Bytes, E>> {
+    let Some(progress) = progress else {
+        return bytes;
+    };
+    let mut transferred = 0;
+    bytes
+        .inspect_ok(move |chunk| {
+            transferred += chunk.len() as u64;
+            progress(TransferProgress { transferred, total });
+        })
+        .boxed()
+}
+
+/// File upload for `POST /files` whose content is streamed rather than held
+/// in memory.
+///
+/// With a known [`length`](Self::length) the file part is sent with its
+/// size; otherwise the request body uses chunked transfer encoding. Streamed
+/// uploads cannot be replayed, so they are sent once even when a
+/// [`RetryPolicy`](crate::client::RetryPolicy) is configured. They are also
+/// exempt from the client's `request` [`Timeouts`](crate::client::Timeouts),
+/// which would cut off a large upload on a slow link; the `connect` and
+/// `read` timeouts still apply.
+///
+/// ```rust,no_run
+/// use openrouter_rs::{OpenRouterClient, api::files::StreamingUpload};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+/// let upload = StreamingUpload::from_path("dataset.jsonl")
+///     .await?
+///     .mime_type("application/jsonl")
+///     .on_progress(|progress| println!("{} bytes sent", progress.transferred));
+/// let file = client.files().upload_stream(upload, None).await?;
+/// println!("uploaded {}", file.id);
+/// # Ok(())
+/// # }
+/// ```
+pub struct StreamingUpload {
+    filename: String,
+    mime_type: Option<String>,
+    length: Option<u64>,
+    body: BoxStream<'static, io::Result<Bytes>>,
+    progress: Option<ProgressCallback>,
+}
+
+impl StreamingUpload {
+    /// Upload the chunks of a byte stream. The length is unknown unless set
+    /// with [`length`](Self::length).
+    pub fn from_stream<S>(filename: impl Into<String>, stream: S) -> Self
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        Self {
+            filename: filename.into(),
+            mime_type: None,
+            length: None,
+            body: stream.boxed(),
+            progress: None,
+        }
+    }
+
+    /// Upload everything read from `reader`. The length is unknown unless set
+    /// with [`length`](Self::length).
+    pub fn from_reader<R>(filename: impl Into<String>, reader: R) -> Self
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        Self::from_stream(filename, ReaderStream::new(reader))
+    }
+
+    /// Upload a file from disk, using its file name and size.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, OpenRouterError> {
+        let path = path.as_ref();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| {
+                OpenRouterError::ConfigError(format!(
+                    "upload path {} has no file name",
+                    path.display()
+                ))
+            })?;
+        let file = tokio::fs::File::open(path).await?;
+        let length = file.metadata().await?.len();
+        Ok(Self::from_reader(filename, file).length(length))
+    }
+
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Declare the exact content length in bytes.
+    pub fn length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Call `callback` after each chunk is handed to the connection.
+    pub fn on_progress(
+        mut self,
+        callback: impl Fn(TransferProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+}
+
+impl std::fmt::Debug for StreamingUpload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingUpload")
+            .field("filename", &self.filename)
+            .field("mime_type", &self.mime_type)
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Streamed binary content, such as `GET /files/{file_id}/content` or a
+/// finished video.
+///
+/// Yields the body chunk by chunk as it arrives. The client's stream idle
+/// timeout applies between chunks, and the request timeout only bounds the
+/// wait for response headers.
+///
+/// ```rust,no_run
+/// use openrouter_rs::OpenRouterClient;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+/// let mut output = tokio::fs::File::create("report.pdf").await?;
+/// let written = client
+///     .files()
+///     .download_stream("file_123", None)
+///     .await?
+///     .on_progress(|progress| println!("{:?}", progress))
+///     .write_to(&mut output)
+///     .await?;
+/// println!("wrote {written} bytes");
+/// # Ok(())
+/// # }
+/// ```
+pub struct FileDownload {
+    content_length: Option<u64>,
+    inner: BoxStream<'static, Result<Bytes, OpenRouterError>>,
+}
+
+impl FileDownload {
+    pub(crate) fn new(response: Response) -> Self {
+        let content_length = response.content_length();
+        let idle_timeout = response
+            .extensions()
+            .get::<StreamIdleTimeout>()
+            .map(|timeout| timeout.0);
+        let bytes = response.bytes_stream().map_err(io::Error::other).boxed();
+        let bytes = match idle_timeout {
+            Some(idle_timeout) => with_idle_timeout(bytes, idle_timeout).boxed(),
+            None => bytes,
+        };
+        Self {
+            content_length,
+            inner: bytes.map_err(stream_read_error).boxed(),
+        }
+    }
+
+    /// Size from the `Content-Length` header, when the server sent one.
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// Call `callback` after each chunk is received.
+    pub fn on_progress(self, callback: impl Fn(TransferProgress) + Send + Sync + 'static) -> Self {
+        Self {
+            content_length: self.content_length,
+            inner: with_progress(self.inner, self.content_length, Some(Arc::new(callback))),
+        }
+    }
+
+    /// Copy the remaining content into `writer`, returning the bytes written.
+    pub async fn write_to<W>(mut self, writer: &mut W) -> Result<u64, OpenRouterError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let mut written = 0;
+        while let Some(chunk) = self.inner.next().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+}
+
+impl Stream for FileDownload {
+    type Item = Result<Bytes, OpenRouterError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl std::fmt::Debug for FileDownload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDownload")
+            .field("content_length", &self.content_length)
+            .finish_non_exhaustive()
+    }
+}
+
 fn workspace_query(workspace_id: Option<&str>) -> FileWorkspaceQuery {
     FileWorkspaceQuery {
         workspace_id: workspace_id.map(ToOwned::to_owned),
@@ -92,9 +333,9 @@ fn workspace_query(workspace_id: Option<&str>) -> FileWorkspaceQuery {
 }
 
 fn apply_workspace_query(
-    req: reqwest::RequestBuilder,
+    req: transport_request::RequestBuilder,
     workspace_id: Option<&str>,
-) -> reqwest::RequestBuilder {
+) -> transport_request::RequestBuilder {
     let query = workspace_query(workspace_id);
     if query.workspace_id.is_none() {
         req
@@ -111,7 +352,7 @@ pub async fn list_files(
     cursor: Option<&str>,
     workspace_id: Option<&str>,
 ) -> Result<FileListResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_files_with_client(&http_client, base_url, api_key, limit, cursor, workspace_id).await
 }
 
@@ -153,7 +394,7 @@ pub async fn upload_file(
     request: &UploadFileRequest,
     workspace_id: Option<&str>,
 ) -> Result<FileMetadata, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     upload_file_with_client(&http_client, base_url, api_key, request, workspace_id).await
 }
 
@@ -163,11 +404,74 @@ pub(crate) async fn upload_file_with_client(
     api_key: &str,
     request: &UploadFileRequest,
     workspace_id: Option<&str>,
+) -> Result<FileMetadata, OpenRouterError> {
+    let part = multipart::Part::bytes(request.content.clone()).file_name(request.filename.clone());
+    post_file_part(
+        http_client,
+        base_url,
+        api_key,
+        part,
+        request.mime_type.as_deref(),
+        workspace_id,
+        false,
+    )
+    .await
+}
+
+/// Upload a file from a stream without buffering it (`POST /files`).
+pub async fn upload_file_stream(
+    base_url: &str,
+    api_key: &str,
+    upload: StreamingUpload,
+    workspace_id: Option<&str>,
+) -> Result<FileMetadata, OpenRouterError> {
+    let http_client = crate::transport::default_client()?;
+    upload_file_stream_with_client(&http_client, base_url, api_key, upload, workspace_id).await
+}
+
+pub(crate) async fn upload_file_stream_with_client(
+    http_client: &HttpClient,
+    base_url: &str,
+    api_key: &str,
+    upload: StreamingUpload,
+    workspace_id: Option<&str>,
+) -> Result<FileMetadata, OpenRouterError> {
+    let StreamingUpload {
+        filename,
+        mime_type,
+        length,
+        body,
+        progress,
+    } = upload;
+    let body = reqwest::Body::wrap_stream(with_progress(body, length, progress));
+    let part = match length {
+        Some(length) => multipart::Part::stream_with_length(body, length),
+        None => multipart::Part::stream(body),
+    }
+    .file_name(filename);
+    post_file_part(
+        http_client,
+        base_url,
+        api_key,
+        part,
+        mime_type.as_deref(),
+        workspace_id,
+        true,
+    )
+    .await
+}
+
+async fn post_file_part(
+    http_client: &HttpClient,
+    base_url: &str,
+    api_key: &str,
+    mut part: multipart::Part,
+    mime_type: Option<&str>,
+    workspace_id: Option<&str>,
+    streamed: bool,
 ) -> Result<FileMetadata, OpenRouterError> {
     let url = format!("{base_url}/files");
-    let mut part =
-        multipart::Part::bytes(request.content.clone()).file_name(request.filename.clone());
-    if let Some(mime_type) = &request.mime_type {
+    if let Some(mime_type) = mime_type {
         part = part
             .mime_str(mime_type)
             .map_err(|error| OpenRouterError::ConfigError(error.to_string()))?;
@@ -175,10 +479,12 @@ pub(crate) async fn upload_file_with_client(
     let form = multipart::Form::new().part("file", part);
     let req =
         transport_request::with_bearer_auth(transport_request::post(http_client, &url), api_key);
-    let response = apply_workspace_query(req, workspace_id)
-        .multipart(form)
-        .send()
-        .await?;
+    let req = apply_workspace_query(req, workspace_id).multipart(form);
+    let response = if streamed {
+        req.send_upload().await?
+    } else {
+        req.send().await?
+    };
 
     if response.status().is_success() {
         transport_response::parse_json_response(response, "file upload").await
@@ -195,7 +501,7 @@ pub async fn get_file_metadata(
     file_id: &str,
     workspace_id: Option<&str>,
 ) -> Result<FileMetadata, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_file_metadata_with_client(&http_client, base_url, api_key, file_id, workspace_id).await
 }
 
@@ -227,7 +533,7 @@ pub async fn download_file_content(
     file_id: &str,
     workspace_id: Option<&str>,
 ) -> Result<Vec<u8>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     download_file_content_with_client(&http_client, base_url, api_key, file_id, workspace_id).await
 }
 
@@ -245,7 +551,41 @@ pub(crate) async fn download_file_content_with_client(
     let response = apply_workspace_query(req, workspace_id).send().await?;
 
     if response.status().is_success() {
-        Ok(response.bytes().await?.to_vec())
+        Ok(transport_response::read_bytes(response).await?.to_vec())
+    } else {
+        transport_response::handle_error(response).await?;
+        unreachable!()
+    }
+}
+
+/// Stream raw file content (`GET /files/{file_id}/content`) without buffering it.
+pub async fn download_file_stream(
+    base_url: &str,
+    api_key: &str,
+    file_id: &str,
+    workspace_id: Option<&str>,
+) -> Result<FileDownload, OpenRouterError> {
+    let http_client = crate::transport::default_client()?;
+    download_file_stream_with_client(&http_client, base_url, api_key, file_id, workspace_id).await
+}
+
+pub(crate) async fn download_file_stream_with_client(
+    http_client: &HttpClient,
+    base_url: &str,
+    api_key: &str,
+    file_id: &str,
+    workspace_id: Option<&str>,
+) -> Result<FileDownload, OpenRouterError> {
+    let encoded_id = encode(file_id);
+    let url = format!("{base_url}/files/{encoded_id}/content");
+    let req =
+        transport_request::with_bearer_auth(transport_request::get(http_client, &url), api_key);
+    let response = apply_workspace_query(req, workspace_id)
+        .send_streaming()
+        .await?;
+
+    if response.status().is_success() {
+        Ok(FileDownload::new(response))
     } else {
         transport_response::handle_error(response).await?;
         unreachable!()
@@ -259,7 +599,7 @@ pub async fn delete_file(
     file_id: &str,
     workspace_id: Option<&str>,
 ) -> Result<FileDeleteResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     delete_file_with_client(&http_client, base_url, api_key, file_id, workspace_id).await
 }
 
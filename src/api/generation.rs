@@ -1,12 +1,11 @@
 use std::collections::HashMap;
 
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::ApiResponse,
 };
 
@@ -75,7 +74,7 @@ pub async fn get_generation(
     api_key: &str,
     id: impl Into<String>,
 ) -> Result<GenerationData, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_generation_with_client(&http_client, base_url, api_key, id).await
 }
 
@@ -109,7 +108,7 @@ pub async fn get_generation_content(
     api_key: &str,
     id: impl Into<String>,
 ) -> Result<GenerationContentData, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_generation_content_with_client(&http_client, base_url, api_key, id).await
 }
 
@@ -1,12 +1,11 @@
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
 use urlencoding::encode;
 
 use crate::{
     error::OpenRouterError,
     strip_option_vec_setter,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, PaginationOptions},
 };
 
@@ -495,7 +494,7 @@ pub async fn list_guardrails(
     management_key: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<GuardrailListResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_guardrails_in_workspace_with_client(
         &http_client,
         base_url,
@@ -528,7 +527,7 @@ pub async fn list_guardrails_in_workspace(
     pagination: Option<PaginationOptions>,
     workspace_id: Option<&str>,
 ) -> Result<GuardrailListResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_guardrails_in_workspace_with_client(
         &http_client,
         base_url,
@@ -576,7 +575,7 @@ pub async fn create_guardrail(
     management_key: &str,
     request: &CreateGuardrailRequest,
 ) -> Result<Guardrail, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_guardrail_with_client(&http_client, base_url, management_key, request).await
 }
 
@@ -610,7 +609,7 @@ pub async fn get_guardrail(
     management_key: &str,
     id: &str,
 ) -> Result<Guardrail, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_guardrail_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -644,7 +643,7 @@ pub async fn update_guardrail(
     id: &str,
     request: &UpdateGuardrailRequest,
 ) -> Result<Guardrail, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     update_guardrail_with_client(&http_client, base_url, management_key, id, request).await
 }
 
@@ -679,7 +678,7 @@ pub async fn delete_guardrail(
     management_key: &str,
     id: &str,
 ) -> Result<bool, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     delete_guardrail_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -713,7 +712,7 @@ pub async fn list_guardrail_key_assignments(
     id: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<GuardrailKeyAssignmentsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_guardrail_key_assignments_with_client(
         &http_client,
         base_url,
@@ -756,7 +755,7 @@ pub async fn bulk_assign_keys_to_guardrail(
     id: &str,
     request: &BulkKeyAssignmentRequest,
 ) -> Result<AssignedCountResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     bulk_assign_keys_to_guardrail_with_client(&http_client, base_url, management_key, id, request)
         .await
 }
@@ -791,7 +790,7 @@ pub async fn bulk_unassign_keys_from_guardrail(
     id: &str,
     request: &BulkKeyAssignmentRequest,
 ) -> Result<UnassignedCountResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     bulk_unassign_keys_from_guardrail_with_client(
         &http_client,
         base_url,
@@ -835,7 +834,7 @@ pub async fn list_guardrail_member_assignments(
     id: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<GuardrailMemberAssignmentsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_guardrail_member_assignments_with_client(
         &http_client,
         base_url,
@@ -878,7 +877,7 @@ pub async fn bulk_assign_members_to_guardrail(
     id: &str,
     request: &BulkMemberAssignmentRequest,
 ) -> Result<AssignedCountResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     bulk_assign_members_to_guardrail_with_client(
         &http_client,
         base_url,
@@ -919,7 +918,7 @@ pub async fn bulk_unassign_members_from_guardrail(
     id: &str,
     request: &BulkMemberAssignmentRequest,
 ) -> Result<UnassignedCountResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     bulk_unassign_members_from_guardrail_with_client(
         &http_client,
         base_url,
@@ -962,7 +961,7 @@ pub async fn list_key_assignments(
     management_key: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<GuardrailKeyAssignmentsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_key_assignments_with_client(&http_client, base_url, management_key, pagination).await
 }
 
@@ -996,7 +995,7 @@ pub async fn list_member_assignments(
     management_key: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<GuardrailMemberAssignmentsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_member_assignments_with_client(&http_client, base_url, management_key, pagination).await
 }
 
@@ -5,7 +5,7 @@ use futures_util::{
     StreamExt,
     stream::{self, BoxStream},
 };
-use reqwest::{Client as HttpClient, header::CONTENT_TYPE};
+use reqwest::header::CONTENT_TYPE;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use urlencoding::encode;
@@ -14,7 +14,8 @@ use crate::{
     error::OpenRouterError,
     strip_option_vec_setter,
     transport::{
-        request as transport_request, response as transport_response, sse::response_lines,
+        HttpClient, request as transport_request, response as transport_response,
+        sse::response_lines,
     },
     utils::parse_sse_frames,
 };
@@ -335,7 +336,7 @@ pub async fn create_image_generation(
     app_categories: &Option<Vec<String>>,
     request: &ImageGenerationRequest,
 ) -> Result<ImageGenerationResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_image_generation_with_client(
         &http_client,
         base_url,
@@ -387,7 +388,7 @@ pub async fn stream_image_generation(
     app_categories: &Option<Vec<String>>,
     request: &ImageGenerationRequest,
 ) -> Result<BoxStream<'static, Result<ImageStreamingResponse, OpenRouterError>>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     stream_image_generation_with_client(
         &http_client,
         base_url,
@@ -419,7 +420,7 @@ pub(crate) async fn stream_image_generation_with_client(
         app_categories,
     )?
     .json(&request)
-    .send()
+    .send_streaming()
     .await?;
 
     if response.status().is_success() {
@@ -492,7 +493,7 @@ pub async fn list_image_models(
     base_url: &str,
     api_key: &str,
 ) -> Result<Vec<ImageModel>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_image_models_with_client(&http_client, base_url, api_key).await
 }
 
@@ -524,7 +525,7 @@ pub async fn list_image_model_endpoints(
     author: &str,
     slug: &str,
 ) -> Result<ImageModelEndpointsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_image_model_endpoints_with_client(&http_client, base_url, api_key, author, slug).await
 }
 
@@ -1,13 +1,12 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::OpenRouterError,
     strip_option_map_setter, strip_option_vec_setter,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{
         ProviderPreferences, ReasoningConfig, ResponseFormat, completion::CompletionsResponse,
     },
@@ -141,7 +140,7 @@ pub async fn send_completion_request(
     app_categories: &Option<Vec<String>>,
     request: &CompletionRequest,
 ) -> Result<CompletionsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     send_completion_request_with_client(
         &http_client,
         base_url,
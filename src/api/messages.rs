@@ -2,7 +2,6 @@ use std::collections::HashMap;
 
 use derive_builder::Builder;
 use futures_util::{StreamExt, stream::BoxStream};
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
 
@@ -11,9 +10,10 @@ use crate::{
     error::OpenRouterError,
     strip_option_vec_setter,
     transport::{
-        request as transport_request, response as transport_response, sse::response_lines,
+        HttpClient, request as transport_request, response as transport_response,
+        sse::response_lines,
     },
-    types::{OpenRouterExperimentalMetadata, ProviderPreferences},
+    types::{OpenRouterExperimentalMetadata, ProviderPreferences, Tool, stream::index_slot},
     utils::parse_sse_frames,
 };
 
@@ -255,6 +255,18 @@ impl AnthropicContentPart {
             cache_control: None,
         }
     }
+
+    pub fn tool_error(
+        tool_use_id: impl Into<String>,
+        content: impl Into<AnthropicMessageContent>,
+    ) -> Self {
+        Self::ToolResult {
+            tool_use_id: tool_use_id.into(),
+            content: Some(content.into()),
+            is_error: Some(true),
+            cache_control: None,
+        }
+    }
 }
 
 /// A user/assistant message in Anthropic format.
@@ -362,6 +374,17 @@ impl AnthropicTool {
     }
 }
 
+impl From<Tool> for AnthropicTool {
+    /// Convert a chat function tool (e.g. from `TypedTool::create_tool`) into a custom tool.
+    fn from(tool: Tool) -> Self {
+        Self::custom(
+            tool.function.name,
+            tool.function.description,
+            tool.function.parameters,
+        )
+    }
+}
+
 /// Tool choice policy for Anthropic-compatible messages.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[non_exhaustive]
@@ -610,9 +633,43 @@ impl AnthropicMessagesRequest {
         req
     }
 
+    pub(crate) fn with_model(&self, model: &str) -> Self {
+        let mut req = self.clone();
+        req.model = model.to_string();
+        req
+    }
+
+    pub(crate) fn with_messages(&self, messages: Vec<AnthropicMessage>) -> Self {
+        let mut req = self.clone();
+        req.messages = messages;
+        req
+    }
+
+    /// Copy of this request with any of `tools` not already declared appended.
+    pub(crate) fn with_missing_tools(&self, tools: &[AnthropicTool]) -> Self {
+        let mut req = self.clone();
+        if tools.is_empty() {
+            return req;
+        }
+        let declared = req.tools.get_or_insert_with(Vec::new);
+        for tool in tools {
+            if !declared.iter().any(|existing| existing.name == tool.name) {
+                declared.push(tool.clone());
+            }
+        }
+        req
+    }
+
     pub fn experimental_metadata(&self) -> Option<OpenRouterExperimentalMetadata> {
         self.experimental_metadata
     }
+
+    pub(crate) fn set_experimental_metadata(
+        &mut self,
+        experimental_metadata: Option<OpenRouterExperimentalMetadata>,
+    ) {
+        self.experimental_metadata = experimental_metadata;
+    }
 }
 
 /// Usage object in Anthropic messages response.
@@ -715,6 +772,196 @@ pub struct AnthropicMessagesSseEvent {
     pub data: AnthropicMessagesStreamEvent,
 }
 
+/// Rebuilds the final [`AnthropicMessagesResponse`] from a Messages API
+/// event stream.
+///
+/// Text, thinking, signature, citation, and `input_json_delta` fragments are
+/// merged into their content blocks, and `message_delta` usage is merged
+/// over the `message_start` usage.
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use openrouter_rs::api::messages::{AnthropicMessagesRequest, AnthropicMessagesStreamAccumulator};
+///
+/// # async fn example(client: openrouter_rs::OpenRouterClient, request: AnthropicMessagesRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = client.messages().stream(&request).await?;
+/// let mut accumulator = AnthropicMessagesStreamAccumulator::new();
+/// while let Some(event) = stream.next().await {
+///     accumulator.push(&event?);
+/// }
+/// let message = accumulator.finish();
+/// println!("{:?}", message.stop_reason);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AnthropicMessagesStreamAccumulator {
+    response: AnthropicMessagesResponse,
+    content: Vec<Option<AnthropicContentPart>>,
+    /// `input_json_delta` fragments for tool use blocks, by content block index.
+    partial_json: HashMap<usize, String>,
+}
+
+impl AnthropicMessagesStreamAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply one event.
+    pub fn push(&mut self, event: &AnthropicMessagesSseEvent) {
+        match &event.data {
+            AnthropicMessagesStreamEvent::MessageStart { message } => {
+                let mut message = message.as_ref().clone();
+                let content = std::mem::take(&mut message.content);
+                if !content.is_empty() {
+                    self.content = content.into_iter().map(Some).collect();
+                }
+                self.response = message;
+            }
+            AnthropicMessagesStreamEvent::ContentBlockStart {
+                index,
+                content_block,
+            } => {
+                if let Some(slot) = self.slot(*index as usize) {
+                    *slot = Some(content_block.as_ref().clone());
+                }
+            }
+            AnthropicMessagesStreamEvent::ContentBlockDelta { index, delta } => {
+                self.apply_block_delta(*index as usize, delta);
+            }
+            AnthropicMessagesStreamEvent::ContentBlockStop { index } => {
+                self.close_block(*index as usize);
+            }
+            AnthropicMessagesStreamEvent::MessageDelta { delta, usage } => {
+                if let Some(reason) = delta.get("stop_reason").and_then(Value::as_str) {
+                    self.response.stop_reason = Some(reason.to_string());
+                }
+                if let Some(sequence) = delta.get("stop_sequence").and_then(Value::as_str) {
+                    self.response.stop_sequence = Some(sequence.to_string());
+                }
+                self.merge_usage(usage);
+            }
+            AnthropicMessagesStreamEvent::MessageStop {
+                openrouter_metadata,
+                ..
+            } => {
+                if let Some(metadata) = openrouter_metadata {
+                    self.response
+                        .extra
+                        .insert("openrouter_metadata".to_string(), metadata.clone());
+                }
+            }
+            AnthropicMessagesStreamEvent::Error { error } => {
+                self.response
+                    .extra
+                    .insert("error".to_string(), error.clone());
+            }
+            AnthropicMessagesStreamEvent::Ping => {}
+        }
+    }
+
+    /// The message assembled so far.
+    pub fn finish(mut self) -> AnthropicMessagesResponse {
+        let open: Vec<usize> = self.partial_json.keys().copied().collect();
+        for index in open {
+            self.close_block(index);
+        }
+        let mut response = self.response;
+        response.content = self.content.into_iter().flatten().collect();
+        response
+    }
+
+    fn slot(&mut self, index: usize) -> Option<&mut Option<AnthropicContentPart>> {
+        index_slot(&mut self.content, index, || None)
+    }
+
+    fn apply_block_delta(&mut self, index: usize, delta: &Value) {
+        let field = |key: &str| delta.get(key).and_then(Value::as_str);
+        match (delta.get("type").and_then(Value::as_str), self.slot(index)) {
+            (Some("input_json_delta"), _) => {
+                if let Some(partial_json) = field("partial_json") {
+                    self.partial_json
+                        .entry(index)
+                        .or_default()
+                        .push_str(partial_json);
+                }
+            }
+            (Some("text_delta"), Some(Some(AnthropicContentPart::Text { text, .. }))) => {
+                text.push_str(field("text").unwrap_or_default());
+            }
+            (Some("text_delta"), Some(slot)) => {
+                *slot = Some(AnthropicContentPart::text(
+                    field("text").unwrap_or_default(),
+                ));
+            }
+            (Some("citations_delta"), Some(Some(AnthropicContentPart::Text { citations, .. }))) => {
+                if let Some(citation) = delta.get("citation") {
+                    citations
+                        .get_or_insert_with(Vec::new)
+                        .push(citation.clone());
+                }
+            }
+            (
+                Some("thinking_delta"),
+                Some(Some(AnthropicContentPart::Thinking { thinking, .. })),
+            ) => {
+                thinking.push_str(field("thinking").unwrap_or_default());
+            }
+            (
+                Some("signature_delta"),
+                Some(Some(AnthropicContentPart::Thinking { signature, .. })),
+            ) => {
+                signature.push_str(field("signature").unwrap_or_default());
+            }
+            _ => {}
+        }
+    }
+
+    /// Parse a tool use block's streamed JSON into its `input`.
+    fn close_block(&mut self, index: usize) {
+        let Some(partial_json) = self.partial_json.remove(&index) else {
+            return;
+        };
+        if partial_json.is_empty() {
+            return;
+        }
+        let parsed = serde_json::from_str(&partial_json).unwrap_or(Value::String(partial_json));
+        if let Some(Some(
+            AnthropicContentPart::ToolUse { input, .. }
+            | AnthropicContentPart::ServerToolUse { input, .. },
+        )) = self.slot(index)
+        {
+            *input = Some(parsed);
+        }
+    }
+
+    /// Merge `message_delta` usage over the usage seen so far.
+    fn merge_usage(&mut self, usage: &Value) {
+        let Value::Object(update) = usage else {
+            return;
+        };
+        let mut merged = self
+            .response
+            .usage
+            .as_ref()
+            .and_then(|usage| serde_json::to_value(usage).ok())
+            .and_then(|usage| match usage {
+                Value::Object(map) => Some(map),
+                _ => None,
+            })
+            .unwrap_or_default();
+        merged.extend(
+            update
+                .iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        if let Ok(usage) = serde_json::from_value(Value::Object(merged)) {
+            self.response.usage = Some(usage);
+        }
+    }
+}
+
 /// Send a non-streaming request to the Anthropic-compatible Messages API.
 pub async fn create_message(
     base_url: &str,
@@ -724,7 +971,7 @@ pub async fn create_message(
     app_categories: &Option<Vec<String>>,
     request: &AnthropicMessagesRequest,
 ) -> Result<AnthropicMessagesResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_message_with_client(
         &http_client,
         base_url,
@@ -783,7 +1030,7 @@ pub async fn stream_messages(
     request: &AnthropicMessagesRequest,
 ) -> Result<BoxStream<'static, Result<AnthropicMessagesSseEvent, OpenRouterError>>, OpenRouterError>
 {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     stream_messages_with_client(
         &http_client,
         base_url,
@@ -820,7 +1067,7 @@ pub(crate) async fn stream_messages_with_client(
         &request.experimental_metadata,
     )
     .json(&request)
-    .send()
+    .send_streaming()
     .await?;
 
     if response.status().is_success() {
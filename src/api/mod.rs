@@ -62,17 +62,19 @@
 //!
 //! ### Responses
 //! ```no_run
-//! use openrouter_rs::{OpenRouterClient, api::responses::ResponsesRequest};
-//! use serde_json::json;
+//! use openrouter_rs::{
+//!     OpenRouterClient,
+//!     api::responses::{ResponsesInputItem, ResponsesRequest},
+//! };
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 //! let client = OpenRouterClient::builder().api_key("your_key").build()?;
 //! let request = ResponsesRequest::builder()
 //!     .model("openai/gpt-5")
-//!     .input(json!([{ "role": "user", "content": "Say hello." }]))
+//!     .input(vec![ResponsesInputItem::user("Say hello.")].into())
 //!     .build()?;
 //! let response = client.responses().create(&request).await?;
-//! println!("{:?}", response.id);
+//! println!("{:?}", response.output_text());
 //! # Ok(())
 //! # }
 //! ```
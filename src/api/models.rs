@@ -1,14 +1,13 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use urlencoding::encode;
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, Effort, ModelCategory, SupportedParameters},
 };
 
@@ -287,7 +286,7 @@ pub async fn list_models(
     category: Option<ModelCategory>,
     supported_parameters: Option<SupportedParameters>,
 ) -> Result<Vec<Model>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     let params = ListModelsParams {
         category,
         supported_parameters,
@@ -317,7 +316,7 @@ pub async fn list_models_with_params(
     api_key: &str,
     params: Option<&ListModelsParams>,
 ) -> Result<Vec<Model>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_models_with_params_and_client(&http_client, base_url, api_key, params).await
 }
 
@@ -352,7 +351,7 @@ pub async fn get_model(
     author: &str,
     slug: &str,
 ) -> Result<Model, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_model_with_client(&http_client, base_url, api_key, author, slug).await
 }
 
@@ -400,7 +399,7 @@ pub async fn list_model_endpoints(
     author: &str,
     slug: &str,
 ) -> Result<EndpointData, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_model_endpoints_with_client(&http_client, base_url, api_key, author, slug).await
 }
 
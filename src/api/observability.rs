@@ -1,5 +1,4 @@
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
 use serde_json::Value;
 use urlencoding::encode;
@@ -7,7 +6,7 @@ use urlencoding::encode;
 use crate::{
     error::OpenRouterError,
     strip_option_vec_setter,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, PaginationOptions},
 };
 
@@ -259,7 +258,7 @@ pub async fn list_observability_destinations(
     pagination: Option<PaginationOptions>,
     workspace_id: Option<&str>,
 ) -> Result<ObservabilityDestinationListResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_observability_destinations_with_client(
         &http_client,
         base_url,
@@ -308,7 +307,7 @@ pub async fn create_observability_destination(
     management_key: &str,
     request: &CreateObservabilityDestinationRequest,
 ) -> Result<ObservabilityDestination, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_observability_destination_with_client(&http_client, base_url, management_key, request)
         .await
 }
@@ -345,7 +344,7 @@ pub async fn get_observability_destination(
     management_key: &str,
     id: &str,
 ) -> Result<ObservabilityDestination, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_observability_destination_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -381,7 +380,7 @@ pub async fn update_observability_destination(
     id: &str,
     request: &UpdateObservabilityDestinationRequest,
 ) -> Result<ObservabilityDestination, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     update_observability_destination_with_client(
         &http_client,
         base_url,
@@ -425,7 +424,7 @@ pub async fn delete_observability_destination(
     management_key: &str,
     id: &str,
 ) -> Result<bool, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     delete_observability_destination_with_client(&http_client, base_url, management_key, id).await
 }
 
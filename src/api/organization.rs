@@ -1,9 +1,8 @@
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::PaginationOptions,
 };
 
@@ -49,7 +48,7 @@ pub async fn list_organization_members(
     management_key: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<OrganizationMembersResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_organization_members_with_client(&http_client, base_url, management_key, pagination).await
 }
 
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use urlencoding::encode;
@@ -11,7 +10,7 @@ use crate::{
         responses::ResponsesRequest,
     },
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, PaginationOptions},
 };
 
@@ -131,7 +130,7 @@ pub async fn list_presets(
     management_key: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<ListPresetsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_presets_with_client(&http_client, base_url, management_key, pagination).await
 }
 
@@ -163,7 +162,7 @@ pub async fn get_preset(
     management_key: &str,
     slug: &str,
 ) -> Result<PresetWithDesignatedVersion, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_preset_with_client(&http_client, base_url, management_key, slug).await
 }
 
@@ -199,7 +198,7 @@ pub async fn list_preset_versions(
     slug: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<ListPresetVersionsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_preset_versions_with_client(&http_client, base_url, management_key, slug, pagination).await
 }
 
@@ -237,7 +236,7 @@ pub async fn get_preset_version(
     slug: &str,
     version: &str,
 ) -> Result<PresetDesignatedVersion, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_preset_version_with_client(&http_client, base_url, management_key, slug, version).await
 }
 
@@ -303,7 +302,7 @@ pub async fn create_chat_completion_preset(
     slug: &str,
     request: &ChatCompletionRequest,
 ) -> Result<PresetWithDesignatedVersion, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_chat_completion_preset_with_client(&http_client, base_url, management_key, slug, request)
         .await
 }
@@ -334,7 +333,7 @@ pub async fn create_response_preset(
     slug: &str,
     request: &ResponsesRequest,
 ) -> Result<PresetWithDesignatedVersion, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_response_preset_with_client(&http_client, base_url, management_key, slug, request).await
 }
 
@@ -364,7 +363,7 @@ pub async fn create_message_preset(
     slug: &str,
     request: &AnthropicMessagesRequest,
 ) -> Result<PresetWithDesignatedVersion, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_message_preset_with_client(&http_client, base_url, management_key, slug, request).await
 }
 
@@ -1,10 +1,9 @@
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::ProviderPreferences,
 };
 
@@ -141,7 +140,7 @@ pub async fn create_rerank(
     app_categories: &Option<Vec<String>>,
     request: &RerankRequest,
 ) -> Result<RerankResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_rerank_with_client(
         &http_client,
         base_url,
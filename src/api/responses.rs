@@ -2,8 +2,7 @@ use std::collections::HashMap;
 
 use derive_builder::Builder;
 use futures_util::{StreamExt, stream::BoxStream};
-use reqwest::Client as HttpClient;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::Value;
 
 use crate::{
@@ -11,12 +10,735 @@ use crate::{
     error::OpenRouterError,
     strip_option_map_setter, strip_option_vec_setter,
     transport::{
-        request as transport_request, response as transport_response, sse::response_lines,
+        HttpClient, request as transport_request, response as transport_response,
+        sse::response_lines,
+    },
+    types::{
+        Effort, OpenRouterExperimentalMetadata, ProviderPreferences, Role, Tool,
+        response_format::JsonSchemaConfig, stream::index_slot,
     },
-    types::{OpenRouterExperimentalMetadata, ProviderPreferences},
     utils::parse_sse_frames,
 };
 
+/// Parse `value` as `T` when doing so round-trips without dropping anything.
+fn typed_or_raw<T: Serialize + DeserializeOwned>(value: &Value) -> Option<T> {
+    let typed: T = serde_json::from_value(value.clone()).ok()?;
+    (serde_json::to_value(&typed).ok()? == *value).then_some(typed)
+}
+
+/// `input` of a Responses API request: a plain prompt or a list of items.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum ResponsesInput {
+    Text(String),
+    Items(Vec<ResponsesInputItem>),
+}
+
+impl From<String> for ResponsesInput {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ResponsesInput {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<Vec<ResponsesInputItem>> for ResponsesInput {
+    fn from(value: Vec<ResponsesInputItem>) -> Self {
+        Self::Items(value)
+    }
+}
+
+impl From<Value> for ResponsesInput {
+    /// Raw JSON input. Items that don't map onto a typed variant without loss
+    /// are kept as [`ResponsesInputItem::Other`].
+    fn from(value: Value) -> Self {
+        match value {
+            Value::String(text) => Self::Text(text),
+            Value::Array(items) => Self::Items(items.into_iter().map(Into::into).collect()),
+            other => Self::Items(vec![other.into()]),
+        }
+    }
+}
+
+/// One item of a Responses API `input` list.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponsesInputItem {
+    Message(ResponsesMessage),
+    FunctionCall(ResponsesFunctionCall),
+    FunctionCallOutput(ResponsesFunctionCallOutput),
+    Reasoning(ResponsesReasoning),
+    /// Any other item, sent as-is.
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl ResponsesInputItem {
+    pub fn message(role: Role, content: impl Into<ResponsesMessageContent>) -> Self {
+        Self::Message(ResponsesMessage::new(role, content))
+    }
+
+    pub fn user(content: impl Into<ResponsesMessageContent>) -> Self {
+        Self::message(Role::User, content)
+    }
+
+    pub fn assistant(content: impl Into<ResponsesMessageContent>) -> Self {
+        Self::message(Role::Assistant, content)
+    }
+
+    pub fn system(content: impl Into<ResponsesMessageContent>) -> Self {
+        Self::message(Role::System, content)
+    }
+
+    pub fn developer(content: impl Into<ResponsesMessageContent>) -> Self {
+        Self::message(Role::Developer, content)
+    }
+
+    pub fn function_call(
+        call_id: impl Into<String>,
+        name: impl Into<String>,
+        arguments: impl Into<String>,
+    ) -> Self {
+        Self::FunctionCall(ResponsesFunctionCall::new(call_id, name, arguments))
+    }
+
+    pub fn function_call_output(call_id: impl Into<String>, output: impl Into<String>) -> Self {
+        Self::FunctionCallOutput(ResponsesFunctionCallOutput::new(call_id, output))
+    }
+}
+
+impl From<Value> for ResponsesInputItem {
+    /// Raw JSON item. Messages may omit `type`; anything that doesn't map onto
+    /// a typed variant without loss becomes [`ResponsesInputItem::Other`].
+    fn from(value: Value) -> Self {
+        let mut typed = value.clone();
+        if let Value::Object(map) = &mut typed {
+            if !map.contains_key("type") && map.contains_key("role") {
+                map.insert("type".to_string(), Value::from("message"));
+            }
+        }
+        match typed_or_raw::<Self>(&typed) {
+            Some(Self::Other(_)) | None => Self::Other(value),
+            Some(item) => item,
+        }
+    }
+}
+
+impl From<ResponsesMessage> for ResponsesInputItem {
+    fn from(value: ResponsesMessage) -> Self {
+        Self::Message(value)
+    }
+}
+
+impl From<ResponsesOutputItem> for ResponsesInputItem {
+    /// Feed an output item back as input on the next turn.
+    fn from(value: ResponsesOutputItem) -> Self {
+        match value {
+            ResponsesOutputItem::Message(message) => Self::Message(message),
+            ResponsesOutputItem::FunctionCall(call) => Self::FunctionCall(call),
+            ResponsesOutputItem::Reasoning(reasoning) => Self::Reasoning(reasoning),
+            other => Self::Other(serde_json::to_value(other).unwrap_or(Value::Null)),
+        }
+    }
+}
+
+/// A `message` item, used both in `input` and in `output`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponsesMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub role: Role,
+    pub content: ResponsesMessageContent,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl ResponsesMessage {
+    pub fn new(role: Role, content: impl Into<ResponsesMessageContent>) -> Self {
+        Self {
+            id: None,
+            role,
+            content: content.into(),
+            status: None,
+        }
+    }
+
+    /// Concatenated text of the message (`input_text` and `output_text` parts).
+    pub fn text(&self) -> String {
+        match &self.content {
+            ResponsesMessageContent::Text(text) => text.clone(),
+            ResponsesMessageContent::Parts(parts) => parts
+                .iter()
+                .filter_map(ResponsesContentPart::text)
+                .collect(),
+        }
+    }
+}
+
+/// Message content: a plain string or a list of typed parts.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum ResponsesMessageContent {
+    Text(String),
+    Parts(Vec<ResponsesContentPart>),
+}
+
+impl From<String> for ResponsesMessageContent {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for ResponsesMessageContent {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<Vec<ResponsesContentPart>> for ResponsesMessageContent {
+    fn from(value: Vec<ResponsesContentPart>) -> Self {
+        Self::Parts(value)
+    }
+}
+
+/// Content part of a Responses API message.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponsesContentPart {
+    InputText {
+        text: String,
+    },
+    InputImage {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        image_url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        detail: Option<String>,
+    },
+    InputFile {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_data: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        file_url: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+    },
+    OutputText {
+        text: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        annotations: Vec<Value>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        logprobs: Option<Vec<Value>>,
+    },
+    Refusal {
+        refusal: String,
+    },
+    /// Any other part, sent as-is.
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl ResponsesContentPart {
+    pub fn input_text(text: impl Into<String>) -> Self {
+        Self::InputText { text: text.into() }
+    }
+
+    pub fn output_text(text: impl Into<String>) -> Self {
+        Self::OutputText {
+            text: text.into(),
+            annotations: Vec::new(),
+            logprobs: None,
+        }
+    }
+
+    /// Image by URL (or `data:` URL).
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::InputImage {
+            image_url: Some(url.into()),
+            file_id: None,
+            detail: None,
+        }
+    }
+
+    pub fn image_url_with_detail(url: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self::InputImage {
+            image_url: Some(url.into()),
+            file_id: None,
+            detail: Some(detail.into()),
+        }
+    }
+
+    pub fn image_file_id(file_id: impl Into<String>) -> Self {
+        Self::InputImage {
+            image_url: None,
+            file_id: Some(file_id.into()),
+            detail: None,
+        }
+    }
+
+    pub fn file_id(file_id: impl Into<String>) -> Self {
+        Self::InputFile {
+            file_id: Some(file_id.into()),
+            file_data: None,
+            file_url: None,
+            filename: None,
+        }
+    }
+
+    pub fn file_url(file_url: impl Into<String>) -> Self {
+        Self::InputFile {
+            file_id: None,
+            file_data: None,
+            file_url: Some(file_url.into()),
+            filename: None,
+        }
+    }
+
+    /// Inline file as a base64 `data:` URL.
+    pub fn file_data(filename: impl Into<String>, file_data: impl Into<String>) -> Self {
+        Self::InputFile {
+            file_id: None,
+            file_data: Some(file_data.into()),
+            file_url: None,
+            filename: Some(filename.into()),
+        }
+    }
+
+    /// Text of `input_text` and `output_text` parts.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::InputText { text } | Self::OutputText { text, .. } => Some(text),
+            _ => None,
+        }
+    }
+}
+
+/// A `function_call` item: the model asking for a function to be run.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponsesFunctionCall {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub call_id: String,
+    pub name: String,
+    /// JSON-encoded arguments.
+    pub arguments: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl ResponsesFunctionCall {
+    pub fn new(
+        call_id: impl Into<String>,
+        name: impl Into<String>,
+        arguments: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: None,
+            call_id: call_id.into(),
+            name: name.into(),
+            arguments: arguments.into(),
+            status: None,
+        }
+    }
+}
+
+/// A `function_call_output` item: the result of a function call.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponsesFunctionCallOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub call_id: String,
+    pub output: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl ResponsesFunctionCallOutput {
+    pub fn new(call_id: impl Into<String>, output: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            call_id: call_id.into(),
+            output: output.into(),
+            status: None,
+        }
+    }
+}
+
+/// A `reasoning` item. Pass it back unchanged to continue a reasoning chain.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ResponsesReasoning {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub summary: Vec<ResponsesReasoningPart>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<Vec<ResponsesReasoningPart>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl ResponsesReasoning {
+    /// Reasoning text, falling back to the summary when no full text is given.
+    pub fn text(&self) -> String {
+        match &self.content {
+            Some(content) if !content.is_empty() => content
+                .iter()
+                .filter_map(ResponsesReasoningPart::text)
+                .collect(),
+            _ => self
+                .summary
+                .iter()
+                .filter_map(ResponsesReasoningPart::text)
+                .collect(),
+        }
+    }
+}
+
+/// Summary or content part of a `reasoning` item.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponsesReasoningPart {
+    SummaryText {
+        text: String,
+    },
+    ReasoningText {
+        text: String,
+    },
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl ResponsesReasoningPart {
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::SummaryText { text } | Self::ReasoningText { text } => Some(text),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+/// A `web_search_call` output item.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponsesWebSearchCall {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<Value>,
+}
+
+/// One item of `ResponsesResponse::output`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponsesOutputItem {
+    Message(ResponsesMessage),
+    FunctionCall(ResponsesFunctionCall),
+    Reasoning(ResponsesReasoning),
+    WebSearchCall(ResponsesWebSearchCall),
+    /// Any other item type, kept as raw JSON.
+    #[serde(untagged)]
+    Other(Value),
+}
+
+/// Tool definition for the Responses API.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponsesTool {
+    Function(ResponsesFunctionTool),
+    #[serde(alias = "web_search_preview")]
+    WebSearch(ResponsesWebSearchTool),
+    /// Any other tool, sent as-is.
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl ResponsesTool {
+    pub fn function(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        parameters: impl Into<Value>,
+    ) -> Self {
+        Self::Function(ResponsesFunctionTool {
+            name: name.into(),
+            description: Some(description.into()),
+            parameters: Some(parameters.into()),
+            strict: None,
+        })
+    }
+
+    pub fn web_search() -> Self {
+        Self::WebSearch(ResponsesWebSearchTool::default())
+    }
+}
+
+impl From<Tool> for ResponsesTool {
+    /// Convert a chat function tool (e.g. from `TypedTool::create_tool`) into a function tool.
+    fn from(tool: Tool) -> Self {
+        Self::Function(ResponsesFunctionTool {
+            name: tool.function.name,
+            description: Some(tool.function.description),
+            parameters: Some(tool.function.parameters),
+            strict: None,
+        })
+    }
+}
+
+impl From<Value> for ResponsesTool {
+    fn from(value: Value) -> Self {
+        typed_or_raw(&value).unwrap_or(Self::Other(value))
+    }
+}
+
+/// A `function` tool. Unlike chat tools, the fields are not nested under `function`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponsesFunctionTool {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+/// The hosted `web_search` tool.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ResponsesWebSearchTool {
+    /// `low`, `medium`, or `high`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_context_size: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_location: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<Value>,
+}
+
+/// `tool_choice` for the Responses API.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(untagged)]
+pub enum ResponsesToolChoice {
+    /// `"none"`, `"auto"`, or `"required"`.
+    String(String),
+    /// Force a specific tool, e.g. `{"type": "function", "name": "get_weather"}`.
+    Specific(ResponsesSpecificToolChoice),
+    /// Any other choice, sent as-is.
+    Other(Value),
+}
+
+impl ResponsesToolChoice {
+    pub fn none() -> Self {
+        Self::String("none".to_string())
+    }
+
+    pub fn auto() -> Self {
+        Self::String("auto".to_string())
+    }
+
+    pub fn required() -> Self {
+        Self::String("required".to_string())
+    }
+
+    pub fn force_tool(tool_name: impl Into<String>) -> Self {
+        Self::Specific(ResponsesSpecificToolChoice {
+            tool_type: "function".to_string(),
+            name: Some(tool_name.into()),
+        })
+    }
+}
+
+impl From<Value> for ResponsesToolChoice {
+    fn from(value: Value) -> Self {
+        typed_or_raw(&value).unwrap_or(Self::Other(value))
+    }
+}
+
+/// Specific tool choice for forcing a particular tool.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponsesSpecificToolChoice {
+    #[serde(rename = "type")]
+    pub tool_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// `text` options of a Responses API request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ResponsesTextConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<ResponsesTextFormat>,
+    /// `low`, `medium`, or `high`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbosity: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl ResponsesTextConfig {
+    pub fn with_format(format: impl Into<ResponsesTextFormat>) -> Self {
+        Self {
+            format: Some(format.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn verbosity(mut self, verbosity: impl Into<String>) -> Self {
+        self.verbosity = Some(verbosity.into());
+        self
+    }
+}
+
+impl From<ResponsesTextFormat> for ResponsesTextConfig {
+    fn from(value: ResponsesTextFormat) -> Self {
+        Self::with_format(value)
+    }
+}
+
+impl From<Value> for ResponsesTextConfig {
+    fn from(value: Value) -> Self {
+        match typed_or_raw(&value) {
+            Some(text) => text,
+            None => Self {
+                extra: match value {
+                    Value::Object(map) => map.into_iter().collect(),
+                    _ => HashMap::new(),
+                },
+                ..Self::default()
+            },
+        }
+    }
+}
+
+/// `text.format` of a Responses API request.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponsesTextFormat {
+    Text,
+    JsonObject,
+    /// Unlike chat `response_format`, the schema fields are not nested.
+    JsonSchema {
+        name: String,
+        schema: Value,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strict: Option<bool>,
+    },
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl ResponsesTextFormat {
+    pub fn json_schema(name: impl Into<String>, schema: impl Into<Value>) -> Self {
+        Self::JsonSchema {
+            name: name.into(),
+            schema: schema.into(),
+            description: None,
+            strict: None,
+        }
+    }
+}
+
+impl From<JsonSchemaConfig> for ResponsesTextFormat {
+    fn from(config: JsonSchemaConfig) -> Self {
+        Self::JsonSchema {
+            name: config.name,
+            schema: config.schema,
+            description: None,
+            strict: Some(config.strict),
+        }
+    }
+}
+
+/// `reasoning` options of a Responses API request.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ResponsesReasoningConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effort: Option<Effort>,
+    /// `auto`, `concise`, or `detailed`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl ResponsesReasoningConfig {
+    pub fn with_effort(effort: Effort) -> Self {
+        Self {
+            effort: Some(effort),
+            ..Self::default()
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            enabled: Some(false),
+            ..Self::default()
+        }
+    }
+
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+}
+
+impl From<Value> for ResponsesReasoningConfig {
+    fn from(value: Value) -> Self {
+        match typed_or_raw(&value) {
+            Some(reasoning) => reasoning,
+            None => Self {
+                extra: match value {
+                    Value::Object(map) => map.into_iter().collect(),
+                    _ => HashMap::new(),
+                },
+                ..Self::default()
+            },
+        }
+    }
+}
+
 /// Request body for the OpenRouter Responses API (`POST /responses`).
 #[derive(Serialize, Deserialize, Debug, Clone, Builder)]
 #[builder(build_fn(error = "OpenRouterError"))]
@@ -24,7 +746,7 @@ use crate::{
 pub struct ResponsesRequest {
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    input: Option<Value>,
+    input: Option<ResponsesInput>,
 
     #[builder(setter(into, strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -36,11 +758,11 @@ pub struct ResponsesRequest {
 
     #[builder(setter(custom), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    tools: Option<Vec<Value>>,
+    tools: Option<Vec<ResponsesTool>>,
 
-    #[builder(setter(strip_option), default)]
+    #[builder(setter(into, strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    tool_choice: Option<Value>,
+    tool_choice: Option<ResponsesToolChoice>,
 
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,13 +776,13 @@ pub struct ResponsesRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     models: Option<Vec<String>>,
 
-    #[builder(setter(strip_option), default)]
+    #[builder(setter(into, strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    text: Option<Value>,
+    text: Option<ResponsesTextConfig>,
 
-    #[builder(setter(strip_option), default)]
+    #[builder(setter(into, strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    reasoning: Option<Value>,
+    reasoning: Option<ResponsesReasoningConfig>,
 
     #[builder(setter(strip_option), default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -181,7 +903,7 @@ pub struct ResponsesRequest {
 
 impl ResponsesRequestBuilder {
     strip_option_map_setter!(metadata, String, String);
-    strip_option_vec_setter!(tools, Value);
+    strip_option_vec_setter!(tools, ResponsesTool);
     strip_option_vec_setter!(models, String);
     strip_option_map_setter!(image_config, String, Value);
     strip_option_vec_setter!(modalities, String);
@@ -194,10 +916,10 @@ impl ResponsesRequest {
         ResponsesRequestBuilder::default()
     }
 
-    pub fn new(model: impl Into<String>, input: Value) -> Self {
+    pub fn new(model: impl Into<String>, input: impl Into<ResponsesInput>) -> Self {
         Self::builder()
             .model(model.into())
-            .input(input)
+            .input(input.into())
             .build()
             .expect("Failed to build ResponsesRequest")
     }
@@ -208,13 +930,47 @@ impl ResponsesRequest {
         req
     }
 
+    pub fn input(&self) -> Option<&ResponsesInput> {
+        self.input.as_ref()
+    }
+
+    pub fn tools(&self) -> Option<&[ResponsesTool]> {
+        self.tools.as_deref()
+    }
+
+    pub(crate) fn with_model(&self, model: &str) -> Self {
+        let mut req = self.clone();
+        req.model = Some(model.to_string());
+        req
+    }
+
+    pub(crate) fn with_input(&self, input: ResponsesInput) -> Self {
+        let mut req = self.clone();
+        req.input = Some(input);
+        req
+    }
+
+    /// Copy of this request with `text.format` set, keeping other `text` options.
+    pub(crate) fn with_text_format(&self, format: ResponsesTextFormat) -> Self {
+        let mut req = self.clone();
+        req.text.get_or_insert_with(Default::default).format = Some(format);
+        req
+    }
+
     pub fn experimental_metadata(&self) -> Option<OpenRouterExperimentalMetadata> {
         self.experimental_metadata
     }
+
+    pub(crate) fn set_experimental_metadata(
+        &mut self,
+        experimental_metadata: Option<OpenRouterExperimentalMetadata>,
+    ) {
+        self.experimental_metadata = experimental_metadata;
+    }
 }
 
 /// Non-streaming response payload returned by `POST /responses`.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[non_exhaustive]
 pub struct ResponsesResponse {
     pub id: Option<String>,
@@ -224,23 +980,631 @@ pub struct ResponsesResponse {
     pub model: Option<String>,
     pub status: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub output: Option<Vec<Value>>,
+    pub output: Option<Vec<ResponsesOutputItem>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage: Option<Value>,
     #[serde(flatten)]
     pub extra: HashMap<String, Value>,
 }
 
-/// Streaming event payload returned by `POST /responses` when `stream=true`.
+impl ResponsesResponse {
+    /// Concatenated `output_text` parts of every `message` output item.
+    pub fn output_text(&self) -> Option<String> {
+        let texts: Vec<&str> = self
+            .output
+            .iter()
+            .flatten()
+            .filter_map(|item| match item {
+                ResponsesOutputItem::Message(message) => Some(&message.content),
+                _ => None,
+            })
+            .flat_map(|content| match content {
+                ResponsesMessageContent::Text(text) => vec![text.as_str()],
+                ResponsesMessageContent::Parts(parts) => parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        ResponsesContentPart::OutputText { text, .. } => Some(text.as_str()),
+                        _ => None,
+                    })
+                    .collect(),
+            })
+            .collect();
+        if texts.is_empty() {
+            None
+        } else {
+            Some(texts.concat())
+        }
+    }
+
+    /// `function_call` items in `output`, in order.
+    pub fn function_calls(&self) -> impl Iterator<Item = &ResponsesFunctionCall> {
+        self.output.iter().flatten().filter_map(|item| match item {
+            ResponsesOutputItem::FunctionCall(call) => Some(call),
+            _ => None,
+        })
+    }
+}
+
+/// Streaming event returned by `POST /responses` when `stream=true`.
+///
+/// Events outside the typed set, or whose payload doesn't match it, are
+/// yielded as [`ResponsesStreamEvent::Unknown`].
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[non_exhaustive]
-pub struct ResponsesStreamEvent {
-    #[serde(rename = "type")]
-    pub event_type: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub sequence_number: Option<u64>,
-    #[serde(flatten)]
-    pub data: HashMap<String, Value>,
+#[serde(tag = "type")]
+pub enum ResponsesStreamEvent {
+    #[serde(rename = "response.created")]
+    Created {
+        response: Box<ResponsesResponse>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.in_progress")]
+    InProgress {
+        response: Box<ResponsesResponse>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.completed")]
+    Completed {
+        response: Box<ResponsesResponse>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.failed")]
+    Failed {
+        response: Box<ResponsesResponse>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.incomplete")]
+    Incomplete {
+        response: Box<ResponsesResponse>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.output_item.added")]
+    OutputItemAdded {
+        #[serde(default)]
+        output_index: usize,
+        item: ResponsesOutputItem,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.output_item.done")]
+    OutputItemDone {
+        #[serde(default)]
+        output_index: usize,
+        item: ResponsesOutputItem,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.content_part.added")]
+    ContentPartAdded {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        content_index: usize,
+        part: ResponsesContentPart,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.content_part.done")]
+    ContentPartDone {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        content_index: usize,
+        part: ResponsesContentPart,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.output_text.delta")]
+    OutputTextDelta {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        content_index: usize,
+        delta: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.output_text.done")]
+    OutputTextDone {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        content_index: usize,
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.function_call_arguments.delta")]
+    FunctionCallArgumentsDelta {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        delta: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.function_call_arguments.done")]
+    FunctionCallArgumentsDone {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        arguments: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.reasoning_summary_part.added")]
+    ReasoningSummaryPartAdded {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        summary_index: usize,
+        part: ResponsesReasoningPart,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.reasoning_summary_text.delta")]
+    ReasoningSummaryTextDelta {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        summary_index: usize,
+        delta: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.reasoning_summary_text.done")]
+    ReasoningSummaryTextDone {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        summary_index: usize,
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "response.reasoning_text.delta")]
+    ReasoningTextDelta {
+        #[serde(default)]
+        item_id: String,
+        #[serde(default)]
+        output_index: usize,
+        #[serde(default)]
+        content_index: usize,
+        delta: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    #[serde(rename = "error")]
+    Error {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+        #[serde(default)]
+        message: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        param: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+    },
+    /// Any other event, with its fields kept as raw JSON.
+    #[serde(untagged)]
+    Unknown {
+        #[serde(rename = "type")]
+        event_type: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sequence_number: Option<u64>,
+        #[serde(flatten)]
+        data: HashMap<String, Value>,
+    },
+}
+
+impl ResponsesStreamEvent {
+    /// The event's `type`, e.g. `response.output_text.delta`.
+    pub fn event_type(&self) -> &str {
+        match self {
+            Self::Created { .. } => "response.created",
+            Self::InProgress { .. } => "response.in_progress",
+            Self::Completed { .. } => "response.completed",
+            Self::Failed { .. } => "response.failed",
+            Self::Incomplete { .. } => "response.incomplete",
+            Self::OutputItemAdded { .. } => "response.output_item.added",
+            Self::OutputItemDone { .. } => "response.output_item.done",
+            Self::ContentPartAdded { .. } => "response.content_part.added",
+            Self::ContentPartDone { .. } => "response.content_part.done",
+            Self::OutputTextDelta { .. } => "response.output_text.delta",
+            Self::OutputTextDone { .. } => "response.output_text.done",
+            Self::FunctionCallArgumentsDelta { .. } => "response.function_call_arguments.delta",
+            Self::FunctionCallArgumentsDone { .. } => "response.function_call_arguments.done",
+            Self::ReasoningSummaryPartAdded { .. } => "response.reasoning_summary_part.added",
+            Self::ReasoningSummaryTextDelta { .. } => "response.reasoning_summary_text.delta",
+            Self::ReasoningSummaryTextDone { .. } => "response.reasoning_summary_text.done",
+            Self::ReasoningTextDelta { .. } => "response.reasoning_text.delta",
+            Self::Error { .. } => "error",
+            Self::Unknown { event_type, .. } => event_type,
+        }
+    }
+
+    pub fn sequence_number(&self) -> Option<u64> {
+        match self {
+            Self::Created {
+                sequence_number, ..
+            }
+            | Self::InProgress {
+                sequence_number, ..
+            }
+            | Self::Completed {
+                sequence_number, ..
+            }
+            | Self::Failed {
+                sequence_number, ..
+            }
+            | Self::Incomplete {
+                sequence_number, ..
+            }
+            | Self::OutputItemAdded {
+                sequence_number, ..
+            }
+            | Self::OutputItemDone {
+                sequence_number, ..
+            }
+            | Self::ContentPartAdded {
+                sequence_number, ..
+            }
+            | Self::ContentPartDone {
+                sequence_number, ..
+            }
+            | Self::OutputTextDelta {
+                sequence_number, ..
+            }
+            | Self::OutputTextDone {
+                sequence_number, ..
+            }
+            | Self::FunctionCallArgumentsDelta {
+                sequence_number, ..
+            }
+            | Self::FunctionCallArgumentsDone {
+                sequence_number, ..
+            }
+            | Self::ReasoningSummaryPartAdded {
+                sequence_number, ..
+            }
+            | Self::ReasoningSummaryTextDelta {
+                sequence_number, ..
+            }
+            | Self::ReasoningSummaryTextDone {
+                sequence_number, ..
+            }
+            | Self::ReasoningTextDelta {
+                sequence_number, ..
+            }
+            | Self::Error {
+                sequence_number, ..
+            }
+            | Self::Unknown {
+                sequence_number, ..
+            } => *sequence_number,
+        }
+    }
+
+    /// Response snapshot carried by `response.*` lifecycle events.
+    pub fn response(&self) -> Option<&ResponsesResponse> {
+        match self {
+            Self::Created { response, .. }
+            | Self::InProgress { response, .. }
+            | Self::Completed { response, .. }
+            | Self::Failed { response, .. }
+            | Self::Incomplete { response, .. } => Some(response),
+            _ => None,
+        }
+    }
+
+    /// Whether this event ends the response (completed, failed, or incomplete).
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed { .. } | Self::Failed { .. } | Self::Incomplete { .. }
+        )
+    }
+}
+
+/// Rebuilds the final [`ResponsesResponse`] from a stream of events.
+///
+/// The terminal snapshot (`response.completed` and friends) wins when it
+/// carries output; otherwise the output assembled from item, text, argument,
+/// and reasoning deltas is used.
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use openrouter_rs::api::responses::{ResponsesRequest, ResponsesStreamAccumulator};
+///
+/// # async fn example(client: openrouter_rs::OpenRouterClient, request: ResponsesRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = client.responses().stream(&request).await?;
+/// let mut accumulator = ResponsesStreamAccumulator::new();
+/// while let Some(event) = stream.next().await {
+///     accumulator.push(&event?);
+/// }
+/// let response = accumulator.finish();
+/// println!("{}", response.output_text().unwrap_or_default());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ResponsesStreamAccumulator {
+    response: Option<ResponsesResponse>,
+    output: Vec<Option<ResponsesOutputItem>>,
+}
+
+impl ResponsesStreamAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply one event.
+    pub fn push(&mut self, event: &ResponsesStreamEvent) {
+        use ResponsesStreamEvent as Event;
+
+        match event {
+            Event::Created { response, .. }
+            | Event::InProgress { response, .. }
+            | Event::Completed { response, .. }
+            | Event::Failed { response, .. }
+            | Event::Incomplete { response, .. } => {
+                let mut response = response.as_ref().clone();
+                if let Some(output) = response.output.take().filter(|items| !items.is_empty()) {
+                    self.output = output.into_iter().map(Some).collect();
+                }
+                self.response = Some(response);
+            }
+            Event::OutputItemAdded {
+                output_index, item, ..
+            }
+            | Event::OutputItemDone {
+                output_index, item, ..
+            } => {
+                if let Some(slot) = self.slot(*output_index) {
+                    *slot = Some(item.clone());
+                }
+            }
+            Event::ContentPartAdded {
+                output_index,
+                content_index,
+                part,
+                ..
+            }
+            | Event::ContentPartDone {
+                output_index,
+                content_index,
+                part,
+                ..
+            } => {
+                if let Some(slot) = self
+                    .message_parts(*output_index)
+                    .and_then(|parts| part_slot(parts, *content_index))
+                {
+                    *slot = part.clone();
+                }
+            }
+            Event::OutputTextDelta {
+                output_index,
+                content_index,
+                delta,
+                ..
+            } => {
+                if let Some(slot) = self
+                    .message_parts(*output_index)
+                    .and_then(|parts| part_slot(parts, *content_index))
+                {
+                    match slot {
+                        ResponsesContentPart::OutputText { text, .. } => text.push_str(delta),
+                        other => *other = ResponsesContentPart::output_text(delta.as_str()),
+                    }
+                }
+            }
+            Event::OutputTextDone {
+                output_index,
+                content_index,
+                text,
+                ..
+            } => {
+                if let Some(slot) = self
+                    .message_parts(*output_index)
+                    .and_then(|parts| part_slot(parts, *content_index))
+                {
+                    match slot {
+                        ResponsesContentPart::OutputText { text: current, .. } => {
+                            current.clone_from(text)
+                        }
+                        other => *other = ResponsesContentPart::output_text(text.as_str()),
+                    }
+                }
+            }
+            Event::FunctionCallArgumentsDelta {
+                output_index,
+                delta,
+                ..
+            } => {
+                if let Some(Some(ResponsesOutputItem::FunctionCall(call))) =
+                    self.slot(*output_index)
+                {
+                    call.arguments.push_str(delta);
+                }
+            }
+            Event::FunctionCallArgumentsDone {
+                output_index,
+                arguments,
+                ..
+            } => {
+                if let Some(Some(ResponsesOutputItem::FunctionCall(call))) =
+                    self.slot(*output_index)
+                {
+                    call.arguments.clone_from(arguments);
+                }
+            }
+            Event::ReasoningSummaryPartAdded {
+                output_index,
+                summary_index,
+                part,
+                ..
+            } => {
+                if let Some(slot) = self
+                    .reasoning_summary(*output_index)
+                    .and_then(|summary| summary_slot(summary, *summary_index))
+                {
+                    *slot = part.clone();
+                }
+            }
+            Event::ReasoningSummaryTextDelta {
+                output_index,
+                summary_index,
+                delta,
+                ..
+            } => {
+                if let Some(slot) = self
+                    .reasoning_summary(*output_index)
+                    .and_then(|summary| summary_slot(summary, *summary_index))
+                {
+                    match slot {
+                        ResponsesReasoningPart::SummaryText { text } => text.push_str(delta),
+                        other => {
+                            *other = ResponsesReasoningPart::SummaryText {
+                                text: delta.clone(),
+                            }
+                        }
+                    }
+                }
+            }
+            Event::ReasoningSummaryTextDone {
+                output_index,
+                summary_index,
+                text,
+                ..
+            } => {
+                if let Some(slot) = self
+                    .reasoning_summary(*output_index)
+                    .and_then(|summary| summary_slot(summary, *summary_index))
+                {
+                    *slot = ResponsesReasoningPart::SummaryText { text: text.clone() };
+                }
+            }
+            Event::ReasoningTextDelta {
+                output_index,
+                content_index,
+                delta,
+                ..
+            } => {
+                if let Some(Some(ResponsesOutputItem::Reasoning(reasoning))) =
+                    self.output.get_mut(*output_index)
+                {
+                    let content = reasoning.content.get_or_insert_with(Vec::new);
+                    if let Some(ResponsesReasoningPart::ReasoningText { text }) =
+                        index_slot(content, *content_index, || {
+                            ResponsesReasoningPart::ReasoningText {
+                                text: String::new(),
+                            }
+                        })
+                    {
+                        text.push_str(delta);
+                    }
+                }
+            }
+            Event::Error {
+                code,
+                message,
+                param,
+                ..
+            } => {
+                let response = self.response.get_or_insert_with(ResponsesResponse::default);
+                response.status = Some("failed".to_string());
+                response.extra.insert(
+                    "error".to_string(),
+                    serde_json::json!({"code": code, "message": message, "param": param}),
+                );
+            }
+            Event::Unknown { .. } => {}
+        }
+    }
+
+    /// The response assembled so far.
+    pub fn finish(self) -> ResponsesResponse {
+        let mut response = self.response.unwrap_or_default();
+        let output: Vec<ResponsesOutputItem> = self.output.into_iter().flatten().collect();
+        if !output.is_empty() {
+            response.output = Some(output);
+        }
+        response
+    }
+
+    fn slot(&mut self, index: usize) -> Option<&mut Option<ResponsesOutputItem>> {
+        index_slot(&mut self.output, index, || None)
+    }
+
+    fn message_parts(&mut self, output_index: usize) -> Option<&mut Vec<ResponsesContentPart>> {
+        let slot = self.slot(output_index)?;
+        if slot.is_none() {
+            *slot = Some(ResponsesOutputItem::Message(ResponsesMessage::new(
+                Role::Assistant,
+                Vec::<ResponsesContentPart>::new(),
+            )));
+        }
+        match slot {
+            Some(ResponsesOutputItem::Message(message)) => {
+                if let ResponsesMessageContent::Text(text) = &message.content {
+                    message.content = vec![ResponsesContentPart::output_text(text.as_str())].into();
+                }
+                match &mut message.content {
+                    ResponsesMessageContent::Parts(parts) => Some(parts),
+                    ResponsesMessageContent::Text(_) => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn reasoning_summary(
+        &mut self,
+        output_index: usize,
+    ) -> Option<&mut Vec<ResponsesReasoningPart>> {
+        match self.slot(output_index) {
+            Some(Some(ResponsesOutputItem::Reasoning(reasoning))) => Some(&mut reasoning.summary),
+            _ => None,
+        }
+    }
+}
+
+fn part_slot(
+    parts: &mut Vec<ResponsesContentPart>,
+    index: usize,
+) -> Option<&mut ResponsesContentPart> {
+    index_slot(parts, index, || ResponsesContentPart::output_text(""))
+}
+
+fn summary_slot(
+    summary: &mut Vec<ResponsesReasoningPart>,
+    index: usize,
+) -> Option<&mut ResponsesReasoningPart> {
+    index_slot(summary, index, || ResponsesReasoningPart::SummaryText {
+        text: String::new(),
+    })
 }
 
 /// Send a non-streaming request to the Responses API.
@@ -252,7 +1616,7 @@ pub async fn create_response(
     app_categories: &Option<Vec<String>>,
     request: &ResponsesRequest,
 ) -> Result<ResponsesResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_response_with_client(
         &http_client,
         base_url,
@@ -310,7 +1674,7 @@ pub async fn stream_response(
     app_categories: &Option<Vec<String>>,
     request: &ResponsesRequest,
 ) -> Result<BoxStream<'static, Result<ResponsesStreamEvent, OpenRouterError>>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     stream_response_with_client(
         &http_client,
         base_url,
@@ -346,7 +1710,7 @@ pub(crate) async fn stream_response_with_client(
         &request.experimental_metadata,
     )
     .json(&request)
-    .send()
+    .send_streaming()
     .await?;
 
     if response.status().is_success() {
@@ -1,13 +1,18 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
+use futures_util::{
+    StreamExt,
+    stream::{self, BoxStream},
+};
 use serde::{Deserialize, Serialize};
 use urlencoding::encode;
 
 use crate::{
-    error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    api::files::FileDownload,
+    error::{JobError, OpenRouterError},
+    transport::{HttpClient, request as transport_request, response as transport_response},
+    types::poll::{PollOptions, Poller},
 };
 
 /// One image URL payload used in video generation requests.
@@ -180,6 +185,30 @@ pub struct VideoGenerationResponse {
     pub usage: Option<VideoGenerationUsage>,
 }
 
+impl VideoGenerationResponse {
+    /// The job finished and its output can be downloaded.
+    pub fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
+
+    /// The job ended without output (`failed`, `cancelled`, `expired`, ...).
+    pub fn is_failed(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "failed" | "error" | "cancelled" | "canceled" | "expired"
+        )
+    }
+
+    /// The job reached a final status and will not change again.
+    pub fn is_terminal(&self) -> bool {
+        self.is_completed() || self.is_failed()
+    }
+
+    fn job_error(&self) -> JobError {
+        JobError::new(&self.id, &self.status, self.error.clone())
+    }
+}
+
 /// Video model metadata returned by `GET /videos/models`.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[non_exhaustive]
@@ -221,7 +250,7 @@ pub async fn create_video_generation(
     app_categories: &Option<Vec<String>>,
     request: &VideoGenerationRequest,
 ) -> Result<VideoGenerationResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_video_generation_with_client(
         &http_client,
         base_url,
@@ -268,7 +297,7 @@ pub async fn list_video_models(
     base_url: &str,
     api_key: &str,
 ) -> Result<Vec<VideoModel>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_video_models_with_client(&http_client, base_url, api_key).await
 }
 
@@ -299,7 +328,7 @@ pub async fn get_video_generation(
     api_key: &str,
     job_id: &str,
 ) -> Result<VideoGenerationResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_video_generation_with_client(&http_client, base_url, api_key, job_id).await
 }
 
@@ -330,7 +359,7 @@ pub async fn get_video_content(
     job_id: &str,
     index: Option<u32>,
 ) -> Result<Vec<u8>, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_video_content_with_client(&http_client, base_url, api_key, job_id, index).await
 }
 
@@ -352,9 +381,176 @@ pub(crate) async fn get_video_content_with_client(
             .await?;
 
     if response.status().is_success() {
-        Ok(response.bytes().await?.to_vec())
+        Ok(transport_response::read_bytes(response).await?.to_vec())
+    } else {
+        transport_response::handle_error(response).await?;
+        unreachable!()
+    }
+}
+
+/// Download video content as a stream instead of buffering it.
+pub async fn get_video_content_stream(
+    base_url: &str,
+    api_key: &str,
+    job_id: &str,
+    index: Option<u32>,
+) -> Result<FileDownload, OpenRouterError> {
+    let http_client = crate::transport::default_client()?;
+    get_video_content_stream_with_client(&http_client, base_url, api_key, job_id, index).await
+}
+
+pub(crate) async fn get_video_content_stream_with_client(
+    http_client: &HttpClient,
+    base_url: &str,
+    api_key: &str,
+    job_id: &str,
+    index: Option<u32>,
+) -> Result<FileDownload, OpenRouterError> {
+    let mut url = format!("{base_url}/videos/{}/content", encode(job_id));
+    if let Some(index) = index {
+        url = format!("{url}?index={index}");
+    }
+
+    let response =
+        transport_request::with_bearer_auth(transport_request::get(http_client, &url), api_key)
+            .send_streaming()
+            .await?;
+
+    if response.status().is_success() {
+        Ok(FileDownload::new(response))
     } else {
         transport_response::handle_error(response).await?;
         unreachable!()
     }
 }
+
+/// Poll a video generation job, yielding the response each time its status changes.
+///
+/// The first item is the current status. The stream ends after yielding a
+/// final status (completed or failed), or after yielding an error: a failed
+/// request, [`OpenRouterError::Cancelled`], or a poll deadline timeout.
+pub fn video_generation_updates(
+    base_url: &str,
+    api_key: &str,
+    job_id: &str,
+    options: PollOptions,
+) -> Result<BoxStream<'static, Result<VideoGenerationResponse, OpenRouterError>>, OpenRouterError> {
+    let http_client = crate::transport::default_client()?;
+    Ok(video_generation_updates_with_client(
+        http_client,
+        base_url.to_string(),
+        api_key.to_string(),
+        job_id.to_string(),
+        options,
+    ))
+}
+
+struct UpdatesState {
+    http_client: HttpClient,
+    base_url: String,
+    api_key: String,
+    job_id: String,
+    poller: Poller,
+    last_status: Option<String>,
+    polled: bool,
+}
+
+pub(crate) fn video_generation_updates_with_client(
+    http_client: HttpClient,
+    base_url: String,
+    api_key: String,
+    job_id: String,
+    options: PollOptions,
+) -> BoxStream<'static, Result<VideoGenerationResponse, OpenRouterError>> {
+    let state = UpdatesState {
+        http_client,
+        base_url,
+        api_key,
+        job_id,
+        poller: Poller::new(options),
+        last_status: None,
+        polled: false,
+    };
+
+    stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+        loop {
+            let paced = if state.polled {
+                state.poller.wait().await
+            } else {
+                state.poller.check()
+            };
+            if let Err(error) = paced {
+                return Some((Err(error), None));
+            }
+            state.polled = true;
+
+            let response = match state
+                .poller
+                .bound(get_video_generation_with_client(
+                    &state.http_client,
+                    &state.base_url,
+                    &state.api_key,
+                    &state.job_id,
+                ))
+                .await
+            {
+                Ok(response) => response,
+                Err(error) => return Some((Err(error), None)),
+            };
+
+            if response.is_terminal() {
+                return Some((Ok(response), None));
+            }
+            if state.last_status.as_deref() != Some(response.status.as_str()) {
+                state.last_status = Some(response.status.clone());
+                return Some((Ok(response), Some(state)));
+            }
+        }
+    })
+    .boxed()
+}
+
+/// Poll a video generation job until it completes.
+///
+/// Returns the completed response, or [`OpenRouterError::JobFailed`] when the
+/// job ends in a failure status.
+pub async fn wait_for_video_generation(
+    base_url: &str,
+    api_key: &str,
+    job_id: &str,
+    options: PollOptions,
+) -> Result<VideoGenerationResponse, OpenRouterError> {
+    let http_client = crate::transport::default_client()?;
+    wait_for_video_generation_with_client(
+        http_client,
+        base_url.to_string(),
+        api_key.to_string(),
+        job_id.to_string(),
+        options,
+    )
+    .await
+}
+
+pub(crate) async fn wait_for_video_generation_with_client(
+    http_client: HttpClient,
+    base_url: String,
+    api_key: String,
+    job_id: String,
+    options: PollOptions,
+) -> Result<VideoGenerationResponse, OpenRouterError> {
+    let mut updates =
+        video_generation_updates_with_client(http_client, base_url, api_key, job_id, options);
+    let mut last = None;
+    while let Some(update) = updates.next().await {
+        last = Some(update?);
+    }
+
+    match last {
+        Some(response) if response.is_completed() => Ok(response),
+        Some(response) => Err(response.job_error().into()),
+        None => Err(OpenRouterError::Unknown(
+            "video generation polling ended without a status".to_string(),
+        )),
+    }
+}
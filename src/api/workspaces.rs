@@ -1,14 +1,13 @@
 use std::collections::HashMap;
 
 use derive_builder::Builder;
-use reqwest::Client as HttpClient;
 use serde::{Deserialize, Serialize, Serializer, ser::SerializeMap};
 use serde_json::Value;
 use urlencoding::encode;
 
 use crate::{
     error::OpenRouterError,
-    transport::{request as transport_request, response as transport_response},
+    transport::{HttpClient, request as transport_request, response as transport_response},
     types::{ApiResponse, PaginationOptions},
 };
 
@@ -278,7 +277,7 @@ pub async fn list_workspaces(
     management_key: &str,
     pagination: Option<PaginationOptions>,
 ) -> Result<WorkspaceListResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_workspaces_with_client(&http_client, base_url, management_key, pagination).await
 }
 
@@ -316,7 +315,7 @@ pub async fn create_workspace(
     management_key: &str,
     request: &CreateWorkspaceRequest,
 ) -> Result<Workspace, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     create_workspace_with_client(&http_client, base_url, management_key, request).await
 }
 
@@ -350,7 +349,7 @@ pub async fn get_workspace(
     management_key: &str,
     id: &str,
 ) -> Result<Workspace, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     get_workspace_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -384,7 +383,7 @@ pub async fn update_workspace(
     id: &str,
     request: &UpdateWorkspaceRequest,
 ) -> Result<Workspace, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     update_workspace_with_client(&http_client, base_url, management_key, id, request).await
 }
 
@@ -394,7 +393,7 @@ pub async fn update_workspace_with_cleared_io_logging_api_key_ids(
     id: &str,
     request: &UpdateWorkspaceRequest,
 ) -> Result<Workspace, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     update_workspace_with_cleared_io_logging_api_key_ids_with_client(
         &http_client,
         base_url,
@@ -457,7 +456,7 @@ pub async fn delete_workspace(
     management_key: &str,
     id: &str,
 ) -> Result<bool, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     delete_workspace_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -490,7 +489,7 @@ pub async fn list_workspace_budgets(
     management_key: &str,
     id: &str,
 ) -> Result<ListWorkspaceBudgetsResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     list_workspace_budgets_with_client(&http_client, base_url, management_key, id).await
 }
 
@@ -523,7 +522,7 @@ pub async fn upsert_workspace_budget(
     interval: &str,
     request: &UpsertWorkspaceBudgetRequest,
 ) -> Result<WorkspaceBudget, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     upsert_workspace_budget_with_client(
         &http_client,
         base_url,
@@ -572,7 +571,7 @@ pub async fn delete_workspace_budget(
     id: &str,
     interval: &str,
 ) -> Result<bool, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     delete_workspace_budget_with_client(&http_client, base_url, management_key, id, interval).await
 }
 
@@ -611,7 +610,7 @@ pub async fn add_workspace_members(
     id: &str,
     request: &WorkspaceMembersRequest,
 ) -> Result<WorkspaceMembersAddResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     add_workspace_members_with_client(&http_client, base_url, management_key, id, request).await
 }
 
@@ -645,7 +644,7 @@ pub async fn remove_workspace_members(
     id: &str,
     request: &WorkspaceMembersRequest,
 ) -> Result<WorkspaceMembersRemoveResponse, OpenRouterError> {
-    let http_client = crate::transport::new_client()?;
+    let http_client = crate::transport::default_client()?;
     remove_workspace_members_with_client(&http_client, base_url, management_key, id, request).await
 }
 
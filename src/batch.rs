@@ -0,0 +1,703 @@
+//! # Batch Runner
+//!
+//! [`BatchRunner`] runs large offline workloads of chat completions or
+//! embeddings read from a JSONL file. Each input line holds a caller-chosen
+//! `custom_id` and the request `body`:
+//!
+//! ```text
+//! {"custom_id": "row-1", "body": {"model": "openai/gpt-4.1-mini", "messages": [...]}}
+//! ```
+//!
+//! Requests run with bounded concurrency. A `429 Too Many Requests` halves
+//! the number of requests in flight and pauses dispatch for a cooldown that
+//! doubles while rate limits continue; the limit grows back by one after a
+//! window of successes. Items that fail with a retryable error (see
+//! [`ApiErrorContext::is_retryable`], connection failures, and timeouts) are
+//! re-queued up to [`BatchRunner::max_attempts`] times; other than after a
+//! `429`, each re-send waits out an exponential backoff set by
+//! [`BatchRunner::retry_backoff`] while the rest of the batch keeps running.
+//!
+//! Every item produces one [`BatchResult`] line in the output file, keyed by
+//! its `custom_id`. With [`BatchRunner::checkpoint`] set, each successful
+//! item is also appended to a checkpoint file together with its usage, after
+//! its output line. A later run with the same checkpoint skips those items
+//! and appends to the existing output, so an interrupted run resumes without
+//! re-billing completed work. Failed items are not checkpointed and are
+//! retried on resume.
+//!
+//! The checkpoint is the source of truth: before resuming, output lines for
+//! items it does not record are removed. That drops the lines of failed
+//! items that are about to be retried, and the line of an item whose
+//! checkpoint entry was lost to a crash, so the output keeps exactly one line
+//! per `custom_id`.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use openrouter_rs::{OpenRouterClient, api::chat::ChatCompletionRequest, batch::BatchRunner};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+//! let summary = BatchRunner::new()
+//!     .max_concurrency(16)
+//!     .checkpoint("results.checkpoint.jsonl")
+//!     .run::<ChatCompletionRequest>(&client, "requests.jsonl", "results.jsonl")
+//!     .await?;
+//!
+//! println!(
+//!     "{} succeeded, {} failed, {} skipped, ${:.4} spent",
+//!     summary.succeeded,
+//!     summary.failed,
+//!     summary.skipped,
+//!     summary.total_usage().cost
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ApiErrorContext::is_retryable`]: crate::error::ApiErrorContext::is_retryable
+
+use std::{
+    collections::{HashSet, VecDeque},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use futures_util::{StreamExt, future::BoxFuture, stream::FuturesUnordered};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tokio::{
+    fs::{File, OpenOptions},
+    io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader, Lines},
+    time::Instant,
+};
+
+use crate::{
+    OpenRouterClient,
+    api::{
+        chat::ChatCompletionRequest,
+        embeddings::{EmbeddingRequest, EmbeddingResponse},
+    },
+    error::OpenRouterError,
+    transport::RetryPolicy,
+    types::completion::CompletionsResponse,
+};
+
+/// A request type that [`BatchRunner`] can execute.
+///
+/// Implemented for [`ChatCompletionRequest`] and [`EmbeddingRequest`].
+pub trait BatchRequest: DeserializeOwned + Send + Sync {
+    /// The response written to the output file for a successful item.
+    type Response: Serialize + Send;
+
+    /// Send the request with `client`.
+    fn send<'a>(
+        &'a self,
+        client: &'a OpenRouterClient,
+    ) -> BoxFuture<'a, Result<Self::Response, OpenRouterError>>;
+
+    /// Token usage and cost reported in `response`.
+    fn usage(response: &Self::Response) -> BatchUsage;
+}
+
+impl BatchRequest for ChatCompletionRequest {
+    type Response = CompletionsResponse;
+
+    fn send<'a>(
+        &'a self,
+        client: &'a OpenRouterClient,
+    ) -> BoxFuture<'a, Result<Self::Response, OpenRouterError>> {
+        Box::pin(async move { client.chat().create(self).await })
+    }
+
+    fn usage(response: &Self::Response) -> BatchUsage {
+        let mut usage = BatchUsage {
+            requests: 1,
+            ..BatchUsage::default()
+        };
+        if let Some(reported) = &response.usage {
+            usage.prompt_tokens = u64::from(reported.prompt_tokens);
+            usage.completion_tokens = u64::from(reported.completion_tokens);
+            usage.total_tokens = u64::from(reported.total_tokens);
+            usage.cost = reported.cost.unwrap_or_default();
+        }
+        usage
+    }
+}
+
+impl BatchRequest for EmbeddingRequest {
+    type Response = EmbeddingResponse;
+
+    fn send<'a>(
+        &'a self,
+        client: &'a OpenRouterClient,
+    ) -> BoxFuture<'a, Result<Self::Response, OpenRouterError>> {
+        Box::pin(async move { client.models().create_embedding(self).await })
+    }
+
+    fn usage(response: &Self::Response) -> BatchUsage {
+        let mut usage = BatchUsage {
+            requests: 1,
+            ..BatchUsage::default()
+        };
+        if let Some(reported) = &response.usage {
+            usage.prompt_tokens = u64::from(reported.prompt_tokens);
+            usage.total_tokens = u64::from(reported.total_tokens);
+            usage.cost = reported.cost.unwrap_or_default();
+        }
+        usage
+    }
+}
+
+/// One input line: a caller-chosen id and the request body.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct BatchItem<R> {
+    pub custom_id: String,
+    pub body: R,
+}
+
+impl<R> BatchItem<R> {
+    pub fn new(custom_id: impl Into<String>, body: R) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            body,
+        }
+    }
+}
+
+/// Why an item failed, as written to the output file.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct BatchItemError {
+    /// HTTP status of the final attempt, for API errors.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    pub message: String,
+}
+
+impl From<&OpenRouterError> for BatchItemError {
+    fn from(error: &OpenRouterError) -> Self {
+        match error {
+            OpenRouterError::Api(context) => Self {
+                status: Some(context.status.as_u16()),
+                message: context.message.clone(),
+            },
+            other => Self {
+                status: None,
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+/// One output line: the response or error for a `custom_id`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct BatchResult<T> {
+    pub custom_id: String,
+    /// Number of requests sent for this item, including retries.
+    pub attempts: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<BatchItemError>,
+}
+
+/// Token and cost totals for a set of batch items.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub struct BatchUsage {
+    /// Number of successful requests counted.
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    /// Total cost in credits, when the API reported it.
+    pub cost: f64,
+}
+
+impl BatchUsage {
+    /// Add another set of totals to these.
+    pub fn add(&mut self, other: &BatchUsage) {
+        self.requests += other.requests;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+        self.cost += other.cost;
+    }
+}
+
+/// Outcome of [`BatchRunner::run`].
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct BatchSummary {
+    /// Items that completed successfully in this run.
+    pub succeeded: u64,
+    /// Items whose final attempt failed in this run.
+    pub failed: u64,
+    /// Items skipped because the checkpoint already recorded them.
+    pub skipped: u64,
+    /// Requests that were re-queued after a retryable error.
+    pub retries: u64,
+    /// `429` responses seen in this run.
+    pub rate_limited: u64,
+    /// Usage of the items completed in this run.
+    pub usage: BatchUsage,
+    /// Usage recorded in the checkpoint by earlier runs.
+    pub checkpoint_usage: BatchUsage,
+}
+
+impl BatchSummary {
+    /// Usage across this run and the runs recorded in the checkpoint.
+    pub fn total_usage(&self) -> BatchUsage {
+        let mut total = self.checkpoint_usage;
+        total.add(&self.usage);
+        total
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CheckpointEntry {
+    custom_id: String,
+    usage: BatchUsage,
+}
+
+/// Runs JSONL batches of requests with adaptive concurrency and
+/// checkpointing.
+#[derive(Debug, Clone)]
+pub struct BatchRunner {
+    max_concurrency: usize,
+    max_attempts: u32,
+    rate_limit_cooldown: Duration,
+    max_rate_limit_cooldown: Duration,
+    retry_backoff: RetryPolicy,
+    checkpoint: Option<PathBuf>,
+}
+
+impl Default for BatchRunner {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 8,
+            max_attempts: 3,
+            rate_limit_cooldown: Duration::from_secs(1),
+            max_rate_limit_cooldown: Duration::from_secs(60),
+            retry_backoff: RetryPolicy::default(),
+            checkpoint: None,
+        }
+    }
+}
+
+impl BatchRunner {
+    /// Create a runner with 8 concurrent requests, 3 attempts per item, and
+    /// no checkpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the most requests in flight at once. Rate limits lower the
+    /// effective limit temporarily.
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Set how many requests may be sent per item before its error is
+    /// recorded.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the dispatch pause after the first `429`, and the ceiling it
+    /// doubles up to while rate limits continue.
+    pub fn rate_limit_cooldown(mut self, cooldown: Duration, max_cooldown: Duration) -> Self {
+        self.rate_limit_cooldown = cooldown;
+        self.max_rate_limit_cooldown = max_cooldown.max(cooldown);
+        self
+    }
+
+    /// Set the backoff before re-sending an item after a retryable error
+    /// other than `429`, and the ceiling it doubles up to per attempt.
+    /// Defaults to 500ms and 30s, with jitter.
+    pub fn retry_backoff(mut self, backoff: Duration, max_backoff: Duration) -> Self {
+        self.retry_backoff = self
+            .retry_backoff
+            .initial_backoff(backoff)
+            .max_backoff(max_backoff.max(backoff));
+        self
+    }
+
+    /// Record completed items in `path` and skip them on later runs.
+    pub fn checkpoint(mut self, path: impl Into<PathBuf>) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+
+    /// Run every item in the JSONL file at `input` and write one
+    /// [`BatchResult`] line per item to `output`.
+    ///
+    /// Without a checkpoint the output file is replaced; with one, results
+    /// are appended to it after removing lines for items the checkpoint does
+    /// not record. Malformed input lines, duplicate `custom_id`s,
+    /// and I/O failures abort the run; request failures do not.
+    pub async fn run<R: BatchRequest>(
+        &self,
+        client: &OpenRouterClient,
+        input: impl AsRef<Path>,
+        output: impl AsRef<Path>,
+    ) -> Result<BatchSummary, OpenRouterError> {
+        let mut summary = BatchSummary::default();
+
+        let (completed, mut checkpoint) = match &self.checkpoint {
+            Some(path) => {
+                let (completed, usage) = load_checkpoint(path).await?;
+                prune_output(output.as_ref(), &completed).await?;
+                summary.checkpoint_usage = usage;
+                (completed, Some(open_append(path).await?))
+            }
+            None => (HashSet::new(), None),
+        };
+        let mut output = if checkpoint.is_some() {
+            open_append(output.as_ref()).await?
+        } else {
+            File::create(output.as_ref()).await?
+        };
+
+        let mut input = ItemReader::<R>::open(input.as_ref(), completed).await?;
+        let mut retry_queue: VecDeque<Requeued<R>> = VecDeque::new();
+        let mut in_flight = FuturesUnordered::new();
+        let mut throttle = Throttle::new(self);
+
+        loop {
+            while in_flight.len() < throttle.limit && !throttle.is_paused() {
+                let (item, attempts) = match take_ready(&mut retry_queue) {
+                    Some(queued) => (queued.item, queued.attempts),
+                    None => match input.next().await? {
+                        Some(item) => (item, 0),
+                        None => break,
+                    },
+                };
+                in_flight.push(async move {
+                    let result = item.body.send(client).await;
+                    (item, attempts + 1, result)
+                });
+            }
+            summary.skipped = input.skipped;
+            let next_retry = retry_queue.iter().map(|queued| queued.ready_at).min();
+
+            if in_flight.is_empty() {
+                if retry_queue.is_empty() && input.is_done() {
+                    break;
+                }
+                let wake = next_retry.map_or(throttle.paused_until, |ready_at| {
+                    ready_at.max(throttle.paused_until)
+                });
+                tokio::time::sleep_until(wake).await;
+                continue;
+            }
+
+            // Wake for a backed-off retry only when there is room to send it.
+            let retry_due =
+                next_retry.filter(|_| in_flight.len() < throttle.limit && !throttle.is_paused());
+            let (item, attempts, result) = tokio::select! {
+                Some(done) = in_flight.next() => done,
+                _ = tokio::time::sleep_until(throttle.paused_until), if throttle.is_paused() => continue,
+                _ = tokio::time::sleep_until(retry_due.unwrap_or(throttle.paused_until)), if retry_due.is_some() => continue,
+            };
+
+            let error = match result {
+                Ok(response) => {
+                    throttle.on_success();
+                    let usage = R::usage(&response);
+                    write_line(
+                        &mut output,
+                        &BatchResult {
+                            custom_id: item.custom_id.clone(),
+                            attempts,
+                            response: Some(response),
+                            error: None,
+                        },
+                    )
+                    .await?;
+                    if let Some(checkpoint) = checkpoint.as_mut() {
+                        write_line(
+                            checkpoint,
+                            &CheckpointEntry {
+                                custom_id: item.custom_id,
+                                usage,
+                            },
+                        )
+                        .await?;
+                    }
+                    summary.succeeded += 1;
+                    summary.usage.add(&usage);
+                    continue;
+                }
+                Err(error) => error,
+            };
+
+            let rate_limited = is_rate_limited(&error);
+            if rate_limited {
+                summary.rate_limited += 1;
+                throttle.on_rate_limited();
+            }
+            if attempts < self.max_attempts && is_retryable(&error) {
+                summary.retries += 1;
+                // A `429` already paused all dispatch; other failures back
+                // off per item.
+                let ready_at = if rate_limited {
+                    Instant::now()
+                } else {
+                    Instant::now() + self.retry_backoff.retry_delay(attempts, None)
+                };
+                retry_queue.push_back(Requeued {
+                    item,
+                    attempts,
+                    ready_at,
+                });
+                continue;
+            }
+
+            write_line(
+                &mut output,
+                &BatchResult::<R::Response> {
+                    custom_id: item.custom_id,
+                    attempts,
+                    response: None,
+                    error: Some(BatchItemError::from(&error)),
+                },
+            )
+            .await?;
+            summary.failed += 1;
+        }
+
+        Ok(summary)
+    }
+}
+
+/// An item waiting to be re-sent after a retryable error.
+struct Requeued<R> {
+    item: BatchItem<R>,
+    attempts: u32,
+    ready_at: Instant,
+}
+
+/// Remove the oldest queued item whose backoff has elapsed.
+fn take_ready<R>(queue: &mut VecDeque<Requeued<R>>) -> Option<Requeued<R>> {
+    let now = Instant::now();
+    let position = queue.iter().position(|queued| queued.ready_at <= now)?;
+    queue.remove(position)
+}
+
+/// Adaptive concurrency limit and rate-limit pause.
+struct Throttle {
+    limit: usize,
+    max_limit: usize,
+    successes: usize,
+    paused_until: Instant,
+    cooldown: Duration,
+    base_cooldown: Duration,
+    max_cooldown: Duration,
+}
+
+impl Throttle {
+    fn new(runner: &BatchRunner) -> Self {
+        Self {
+            limit: runner.max_concurrency,
+            max_limit: runner.max_concurrency,
+            successes: 0,
+            paused_until: Instant::now(),
+            cooldown: runner.rate_limit_cooldown,
+            base_cooldown: runner.rate_limit_cooldown,
+            max_cooldown: runner.max_rate_limit_cooldown,
+        }
+    }
+
+    fn is_paused(&self) -> bool {
+        Instant::now() < self.paused_until
+    }
+
+    fn on_success(&mut self) {
+        self.cooldown = self.base_cooldown;
+        self.successes += 1;
+        if self.successes >= self.limit && self.limit < self.max_limit {
+            self.limit += 1;
+            self.successes = 0;
+        }
+    }
+
+    fn on_rate_limited(&mut self) {
+        self.limit = (self.limit / 2).max(1);
+        self.successes = 0;
+        // Responses already in flight may report the same limit; only the
+        // first one starts a new pause.
+        if !self.is_paused() {
+            self.paused_until = Instant::now() + self.cooldown;
+            self.cooldown = (self.cooldown * 2).min(self.max_cooldown);
+        }
+    }
+}
+
+fn is_rate_limited(error: &OpenRouterError) -> bool {
+    matches!(error, OpenRouterError::Api(context) if context.status == http::StatusCode::TOO_MANY_REQUESTS)
+}
+
+fn is_retryable(error: &OpenRouterError) -> bool {
+    match error {
+        OpenRouterError::Api(context) => context.is_retryable(),
+        OpenRouterError::HttpRequest(error) => error.is_connect(),
+        OpenRouterError::Timeout(_) => true,
+        _ => false,
+    }
+}
+
+/// Reads input items lazily, skipping blank lines and checkpointed ids.
+struct ItemReader<R> {
+    lines: Lines<BufReader<File>>,
+    line_number: usize,
+    seen: HashSet<String>,
+    completed: HashSet<String>,
+    skipped: u64,
+    done: bool,
+    _request: std::marker::PhantomData<fn() -> R>,
+}
+
+impl<R: DeserializeOwned> ItemReader<R> {
+    async fn open(path: &Path, completed: HashSet<String>) -> Result<Self, OpenRouterError> {
+        let file = File::open(path).await?;
+        Ok(Self {
+            lines: BufReader::new(file).lines(),
+            line_number: 0,
+            seen: HashSet::new(),
+            completed,
+            skipped: 0,
+            done: false,
+            _request: std::marker::PhantomData,
+        })
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    async fn next(&mut self) -> Result<Option<BatchItem<R>>, OpenRouterError> {
+        while !self.done {
+            let Some(line) = self.lines.next_line().await? else {
+                self.done = true;
+                break;
+            };
+            self.line_number += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let item: BatchItem<R> = serde_json::from_str(&line).map_err(|error| {
+                OpenRouterError::ConfigError(format!(
+                    "invalid batch input on line {}: {error}",
+                    self.line_number
+                ))
+            })?;
+            if !self.seen.insert(item.custom_id.clone()) {
+                return Err(OpenRouterError::ConfigError(format!(
+                    "duplicate custom_id `{}` on line {}",
+                    item.custom_id, self.line_number
+                )));
+            }
+            if self.completed.contains(&item.custom_id) {
+                self.skipped += 1;
+                continue;
+            }
+            return Ok(Some(item));
+        }
+        Ok(None)
+    }
+}
+
+async fn load_checkpoint(path: &Path) -> Result<(HashSet<String>, BatchUsage), OpenRouterError> {
+    let mut completed = HashSet::new();
+    let mut usage = BatchUsage::default();
+    let contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            return Ok((completed, usage));
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    for line in contents.lines() {
+        // A line torn by a crash mid-write is ignored; its item runs again.
+        let Ok(entry) = serde_json::from_str::<CheckpointEntry>(line) else {
+            continue;
+        };
+        if completed.insert(entry.custom_id) {
+            usage.add(&entry.usage);
+        }
+    }
+    Ok((completed, usage))
+}
+
+/// Rewrite the output file at `path` keeping only the first line for each
+/// checkpointed item.
+async fn prune_output(path: &Path, completed: &HashSet<String>) -> Result<(), OpenRouterError> {
+    #[derive(Deserialize)]
+    struct ResultId {
+        custom_id: String,
+    }
+
+    let contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut kept = String::with_capacity(contents.len());
+    let mut written = HashSet::new();
+    for line in contents.lines() {
+        let keep = serde_json::from_str::<ResultId>(line).is_ok_and(|result| {
+            completed.contains(&result.custom_id) && written.insert(result.custom_id)
+        });
+        if keep {
+            kept.push_str(line);
+            kept.push('\n');
+        }
+    }
+    if kept.len() == contents.len() {
+        return Ok(());
+    }
+
+    // Replace the file in one step so a crash mid-write cannot lose lines.
+    let mut staging = path.as_os_str().to_owned();
+    staging.push(".tmp");
+    tokio::fs::write(&staging, kept).await?;
+    tokio::fs::rename(&staging, path).await?;
+    Ok(())
+}
+
+/// Open `path` for appending, first terminating a torn final line so the
+/// next entry starts on its own line.
+async fn open_append(path: &Path) -> Result<File, OpenRouterError> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .read(true)
+        .append(true)
+        .open(path)
+        .await?;
+    let length = file.metadata().await?.len();
+    if length > 0 {
+        let mut last = [0_u8; 1];
+        file.seek(std::io::SeekFrom::Start(length - 1)).await?;
+        file.read_exact(&mut last).await?;
+        if last[0] != b'\n' {
+            file.write_all(b"\n").await?;
+        }
+    }
+    Ok(file)
+}
+
+async fn write_line<T: Serialize>(file: &mut File, value: &T) -> Result<(), OpenRouterError> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    file.write_all(&line).await?;
+    file.flush().await?;
+    Ok(())
+}
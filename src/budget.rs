@@ -0,0 +1,532 @@
+//! # Budget Guard
+//!
+//! [`BudgetGuard`] keeps a running total of what a client spends and stops
+//! sending model requests once a limit is crossed. Attach it with
+//! [`OpenRouterClientBuilder::budget_guard`](crate::client::OpenRouterClientBuilder::budget_guard).
+//!
+//! Spend is read from the `usage.cost` the API reports on each successful
+//! response, including the final usage chunk of a stream, and is counted
+//! once the response body has been read or dropped. Responses without a
+//! reported cost count as requests with no spend.
+//!
+//! Before each request that names a `model`, the guard checks its limits:
+//!
+//! - a session budget, covering spend since the guard was created or
+//!   [`reset_session`](BudgetGuard::reset_session) was called;
+//! - a daily budget, covering spend since midnight UTC;
+//! - a remaining-credit floor, based on the last known account credits
+//!   (set by hand, or refreshed from `/credits` every interval) minus the
+//!   spend recorded since.
+//!
+//! Once one is crossed the request is rejected with
+//! [`OpenRouterError::BudgetExceeded`], or, with [`BudgetAction::Warn`],
+//! sent anyway after the warning handler is told. Limits are checked
+//! before sending, so requests already in flight can take spend past a
+//! limit.
+//!
+//! [`BudgetGuard::report`] breaks the session's spend down per model and per
+//! request `metadata` tag. Clones share the same totals, so one guard can
+//! cover several clients.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use openrouter_rs::{
+//!     OpenRouterClient,
+//!     api::chat::{ChatCompletionRequest, Message},
+//!     budget::BudgetGuard,
+//!     types::Role,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let budget = BudgetGuard::new()
+//!     .daily_budget(5.0)
+//!     .min_remaining_credits(1.0)
+//!     .refresh_credits_every(Duration::from_secs(300));
+//!
+//! let client = OpenRouterClient::builder()
+//!     .api_key("your_api_key")
+//!     .budget_guard(budget.clone())
+//!     .build()?;
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("openai/gpt-4.1-mini")
+//!     .messages(vec![Message::new(Role::User, "Hello!")])
+//!     .metadata([("team", "search")])
+//!     .build()?;
+//! client.chat().create(&request).await?;
+//!
+//! let report = budget.report();
+//! println!("spent {} today", report.today.cost);
+//! for (team, spend) in report.by_tag.get("team").into_iter().flatten() {
+//!     println!("{team}: {}", spend.cost);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    OpenRouterClient,
+    api::credits::CreditsData,
+    error::{BudgetError, BudgetKind, OpenRouterError},
+    transport::{HttpClient, response as transport_response, sse::JsonEvents},
+    types::ApiResponse,
+};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// What a [`BudgetGuard`] does with new requests once a limit is crossed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BudgetAction {
+    /// Fail with [`OpenRouterError::BudgetExceeded`] without sending.
+    #[default]
+    Reject,
+    /// Send the request and report the crossed limit to the warning handler.
+    Warn,
+}
+
+/// Request count, token, and cost totals.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Spend {
+    /// Number of successful requests counted.
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// Total cost in credits, as reported by the API.
+    pub cost: f64,
+}
+
+impl Spend {
+    fn add(&mut self, other: &Spend) {
+        self.requests += other.requests;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.cost += other.cost;
+    }
+}
+
+/// Snapshot of a [`BudgetGuard`]'s totals.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SpendReport {
+    /// Spend since the guard was created or its session was reset.
+    pub session: Spend,
+    /// Spend since midnight UTC.
+    pub today: Spend,
+    /// Last known account credits minus the spend recorded since, if known.
+    pub remaining_credits: Option<f64>,
+    /// Session spend per model, as named by the response.
+    pub by_model: BTreeMap<String, Spend>,
+    /// Session spend per request `metadata` entry, keyed by metadata key and
+    /// then value.
+    pub by_tag: BTreeMap<String, BTreeMap<String, Spend>>,
+}
+
+type WarningHandler = dyn Fn(&BudgetError) + Send + Sync;
+
+/// Spend tracker and limit check for requests sent by an
+/// [`OpenRouterClient`]. See the [module docs](self) for how spend is
+/// counted.
+#[derive(Clone, Default)]
+pub struct BudgetGuard {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    session_budget: Option<f64>,
+    daily_budget: Option<f64>,
+    credit_floor: Option<f64>,
+    action: BudgetAction,
+    on_warning: Option<Arc<WarningHandler>>,
+    credit_refresh: Option<Duration>,
+    credits_checked: Option<Instant>,
+    remaining_credits: Option<f64>,
+    day: u64,
+    today: Spend,
+    session: Spend,
+    by_model: BTreeMap<String, Spend>,
+    by_tag: BTreeMap<String, BTreeMap<String, Spend>>,
+}
+
+impl fmt::Debug for BudgetGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.lock();
+        f.debug_struct("BudgetGuard")
+            .field("session_budget", &state.session_budget)
+            .field("daily_budget", &state.daily_budget)
+            .field("credit_floor", &state.credit_floor)
+            .field("action", &state.action)
+            .field("credit_refresh", &state.credit_refresh)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BudgetGuard {
+    /// Create a guard with no limits that only tracks spend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop new requests once the session has spent `limit` credits.
+    pub fn session_budget(self, limit: f64) -> Self {
+        self.lock().session_budget = Some(limit);
+        self
+    }
+
+    /// Stop new requests once `limit` credits have been spent since
+    /// midnight UTC.
+    pub fn daily_budget(self, limit: f64) -> Self {
+        self.lock().daily_budget = Some(limit);
+        self
+    }
+
+    /// Stop new requests once remaining account credits fall to `floor`.
+    ///
+    /// Has no effect until credits are known, through
+    /// [`set_credits`](Self::set_credits), [`refresh_credits`](Self::refresh_credits),
+    /// or [`refresh_credits_every`](Self::refresh_credits_every).
+    pub fn min_remaining_credits(self, floor: f64) -> Self {
+        self.lock().credit_floor = Some(floor);
+        self
+    }
+
+    /// Choose whether crossed limits reject requests (the default) or only warn.
+    pub fn action(self, action: BudgetAction) -> Self {
+        self.lock().action = action;
+        self
+    }
+
+    /// Call `handler` for each request sent past a crossed limit under
+    /// [`BudgetAction::Warn`].
+    pub fn on_warning(self, handler: impl Fn(&BudgetError) + Send + Sync + 'static) -> Self {
+        self.lock().on_warning = Some(Arc::new(handler));
+        self
+    }
+
+    /// Fetch account credits from `/credits` before a request when the last
+    /// fetch is older than `interval`.
+    ///
+    /// Uses the client's API key. A failed fetch keeps the last known value
+    /// and is tried again after the next interval.
+    pub fn refresh_credits_every(self, interval: Duration) -> Self {
+        self.lock().credit_refresh = Some(interval);
+        self
+    }
+
+    /// Record the account's credits, as returned by `get_credits`.
+    pub fn set_credits(&self, credits: &CreditsData) {
+        let mut state = self.lock();
+        state.remaining_credits = Some(credits.total_credits - credits.total_usage);
+        state.credits_checked = Some(Instant::now());
+    }
+
+    /// Fetch the account's credits through `client` and record them.
+    pub async fn refresh_credits(&self, client: &OpenRouterClient) -> Result<(), OpenRouterError> {
+        let credits = client.management().get_credits().await?;
+        self.set_credits(&credits);
+        Ok(())
+    }
+
+    /// The first crossed limit, if any; new requests are allowed when `Ok`.
+    pub fn check(&self) -> Result<(), BudgetError> {
+        self.lock().crossed().map_or(Ok(()), Err)
+    }
+
+    /// Snapshot the current totals.
+    pub fn report(&self) -> SpendReport {
+        let mut state = self.lock();
+        state.roll_day();
+        SpendReport {
+            session: state.session,
+            today: state.today,
+            remaining_credits: state.remaining_credits,
+            by_model: state.by_model.clone(),
+            by_tag: state.by_tag.clone(),
+        }
+    }
+
+    /// Start a new session: clear the session total and its per-model and
+    /// per-tag breakdowns. Daily totals and credits are kept.
+    pub fn reset_session(&self) {
+        let mut state = self.lock();
+        state.session = Spend::default();
+        state.by_model.clear();
+        state.by_tag.clear();
+    }
+
+    pub(crate) fn bind(&self, base_url: &str, api_key: Option<&str>) -> BoundBudgetGuard {
+        BoundBudgetGuard {
+            guard: self.clone(),
+            base_url: base_url.to_string(),
+            api_key: api_key.map(str::to_string),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl State {
+    fn crossed(&mut self) -> Option<BudgetError> {
+        self.roll_day();
+        let over = |kind, limit: Option<f64>, spent: f64| {
+            limit
+                .filter(|limit| spent >= *limit)
+                .map(|limit| BudgetError::new(kind, limit, spent))
+        };
+        over(BudgetKind::Session, self.session_budget, self.session.cost)
+            .or_else(|| over(BudgetKind::Daily, self.daily_budget, self.today.cost))
+            .or_else(|| {
+                let (floor, remaining) = self.credit_floor.zip(self.remaining_credits)?;
+                (remaining <= floor)
+                    .then(|| BudgetError::new(BudgetKind::CreditFloor, floor, remaining))
+            })
+    }
+
+    /// Start a new daily total when the UTC date has changed.
+    fn roll_day(&mut self) {
+        let day = current_day();
+        if day != self.day {
+            self.day = day;
+            self.today = Spend::default();
+        }
+    }
+
+    fn record(&mut self, model: &str, tags: &[(String, String)], spend: &Spend) {
+        self.roll_day();
+        self.session.add(spend);
+        self.today.add(spend);
+        if let Some(remaining) = &mut self.remaining_credits {
+            *remaining -= spend.cost;
+        }
+        self.by_model
+            .entry(model.to_string())
+            .or_default()
+            .add(spend);
+        for (key, value) in tags {
+            self.by_tag
+                .entry(key.clone())
+                .or_default()
+                .entry(value.clone())
+                .or_default()
+                .add(spend);
+        }
+    }
+}
+
+fn current_day() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or_default()
+}
+
+/// A [`BudgetGuard`] together with the client settings it needs to refresh
+/// credits.
+#[derive(Debug, Clone)]
+pub(crate) struct BoundBudgetGuard {
+    guard: BudgetGuard,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl BoundBudgetGuard {
+    /// Check the guard's limits for `request`, returning the charge to track
+    /// its response with. Requests without a `model` are not guarded.
+    pub(crate) async fn admit(
+        &self,
+        request: &Request,
+        http_client: &HttpClient,
+    ) -> Result<Option<Charge>, OpenRouterError> {
+        let Some(body) = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .and_then(|body| serde_json::from_slice::<Value>(body).ok())
+        else {
+            return Ok(None);
+        };
+        let Some(model) = body.get("model").and_then(Value::as_str) else {
+            return Ok(None);
+        };
+
+        if self.credits_due() {
+            self.refresh_credits(http_client).await;
+        }
+
+        let (crossed, action, on_warning) = {
+            let mut state = self.guard.lock();
+            (state.crossed(), state.action, state.on_warning.clone())
+        };
+        if let Some(crossed) = crossed {
+            match action {
+                BudgetAction::Reject => return Err(crossed.into()),
+                BudgetAction::Warn => {
+                    if let Some(on_warning) = on_warning {
+                        on_warning(&crossed);
+                    }
+                }
+            }
+        }
+
+        let tags = body
+            .get("metadata")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+            .collect();
+        Ok(Some(Charge {
+            guard: self.guard.clone(),
+            model: model.to_string(),
+            tags,
+            events: None,
+            usage: None,
+            tracked: false,
+        }))
+    }
+
+    /// Whether a credit refresh is due, marking it as attempted if so.
+    fn credits_due(&self) -> bool {
+        if self.api_key.is_none() {
+            return false;
+        }
+        let mut state = self.guard.lock();
+        let Some(interval) = state.credit_refresh else {
+            return false;
+        };
+        let due = state
+            .credits_checked
+            .is_none_or(|checked| checked.elapsed() >= interval);
+        if due {
+            state.credits_checked = Some(Instant::now());
+        }
+        due
+    }
+
+    /// Fetch credits straight through the transport: sending them through
+    /// [`HttpClient::execute`] would re-enter this guard.
+    async fn refresh_credits(&self, http_client: &HttpClient) {
+        let Some(api_key) = &self.api_key else {
+            return;
+        };
+        let mut request = http_client
+            .inner()
+            .get(format!("{}/credits", self.base_url))
+            .bearer_auth(api_key);
+        if let Some(request_timeout) = http_client.timeouts().request_timeout() {
+            request = request.timeout(request_timeout);
+        }
+        let Ok(request) = request.build() else {
+            return;
+        };
+        let Ok(response) = http_client.dispatch(request, false).await else {
+            return;
+        };
+        if !response.status().is_success() {
+            return;
+        }
+        if let Ok(credits) =
+            transport_response::parse_json_response::<ApiResponse<CreditsData>>(response, "credits")
+                .await
+        {
+            self.guard.set_credits(&credits.data);
+        }
+    }
+}
+
+/// Spend owed by one admitted request, recorded when its response body has
+/// been read or dropped.
+///
+/// Only requests whose successful response reached [`Charge::track`] are
+/// recorded; a charge dropped after an error status or a transport failure
+/// leaves the budget untouched.
+pub(crate) struct Charge {
+    guard: BudgetGuard,
+    model: String,
+    tags: Vec<(String, String)>,
+    events: Option<JsonEvents>,
+    usage: Option<Value>,
+    tracked: bool,
+}
+
+impl Charge {
+    /// Wrap `response` so its usage is recorded once the body is consumed.
+    pub(crate) fn track(mut self, response: Response) -> Response {
+        self.tracked = true;
+        self.events = JsonEvents::new(&response);
+        crate::transport::inspect_body(response, move |chunk| {
+            if let (Ok(chunk), Some(events)) = (chunk, &mut self.events) {
+                events.push(chunk, |event| {
+                    if let Some(usage) = usage_of(event) {
+                        self.usage = Some(usage);
+                    }
+                });
+            }
+        })
+    }
+}
+
+impl Drop for Charge {
+    fn drop(&mut self) {
+        if !self.tracked {
+            return;
+        }
+        if let Some(events) = &mut self.events {
+            events.finish(|body| self.usage = usage_of(body));
+        }
+        let usage = self.usage.take().unwrap_or_default();
+        let model = usage
+            .get("model")
+            .and_then(Value::as_str)
+            .unwrap_or(&self.model);
+        let tokens = |fields: [&str; 2]| {
+            fields
+                .iter()
+                .find_map(|field| usage["usage"].get(field).and_then(Value::as_u64))
+                .unwrap_or_default()
+        };
+        let spend = Spend {
+            requests: 1,
+            prompt_tokens: tokens(["prompt_tokens", "input_tokens"]),
+            completion_tokens: tokens(["completion_tokens", "output_tokens"]),
+            cost: usage["usage"]
+                .get("cost")
+                .and_then(Value::as_f64)
+                .unwrap_or_default(),
+        };
+        self.guard.lock().record(model, &self.tags, &spend);
+    }
+}
+
+/// The object carrying `usage` in a response body or stream event: the
+/// body itself, or the `response` of a Responses API `response.completed`
+/// event.
+fn usage_of(mut value: Value) -> Option<Value> {
+    if value.get("usage").is_some_and(Value::is_object) {
+        return Some(value);
+    }
+    let response = value.get_mut("response")?.take();
+    response
+        .get("usage")
+        .is_some_and(Value::is_object)
+        .then_some(response)
+}
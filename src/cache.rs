@@ -0,0 +1,650 @@
+//! # Response Cache
+//!
+//! An opt-in cache for chat completions, for workloads that send the same
+//! deterministic request many times (evaluation suites with a fixed `seed`
+//! and `temperature` 0, for example). Configure it with
+//! [`OpenRouterClientBuilder::cache`](crate::client::OpenRouterClientBuilder::cache);
+//! [`ChatClient::create`](crate::client::ChatClient::create) and the
+//! `stream*` methods then consult it automatically.
+//!
+//! Entries are keyed by a SHA-256 hash of the canonical JSON of the request
+//! (object keys sorted), so field order never changes the key. A cached
+//! completion is replayed to the `stream*` methods as a single synthetic
+//! chunk; a completed stream is cached as its chunks and replayed verbatim.
+//! Responses and streams that report an error, either as a failed request
+//! or as a choice with an `error` or an `error` finish reason, are not
+//! cached.
+//!
+//! Storage is pluggable through [`CacheStore`]: [`MemoryCache`] is a
+//! bounded LRU map and [`DiskCache`] keeps one JSON file per entry so a
+//! cache survives restarts. Cache read and write failures count as misses
+//! and never fail the request.
+//!
+//! Use [`ChatClient::create_with_cache`](crate::client::ChatClient::create_with_cache)
+//! or [`ChatClient::stream_with_cache`](crate::client::ChatClient::stream_with_cache)
+//! to bypass or refresh the cache for one request and to see whether it hit.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use openrouter_rs::{
+//!     OpenRouterClient,
+//!     api::chat::{ChatCompletionRequest, Message},
+//!     cache::{CacheMode, CacheStatus, ResponseCache},
+//!     types::Role,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenRouterClient::builder()
+//!     .api_key("your_api_key")
+//!     .cache(ResponseCache::disk(".openrouter-cache").ttl(Duration::from_secs(7 * 24 * 3600)))
+//!     .build()?;
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("openai/gpt-4.1-mini")
+//!     .messages(vec![Message::new(Role::User, "What is 2 + 2?")])
+//!     .temperature(0.0)
+//!     .seed(7)
+//!     .build()?;
+//!
+//! let cached = client.chat().create_with_cache(&request, CacheMode::Use).await?;
+//! if cached.cache.status == CacheStatus::Hit {
+//!     println!("served from cache, {:?} old", cached.cache.age);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    path::PathBuf,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::{
+    StreamExt,
+    future::{BoxFuture, ready},
+    stream::{self, BoxStream},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    OpenRouterClient,
+    api::chat::ChatCompletionRequest,
+    error::OpenRouterError,
+    types::completion::{CompletionsResponse, FinishReason},
+};
+
+/// Key namespace for non-streaming chat completions.
+const CHAT_NAMESPACE: &str = "chat.completions";
+/// Key namespace for streamed chat completion transcripts.
+const CHAT_STREAM_NAMESPACE: &str = "chat.completions.stream";
+
+/// One cached value with its storage time and optional expiry, both in
+/// milliseconds since the Unix epoch.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct CacheEntry {
+    pub value: Value,
+    pub stored_at_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at_ms: Option<u64>,
+}
+
+impl CacheEntry {
+    /// Wrap `value`, stored now and expiring after `ttl` if given.
+    pub fn new(value: Value, ttl: Option<Duration>) -> Self {
+        let now = now_ms();
+        Self {
+            value,
+            stored_at_ms: now,
+            expires_at_ms: ttl.map(|ttl| now.saturating_add(ttl.as_millis() as u64)),
+        }
+    }
+
+    /// Whether the entry's TTL has passed.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at_ms
+            .is_some_and(|expires| now_ms() >= expires)
+    }
+
+    /// Time since the entry was stored.
+    pub fn age(&self) -> Duration {
+        Duration::from_millis(now_ms().saturating_sub(self.stored_at_ms))
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Storage backend for [`ResponseCache`].
+///
+/// Keys are lowercase hex strings, safe to use as file names.
+pub trait CacheStore: Send + Sync + 'static {
+    /// Look up `key`.
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Result<Option<CacheEntry>, OpenRouterError>>;
+
+    /// Store `entry` under `key`, replacing any previous entry.
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        entry: CacheEntry,
+    ) -> BoxFuture<'a, Result<(), OpenRouterError>>;
+
+    /// Delete `key` if present.
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), OpenRouterError>>;
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<String, (CacheEntry, u64)>,
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl LruState {
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some((_, used)) = self.entries.get_mut(key) {
+            self.recency.remove(used);
+            *used = tick;
+            self.recency.insert(tick, key.to_string());
+        }
+    }
+}
+
+/// In-memory [`CacheStore`] that evicts the least recently used entry once
+/// `capacity` entries are stored.
+///
+/// Clones share the same entries.
+#[derive(Debug, Clone)]
+pub struct MemoryCache {
+    capacity: usize,
+    state: Arc<Mutex<LruState>>,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Arc::default(),
+        }
+    }
+
+    /// Number of stored entries, including expired ones not yet read.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LruState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Result<Option<CacheEntry>, OpenRouterError>> {
+        let mut state = self.lock();
+        state.touch(key);
+        let entry = state.entries.get(key).map(|(entry, _)| entry.clone());
+        Box::pin(ready(Ok(entry)))
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        entry: CacheEntry,
+    ) -> BoxFuture<'a, Result<(), OpenRouterError>> {
+        let mut state = self.lock();
+        if let Some((_, used)) = state.entries.remove(key) {
+            state.recency.remove(&used);
+        }
+        while state.entries.len() >= self.capacity {
+            let Some((_, oldest)) = state.recency.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+        state.tick += 1;
+        let tick = state.tick;
+        state.entries.insert(key.to_string(), (entry, tick));
+        state.recency.insert(tick, key.to_string());
+        Box::pin(ready(Ok(())))
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), OpenRouterError>> {
+        let mut state = self.lock();
+        if let Some((_, used)) = state.entries.remove(key) {
+            state.recency.remove(&used);
+        }
+        Box::pin(ready(Ok(())))
+    }
+}
+
+/// On-disk [`CacheStore`] that keeps each entry in `<dir>/<key>.json`.
+///
+/// The directory is created on the first write. Entries are written to a
+/// temporary file and renamed into place, so readers never see a partial
+/// entry.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json"))
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Result<Option<CacheEntry>, OpenRouterError>> {
+        Box::pin(async move {
+            match tokio::fs::read(self.path(key)).await {
+                Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+                Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(error) => Err(error.into()),
+            }
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        entry: CacheEntry,
+    ) -> BoxFuture<'a, Result<(), OpenRouterError>> {
+        Box::pin(async move {
+            tokio::fs::create_dir_all(&self.dir).await?;
+            let path = self.path(key);
+            let temp = self.dir.join(format!(".{key}.{}.tmp", fastrand::u64(..)));
+            tokio::fs::write(&temp, serde_json::to_vec(&entry)?).await?;
+            tokio::fs::rename(&temp, &path).await?;
+            Ok(())
+        })
+    }
+
+    fn remove<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), OpenRouterError>> {
+        Box::pin(async move {
+            match tokio::fs::remove_file(self.path(key)).await {
+                Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+                _ => Ok(()),
+            }
+        })
+    }
+}
+
+/// Cache configuration attached to an
+/// [`OpenRouterClient`](crate::OpenRouterClient): a [`CacheStore`] and an
+/// optional time to live for new entries.
+///
+/// Clones share the same store.
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttl: Option<Duration>,
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResponseCache {
+    /// Cache into `store`, with entries that never expire.
+    pub fn new(store: impl CacheStore) -> Self {
+        Self {
+            store: Arc::new(store),
+            ttl: None,
+        }
+    }
+
+    /// Cache in memory, keeping at most `capacity` entries.
+    pub fn memory(capacity: usize) -> Self {
+        Self::new(MemoryCache::new(capacity))
+    }
+
+    /// Cache on disk under `dir`.
+    pub fn disk(dir: impl Into<PathBuf>) -> Self {
+        Self::new(DiskCache::new(dir))
+    }
+
+    /// Expire entries `ttl` after they are stored. Expired entries are
+    /// treated as misses and removed when read.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Unexpired entry for `key`, if any.
+    async fn lookup(&self, key: &str) -> Option<CacheEntry> {
+        let entry = self.store.get(key).await.ok().flatten()?;
+        if entry.is_expired() {
+            let _ = self.store.remove(key).await;
+            return None;
+        }
+        Some(entry)
+    }
+
+    async fn store(&self, key: &str, value: Value) {
+        let _ = self.store.put(key, CacheEntry::new(value, self.ttl)).await;
+    }
+}
+
+/// Cache key for `request` in `namespace`: the hex SHA-256 of the namespace
+/// and the request's canonical JSON.
+pub fn cache_key<T: Serialize + ?Sized>(
+    namespace: &str,
+    request: &T,
+) -> Result<String, OpenRouterError> {
+    let mut canonical = format!("{namespace}\n");
+    write_canonical(&serde_json::to_value(request)?, &mut canonical);
+    let digest = Sha256::digest(canonical.as_bytes());
+    Ok(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+/// Serialize `value` as JSON with object keys in sorted order.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(object) => {
+            let mut keys: Vec<&String> = object.keys().collect();
+            keys.sort();
+            out.push('{');
+            for (index, key) in keys.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&object[key], out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+/// How one request uses the client's cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum CacheMode {
+    /// Serve hits from the cache and store misses.
+    #[default]
+    Use,
+    /// Always send the request and overwrite the cached entry.
+    Refresh,
+    /// Neither read nor write the cache.
+    Bypass,
+}
+
+/// Whether a response came from the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CacheStatus {
+    /// Served from the cache without a request.
+    Hit,
+    /// Sent to the API; the result is cached once complete.
+    Miss,
+    /// Sent to the API because of [`CacheMode::Refresh`]; the result
+    /// replaces the cached entry.
+    Refreshed,
+    /// Sent to the API without touching the cache ([`CacheMode::Bypass`]).
+    Bypassed,
+    /// The client has no cache configured.
+    Disabled,
+}
+
+/// Cache details for one request.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CacheInfo {
+    pub status: CacheStatus,
+    /// The request's cache key, when the cache was consulted or written.
+    pub key: Option<String>,
+    /// Age of the cached entry, for hits.
+    pub age: Option<Duration>,
+}
+
+impl CacheInfo {
+    fn new(status: CacheStatus, key: Option<String>) -> Self {
+        Self {
+            status,
+            key,
+            age: None,
+        }
+    }
+}
+
+/// A response together with its [`CacheInfo`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Cached<T> {
+    pub response: T,
+    pub cache: CacheInfo,
+}
+
+type ChatStream = BoxStream<'static, Result<CompletionsResponse, OpenRouterError>>;
+
+pub(crate) async fn create_chat(
+    client: &OpenRouterClient,
+    request: &ChatCompletionRequest,
+    mode: CacheMode,
+) -> Result<Cached<CompletionsResponse>, OpenRouterError> {
+    let Some(cache) = client
+        .response_cache()
+        .filter(|_| mode != CacheMode::Bypass)
+    else {
+        let status = match client.response_cache() {
+            Some(_) => CacheStatus::Bypassed,
+            None => CacheStatus::Disabled,
+        };
+        return Ok(Cached {
+            response: client.send_chat_completion(request).await?,
+            cache: CacheInfo::new(status, None),
+        });
+    };
+
+    let key = cache_key(CHAT_NAMESPACE, request)?;
+    if mode == CacheMode::Use {
+        if let Some(entry) = cache.lookup(&key).await {
+            if let Ok(response) = serde_json::from_value(entry.value.clone()) {
+                return Ok(Cached {
+                    response,
+                    cache: CacheInfo {
+                        status: CacheStatus::Hit,
+                        key: Some(key),
+                        age: Some(entry.age()),
+                    },
+                });
+            }
+        }
+    }
+
+    let response = client.send_chat_completion(request).await?;
+    if !has_error(&response) {
+        if let Ok(value) = serde_json::to_value(&response) {
+            cache.store(&key, value).await;
+        }
+    }
+    Ok(Cached {
+        response,
+        cache: CacheInfo::new(miss_status(mode), Some(key)),
+    })
+}
+
+pub(crate) async fn stream_chat(
+    client: &OpenRouterClient,
+    request: &ChatCompletionRequest,
+    mode: CacheMode,
+) -> Result<Cached<ChatStream>, OpenRouterError> {
+    let Some(cache) = client
+        .response_cache()
+        .filter(|_| mode != CacheMode::Bypass)
+    else {
+        let status = match client.response_cache() {
+            Some(_) => CacheStatus::Bypassed,
+            None => CacheStatus::Disabled,
+        };
+        return Ok(Cached {
+            response: client.stream_chat_completion(request).await?,
+            cache: CacheInfo::new(status, None),
+        });
+    };
+
+    let key = cache_key(CHAT_STREAM_NAMESPACE, request)?;
+    if mode == CacheMode::Use {
+        if let Some((chunks, age)) = cached_chunks(cache, &key, request).await? {
+            return Ok(Cached {
+                response: stream::iter(chunks.into_iter().map(Ok)).boxed(),
+                cache: CacheInfo {
+                    status: CacheStatus::Hit,
+                    key: Some(key),
+                    age: Some(age),
+                },
+            });
+        }
+    }
+
+    let live = client.stream_chat_completion(request).await?;
+    Ok(Cached {
+        response: record_stream(live, cache.clone(), key.clone()),
+        cache: CacheInfo::new(miss_status(mode), Some(key)),
+    })
+}
+
+fn miss_status(mode: CacheMode) -> CacheStatus {
+    match mode {
+        CacheMode::Refresh => CacheStatus::Refreshed,
+        _ => CacheStatus::Miss,
+    }
+}
+
+/// Chunks to replay for a stream request: a cached stream transcript, or
+/// else a cached non-streaming completion turned into one chunk.
+async fn cached_chunks(
+    cache: &ResponseCache,
+    stream_key: &str,
+    request: &ChatCompletionRequest,
+) -> Result<Option<(Vec<CompletionsResponse>, Duration)>, OpenRouterError> {
+    if let Some(entry) = cache.lookup(stream_key).await {
+        if let Ok(chunks) = serde_json::from_value(entry.value.clone()) {
+            return Ok(Some((chunks, entry.age())));
+        }
+    }
+
+    let key = cache_key(CHAT_NAMESPACE, request)?;
+    let Some(entry) = cache.lookup(&key).await else {
+        return Ok(None);
+    };
+    let age = entry.age();
+    Ok(serde_json::from_value(completion_to_chunk(entry.value))
+        .ok()
+        .map(|chunk| (vec![chunk], age)))
+}
+
+/// Rewrite a `chat.completion` body as one `chat.completion.chunk` whose
+/// deltas carry each choice's full message.
+fn completion_to_chunk(mut completion: Value) -> Value {
+    completion["object"] = Value::from("chat.completion.chunk");
+    if let Some(choices) = completion["choices"].as_array_mut() {
+        for choice in choices {
+            let Some(choice) = choice.as_object_mut() else {
+                continue;
+            };
+            let Some(mut message) = choice.remove("message") else {
+                continue;
+            };
+            if let Some(tool_calls) = message["tool_calls"].as_array_mut() {
+                for (index, tool_call) in tool_calls.iter_mut().enumerate() {
+                    if tool_call.get("index").is_none() {
+                        tool_call["index"] = Value::from(index);
+                    }
+                }
+            }
+            choice.insert("delta".to_string(), message);
+        }
+    }
+    completion
+}
+
+/// Whether any choice in `response` reports an error.
+fn has_error(response: &CompletionsResponse) -> bool {
+    response.choices.iter().any(|choice| {
+        choice.error().is_some() || matches!(choice.finish_reason(), Some(FinishReason::Error))
+    })
+}
+
+/// Pass `live` through, caching its chunks once it ends without an error.
+fn record_stream(live: ChatStream, cache: ResponseCache, key: String) -> ChatStream {
+    let recorded = Arc::new(Mutex::new(Some(Vec::new())));
+    let observer = Arc::clone(&recorded);
+    let passthrough = live.map(move |item| {
+        let mut chunks = observer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match &item {
+            Ok(chunk) if has_error(chunk) => *chunks = None,
+            Ok(chunk) => {
+                let value = serde_json::to_value(chunk).ok();
+                match (chunks.as_mut(), value) {
+                    (Some(chunks), Some(value)) => chunks.push(value),
+                    _ => *chunks = None,
+                }
+            }
+            Err(_) => *chunks = None,
+        }
+        Some(item)
+    });
+    let finish = stream::once(async move {
+        let chunks = recorded
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+        if let Some(chunks) = chunks.filter(|chunks| !chunks.is_empty()) {
+            cache.store(&key, Value::Array(chunks)).await;
+        }
+        None
+    });
+    passthrough.chain(finish).filter_map(ready).boxed()
+}
@@ -1,19 +1,37 @@
+use std::path::{Path, PathBuf};
+
 use derive_builder::Builder;
 use futures_util::stream::BoxStream;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use tokio::io::AsyncWrite;
 
 #[cfg(feature = "legacy-completions")]
 use crate::api::legacy::completion;
+pub use crate::transport::{
+    RateLimiter, RetryPolicy, Timeouts,
+    hook::{Network, Transport},
+    middleware::{
+        ByteStream, Middleware, MiddlewareFuture, MiddlewareRequest, MiddlewareResponse, Next,
+    },
+};
 use crate::{
     api::{
         analytics, api_keys, audio, auth, byok, chat, credits, discovery, embeddings, files,
         generation, guardrails, images, messages, models, observability, organization, presets,
         rerank, responses, videos, workspaces,
     },
+    budget::BudgetGuard,
+    cache::{self, CacheMode, Cached, ResponseCache},
     error::OpenRouterError,
     strip_option_vec_setter,
+    transport::{HttpClient, hook::TransportHook, middleware::MiddlewareStack},
     types::{
-        ModelCategory, PaginationOptions, SupportedParameters,
+        JsonSchemaConfig, ModelCategory, PaginationOptions, Paginator, PollOptions, ResponseFormat,
+        Role, SupportedParameters,
         completion::CompletionsResponse,
+        poll::Poller,
+        response_format::{parse_structured, repair_prompt},
         stream::{
             ToolAwareStream, UnifiedStream, adapt_chat_stream, adapt_messages_stream,
             adapt_responses_stream,
@@ -42,8 +60,30 @@ pub struct OpenRouterClient {
     x_title: Option<String>,
     #[builder(setter(custom), default)]
     app_categories: Option<Vec<String>>,
-    #[builder(setter(into), default = "crate::transport::new_client()?")]
+    #[builder(setter(into), default = "self.default_http_client()?")]
     http_client: reqwest::Client,
+    /// Retry policy applied to every request sent by this client.
+    #[builder(setter(strip_option), default)]
+    retry_policy: Option<RetryPolicy>,
+    /// Connect, read, request, and stream-idle timeouts.
+    #[builder(default)]
+    timeouts: Timeouts,
+    /// Middleware run around every request, in registration order.
+    #[builder(setter(custom), default)]
+    middleware: MiddlewareStack,
+    /// Stand-in for the network below the retry, rate-limit, and timeout
+    /// policies, when configured.
+    #[builder(setter(custom), default)]
+    transport: Option<TransportHook>,
+    /// Client-side rate limits and in-flight cap applied before sending.
+    #[builder(setter(strip_option), default)]
+    rate_limiter: Option<RateLimiter>,
+    /// Response cache consulted by chat completions, when configured.
+    #[builder(setter(strip_option), default)]
+    cache: Option<ResponseCache>,
+    /// Spend tracking and budget limits checked before model requests.
+    #[builder(setter(strip_option), default)]
+    budget_guard: Option<BudgetGuard>,
 }
 
 impl OpenRouterClient {
@@ -178,13 +218,75 @@ impl OpenRouterClient {
         LegacyClient { client: self }
     }
 
-    pub(crate) fn http_client(&self) -> &reqwest::Client {
-        &self.http_client
+    /// Returns a copy of this client with per-call timeout overrides applied.
+    ///
+    /// Only the values set on `overrides` replace the client-wide settings.
+    /// `request` and `stream_idle` take effect immediately; `connect` and
+    /// `read` belong to the underlying HTTP client and are not changed here.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use openrouter_rs::{OpenRouterClient, api::chat, client::Timeouts};
+    /// # async fn example(client: OpenRouterClient, request: chat::ChatCompletionRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let response = client
+    ///     .with_timeouts(Timeouts::new().request(Duration::from_secs(5)))
+    ///     .chat()
+    ///     .create(&request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_timeouts(&self, overrides: Timeouts) -> OpenRouterClient {
+        let mut client = self.clone();
+        client.timeouts = self.timeouts.merge(overrides);
+        client
+    }
+
+    pub(crate) fn response_cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
+
+    pub(crate) fn http_client(&self) -> HttpClient {
+        HttpClient::new(self.http_client.clone())
+            .with_retry_policy(self.retry_policy.clone())
+            .with_timeouts(self.timeouts)
+            .with_middleware(self.middleware.clone())
+            .with_transport(self.transport.clone())
+            .with_rate_limiter(self.rate_limiter.clone())
+            .with_budget_guard(
+                self.budget_guard
+                    .as_ref()
+                    .map(|guard| guard.bind(&self.base_url, self.api_key.as_deref())),
+            )
     }
 }
 
 impl OpenRouterClientBuilder {
     strip_option_vec_setter!(app_categories, String);
+
+    /// Append a [`Middleware`] layer. Layers run in the order they are added.
+    pub fn middleware(&mut self, middleware: impl Middleware) -> &mut Self {
+        self.middleware
+            .get_or_insert_with(MiddlewareStack::default)
+            .push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Send every request attempt through `transport` instead of the network.
+    ///
+    /// Unlike [`middleware`](Self::middleware), a transport sits below the
+    /// retry policy, rate limiter, and request timeout, so they apply to its
+    /// responses. Setting a transport again replaces the previous one.
+    pub fn transport(&mut self, transport: impl Transport) -> &mut Self {
+        self.transport = Some(Some(TransportHook::new(transport)));
+        self
+    }
+
+    fn default_http_client(&self) -> Result<reqwest::Client, OpenRouterError> {
+        crate::transport::new_reqwest_client(&self.timeouts.unwrap_or_default())
+    }
 }
 
 #[doc(hidden)]
@@ -218,7 +320,7 @@ impl OpenRouterClient {
     ) -> Result<api_keys::ApiKey, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             api_keys::create_api_key_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 name,
@@ -239,7 +341,7 @@ impl OpenRouterClient {
     ) -> Result<api_keys::ApiKey, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             api_keys::create_api_key_in_workspace_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 name,
@@ -273,7 +375,7 @@ impl OpenRouterClient {
         &self,
     ) -> Result<api_keys::ApiKeyDetails, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            api_keys::get_current_api_key_with_client(self.http_client(), &self.base_url, api_key)
+            api_keys::get_current_api_key_with_client(&self.http_client(), &self.base_url, api_key)
                 .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
@@ -304,7 +406,7 @@ impl OpenRouterClient {
     pub async fn delete_api_key(&self, hash: &str) -> Result<bool, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             api_keys::delete_api_key_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 hash,
@@ -348,7 +450,7 @@ impl OpenRouterClient {
     ) -> Result<api_keys::ApiKey, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             api_keys::update_api_key_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 hash,
@@ -369,7 +471,7 @@ impl OpenRouterClient {
     ) -> Result<Vec<api_keys::ApiKey>, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             api_keys::list_api_keys_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -389,7 +491,7 @@ impl OpenRouterClient {
     ) -> Result<Vec<api_keys::ApiKey>, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             api_keys::list_api_keys_in_workspace_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -426,7 +528,7 @@ impl OpenRouterClient {
     pub async fn get_api_key(&self, hash: &str) -> Result<api_keys::ApiKey, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             api_keys::get_api_key_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 hash,
@@ -479,8 +581,13 @@ impl OpenRouterClient {
         request: &auth::CreateAuthCodeRequest,
     ) -> Result<auth::AuthCodeData, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            auth::create_auth_code_with_client(self.http_client(), &self.base_url, api_key, request)
-                .await
+            auth::create_auth_code_with_client(
+                &self.http_client(),
+                &self.base_url,
+                api_key,
+                request,
+            )
+            .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -493,7 +600,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::GuardrailListResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::list_guardrails_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -512,7 +619,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::GuardrailListResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::list_guardrails_in_workspace_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -531,7 +638,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::Guardrail, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::create_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 request,
@@ -546,7 +653,7 @@ impl OpenRouterClient {
     pub async fn get_guardrail(&self, id: &str) -> Result<guardrails::Guardrail, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::get_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -565,7 +672,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::Guardrail, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::update_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -581,7 +688,7 @@ impl OpenRouterClient {
     pub async fn delete_guardrail(&self, id: &str) -> Result<bool, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::delete_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -600,7 +707,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::GuardrailKeyAssignmentsResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::list_guardrail_key_assignments_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -620,7 +727,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::AssignedCountResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::bulk_assign_keys_to_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -640,7 +747,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::UnassignedCountResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::bulk_unassign_keys_from_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -660,7 +767,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::GuardrailMemberAssignmentsResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::list_guardrail_member_assignments_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -680,7 +787,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::AssignedCountResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::bulk_assign_members_to_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -700,7 +807,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::UnassignedCountResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::bulk_unassign_members_from_guardrail_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -719,7 +826,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::GuardrailKeyAssignmentsResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::list_key_assignments_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -737,7 +844,7 @@ impl OpenRouterClient {
     ) -> Result<guardrails::GuardrailMemberAssignmentsResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             guardrails::list_member_assignments_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -782,7 +889,7 @@ impl OpenRouterClient {
         code_challenge_method: Option<auth::CodeChallengeMethod>,
     ) -> Result<auth::AuthResponse, OpenRouterError> {
         auth::exchange_code_for_api_key_with_client(
-            self.http_client(),
+            &self.http_client(),
             &self.base_url,
             code,
             code_verifier,
@@ -824,7 +931,7 @@ impl OpenRouterClient {
     ) -> Result<CompletionsResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             chat::send_chat_completion_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -878,7 +985,7 @@ impl OpenRouterClient {
     {
         if let Some(api_key) = &self.api_key {
             chat::stream_chat_completion_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -965,7 +1072,7 @@ impl OpenRouterClient {
     ) -> Result<responses::ResponsesResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             responses::create_response_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -997,7 +1104,7 @@ impl OpenRouterClient {
     > {
         if let Some(api_key) = &self.api_key {
             responses::stream_response_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1027,7 +1134,7 @@ impl OpenRouterClient {
     ) -> Result<messages::AnthropicMessagesResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             messages::create_message_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1051,7 +1158,7 @@ impl OpenRouterClient {
     > {
         if let Some(api_key) = &self.api_key {
             messages::stream_messages_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1084,7 +1191,7 @@ impl OpenRouterClient {
     ) -> Result<presets::PresetWithDesignatedVersion, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             presets::create_chat_completion_preset_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 slug,
@@ -1106,7 +1213,7 @@ impl OpenRouterClient {
     ) -> Result<presets::PresetWithDesignatedVersion, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             presets::create_response_preset_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 slug,
@@ -1128,7 +1235,7 @@ impl OpenRouterClient {
     ) -> Result<presets::PresetWithDesignatedVersion, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             presets::create_message_preset_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 slug,
@@ -1147,7 +1254,7 @@ impl OpenRouterClient {
     ) -> Result<presets::ListPresetsResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             presets::list_presets_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -1165,7 +1272,7 @@ impl OpenRouterClient {
     ) -> Result<presets::PresetWithDesignatedVersion, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             presets::get_preset_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 slug,
@@ -1184,7 +1291,7 @@ impl OpenRouterClient {
     ) -> Result<presets::ListPresetVersionsResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             presets::list_preset_versions_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 slug,
@@ -1204,7 +1311,7 @@ impl OpenRouterClient {
     ) -> Result<presets::PresetDesignatedVersion, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             presets::get_preset_version_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 slug,
@@ -1231,7 +1338,7 @@ impl OpenRouterClient {
     ) -> Result<embeddings::EmbeddingResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             embeddings::create_embedding_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1252,7 +1359,7 @@ impl OpenRouterClient {
     ) -> Result<rerank::RerankResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             rerank::create_rerank_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1273,7 +1380,7 @@ impl OpenRouterClient {
     ) -> Result<Vec<u8>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             audio::create_speech_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1294,7 +1401,7 @@ impl OpenRouterClient {
     ) -> Result<audio::TranscriptionResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             audio::create_transcription_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1324,7 +1431,7 @@ impl OpenRouterClient {
     ) -> Result<images::ImageGenerationResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             images::create_image_generation_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1348,7 +1455,7 @@ impl OpenRouterClient {
     > {
         if let Some(api_key) = &self.api_key {
             images::stream_image_generation_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1365,7 +1472,8 @@ impl OpenRouterClient {
     /// List all available image generation models.
     pub async fn list_image_models(&self) -> Result<Vec<images::ImageModel>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            images::list_image_models_with_client(self.http_client(), &self.base_url, api_key).await
+            images::list_image_models_with_client(&self.http_client(), &self.base_url, api_key)
+                .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1379,7 +1487,7 @@ impl OpenRouterClient {
     ) -> Result<images::ImageModelEndpointsResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             images::list_image_model_endpoints_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 author,
@@ -1398,7 +1506,7 @@ impl OpenRouterClient {
     ) -> Result<videos::VideoGenerationResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             videos::create_video_generation_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 &self.x_title,
@@ -1415,7 +1523,8 @@ impl OpenRouterClient {
     /// List all available video generation models.
     pub async fn list_video_models(&self) -> Result<Vec<videos::VideoModel>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            videos::list_video_models_with_client(self.http_client(), &self.base_url, api_key).await
+            videos::list_video_models_with_client(&self.http_client(), &self.base_url, api_key)
+                .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1428,7 +1537,7 @@ impl OpenRouterClient {
     ) -> Result<videos::VideoGenerationResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             videos::get_video_generation_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 job_id,
@@ -1447,7 +1556,27 @@ impl OpenRouterClient {
     ) -> Result<Vec<u8>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             videos::get_video_content_with_client(
-                self.http_client(),
+                &self.http_client(),
+                &self.base_url,
+                api_key,
+                job_id,
+                index,
+            )
+            .await
+        } else {
+            Err(OpenRouterError::KeyNotConfigured)
+        }
+    }
+
+    /// Stream video output for a completed job instead of buffering it.
+    pub async fn get_video_content_stream(
+        &self,
+        job_id: &str,
+        index: Option<u32>,
+    ) -> Result<files::FileDownload, OpenRouterError> {
+        if let Some(api_key) = &self.api_key {
+            videos::get_video_content_stream_with_client(
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 job_id,
@@ -1459,6 +1588,48 @@ impl OpenRouterClient {
         }
     }
 
+    /// Poll a video generation job, yielding each status change.
+    pub fn video_generation_updates(
+        &self,
+        job_id: &str,
+        options: PollOptions,
+    ) -> Result<
+        BoxStream<'static, Result<videos::VideoGenerationResponse, OpenRouterError>>,
+        OpenRouterError,
+    > {
+        let api_key = self
+            .api_key
+            .clone()
+            .ok_or(OpenRouterError::KeyNotConfigured)?;
+        Ok(videos::video_generation_updates_with_client(
+            self.http_client(),
+            self.base_url.clone(),
+            api_key,
+            job_id.to_string(),
+            options,
+        ))
+    }
+
+    /// Poll a video generation job until it completes.
+    pub async fn wait_for_video_generation(
+        &self,
+        job_id: &str,
+        options: PollOptions,
+    ) -> Result<videos::VideoGenerationResponse, OpenRouterError> {
+        if let Some(api_key) = &self.api_key {
+            videos::wait_for_video_generation_with_client(
+                self.http_client(),
+                self.base_url.clone(),
+                api_key.clone(),
+                job_id.to_string(),
+                options,
+            )
+            .await
+        } else {
+            Err(OpenRouterError::KeyNotConfigured)
+        }
+    }
+
     /// List files in the default or selected workspace.
     pub async fn list_files(
         &self,
@@ -1468,7 +1639,7 @@ impl OpenRouterClient {
     ) -> Result<files::FileListResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             files::list_files_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 limit,
@@ -1489,7 +1660,7 @@ impl OpenRouterClient {
     ) -> Result<files::FileMetadata, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             files::upload_file_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 request,
@@ -1501,6 +1672,26 @@ impl OpenRouterClient {
         }
     }
 
+    /// Upload a file from a stream without buffering it in memory.
+    pub async fn upload_file_stream(
+        &self,
+        upload: files::StreamingUpload,
+        workspace_id: Option<&str>,
+    ) -> Result<files::FileMetadata, OpenRouterError> {
+        if let Some(api_key) = &self.api_key {
+            files::upload_file_stream_with_client(
+                &self.http_client(),
+                &self.base_url,
+                api_key,
+                upload,
+                workspace_id,
+            )
+            .await
+        } else {
+            Err(OpenRouterError::KeyNotConfigured)
+        }
+    }
+
     /// Get metadata for one file.
     pub async fn get_file_metadata(
         &self,
@@ -1509,7 +1700,7 @@ impl OpenRouterClient {
     ) -> Result<files::FileMetadata, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             files::get_file_metadata_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 file_id,
@@ -1529,7 +1720,27 @@ impl OpenRouterClient {
     ) -> Result<Vec<u8>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             files::download_file_content_with_client(
-                self.http_client(),
+                &self.http_client(),
+                &self.base_url,
+                api_key,
+                file_id,
+                workspace_id,
+            )
+            .await
+        } else {
+            Err(OpenRouterError::KeyNotConfigured)
+        }
+    }
+
+    /// Stream raw file content without buffering it in memory.
+    pub async fn download_file_stream(
+        &self,
+        file_id: &str,
+        workspace_id: Option<&str>,
+    ) -> Result<files::FileDownload, OpenRouterError> {
+        if let Some(api_key) = &self.api_key {
+            files::download_file_stream_with_client(
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 file_id,
@@ -1549,7 +1760,7 @@ impl OpenRouterClient {
     ) -> Result<files::FileDeleteResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             files::delete_file_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 file_id,
@@ -1565,7 +1776,7 @@ impl OpenRouterClient {
     pub async fn list_embedding_models(&self) -> Result<Vec<models::Model>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             embeddings::list_embedding_models_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
             )
@@ -1607,7 +1818,7 @@ impl OpenRouterClient {
     ) -> Result<credits::CoinbaseChargeData, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             credits::create_coinbase_charge_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 request,
@@ -1637,7 +1848,7 @@ impl OpenRouterClient {
     /// ```
     pub async fn get_credits(&self) -> Result<credits::CreditsData, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            credits::get_credits_with_client(self.http_client(), &self.base_url, api_key).await
+            credits::get_credits_with_client(&self.http_client(), &self.base_url, api_key).await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1669,7 +1880,7 @@ impl OpenRouterClient {
         id: impl Into<String>,
     ) -> Result<generation::GenerationData, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            generation::get_generation_with_client(self.http_client(), &self.base_url, api_key, id)
+            generation::get_generation_with_client(&self.http_client(), &self.base_url, api_key, id)
                 .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
@@ -1699,7 +1910,7 @@ impl OpenRouterClient {
     ) -> Result<generation::GenerationContentData, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             generation::get_generation_content_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 id,
@@ -1729,8 +1940,14 @@ impl OpenRouterClient {
     /// ```
     pub async fn list_models(&self) -> Result<Vec<models::Model>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            models::list_models_with_client(self.http_client(), &self.base_url, api_key, None, None)
-                .await
+            models::list_models_with_client(
+                &self.http_client(),
+                &self.base_url,
+                api_key,
+                None,
+                None,
+            )
+            .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1743,7 +1960,7 @@ impl OpenRouterClient {
     ) -> Result<Vec<models::Model>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             models::list_models_with_params_and_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 params,
@@ -1761,8 +1978,14 @@ impl OpenRouterClient {
         slug: &str,
     ) -> Result<models::Model, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            models::get_model_with_client(self.http_client(), &self.base_url, api_key, author, slug)
-                .await
+            models::get_model_with_client(
+                &self.http_client(),
+                &self.base_url,
+                api_key,
+                author,
+                slug,
+            )
+            .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1795,7 +2018,7 @@ impl OpenRouterClient {
     ) -> Result<Vec<models::Model>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             models::list_models_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 Some(category),
@@ -1834,7 +2057,7 @@ impl OpenRouterClient {
     ) -> Result<Vec<models::Model>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             models::list_models_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 None,
@@ -1875,7 +2098,7 @@ impl OpenRouterClient {
     ) -> Result<models::EndpointData, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             models::list_model_endpoints_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 author,
@@ -1893,7 +2116,8 @@ impl OpenRouterClient {
     /// for consistency with other client operations.
     pub async fn list_providers(&self) -> Result<Vec<discovery::Provider>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            discovery::list_providers_with_client(self.http_client(), &self.base_url, api_key).await
+            discovery::list_providers_with_client(&self.http_client(), &self.base_url, api_key)
+                .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1904,8 +2128,12 @@ impl OpenRouterClient {
     /// Equivalent to `GET /models/user`.
     pub async fn list_models_for_user(&self) -> Result<Vec<discovery::UserModel>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            discovery::list_models_for_user_with_client(self.http_client(), &self.base_url, api_key)
-                .await
+            discovery::list_models_for_user_with_client(
+                &self.http_client(),
+                &self.base_url,
+                api_key,
+            )
+            .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1916,7 +2144,7 @@ impl OpenRouterClient {
     /// Equivalent to `GET /models/count`.
     pub async fn count_models(&self) -> Result<discovery::ModelsCountData, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            discovery::count_models_with_client(self.http_client(), &self.base_url, api_key).await
+            discovery::count_models_with_client(&self.http_client(), &self.base_url, api_key).await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
         }
@@ -1932,7 +2160,7 @@ impl OpenRouterClient {
     ) -> Result<discovery::RankingsDailyResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             discovery::get_rankings_daily_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 start_date,
@@ -1951,7 +2179,7 @@ impl OpenRouterClient {
     ) -> Result<discovery::AppRankingsResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             discovery::get_app_rankings_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 params,
@@ -1971,7 +2199,7 @@ impl OpenRouterClient {
     ) -> Result<discovery::TaskClassificationsResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             discovery::get_task_classifications_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 window,
@@ -1991,7 +2219,7 @@ impl OpenRouterClient {
     ) -> Result<discovery::UnifiedBenchmarksResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             discovery::get_benchmarks_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 params,
@@ -2011,7 +2239,7 @@ impl OpenRouterClient {
     ) -> Result<discovery::BenchmarksAAResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             discovery::get_benchmarks_artificial_analysis_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 max_results,
@@ -2033,7 +2261,7 @@ impl OpenRouterClient {
     ) -> Result<discovery::BenchmarksDAResponse, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
             discovery::get_benchmarks_design_arena_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 api_key,
                 arena,
@@ -2053,7 +2281,7 @@ impl OpenRouterClient {
         &self,
     ) -> Result<Vec<discovery::PublicEndpoint>, OpenRouterError> {
         if let Some(api_key) = &self.api_key {
-            discovery::list_zdr_endpoints_with_client(self.http_client(), &self.base_url, api_key)
+            discovery::list_zdr_endpoints_with_client(&self.http_client(), &self.base_url, api_key)
                 .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
@@ -2074,7 +2302,7 @@ impl OpenRouterClient {
     ) -> Result<Vec<discovery::ActivityItem>, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             discovery::get_activity_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 date,
@@ -2089,7 +2317,7 @@ impl OpenRouterClient {
     pub async fn get_analytics_meta(&self) -> Result<analytics::AnalyticsMeta, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             analytics::get_analytics_meta_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
             )
@@ -2106,7 +2334,7 @@ impl OpenRouterClient {
     ) -> Result<analytics::AnalyticsQueryResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             analytics::query_analytics_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 request,
@@ -2126,7 +2354,7 @@ impl OpenRouterClient {
     ) -> Result<byok::ByokKeyListResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             byok::list_byok_keys_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -2146,7 +2374,7 @@ impl OpenRouterClient {
     ) -> Result<byok::ByokKey, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             byok::create_byok_key_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 request,
@@ -2160,7 +2388,7 @@ impl OpenRouterClient {
     /// Get a BYOK provider credential (`GET /byok/{id}`). Requires a management key.
     pub async fn get_byok_key(&self, id: &str) -> Result<byok::ByokKey, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
-            byok::get_byok_key_with_client(self.http_client(), &self.base_url, management_key, id)
+            byok::get_byok_key_with_client(&self.http_client(), &self.base_url, management_key, id)
                 .await
         } else {
             Err(OpenRouterError::KeyNotConfigured)
@@ -2175,7 +2403,7 @@ impl OpenRouterClient {
     ) -> Result<byok::ByokKey, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             byok::update_byok_key_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2191,7 +2419,7 @@ impl OpenRouterClient {
     pub async fn delete_byok_key(&self, id: &str) -> Result<bool, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             byok::delete_byok_key_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2210,7 +2438,7 @@ impl OpenRouterClient {
     ) -> Result<observability::ObservabilityDestinationListResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             observability::list_observability_destinations_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -2229,7 +2457,7 @@ impl OpenRouterClient {
     ) -> Result<observability::ObservabilityDestination, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             observability::create_observability_destination_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 request,
@@ -2247,7 +2475,7 @@ impl OpenRouterClient {
     ) -> Result<observability::ObservabilityDestination, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             observability::get_observability_destination_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2266,7 +2494,7 @@ impl OpenRouterClient {
     ) -> Result<observability::ObservabilityDestination, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             observability::update_observability_destination_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2285,7 +2513,7 @@ impl OpenRouterClient {
     ) -> Result<bool, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             observability::delete_observability_destination_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2303,7 +2531,7 @@ impl OpenRouterClient {
     ) -> Result<organization::OrganizationMembersResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             organization::list_organization_members_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -2321,7 +2549,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::WorkspaceListResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::list_workspaces_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 pagination,
@@ -2339,7 +2567,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::Workspace, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::create_workspace_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 request,
@@ -2354,7 +2582,7 @@ impl OpenRouterClient {
     pub async fn get_workspace(&self, id: &str) -> Result<workspaces::Workspace, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::get_workspace_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2373,7 +2601,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::Workspace, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::update_workspace_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2393,7 +2621,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::Workspace, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::update_workspace_with_cleared_io_logging_api_key_ids_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2409,7 +2637,7 @@ impl OpenRouterClient {
     pub async fn delete_workspace(&self, id: &str) -> Result<bool, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::delete_workspace_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2427,7 +2655,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::ListWorkspaceBudgetsResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::list_workspace_budgets_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2447,7 +2675,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::WorkspaceBudget, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::upsert_workspace_budget_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2468,7 +2696,7 @@ impl OpenRouterClient {
     ) -> Result<bool, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::delete_workspace_budget_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2488,7 +2716,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::WorkspaceMembersAddResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::add_workspace_members_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2508,7 +2736,7 @@ impl OpenRouterClient {
     ) -> Result<workspaces::WorkspaceMembersRemoveResponse, OpenRouterError> {
         if let Some(management_key) = &self.management_key {
             workspaces::remove_workspace_members_with_client(
-                self.http_client(),
+                &self.http_client(),
                 &self.base_url,
                 management_key,
                 id,
@@ -2529,20 +2757,103 @@ pub struct ChatClient<'a> {
 
 impl<'a> ChatClient<'a> {
     /// Create a chat completion (`POST /chat/completions`).
+    ///
+    /// Served from the client's [`ResponseCache`] when one is configured and
+    /// holds this request.
     pub async fn create(
         &self,
         request: &chat::ChatCompletionRequest,
     ) -> Result<CompletionsResponse, OpenRouterError> {
-        self.client.send_chat_completion(request).await
+        self.create_with_cache(request, CacheMode::Use)
+            .await
+            .map(|cached| cached.response)
+    }
+
+    /// Create a chat completion with an explicit [`CacheMode`], reporting
+    /// whether the response came from the cache.
+    pub async fn create_with_cache(
+        &self,
+        request: &chat::ChatCompletionRequest,
+        mode: CacheMode,
+    ) -> Result<Cached<CompletionsResponse>, OpenRouterError> {
+        cache::create_chat(self.client, request, mode).await
+    }
+
+    /// Create a chat completion whose reply is deserialized into `T`.
+    ///
+    /// A strict `json_schema` `response_format` is generated from `T`. A reply
+    /// that does not parse fails with [`OpenRouterError::StructuredOutput`],
+    /// which keeps the raw text.
+    pub async fn create_structured<T>(
+        &self,
+        request: &chat::ChatCompletionRequest,
+    ) -> Result<T, OpenRouterError>
+    where
+        T: JsonSchema + DeserializeOwned,
+    {
+        self.create_structured_with_repair(request, 0).await
+    }
+
+    /// Like [`create_structured`](Self::create_structured), but on a parse
+    /// failure sends the error back to the model and retries, up to
+    /// `max_repairs` extra calls.
+    pub async fn create_structured_with_repair<T>(
+        &self,
+        request: &chat::ChatCompletionRequest,
+        max_repairs: usize,
+    ) -> Result<T, OpenRouterError>
+    where
+        T: JsonSchema + DeserializeOwned,
+    {
+        let mut request = request.with_response_format(ResponseFormat::json_schema_for::<T>());
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let response = self.create(&request).await?;
+            let raw = response
+                .choices
+                .first()
+                .and_then(|choice| choice.content())
+                .unwrap_or_default()
+                .to_string();
+            let error = match parse_structured::<T>(&raw, attempts) {
+                Ok(value) => return Ok(value),
+                Err(error) if attempts > max_repairs => return Err(error.into()),
+                Err(error) => error,
+            };
+
+            let mut messages = request.messages().to_vec();
+            messages.push(chat::Message::new(Role::Assistant, raw));
+            messages.push(chat::Message::new(Role::User, repair_prompt(&error)));
+            request = request.with_messages(messages);
+        }
     }
 
     /// Stream chat completion chunks.
+    ///
+    /// Cached results are replayed as a synthetic stream when the client has
+    /// a [`ResponseCache`].
     pub async fn stream(
         &self,
         request: &chat::ChatCompletionRequest,
     ) -> Result<BoxStream<'static, Result<CompletionsResponse, OpenRouterError>>, OpenRouterError>
     {
-        self.client.stream_chat_completion(request).await
+        self.stream_with_cache(request, CacheMode::Use)
+            .await
+            .map(|cached| cached.response)
+    }
+
+    /// Stream chat completion chunks with an explicit [`CacheMode`],
+    /// reporting whether the stream is replayed from the cache.
+    pub async fn stream_with_cache(
+        &self,
+        request: &chat::ChatCompletionRequest,
+        mode: CacheMode,
+    ) -> Result<
+        Cached<BoxStream<'static, Result<CompletionsResponse, OpenRouterError>>>,
+        OpenRouterError,
+    > {
+        cache::stream_chat(self.client, request, mode).await
     }
 
     /// Stream chat completion chunks with tool-call-aware aggregation.
@@ -2550,7 +2861,7 @@ impl<'a> ChatClient<'a> {
         &self,
         request: &chat::ChatCompletionRequest,
     ) -> Result<ToolAwareStream, OpenRouterError> {
-        self.client.stream_chat_completion_tool_aware(request).await
+        Ok(ToolAwareStream::new(self.stream(request).await?))
     }
 
     /// Stream chat events using the unified stream abstraction.
@@ -2558,7 +2869,7 @@ impl<'a> ChatClient<'a> {
         &self,
         request: &chat::ChatCompletionRequest,
     ) -> Result<UnifiedStream, OpenRouterError> {
-        self.client.stream_chat_completion_unified(request).await
+        Ok(adapt_chat_stream(self.stream(request).await?))
     }
 }
 
@@ -2577,6 +2888,58 @@ impl<'a> ResponsesClient<'a> {
         self.client.create_response(request).await
     }
 
+    /// Create a response whose output text is deserialized into `T`.
+    ///
+    /// A strict `json_schema` `text.format` is generated from `T`. Output that
+    /// does not parse fails with [`OpenRouterError::StructuredOutput`], which
+    /// keeps the raw text.
+    pub async fn create_structured<T>(
+        &self,
+        request: &responses::ResponsesRequest,
+    ) -> Result<T, OpenRouterError>
+    where
+        T: JsonSchema + DeserializeOwned,
+    {
+        self.create_structured_with_repair(request, 0).await
+    }
+
+    /// Like [`create_structured`](Self::create_structured), but on a parse
+    /// failure sends the error back to the model and retries, up to
+    /// `max_repairs` extra calls.
+    pub async fn create_structured_with_repair<T>(
+        &self,
+        request: &responses::ResponsesRequest,
+        max_repairs: usize,
+    ) -> Result<T, OpenRouterError>
+    where
+        T: JsonSchema + DeserializeOwned,
+    {
+        let config = JsonSchemaConfig::for_type::<T>();
+        let mut request = request.with_text_format(config.into());
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let response = self.create(&request).await?;
+            let raw = response.output_text().unwrap_or_default();
+            let error = match parse_structured::<T>(&raw, attempts) {
+                Ok(value) => return Ok(value),
+                Err(error) if attempts > max_repairs => return Err(error.into()),
+                Err(error) => error,
+            };
+
+            let mut input = match request.input() {
+                Some(responses::ResponsesInput::Items(items)) => items.clone(),
+                Some(responses::ResponsesInput::Text(text)) => {
+                    vec![responses::ResponsesInputItem::user(text.as_str())]
+                }
+                None => Vec::new(),
+            };
+            input.push(responses::ResponsesInputItem::assistant(raw));
+            input.push(responses::ResponsesInputItem::user(repair_prompt(&error)));
+            request = request.with_input(input.into());
+        }
+    }
+
     /// Stream response events (`POST /responses`, `stream=true`).
     pub async fn stream(
         &self,
@@ -2779,6 +3142,98 @@ impl<'a> VideosClient<'a> {
     ) -> Result<Vec<u8>, OpenRouterError> {
         self.client.get_video_content(job_id, index).await
     }
+
+    /// Stream video output for a completed job chunk by chunk.
+    pub async fn content_stream(
+        &self,
+        job_id: &str,
+        index: Option<u32>,
+    ) -> Result<files::FileDownload, OpenRouterError> {
+        self.client.get_video_content_stream(job_id, index).await
+    }
+
+    /// Poll a job and yield its response each time the status changes, ending
+    /// after the final status. Useful for progress displays.
+    pub fn status_updates(
+        &self,
+        job_id: &str,
+        options: PollOptions,
+    ) -> Result<
+        BoxStream<'static, Result<videos::VideoGenerationResponse, OpenRouterError>>,
+        OpenRouterError,
+    > {
+        self.client.video_generation_updates(job_id, options)
+    }
+
+    /// Poll a job until it completes, returning the final response.
+    ///
+    /// A job that ends as `failed`, `cancelled`, or `expired` returns
+    /// [`OpenRouterError::JobFailed`]. The options' deadline and cancellation
+    /// token end the wait with a poll timeout or [`OpenRouterError::Cancelled`].
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// use openrouter_rs::{OpenRouterClient, api::videos::VideoGenerationRequest, types::PollOptions};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+    /// let request = VideoGenerationRequest::builder()
+    ///     .model("google/veo-3.1")
+    ///     .prompt("A paper boat drifting down a rainy street")
+    ///     .build()?;
+    /// let job = client.videos().create(&request).await?;
+    ///
+    /// let options = PollOptions::new().deadline(Duration::from_secs(20 * 60));
+    /// client.videos().wait_and_save(&job.id, options, "boat.mp4").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_completion(
+        &self,
+        job_id: &str,
+        options: PollOptions,
+    ) -> Result<videos::VideoGenerationResponse, OpenRouterError> {
+        self.client.wait_for_video_generation(job_id, options).await
+    }
+
+    /// Wait for a job to complete, then stream its first output to `path`.
+    ///
+    /// The options' deadline and cancellation token also bound the download.
+    /// It is written next to `path` with a `.part` suffix and renamed into
+    /// place once complete, so a failed download never leaves a truncated
+    /// file at `path`.
+    pub async fn wait_and_save(
+        &self,
+        job_id: &str,
+        options: PollOptions,
+        path: impl AsRef<Path>,
+    ) -> Result<videos::VideoGenerationResponse, OpenRouterError> {
+        let poller = Poller::new(options.clone());
+        let response = self.wait_for_completion(job_id, options).await?;
+
+        let path = path.as_ref();
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(".part");
+        let partial = PathBuf::from(partial);
+        let saved = poller
+            .bound(async {
+                let download = self.content_stream(job_id, None).await?;
+                let mut file = tokio::fs::File::create(&partial).await?;
+                download.write_to(&mut file).await?;
+                Ok(())
+            })
+            .await;
+        let saved = match saved {
+            Ok(()) => tokio::fs::rename(&partial, path).await.map_err(Into::into),
+            Err(error) => Err(error),
+        };
+        if let Err(error) = saved {
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(error);
+        }
+        Ok(response)
+    }
 }
 
 /// Domain client for file endpoints.
@@ -2798,6 +3253,30 @@ impl<'a> FilesClient<'a> {
         self.client.list_files(limit, cursor, workspace_id).await
     }
 
+    /// Stream every file (`GET /files`), following the list cursor.
+    pub fn paginate(
+        &self,
+        page_size: Option<u32>,
+        workspace_id: Option<&str>,
+    ) -> Paginator<'a, files::FileMetadata> {
+        let client = self.client;
+        let workspace_id = workspace_id.map(str::to_string);
+        Paginator::cursor(move |cursor| {
+            let workspace_id = workspace_id.clone();
+            async move {
+                let page = client
+                    .list_files(page_size, cursor.as_deref(), workspace_id.as_deref())
+                    .await?;
+                let next_cursor = if page.has_more {
+                    page.cursor.or(page.last_id)
+                } else {
+                    None
+                };
+                Ok((page.data, next_cursor))
+            }
+        })
+    }
+
     /// Upload a file (`POST /files`).
     pub async fn upload(
         &self,
@@ -2807,6 +3286,31 @@ impl<'a> FilesClient<'a> {
         self.client.upload_file(request, workspace_id).await
     }
 
+    /// Upload a file from a stream (`POST /files`) without buffering it in memory.
+    ///
+    /// The `request` timeout does not apply; see [`files::StreamingUpload`].
+    pub async fn upload_stream(
+        &self,
+        upload: files::StreamingUpload,
+        workspace_id: Option<&str>,
+    ) -> Result<files::FileMetadata, OpenRouterError> {
+        self.client.upload_file_stream(upload, workspace_id).await
+    }
+
+    /// Upload a file from disk (`POST /files`), streaming its content.
+    pub async fn upload_from_path(
+        &self,
+        path: impl AsRef<Path>,
+        mime_type: Option<&str>,
+        workspace_id: Option<&str>,
+    ) -> Result<files::FileMetadata, OpenRouterError> {
+        let mut upload = files::StreamingUpload::from_path(path).await?;
+        if let Some(mime_type) = mime_type {
+            upload = upload.mime_type(mime_type);
+        }
+        self.upload_stream(upload, workspace_id).await
+    }
+
     /// Get file metadata (`GET /files/{file_id}`).
     pub async fn get_metadata(
         &self,
@@ -2827,6 +3331,33 @@ impl<'a> FilesClient<'a> {
             .await
     }
 
+    /// Stream file content (`GET /files/{file_id}/content`) chunk by chunk.
+    pub async fn download_stream(
+        &self,
+        file_id: &str,
+        workspace_id: Option<&str>,
+    ) -> Result<files::FileDownload, OpenRouterError> {
+        self.client
+            .download_file_stream(file_id, workspace_id)
+            .await
+    }
+
+    /// Stream file content into `writer`, returning the number of bytes written.
+    pub async fn download_to_writer<W>(
+        &self,
+        file_id: &str,
+        workspace_id: Option<&str>,
+        writer: &mut W,
+    ) -> Result<u64, OpenRouterError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        self.download_stream(file_id, workspace_id)
+            .await?
+            .write_to(writer)
+            .await
+    }
+
     /// Delete a file (`DELETE /files/{file_id}`).
     pub async fn delete(
         &self,
@@ -3111,6 +3642,21 @@ impl<'a> ManagementClient<'a> {
             .await
     }
 
+    /// Stream every API key (`GET /keys`), fetching `page_size` keys per request.
+    pub fn paginate_api_keys(
+        &self,
+        page_size: Option<u32>,
+        include_disabled: Option<bool>,
+    ) -> Paginator<'a, api_keys::ApiKey> {
+        let client = self.client;
+        Paginator::offset(page_size, move |pagination| async move {
+            let keys = client
+                .list_api_keys_paginated(Some(pagination), include_disabled)
+                .await?;
+            Ok((keys, None))
+        })
+    }
+
     /// List API keys scoped to a workspace (`GET /keys?workspace_id=...`).
     pub async fn list_api_keys_in_workspace(
         &self,
@@ -3210,6 +3756,32 @@ impl<'a> ManagementClient<'a> {
             .await
     }
 
+    /// Stream every BYOK provider credential (`GET /byok`).
+    pub fn paginate_byok_keys(
+        &self,
+        page_size: Option<u32>,
+        workspace_id: Option<&str>,
+        provider: Option<&str>,
+    ) -> Paginator<'a, byok::ByokKey> {
+        let client = self.client;
+        let workspace_id = workspace_id.map(str::to_string);
+        let provider = provider.map(str::to_string);
+        Paginator::offset(page_size, move |pagination| {
+            let workspace_id = workspace_id.clone();
+            let provider = provider.clone();
+            async move {
+                let page = client
+                    .list_byok_keys(
+                        Some(pagination),
+                        workspace_id.as_deref(),
+                        provider.as_deref(),
+                    )
+                    .await?;
+                Ok((page.data, Some(page.total_count)))
+            }
+        })
+    }
+
     /// Create a BYOK provider credential (`POST /byok`).
     pub async fn create_byok_key(
         &self,
@@ -3248,6 +3820,25 @@ impl<'a> ManagementClient<'a> {
             .await
     }
 
+    /// Stream every observability destination (`GET /observability/destinations`).
+    pub fn paginate_observability_destinations(
+        &self,
+        page_size: Option<u32>,
+        workspace_id: Option<&str>,
+    ) -> Paginator<'a, observability::ObservabilityDestination> {
+        let client = self.client;
+        let workspace_id = workspace_id.map(str::to_string);
+        Paginator::offset(page_size, move |pagination| {
+            let workspace_id = workspace_id.clone();
+            async move {
+                let page = client
+                    .list_observability_destinations(Some(pagination), workspace_id.as_deref())
+                    .await?;
+                Ok((page.data, Some(page.total_count)))
+            }
+        })
+    }
+
     /// Create an observability destination (`POST /observability/destinations`).
     pub async fn create_observability_destination(
         &self,
@@ -3291,6 +3882,18 @@ impl<'a> ManagementClient<'a> {
         self.client.list_guardrails(pagination).await
     }
 
+    /// Stream every guardrail (`GET /guardrails`).
+    pub fn paginate_guardrails(
+        &self,
+        page_size: Option<u32>,
+    ) -> Paginator<'a, guardrails::Guardrail> {
+        let client = self.client;
+        Paginator::offset(page_size, move |pagination| async move {
+            let page = client.list_guardrails(Some(pagination)).await?;
+            Ok((page.data, Some(page.total_count as u64)))
+        })
+    }
+
     /// List guardrails scoped to a workspace (`GET /guardrails?workspace_id=...`).
     pub async fn list_guardrails_in_workspace(
         &self,
@@ -3340,6 +3943,25 @@ impl<'a> ManagementClient<'a> {
             .await
     }
 
+    /// Stream every key assignment of a guardrail.
+    pub fn paginate_guardrail_key_assignments(
+        &self,
+        id: &str,
+        page_size: Option<u32>,
+    ) -> Paginator<'a, guardrails::GuardrailKeyAssignment> {
+        let client = self.client;
+        let id = id.to_string();
+        Paginator::offset(page_size, move |pagination| {
+            let id = id.clone();
+            async move {
+                let page = client
+                    .list_guardrail_key_assignments(&id, Some(pagination))
+                    .await?;
+                Ok((page.data, Some(page.total_count as u64)))
+            }
+        })
+    }
+
     /// Create key assignments for a guardrail.
     pub async fn create_guardrail_key_assignments(
         &self,
@@ -3417,6 +4039,18 @@ impl<'a> ManagementClient<'a> {
         self.client.list_organization_members(pagination).await
     }
 
+    /// Stream every organization member (`GET /organization/members`).
+    pub fn paginate_organization_members(
+        &self,
+        page_size: Option<u32>,
+    ) -> Paginator<'a, organization::OrganizationMember> {
+        let client = self.client;
+        Paginator::offset(page_size, move |pagination| async move {
+            let page = client.list_organization_members(Some(pagination)).await?;
+            Ok((page.data, Some(page.total_count)))
+        })
+    }
+
     /// List workspaces (`GET /workspaces`).
     pub async fn list_workspaces(
         &self,
@@ -3425,6 +4059,18 @@ impl<'a> ManagementClient<'a> {
         self.client.list_workspaces(pagination).await
     }
 
+    /// Stream every workspace (`GET /workspaces`).
+    pub fn paginate_workspaces(
+        &self,
+        page_size: Option<u32>,
+    ) -> Paginator<'a, workspaces::Workspace> {
+        let client = self.client;
+        Paginator::offset(page_size, move |pagination| async move {
+            let page = client.list_workspaces(Some(pagination)).await?;
+            Ok((page.data, Some(page.total_count as u64)))
+        })
+    }
+
     /// Create a workspace (`POST /workspaces`).
     pub async fn create_workspace(
         &self,
@@ -3544,7 +4190,7 @@ impl<'a> LegacyCompletionsClient<'a> {
     ) -> Result<CompletionsResponse, OpenRouterError> {
         if let Some(api_key) = &self.client.api_key {
             completion::send_completion_request_with_client(
-                self.client.http_client(),
+                &self.client.http_client(),
                 &self.client.base_url,
                 api_key,
                 &self.client.x_title,
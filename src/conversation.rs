@@ -0,0 +1,484 @@
+//! # Conversation State
+//!
+//! [`Conversation`] keeps the state of a chat session: the system prompt, the
+//! message history (including tool calls and tool results), declared tools,
+//! and accumulated [`ResponseUsage`] totals. It produces a
+//! [`ChatCompletionRequest`] on demand and keeps the prompt inside a context
+//! budget, usually taken from the target model's `context_length`.
+//!
+//! History is trimmed by whole turns (a user message plus the assistant and
+//! tool messages that follow it), so tool calls are never separated from
+//! their results. The newest turn is always kept. Token counts are estimated
+//! locally with [`EstimateTokens`], so leave some headroom.
+//!
+//! A conversation serializes to and from JSON, so sessions can be persisted.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use openrouter_rs::{OpenRouterClient, conversation::{Conversation, TrimStrategy}};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+//! let mut conversation = Conversation::new("openai/gpt-4.1-mini")
+//!     .with_system_prompt("You are a concise assistant.")
+//!     .with_context_length(8_000)
+//!     .with_reserved_completion_tokens(1_000)
+//!     .with_strategy(TrimStrategy::KeepPinned);
+//!
+//! conversation.push_user("What is the capital of France?");
+//! let request = conversation.to_request()?;
+//! let response = client.chat().create(&request).await?;
+//! conversation.record_response(&response);
+//!
+//! let saved = serde_json::to_string(&conversation)?;
+//! let restored: Conversation = serde_json::from_str(&saved)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    OpenRouterClient,
+    api::{
+        chat::{ChatCompletionRequest, Content, ContentPart, Message},
+        models::Model,
+    },
+    error::OpenRouterError,
+    estimate::EstimateTokens,
+    types::{CompletionsResponse, ResponseUsage, Role, Tool},
+};
+
+/// How [`Conversation`] brings the history back under its context budget.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TrimStrategy {
+    /// Drop the oldest turns, pinned or not.
+    DropOldest,
+    /// Drop the oldest turns that contain no pinned message.
+    #[default]
+    KeepPinned,
+    /// Replace older unpinned turns with a summary written by `model`,
+    /// keeping the `keep_recent` newest turns verbatim. Requires
+    /// [`Conversation::to_request_with_client`].
+    Summarize { model: String, keep_recent: usize },
+}
+
+/// Accumulated usage across every response recorded in a [`Conversation`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ConversationUsage {
+    /// Number of responses whose usage was recorded.
+    pub requests: u32,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    /// Total cost in credits, when the API reported it.
+    pub cost: f64,
+}
+
+impl ConversationUsage {
+    /// Add one response's usage to the totals.
+    pub fn add(&mut self, usage: &ResponseUsage) {
+        self.requests += 1;
+        self.prompt_tokens += u64::from(usage.prompt_tokens);
+        self.completion_tokens += u64::from(usage.completion_tokens);
+        self.total_tokens += u64::from(usage.total_tokens);
+        self.cost += usage.cost.unwrap_or_default();
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    message: Message,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pinned: bool,
+}
+
+/// A chat session with token-budget-aware history trimming.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Conversation {
+    model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    system_prompt: Option<String>,
+    /// Summary of turns removed by [`TrimStrategy::Summarize`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(default)]
+    entries: Vec<Entry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<Tool>,
+    #[serde(default)]
+    usage: ConversationUsage,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    context_length: Option<u32>,
+    #[serde(default)]
+    reserved_completion_tokens: u32,
+    #[serde(default)]
+    strategy: TrimStrategy,
+}
+
+impl Conversation {
+    /// Start an empty conversation for `model` with no context budget.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            system_prompt: None,
+            summary: None,
+            entries: Vec::new(),
+            tools: Vec::new(),
+            usage: ConversationUsage::default(),
+            context_length: None,
+            reserved_completion_tokens: 0,
+            strategy: TrimStrategy::default(),
+        }
+    }
+
+    pub fn with_system_prompt(mut self, system_prompt: impl Into<String>) -> Self {
+        self.system_prompt = Some(system_prompt.into());
+        self
+    }
+
+    pub fn with_tools(mut self, tools: Vec<Tool>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Set the context window, in tokens, that prompts must fit into.
+    pub fn with_context_length(mut self, context_length: u32) -> Self {
+        self.context_length = Some(context_length);
+        self
+    }
+
+    /// Take the context window from the model metadata (falling back to the
+    /// top provider's limit). Leaves the budget unchanged if neither is known.
+    pub fn with_model_limits(mut self, model: &Model) -> Self {
+        if let Some(context_length) = model
+            .context_length
+            .or(model.top_provider.context_length)
+            .filter(|length| *length > 0.0)
+        {
+            self.context_length = Some(context_length as u32);
+        }
+        self
+    }
+
+    /// Keep this many tokens of the context window free for the completion.
+    pub fn with_reserved_completion_tokens(mut self, tokens: u32) -> Self {
+        self.reserved_completion_tokens = tokens;
+        self
+    }
+
+    pub fn with_strategy(mut self, strategy: TrimStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    pub fn set_model(&mut self, model: impl Into<String>) {
+        self.model = model.into();
+    }
+
+    pub fn system_prompt(&self) -> Option<&str> {
+        self.system_prompt.as_deref()
+    }
+
+    pub fn set_system_prompt(&mut self, system_prompt: impl Into<String>) {
+        self.system_prompt = Some(system_prompt.into());
+    }
+
+    /// Summary of earlier turns written by [`TrimStrategy::Summarize`].
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    pub fn tools(&self) -> &[Tool] {
+        &self.tools
+    }
+
+    pub fn usage(&self) -> &ConversationUsage {
+        &self.usage
+    }
+
+    pub fn strategy(&self) -> &TrimStrategy {
+        &self.strategy
+    }
+
+    /// Tokens available to the prompt, if a context budget is set.
+    pub fn prompt_budget(&self) -> Option<u32> {
+        self.context_length
+            .map(|length| length.saturating_sub(self.reserved_completion_tokens))
+    }
+
+    /// Number of history messages, excluding the system prompt and summary.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Append a message to the history.
+    pub fn push(&mut self, message: Message) {
+        self.entries.push(Entry {
+            message,
+            pinned: false,
+        });
+    }
+
+    /// Append a message that trimming never removes (except under
+    /// [`TrimStrategy::DropOldest`]). Its whole turn is kept with it.
+    pub fn push_pinned(&mut self, message: Message) {
+        self.entries.push(Entry {
+            message,
+            pinned: true,
+        });
+    }
+
+    pub fn push_user(&mut self, content: impl Into<Content>) {
+        self.push(Message::new(Role::User, content));
+    }
+
+    /// Pin or unpin the history message at `index`. Returns false if out of range.
+    pub fn set_pinned(&mut self, index: usize, pinned: bool) -> bool {
+        match self.entries.get_mut(index) {
+            Some(entry) => {
+                entry.pinned = pinned;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Append the assistant message of `response` (content and tool calls)
+    /// and add its usage to the totals.
+    pub fn record_response(&mut self, response: &CompletionsResponse) {
+        if let Some(choice) = response.choices.first() {
+            let content = choice.content().unwrap_or_default();
+            let message = match choice.tool_calls() {
+                Some(tool_calls) if !tool_calls.is_empty() => {
+                    Message::assistant_with_tool_calls(content, tool_calls.to_vec())
+                }
+                _ => Message::new(Role::Assistant, content),
+            };
+            self.push(message);
+        }
+        if let Some(usage) = &response.usage {
+            self.usage.add(usage);
+        }
+    }
+
+    /// The full prompt: system prompt, summary, then history.
+    pub fn messages(&self) -> Vec<Message> {
+        let mut messages = Vec::with_capacity(self.entries.len() + 2);
+        if let Some(system_prompt) = &self.system_prompt {
+            messages.push(Message::new(Role::System, system_prompt.as_str()));
+        }
+        if let Some(summary) = &self.summary {
+            messages.push(summary_message(summary));
+        }
+        messages.extend(self.entries.iter().map(|entry| entry.message.clone()));
+        messages
+    }
+
+    /// Estimated prompt size in tokens, including tool definitions.
+    pub fn estimated_tokens(&self) -> u32 {
+        let messages: u32 = self
+            .messages()
+            .iter()
+            .map(|message| message.estimate_tokens().prompt_tokens())
+            .sum();
+        let tools: u32 = self
+            .tools
+            .iter()
+            .map(|tool| tool.estimate_tokens().prompt_tokens())
+            .sum();
+        messages + tools
+    }
+
+    /// Trim the history to the budget and build a request.
+    ///
+    /// Fails with [`OpenRouterError::ConfigError`] if the prompt cannot be
+    /// brought under budget, or if [`TrimStrategy::Summarize`] needs to run
+    /// (use [`to_request_with_client`](Self::to_request_with_client)).
+    pub fn to_request(&mut self) -> Result<ChatCompletionRequest, OpenRouterError> {
+        if let TrimStrategy::Summarize { .. } = self.strategy {
+            if self.over_budget().is_some() {
+                return Err(OpenRouterError::ConfigError(
+                    "summarizing conversation trim needs a client; use to_request_with_client"
+                        .to_string(),
+                ));
+            }
+        }
+        self.trim(self.strategy == TrimStrategy::DropOldest)?;
+        self.build_request()
+    }
+
+    /// Like [`to_request`](Self::to_request), but able to run
+    /// [`TrimStrategy::Summarize`] through `client`.
+    pub async fn to_request_with_client(
+        &mut self,
+        client: &OpenRouterClient,
+    ) -> Result<ChatCompletionRequest, OpenRouterError> {
+        if let TrimStrategy::Summarize { model, keep_recent } = self.strategy.clone() {
+            if self.over_budget().is_some() {
+                self.summarize(client, &model, keep_recent).await?;
+            }
+        }
+        self.trim(self.strategy == TrimStrategy::DropOldest)?;
+        self.build_request()
+    }
+
+    fn build_request(&self) -> Result<ChatCompletionRequest, OpenRouterError> {
+        let mut builder = ChatCompletionRequest::builder();
+        builder.model(self.model.clone()).messages(self.messages());
+        if !self.tools.is_empty() {
+            builder.tools(self.tools.clone());
+        }
+        builder.build()
+    }
+
+    /// `Some((estimated, budget))` when the prompt exceeds the budget.
+    fn over_budget(&self) -> Option<(u32, u32)> {
+        let budget = self.prompt_budget()?;
+        let estimated = self.estimated_tokens();
+        (estimated > budget).then_some((estimated, budget))
+    }
+
+    /// Drop the oldest droppable turns until the prompt fits.
+    fn trim(&mut self, drop_pinned: bool) -> Result<(), OpenRouterError> {
+        while let Some((estimated, budget)) = self.over_budget() {
+            let turns = turn_ranges(&self.entries);
+            // The newest turn is never dropped.
+            let droppable = turns[..turns.len().saturating_sub(1)]
+                .iter()
+                .find(|range| drop_pinned || !is_pinned(&self.entries, range))
+                .cloned();
+            match droppable {
+                Some(range) => {
+                    self.entries.drain(range);
+                }
+                None => {
+                    return Err(OpenRouterError::ConfigError(format!(
+                        "conversation needs ~{estimated} tokens but the prompt budget is {budget}, \
+                         and no more turns can be trimmed"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn summarize(
+        &mut self,
+        client: &OpenRouterClient,
+        model: &str,
+        keep_recent: usize,
+    ) -> Result<(), OpenRouterError> {
+        let turns = turn_ranges(&self.entries);
+        let keep_from = turns.len().saturating_sub(keep_recent.max(1));
+        let summarized: Vec<_> = turns[..keep_from]
+            .iter()
+            .filter(|range| !is_pinned(&self.entries, range))
+            .cloned()
+            .collect();
+        if summarized.is_empty() {
+            return Ok(());
+        }
+
+        let mut transcript = String::new();
+        if let Some(summary) = &self.summary {
+            transcript.push_str(&format!("Earlier summary: {summary}\n\n"));
+        }
+        for range in &summarized {
+            for entry in &self.entries[range.clone()] {
+                transcript.push_str(&format!(
+                    "{}: {}\n",
+                    entry.message.role,
+                    message_text(&entry.message)
+                ));
+            }
+        }
+
+        let request = ChatCompletionRequest::builder()
+            .model(model)
+            .messages(vec![
+                Message::new(
+                    Role::System,
+                    "Summarize the following conversation so it can replace the original \
+                     messages. Keep facts, decisions, names, and open questions. Reply with the \
+                     summary only.",
+                ),
+                Message::new(Role::User, transcript),
+            ])
+            .build()?;
+        let response = client.chat().create(&request).await?;
+        let summary = response
+            .choices
+            .first()
+            .and_then(|choice| choice.content())
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        if let Some(usage) = &response.usage {
+            self.usage.add(usage);
+        }
+
+        // Drain from the back so earlier ranges stay valid.
+        for range in summarized.into_iter().rev() {
+            self.entries.drain(range);
+        }
+        self.summary = Some(summary);
+        Ok(())
+    }
+}
+
+fn summary_message(summary: &str) -> Message {
+    Message::new(
+        Role::System,
+        format!("Summary of the earlier conversation:\n{summary}"),
+    )
+}
+
+/// Index ranges of turns: each user message starts a new turn.
+fn turn_ranges(entries: &[Entry]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for (index, entry) in entries.iter().enumerate() {
+        if index > start && entry.message.role == Role::User {
+            ranges.push(start..index);
+            start = index;
+        }
+    }
+    if start < entries.len() {
+        ranges.push(start..entries.len());
+    }
+    ranges
+}
+
+fn is_pinned(entries: &[Entry], turn: &Range<usize>) -> bool {
+    entries[turn.clone()].iter().any(|entry| entry.pinned)
+}
+
+fn message_text(message: &Message) -> String {
+    match &message.content {
+        Content::Text(text) => text.clone(),
+        Content::Parts(parts) => parts
+            .iter()
+            .filter_map(|part| match part {
+                ContentPart::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
@@ -0,0 +1,2250 @@
+//! # Converting Between Chat, Responses, and Messages
+//!
+//! Map requests and responses between the chat completions API
+//! ([`ChatCompletionRequest`] / [`CompletionsResponse`]), the Responses API
+//! ([`ResponsesRequest`] / [`ResponsesResponse`]), and the Anthropic-compatible
+//! Messages API ([`AnthropicMessagesRequest`] / [`AnthropicMessagesResponse`]).
+//!
+//! Every pair implements `TryFrom<&Source>`, both for the target type and for
+//! [`Converted<Target>`], which also carries a [`ConversionReport`] of the
+//! source fields that have no equivalent in the target and were dropped.
+//! Conversions are lossless where the target can express the field:
+//!
+//! - system and developer messages map to Responses input messages with the
+//!   same role (Responses `instructions` become a leading system message) and,
+//!   when they open the conversation, to the Messages `system` prompt
+//! - text, image, and file parts map between all three, including
+//!   `cache_control` on text parts where the target supports it
+//! - tools, tool choice, tool calls, and tool results map between function
+//!   calls, `function_call` / `function_call_output` items, and
+//!   `tool_use` / `tool_result` blocks
+//! - reasoning config maps to Responses `reasoning` and to Messages
+//!   `thinking` / `output_config`
+//!
+//! Conversions between the Responses and Messages models go through the chat
+//! model, so their report paths name chat fields for the second step.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use openrouter_rs::{
+//!     api::{
+//!         chat::{ChatCompletionRequest, ContentPart, Message},
+//!         messages::AnthropicMessagesRequest,
+//!     },
+//!     convert::Converted,
+//!     types::Role,
+//! };
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("anthropic/claude-sonnet-4")
+//!     .messages(vec![
+//!         Message::with_parts(
+//!             Role::System,
+//!             vec![ContentPart::cacheable_text("You are a terse assistant.")],
+//!         ),
+//!         Message::new(Role::User, "Name a prime number."),
+//!     ])
+//!     .max_tokens(64)
+//!     .logprobs(true)
+//!     .build()?;
+//!
+//! let converted = Converted::<AnthropicMessagesRequest>::try_from(&request)?;
+//! assert!(!converted.report.is_lossless());
+//! assert_eq!(converted.report.unmapped()[0].path, "logprobs");
+//!
+//! let messages_request = AnthropicMessagesRequest::try_from(&request)?;
+//! assert_eq!(messages_request.messages().len(), 1);
+//! # Ok::<(), openrouter_rs::error::OpenRouterError>(())
+//! ```
+
+use std::{collections::HashMap, fmt};
+
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value, json};
+
+use crate::{
+    api::{
+        chat::{ChatCompletionRequest, Content, ContentPart, FileInput, ImageUrl, Message},
+        messages::{
+            AnthropicContentPart, AnthropicMessage, AnthropicMessageContent,
+            AnthropicMessagesRequest, AnthropicMessagesResponse, AnthropicMessagesUsage,
+            AnthropicOutputConfig, AnthropicOutputEffort, AnthropicRole, AnthropicSystemPrompt,
+            AnthropicSystemTextBlock, AnthropicThinking, AnthropicTool, AnthropicToolChoice,
+        },
+        responses::{
+            ResponsesContentPart, ResponsesFunctionCall, ResponsesInput, ResponsesInputItem,
+            ResponsesMessage, ResponsesMessageContent, ResponsesOutputItem, ResponsesReasoning,
+            ResponsesReasoningConfig, ResponsesReasoningPart, ResponsesRequest, ResponsesResponse,
+            ResponsesTextConfig, ResponsesTextFormat, ResponsesTool, ResponsesToolChoice,
+        },
+    },
+    error::OpenRouterError,
+    types::{
+        Effort, ReasoningConfig, ResponseFormat, ResponseFormatType, Role, Tool, ToolCall,
+        ToolChoice,
+        completion::{Choice, CompletionsResponse, FinishReason, NonStreamingChoice},
+    },
+};
+
+const CHAT: &str = "chat completions";
+const RESPONSES: &str = "Responses";
+const MESSAGES: &str = "Messages";
+
+/// Source fields copied verbatim between chat and Responses requests.
+const CHAT_RESPONSES_SHARED: &[&str] = &[
+    "model",
+    "models",
+    "temperature",
+    "top_p",
+    "top_logprobs",
+    "presence_penalty",
+    "frequency_penalty",
+    "parallel_tool_calls",
+    "metadata",
+    "image_config",
+    "modalities",
+    "provider",
+    "plugins",
+    "route",
+    "user",
+    "session_id",
+    "cache_control",
+    "trace",
+    "debug",
+];
+
+/// Source fields copied verbatim between chat and Messages requests.
+const CHAT_MESSAGES_SHARED: &[&str] = &[
+    "model",
+    "models",
+    "temperature",
+    "top_p",
+    "top_k",
+    "provider",
+    "plugins",
+    "route",
+    "user",
+    "session_id",
+    "trace",
+];
+
+/// A source field that has no equivalent in the target model and was dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct UnmappedField {
+    /// Path of the field in the source, e.g. `messages[2].content[0].cache_control`.
+    pub path: String,
+    /// Why the field could not be carried over.
+    pub reason: String,
+}
+
+impl fmt::Display for UnmappedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.reason)
+    }
+}
+
+/// Fields dropped by a conversion.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConversionReport {
+    unmapped: Vec<UnmappedField>,
+}
+
+impl ConversionReport {
+    /// Whether every source field was carried over.
+    pub fn is_lossless(&self) -> bool {
+        self.unmapped.is_empty()
+    }
+
+    pub fn unmapped(&self) -> &[UnmappedField] {
+        &self.unmapped
+    }
+
+    /// Whether the field at `path` was dropped.
+    pub fn contains(&self, path: &str) -> bool {
+        self.unmapped.iter().any(|field| field.path == path)
+    }
+
+    fn push(&mut self, path: impl Into<String>, reason: impl Into<String>) {
+        self.unmapped.push(UnmappedField {
+            path: path.into(),
+            reason: reason.into(),
+        });
+    }
+
+    fn extend(&mut self, other: ConversionReport) {
+        self.unmapped.extend(other.unmapped);
+    }
+}
+
+impl fmt::Display for ConversionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.unmapped.is_empty() {
+            return f.write_str("lossless");
+        }
+        for (index, field) in self.unmapped.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{field}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A converted value and the report of what could not be mapped.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Converted<T> {
+    pub value: T,
+    pub report: ConversionReport,
+}
+
+impl<T> Converted<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// The converted value, or [`OpenRouterError::ConfigError`] if any field was dropped.
+    pub fn into_lossless(self) -> Result<T, OpenRouterError> {
+        if self.report.is_lossless() {
+            Ok(self.value)
+        } else {
+            Err(OpenRouterError::ConfigError(format!(
+                "conversion dropped fields: {}",
+                self.report
+            )))
+        }
+    }
+}
+
+/// Conversion from another API's request or response model.
+///
+/// Implemented for every pair of chat, Responses, and Messages requests and
+/// for every pair of their responses. `TryFrom<&S>` is implemented on top of
+/// it for both `Self` and [`Converted<Self>`].
+pub trait ConvertFrom<S>: Sized {
+    fn convert_from(source: &S) -> Result<Converted<Self>, OpenRouterError>;
+}
+
+macro_rules! impl_try_from {
+    ($($source:ty => $target:ty),* $(,)?) => {
+        $(
+            impl TryFrom<&$source> for $target {
+                type Error = OpenRouterError;
+
+                fn try_from(source: &$source) -> Result<Self, Self::Error> {
+                    <$target as ConvertFrom<$source>>::convert_from(source)
+                        .map(Converted::into_inner)
+                }
+            }
+
+            impl TryFrom<&$source> for Converted<$target> {
+                type Error = OpenRouterError;
+
+                fn try_from(source: &$source) -> Result<Self, Self::Error> {
+                    <$target as ConvertFrom<$source>>::convert_from(source)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from!(
+    ChatCompletionRequest => ResponsesRequest,
+    ChatCompletionRequest => AnthropicMessagesRequest,
+    ResponsesRequest => ChatCompletionRequest,
+    ResponsesRequest => AnthropicMessagesRequest,
+    AnthropicMessagesRequest => ChatCompletionRequest,
+    AnthropicMessagesRequest => ResponsesRequest,
+    CompletionsResponse => ResponsesResponse,
+    CompletionsResponse => AnthropicMessagesResponse,
+    ResponsesResponse => CompletionsResponse,
+    ResponsesResponse => AnthropicMessagesResponse,
+    AnthropicMessagesResponse => CompletionsResponse,
+    AnthropicMessagesResponse => ResponsesResponse,
+);
+
+/// Convert `S` to `T` through the intermediate model `M`, merging both reports.
+fn compose<S, M, T>(source: &S) -> Result<Converted<T>, OpenRouterError>
+where
+    M: ConvertFrom<S>,
+    T: ConvertFrom<M>,
+{
+    let first = M::convert_from(source)?;
+    let second = T::convert_from(&first.value)?;
+    let mut report = first.report;
+    report.extend(second.report);
+    Ok(Converted {
+        value: second.value,
+        report,
+    })
+}
+
+/// Request fields as JSON: mapped fields are moved from `source` to `target`,
+/// and whatever is left in `source` is reported as unmapped.
+struct Fields {
+    source: Map<String, Value>,
+    target: Map<String, Value>,
+    report: ConversionReport,
+    target_api: &'static str,
+}
+
+impl Fields {
+    fn new(source: &impl Serialize, target_api: &'static str) -> Result<Self, OpenRouterError> {
+        let mut source = match serde_json::to_value(source)? {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        source.remove("stream");
+        Ok(Self {
+            source,
+            target: Map::new(),
+            report: ConversionReport::default(),
+            target_api,
+        })
+    }
+
+    fn take<T: DeserializeOwned>(&mut self, key: &str) -> Result<Option<T>, OpenRouterError> {
+        match self.source.remove(key) {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => Ok(Some(serde_json::from_value(value)?)),
+        }
+    }
+
+    fn copy(&mut self, keys: &[&str]) {
+        for key in keys {
+            if let Some(value) = self.source.remove(*key) {
+                self.target.insert((*key).to_string(), value);
+            }
+        }
+    }
+
+    fn set(&mut self, key: &str, value: impl Serialize) -> Result<(), OpenRouterError> {
+        let value = serde_json::to_value(value)?;
+        if !value.is_null() {
+            self.target.insert(key.to_string(), value);
+        }
+        Ok(())
+    }
+
+    fn finish<T: DeserializeOwned>(mut self) -> Result<Converted<T>, OpenRouterError> {
+        for key in self.source.keys() {
+            self.report.push(
+                key.clone(),
+                format!("no equivalent in the {} API", self.target_api),
+            );
+        }
+        Ok(Converted {
+            value: serde_json::from_value(Value::Object(self.target))?,
+            report: self.report,
+        })
+    }
+}
+
+impl ConvertFrom<ChatCompletionRequest> for ResponsesRequest {
+    fn convert_from(source: &ChatCompletionRequest) -> Result<Converted<Self>, OpenRouterError> {
+        let mut fields = Fields::new(source, RESPONSES)?;
+        fields.copy(CHAT_RESPONSES_SHARED);
+        fields.copy(&["top_k"]);
+
+        let max_completion_tokens: Option<u32> = fields.take("max_completion_tokens")?;
+        let max_tokens: Option<u32> = fields.take("max_tokens")?;
+        fields.set("max_output_tokens", max_completion_tokens.or(max_tokens))?;
+
+        let messages: Vec<Message> = fields.take("messages")?.unwrap_or_default();
+        let input = chat_to_responses_input(&messages, &mut fields.report);
+        fields.set("input", input)?;
+
+        if let Some(tools) = fields.take::<Vec<Tool>>("tools")? {
+            let tools: Vec<ResponsesTool> = tools.into_iter().map(ResponsesTool::from).collect();
+            fields.set("tools", tools)?;
+        }
+        if let Some(choice) = fields.take::<ToolChoice>("tool_choice")? {
+            let choice = match choice {
+                ToolChoice::String(mode) => ResponsesToolChoice::String(mode),
+                ToolChoice::Specific(specific) => {
+                    ResponsesToolChoice::force_tool(specific.function.name)
+                }
+            };
+            fields.set("tool_choice", choice)?;
+        }
+        if let Some(format) = fields.take::<ResponseFormat>("response_format")? {
+            let format = chat_format_to_responses(format, &mut fields.report);
+            fields.set("text", format.map(ResponsesTextConfig::from))?;
+        }
+        if let Some(reasoning) = fields.take::<ReasoningConfig>("reasoning")? {
+            if reasoning.exclude.is_some() {
+                fields
+                    .report
+                    .push("reasoning.exclude", "the Responses API has no exclude flag");
+            }
+            let reasoning = ResponsesReasoningConfig {
+                effort: reasoning.effort,
+                summary: None,
+                max_tokens: reasoning.max_tokens,
+                enabled: reasoning.enabled,
+                extra: HashMap::new(),
+            };
+            fields.set("reasoning", reasoning)?;
+        }
+
+        let mut converted = fields.finish::<Self>()?;
+        converted
+            .value
+            .set_experimental_metadata(source.experimental_metadata());
+        Ok(converted)
+    }
+}
+
+impl ConvertFrom<ResponsesRequest> for ChatCompletionRequest {
+    fn convert_from(source: &ResponsesRequest) -> Result<Converted<Self>, OpenRouterError> {
+        let mut fields = Fields::new(source, CHAT)?;
+        if !fields.source.contains_key("model") {
+            return Err(OpenRouterError::ConfigError(
+                "a model is required to convert to a chat completion request".to_string(),
+            ));
+        }
+        fields.copy(CHAT_RESPONSES_SHARED);
+
+        if let Some(top_k) = fields.take::<f64>("top_k")? {
+            if top_k.fract() == 0.0 && top_k >= 0.0 {
+                fields.set("top_k", top_k as u32)?;
+            } else {
+                fields
+                    .report
+                    .push("top_k", "chat top_k must be a whole number");
+            }
+        }
+        let max_output_tokens: Option<u32> = fields.take("max_output_tokens")?;
+        fields.set("max_tokens", max_output_tokens)?;
+
+        let instructions: Option<String> = fields.take("instructions")?;
+        let input: Option<ResponsesInput> = fields.take("input")?;
+        let messages = responses_to_chat_messages(instructions, input, &mut fields.report);
+        fields.set("messages", messages)?;
+
+        if let Some(tools) = fields.take::<Vec<ResponsesTool>>("tools")? {
+            let tools = responses_tools_to_chat(tools, &mut fields.report);
+            if !tools.is_empty() {
+                fields.set("tools", tools)?;
+            }
+        }
+        if let Some(choice) = fields.take::<ResponsesToolChoice>("tool_choice")? {
+            let choice = match choice {
+                ResponsesToolChoice::String(mode) => Some(ToolChoice::String(mode)),
+                ResponsesToolChoice::Specific(specific) if specific.tool_type == "function" => {
+                    specific.name.as_deref().map(ToolChoice::force_tool)
+                }
+                _ => None,
+            };
+            if choice.is_none() {
+                fields
+                    .report
+                    .push("tool_choice", "only function tool choices map to chat");
+            }
+            fields.set("tool_choice", choice)?;
+        }
+        if let Some(text) = fields.take::<ResponsesTextConfig>("text")? {
+            if text.verbosity.is_some() {
+                fields
+                    .report
+                    .push("text.verbosity", "chat requests have no verbosity");
+            }
+            for key in text.extra.keys() {
+                fields.report.push(
+                    format!("text.{key}"),
+                    "no equivalent in the chat completions API",
+                );
+            }
+            if let Some(format) = text.format {
+                let format = responses_format_to_chat(format, &mut fields.report);
+                fields.set("response_format", format)?;
+            }
+        }
+        if let Some(reasoning) = fields.take::<ResponsesReasoningConfig>("reasoning")? {
+            if reasoning.summary.is_some() {
+                fields.report.push(
+                    "reasoning.summary",
+                    "chat requests have no reasoning summary setting",
+                );
+            }
+            for key in reasoning.extra.keys() {
+                fields.report.push(
+                    format!("reasoning.{key}"),
+                    "no equivalent in the chat completions API",
+                );
+            }
+            let reasoning = ReasoningConfig {
+                effort: reasoning.effort,
+                max_tokens: reasoning.max_tokens,
+                exclude: None,
+                enabled: reasoning.enabled,
+            };
+            fields.set("reasoning", reasoning)?;
+        }
+
+        let mut converted = fields.finish::<Self>()?;
+        converted
+            .value
+            .set_experimental_metadata(source.experimental_metadata());
+        Ok(converted)
+    }
+}
+
+impl ConvertFrom<ChatCompletionRequest> for AnthropicMessagesRequest {
+    fn convert_from(source: &ChatCompletionRequest) -> Result<Converted<Self>, OpenRouterError> {
+        let mut fields = Fields::new(source, MESSAGES)?;
+        fields.copy(CHAT_MESSAGES_SHARED);
+
+        let max_completion_tokens: Option<u32> = fields.take("max_completion_tokens")?;
+        let max_tokens: Option<u32> = fields.take("max_tokens")?;
+        let Some(max_tokens) = max_completion_tokens.or(max_tokens) else {
+            return Err(OpenRouterError::ConfigError(
+                "max_tokens is required to convert to a messages request".to_string(),
+            ));
+        };
+        fields.set("max_tokens", max_tokens)?;
+
+        let messages: Vec<Message> = fields.take("messages")?.unwrap_or_default();
+        let (system, messages) = chat_to_anthropic_messages(&messages, &mut fields.report);
+        fields.set("system", system)?;
+        fields.set("messages", messages)?;
+
+        if let Some(stop) = fields.take::<Value>("stop")? {
+            let stop = match stop {
+                Value::String(sequence) => vec![sequence],
+                other => serde_json::from_value(other)?,
+            };
+            fields.set("stop_sequences", stop)?;
+        }
+        // `user_id` lands in `AnthropicMessagesMetadata::user_id`, the rest in `extra`.
+        fields.copy(&["metadata"]);
+
+        if let Some(tools) = fields.take::<Vec<Tool>>("tools")? {
+            let tools: Vec<AnthropicTool> = tools.into_iter().map(AnthropicTool::from).collect();
+            fields.set("tools", tools)?;
+        }
+        let parallel_tool_calls: Option<bool> = fields.take("parallel_tool_calls")?;
+        let disable_parallel_tool_use = (parallel_tool_calls == Some(false)).then_some(true);
+        let choice = match fields.take::<ToolChoice>("tool_choice")? {
+            Some(ToolChoice::String(mode)) => match mode.as_str() {
+                "auto" => Some(AnthropicToolChoice::Auto {
+                    disable_parallel_tool_use,
+                }),
+                "required" => Some(AnthropicToolChoice::Any {
+                    disable_parallel_tool_use,
+                }),
+                "none" => Some(AnthropicToolChoice::None),
+                _ => {
+                    fields.report.push(
+                        "tool_choice",
+                        format!("`{mode}` has no Messages API equivalent"),
+                    );
+                    None
+                }
+            },
+            Some(ToolChoice::Specific(specific)) => Some(AnthropicToolChoice::Tool {
+                name: specific.function.name,
+                disable_parallel_tool_use,
+            }),
+            None => disable_parallel_tool_use.map(|_| AnthropicToolChoice::Auto {
+                disable_parallel_tool_use,
+            }),
+        };
+        fields.set("tool_choice", choice)?;
+
+        if let Some(reasoning) = fields.take::<ReasoningConfig>("reasoning")? {
+            let (thinking, output_config) =
+                chat_reasoning_to_anthropic(reasoning, &mut fields.report);
+            fields.set("thinking", thinking)?;
+            fields.set("output_config", output_config)?;
+        }
+
+        let mut converted = fields.finish::<Self>()?;
+        converted
+            .value
+            .set_experimental_metadata(source.experimental_metadata());
+        Ok(converted)
+    }
+}
+
+impl ConvertFrom<AnthropicMessagesRequest> for ChatCompletionRequest {
+    fn convert_from(source: &AnthropicMessagesRequest) -> Result<Converted<Self>, OpenRouterError> {
+        let mut fields = Fields::new(source, CHAT)?;
+        fields.copy(CHAT_MESSAGES_SHARED);
+        fields.copy(&["max_tokens"]);
+
+        let system: Option<AnthropicSystemPrompt> = fields.take("system")?;
+        let messages: Vec<AnthropicMessage> = fields.take("messages")?.unwrap_or_default();
+        let messages = anthropic_to_chat_messages(system, messages, &mut fields.report);
+        fields.set("messages", messages)?;
+
+        let stop_sequences: Option<Vec<String>> = fields.take("stop_sequences")?;
+        fields.set("stop", stop_sequences)?;
+        if let Some(metadata) = fields.take::<Map<String, Value>>("metadata")? {
+            let mut strings = HashMap::new();
+            for (key, value) in metadata {
+                match value {
+                    Value::String(value) => {
+                        strings.insert(key, value);
+                    }
+                    _ => fields.report.push(
+                        format!("metadata.{key}"),
+                        "chat metadata values must be strings",
+                    ),
+                }
+            }
+            fields.set("metadata", strings)?;
+        }
+
+        if let Some(tools) = fields.take::<Vec<AnthropicTool>>("tools")? {
+            let tools = anthropic_tools_to_chat(tools, &mut fields.report);
+            if !tools.is_empty() {
+                fields.set("tools", tools)?;
+            }
+        }
+        if let Some(choice) = fields.take::<AnthropicToolChoice>("tool_choice")? {
+            let (choice, disable_parallel_tool_use) = match choice {
+                AnthropicToolChoice::Auto {
+                    disable_parallel_tool_use,
+                } => (ToolChoice::auto(), disable_parallel_tool_use),
+                AnthropicToolChoice::Any {
+                    disable_parallel_tool_use,
+                } => (ToolChoice::required(), disable_parallel_tool_use),
+                AnthropicToolChoice::None => (ToolChoice::none(), None),
+                AnthropicToolChoice::Tool {
+                    name,
+                    disable_parallel_tool_use,
+                } => (ToolChoice::force_tool(&name), disable_parallel_tool_use),
+            };
+            fields.set("tool_choice", choice)?;
+            fields.set(
+                "parallel_tool_calls",
+                disable_parallel_tool_use.map(|disable| !disable),
+            )?;
+        }
+
+        let thinking: Option<AnthropicThinking> = fields.take("thinking")?;
+        let output_config: Option<AnthropicOutputConfig> = fields.take("output_config")?;
+        fields.set(
+            "reasoning",
+            anthropic_reasoning_to_chat(thinking, output_config),
+        )?;
+
+        let mut converted = fields.finish::<Self>()?;
+        converted
+            .value
+            .set_experimental_metadata(source.experimental_metadata());
+        Ok(converted)
+    }
+}
+
+impl ConvertFrom<ResponsesRequest> for AnthropicMessagesRequest {
+    fn convert_from(source: &ResponsesRequest) -> Result<Converted<Self>, OpenRouterError> {
+        compose::<_, ChatCompletionRequest, _>(source)
+    }
+}
+
+impl ConvertFrom<AnthropicMessagesRequest> for ResponsesRequest {
+    fn convert_from(source: &AnthropicMessagesRequest) -> Result<Converted<Self>, OpenRouterError> {
+        compose::<_, ChatCompletionRequest, _>(source)
+    }
+}
+
+impl ConvertFrom<CompletionsResponse> for ResponsesResponse {
+    fn convert_from(source: &CompletionsResponse) -> Result<Converted<Self>, OpenRouterError> {
+        let mut report = ConversionReport::default();
+        let mut output = Vec::new();
+        let mut extra = HashMap::new();
+        let mut status = "completed";
+
+        if let Some(choice) = first_choice(source, &mut report) {
+            let message = &choice.message;
+            if let Some(reasoning) = chat_reasoning_to_responses_item(choice) {
+                output.push(ResponsesOutputItem::Reasoning(reasoning));
+            }
+
+            let mut parts = Vec::new();
+            if let Some(text) = &message.content {
+                let logprobs = match &choice.logprobs {
+                    None | Some(Value::Null) => None,
+                    Some(logprobs) => {
+                        let content = logprobs.get("content").and_then(Value::as_array).cloned();
+                        if content.is_none() {
+                            report.push(
+                                "choices[0].logprobs",
+                                "only `logprobs.content` maps to output text logprobs",
+                            );
+                        }
+                        content
+                    }
+                };
+                parts.push(ResponsesContentPart::OutputText {
+                    text: text.clone(),
+                    annotations: message.annotations.clone().unwrap_or_default(),
+                    logprobs,
+                });
+            }
+            if let Some(refusal) = &message.refusal {
+                parts.push(ResponsesContentPart::Refusal {
+                    refusal: refusal.clone(),
+                });
+            }
+            if !parts.is_empty() {
+                output.push(ResponsesOutputItem::Message(ResponsesMessage {
+                    id: None,
+                    role: Role::Assistant,
+                    content: ResponsesMessageContent::Parts(parts),
+                    status: Some("completed".to_string()),
+                }));
+            }
+            for call in message.tool_calls.iter().flatten() {
+                let mut item = ResponsesFunctionCall::new(
+                    &call.id,
+                    &call.function.name,
+                    &call.function.arguments,
+                );
+                item.status = Some("completed".to_string());
+                output.push(ResponsesOutputItem::FunctionCall(item));
+            }
+            if message.images.is_some() {
+                report.push(
+                    "choices[0].message.images",
+                    "Responses output has no generated images",
+                );
+            }
+            if message.audio.is_some() {
+                report.push("choices[0].message.audio", "Responses output has no audio");
+            }
+            if let Some(error) = &choice.error {
+                extra.insert("error".to_string(), serde_json::to_value(error)?);
+            }
+
+            let incomplete_reason = match choice.finish_reason {
+                Some(FinishReason::Length) => Some("max_output_tokens"),
+                Some(FinishReason::ContentFilter) => Some("content_filter"),
+                Some(FinishReason::Error) => {
+                    status = "failed";
+                    None
+                }
+                _ => None,
+            };
+            if let Some(reason) = incomplete_reason {
+                status = "incomplete";
+                extra.insert(
+                    "incomplete_details".to_string(),
+                    json!({ "reason": reason }),
+                );
+            }
+        }
+
+        if let Some(service_tier) = &source.service_tier {
+            extra.insert(
+                "service_tier".to_string(),
+                Value::from(service_tier.clone()),
+            );
+        }
+        report_chat_response_extras(source, &mut report);
+
+        let usage = match &source.usage {
+            Some(usage) => {
+                let mut value = json!({
+                    "input_tokens": usage.prompt_tokens,
+                    "output_tokens": usage.completion_tokens,
+                    "total_tokens": usage.total_tokens,
+                });
+                if let (Value::Object(value), Value::Object(usage)) =
+                    (&mut value, serde_json::to_value(usage)?)
+                {
+                    for key in ["cost", "cost_details", "is_byok", "server_tool_use_details"] {
+                        if let Some(field) = usage.get(key) {
+                            value.insert(key.to_string(), field.clone());
+                        }
+                    }
+                }
+                Some(value)
+            }
+            None => None,
+        };
+
+        Ok(Converted {
+            value: ResponsesResponse {
+                id: Some(source.id.clone()),
+                object_type: Some("response".to_string()),
+                created_at: Some(source.created),
+                model: Some(source.model.clone()),
+                status: Some(status.to_string()),
+                output: Some(output),
+                usage,
+                extra,
+            },
+            report,
+        })
+    }
+}
+
+impl ConvertFrom<ResponsesResponse> for CompletionsResponse {
+    fn convert_from(source: &ResponsesResponse) -> Result<Converted<Self>, OpenRouterError> {
+        let mut report = ConversionReport::default();
+        let mut content: Option<String> = None;
+        let mut refusal: Option<String> = None;
+        let mut annotations = Vec::new();
+        let mut logprobs = Vec::new();
+        let mut tool_calls = Vec::new();
+        let mut reasoning: Option<String> = None;
+        let mut reasoning_details = Vec::new();
+
+        for (index, item) in source.output.iter().flatten().enumerate() {
+            let path = format!("output[{index}]");
+            match item {
+                ResponsesOutputItem::Message(message) => match &message.content {
+                    ResponsesMessageContent::Text(text) => append(&mut content, text),
+                    ResponsesMessageContent::Parts(parts) => {
+                        for (part_index, part) in parts.iter().enumerate() {
+                            match part {
+                                ResponsesContentPart::OutputText {
+                                    text,
+                                    annotations: part_annotations,
+                                    logprobs: part_logprobs,
+                                } => {
+                                    append(&mut content, text);
+                                    annotations.extend(part_annotations.iter().cloned());
+                                    logprobs.extend(part_logprobs.iter().flatten().cloned());
+                                }
+                                ResponsesContentPart::InputText { text } => {
+                                    append(&mut content, text)
+                                }
+                                ResponsesContentPart::Refusal { refusal: text } => {
+                                    append(&mut refusal, text)
+                                }
+                                other => report.push(
+                                    format!("{path}.content[{part_index}]"),
+                                    format!(
+                                        "`{}` parts have no chat message equivalent",
+                                        type_name(other)
+                                    ),
+                                ),
+                            }
+                        }
+                    }
+                },
+                ResponsesOutputItem::FunctionCall(call) => {
+                    tool_calls.push(ToolCall::new(&call.call_id, &call.name, &call.arguments));
+                }
+                ResponsesOutputItem::Reasoning(item) => {
+                    let text = item.text();
+                    if !text.is_empty() {
+                        append(&mut reasoning, &text);
+                    }
+                    reasoning_details.extend(responses_reasoning_to_details(item));
+                }
+                other => report.push(
+                    path,
+                    format!(
+                        "`{}` items have no chat message equivalent",
+                        type_name(other)
+                    ),
+                ),
+            }
+        }
+
+        let incomplete_reason = source
+            .extra
+            .get("incomplete_details")
+            .and_then(|details| details.get("reason"))
+            .and_then(Value::as_str);
+        let finish_reason = match source.status.as_deref() {
+            Some("incomplete") if incomplete_reason == Some("content_filter") => {
+                Some(FinishReason::ContentFilter)
+            }
+            Some("incomplete") => Some(FinishReason::Length),
+            Some("failed") => Some(FinishReason::Error),
+            _ if !tool_calls.is_empty() => Some(FinishReason::ToolCalls),
+            Some("completed") => Some(FinishReason::Stop),
+            _ => None,
+        };
+        for key in source.extra.keys() {
+            if !matches!(key.as_str(), "incomplete_details" | "service_tier") {
+                report.push(key.clone(), "no equivalent in the chat completions API");
+            }
+        }
+
+        let usage = source
+            .usage
+            .as_ref()
+            .map(|usage| responses_usage_to_chat(usage, &mut report));
+        let message = json!({
+            "role": "assistant",
+            "content": content,
+            "tool_calls": (!tool_calls.is_empty()).then_some(tool_calls),
+            "reasoning": reasoning,
+            "reasoning_details": (!reasoning_details.is_empty()).then_some(reasoning_details),
+            "refusal": refusal,
+            "annotations": (!annotations.is_empty()).then_some(annotations),
+        });
+        let value = json!({
+            "id": source.id.clone().unwrap_or_default(),
+            "choices": [{
+                "index": 0,
+                "finish_reason": finish_reason,
+                "native_finish_reason": null,
+                "message": message,
+                "error": null,
+                "logprobs": (!logprobs.is_empty()).then(|| json!({ "content": logprobs })),
+            }],
+            "created": source.created_at.unwrap_or_default(),
+            "model": source.model.clone().unwrap_or_default(),
+            "object": "chat.completion",
+            "provider": null,
+            "system_fingerprint": null,
+            "usage": usage,
+            "service_tier": source.extra.get("service_tier"),
+        });
+
+        Ok(Converted {
+            value: serde_json::from_value(value)?,
+            report,
+        })
+    }
+}
+
+impl ConvertFrom<CompletionsResponse> for AnthropicMessagesResponse {
+    fn convert_from(source: &CompletionsResponse) -> Result<Converted<Self>, OpenRouterError> {
+        let mut report = ConversionReport::default();
+        let mut content = Vec::new();
+        let mut stop_reason = None;
+
+        if let Some(choice) = first_choice(source, &mut report) {
+            let message = &choice.message;
+            let details = message.reasoning_details.as_deref().unwrap_or_default();
+            for (index, detail) in details.iter().enumerate() {
+                match detail.block_type.as_str() {
+                    "reasoning.encrypted" => content.push(AnthropicContentPart::RedactedThinking {
+                        data: detail.data.clone().unwrap_or_default(),
+                    }),
+                    "reasoning.text" => content.push(AnthropicContentPart::Thinking {
+                        thinking: detail.text.clone().unwrap_or_default(),
+                        signature: detail.signature.clone().unwrap_or_default(),
+                    }),
+                    other => report.push(
+                        format!("choices[0].message.reasoning_details[{index}]"),
+                        format!("`{other}` details have no thinking block equivalent"),
+                    ),
+                }
+            }
+            if details.is_empty() {
+                if let Some(reasoning) = &message.reasoning {
+                    content.push(AnthropicContentPart::Thinking {
+                        thinking: reasoning.clone(),
+                        signature: String::new(),
+                    });
+                }
+            }
+            if let Some(text) = &message.content {
+                content.push(AnthropicContentPart::text(text));
+            }
+            if message.annotations.as_ref().is_some_and(|a| !a.is_empty()) {
+                report.push(
+                    "choices[0].message.annotations",
+                    "annotations have no Messages API equivalent",
+                );
+            }
+            if message.refusal.is_some() {
+                report.push(
+                    "choices[0].message.refusal",
+                    "refusals have no Messages API content block",
+                );
+            }
+            for (index, call) in message.tool_calls.iter().flatten().enumerate() {
+                let input = parse_arguments(
+                    &call.function.arguments,
+                    format!("choices[0].message.tool_calls[{index}].function.arguments"),
+                    &mut report,
+                );
+                content.push(AnthropicContentPart::tool_use(
+                    &call.id,
+                    &call.function.name,
+                    input,
+                ));
+            }
+            for (field, present) in [
+                ("images", message.images.is_some()),
+                ("audio", message.audio.is_some()),
+            ] {
+                if present {
+                    report.push(
+                        format!("choices[0].message.{field}"),
+                        "no equivalent in the Messages API",
+                    );
+                }
+            }
+            if choice.logprobs.as_ref().is_some_and(|l| !l.is_null()) {
+                report.push("choices[0].logprobs", "no equivalent in the Messages API");
+            }
+
+            stop_reason = match choice.native_finish_reason.as_deref() {
+                Some(
+                    reason @ ("end_turn" | "max_tokens" | "stop_sequence" | "tool_use"
+                    | "pause_turn" | "refusal"),
+                ) => Some(reason),
+                _ => match choice.finish_reason {
+                    Some(FinishReason::Stop) => Some("end_turn"),
+                    Some(FinishReason::Length) => Some("max_tokens"),
+                    Some(FinishReason::ToolCalls) => Some("tool_use"),
+                    Some(FinishReason::ContentFilter) => Some("refusal"),
+                    Some(FinishReason::Error) => {
+                        report.push(
+                            "choices[0].finish_reason",
+                            "the Messages API has no error stop reason",
+                        );
+                        None
+                    }
+                    None => None,
+                },
+            };
+        }
+        report_chat_response_extras(source, &mut report);
+
+        let usage = source.usage.as_ref().map(|usage| {
+            let mut extra = HashMap::new();
+            if let Some(cost) = usage.cost {
+                extra.insert("cost".to_string(), Value::from(cost));
+            }
+            AnthropicMessagesUsage {
+                input_tokens: Some(usage.prompt_tokens.into()),
+                output_tokens: Some(usage.completion_tokens.into()),
+                cache_creation_input_tokens: None,
+                cache_read_input_tokens: None,
+                service_tier: source.service_tier.clone(),
+                extra,
+            }
+        });
+
+        Ok(Converted {
+            value: AnthropicMessagesResponse {
+                id: Some(source.id.clone()),
+                object_type: Some("message".to_string()),
+                role: Some("assistant".to_string()),
+                content,
+                model: Some(source.model.clone()),
+                stop_reason: stop_reason.map(str::to_string),
+                stop_sequence: None,
+                usage,
+                extra: HashMap::new(),
+            },
+            report,
+        })
+    }
+}
+
+impl ConvertFrom<AnthropicMessagesResponse> for CompletionsResponse {
+    fn convert_from(
+        source: &AnthropicMessagesResponse,
+    ) -> Result<Converted<Self>, OpenRouterError> {
+        let mut report = ConversionReport::default();
+        let mut content: Option<String> = None;
+        let mut reasoning: Option<String> = None;
+        let mut reasoning_details = Vec::new();
+        let mut tool_calls = Vec::new();
+
+        for (index, part) in source.content.iter().enumerate() {
+            let path = format!("content[{index}]");
+            match part {
+                AnthropicContentPart::Text {
+                    text, citations, ..
+                } => {
+                    append(&mut content, text);
+                    if citations.is_some() {
+                        report.push(
+                            format!("{path}.citations"),
+                            "citations have no chat message equivalent",
+                        );
+                    }
+                }
+                AnthropicContentPart::Thinking {
+                    thinking,
+                    signature,
+                } => {
+                    append(&mut reasoning, thinking);
+                    let mut detail = json!({ "type": "reasoning.text", "text": thinking });
+                    if !signature.is_empty() {
+                        detail["signature"] = Value::from(signature.clone());
+                    }
+                    reasoning_details.push(detail);
+                }
+                AnthropicContentPart::RedactedThinking { data } => {
+                    reasoning_details.push(json!({ "type": "reasoning.encrypted", "data": data }));
+                }
+                AnthropicContentPart::ToolUse {
+                    id, name, input, ..
+                } => {
+                    let arguments = input
+                        .as_ref()
+                        .map_or_else(|| "{}".to_string(), Value::to_string);
+                    tool_calls.push(ToolCall::new(id, name, arguments));
+                }
+                other => report.push(
+                    path,
+                    format!(
+                        "`{}` blocks have no chat message equivalent",
+                        type_name(other)
+                    ),
+                ),
+            }
+        }
+
+        let finish_reason = source.stop_reason.as_deref().map(|reason| match reason {
+            "max_tokens" => FinishReason::Length,
+            "tool_use" => FinishReason::ToolCalls,
+            "refusal" => FinishReason::ContentFilter,
+            _ => FinishReason::Stop,
+        });
+        if source.stop_sequence.is_some() {
+            report.push(
+                "stop_sequence",
+                "chat responses do not report the matched stop sequence",
+            );
+        }
+        for key in source.extra.keys() {
+            report.push(key.clone(), "no equivalent in the chat completions API");
+        }
+
+        let usage = source.usage.as_ref().map(|usage| {
+            let cache_creation = usage.cache_creation_input_tokens.unwrap_or_default();
+            let cache_read = usage.cache_read_input_tokens.unwrap_or_default();
+            for (field, present) in [
+                ("cache_creation_input_tokens", cache_creation > 0),
+                ("cache_read_input_tokens", cache_read > 0),
+            ] {
+                if present {
+                    report.push(format!("usage.{field}"), "folded into chat prompt_tokens");
+                }
+            }
+            for key in usage.extra.keys().filter(|key| *key != "cost") {
+                report.push(
+                    format!("usage.{key}"),
+                    "no equivalent in the chat completions API",
+                );
+            }
+            let prompt_tokens =
+                usage.input_tokens.unwrap_or_default() + cache_creation + cache_read;
+            let completion_tokens = usage.output_tokens.unwrap_or_default();
+            json!({
+                "prompt_tokens": prompt_tokens,
+                "completion_tokens": completion_tokens,
+                "total_tokens": prompt_tokens + completion_tokens,
+                "cost": usage.extra.get("cost"),
+            })
+        });
+        let message = json!({
+            "role": "assistant",
+            "content": content,
+            "tool_calls": (!tool_calls.is_empty()).then_some(tool_calls),
+            "reasoning": reasoning,
+            "reasoning_details": (!reasoning_details.is_empty()).then_some(reasoning_details),
+            "refusal": null,
+        });
+        let value = json!({
+            "id": source.id.clone().unwrap_or_default(),
+            "choices": [{
+                "index": 0,
+                "finish_reason": finish_reason,
+                "native_finish_reason": source.stop_reason,
+                "message": message,
+                "error": null,
+                "logprobs": null,
+            }],
+            "created": 0,
+            "model": source.model.clone().unwrap_or_default(),
+            "object": "chat.completion",
+            "provider": null,
+            "system_fingerprint": null,
+            "usage": usage,
+            "service_tier": source.usage.as_ref().and_then(|usage| usage.service_tier.clone()),
+        });
+
+        Ok(Converted {
+            value: serde_json::from_value(value)?,
+            report,
+        })
+    }
+}
+
+impl ConvertFrom<ResponsesResponse> for AnthropicMessagesResponse {
+    fn convert_from(source: &ResponsesResponse) -> Result<Converted<Self>, OpenRouterError> {
+        compose::<_, CompletionsResponse, _>(source)
+    }
+}
+
+impl ConvertFrom<AnthropicMessagesResponse> for ResponsesResponse {
+    fn convert_from(
+        source: &AnthropicMessagesResponse,
+    ) -> Result<Converted<Self>, OpenRouterError> {
+        compose::<_, CompletionsResponse, _>(source)
+    }
+}
+
+fn chat_to_responses_input(
+    messages: &[Message],
+    report: &mut ConversionReport,
+) -> Vec<ResponsesInputItem> {
+    let mut items = Vec::new();
+    for (index, message) in messages.iter().enumerate() {
+        let path = format!("messages[{index}]");
+        if message.name.is_some() {
+            report.push(format!("{path}.name"), "Responses input items have no name");
+        }
+        if message.role == Role::Tool {
+            let output = chat_content_text(&message.content, &path, report);
+            items.push(ResponsesInputItem::function_call_output(
+                message.tool_call_id.clone().unwrap_or_default(),
+                output,
+            ));
+            continue;
+        }
+
+        let assistant = message.role == Role::Assistant;
+        let content = match &message.content {
+            Content::Text(text) => ResponsesMessageContent::Text(text.clone()),
+            Content::Parts(parts) => ResponsesMessageContent::Parts(
+                parts
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(part_index, part)| {
+                        let path = format!("{path}.content[{part_index}]");
+                        chat_part_to_responses(part, assistant, &path, report)
+                    })
+                    .collect(),
+            ),
+        };
+        let has_tool_calls = message.tool_calls.as_ref().is_some_and(|c| !c.is_empty());
+        let empty = match &content {
+            ResponsesMessageContent::Text(text) => text.is_empty(),
+            ResponsesMessageContent::Parts(parts) => parts.is_empty(),
+        };
+        if !(has_tool_calls && empty) {
+            items.push(ResponsesMessage::new(message.role.clone(), content).into());
+        }
+        for call in message.tool_calls.iter().flatten() {
+            items.push(ResponsesInputItem::function_call(
+                &call.id,
+                &call.function.name,
+                &call.function.arguments,
+            ));
+        }
+    }
+    items
+}
+
+fn chat_part_to_responses(
+    part: &ContentPart,
+    assistant: bool,
+    path: &str,
+    report: &mut ConversionReport,
+) -> Option<ResponsesContentPart> {
+    match part {
+        ContentPart::Text {
+            text,
+            cache_control,
+        } => {
+            if cache_control.is_some() {
+                report.push(
+                    format!("{path}.cache_control"),
+                    "Responses content parts have no cache_control",
+                );
+            }
+            Some(if assistant {
+                ResponsesContentPart::output_text(text)
+            } else {
+                ResponsesContentPart::input_text(text)
+            })
+        }
+        ContentPart::ImageUrl { image_url } => Some(ResponsesContentPart::InputImage {
+            image_url: Some(image_url.url.clone()),
+            file_id: None,
+            detail: image_url.detail.clone(),
+        }),
+        ContentPart::File { file } => {
+            let (file_data, file_url) = match &file.file_data {
+                Some(data) if is_remote_url(data) => (None, Some(data.clone())),
+                other => (other.clone(), None),
+            };
+            Some(ResponsesContentPart::InputFile {
+                file_id: file.file_id.clone(),
+                file_data,
+                file_url,
+                filename: file.filename.clone(),
+            })
+        }
+        ContentPart::InputAudio { .. }
+        | ContentPart::VideoUrl { .. }
+        | ContentPart::InputVideo { .. } => {
+            report.push(
+                path,
+                format!(
+                    "`{}` parts are not supported by the Responses API",
+                    type_name(part)
+                ),
+            );
+            None
+        }
+    }
+}
+
+fn responses_to_chat_messages(
+    instructions: Option<String>,
+    input: Option<ResponsesInput>,
+    report: &mut ConversionReport,
+) -> Vec<Message> {
+    let mut messages = Vec::new();
+    if let Some(instructions) = instructions {
+        messages.push(Message::new(Role::System, instructions));
+    }
+    let items = match input {
+        None => Vec::new(),
+        Some(ResponsesInput::Text(text)) => {
+            messages.push(Message::new(Role::User, text));
+            Vec::new()
+        }
+        Some(ResponsesInput::Items(items)) => items,
+    };
+
+    for (index, item) in items.into_iter().enumerate() {
+        let path = format!("input[{index}]");
+        match item {
+            ResponsesInputItem::Message(message) => {
+                let content = match message.content {
+                    ResponsesMessageContent::Text(text) => Content::Text(text),
+                    ResponsesMessageContent::Parts(parts) => Content::Parts(
+                        parts
+                            .into_iter()
+                            .enumerate()
+                            .filter_map(|(part_index, part)| {
+                                let path = format!("{path}.content[{part_index}]");
+                                responses_part_to_chat(part, &path, report)
+                            })
+                            .collect(),
+                    ),
+                };
+                messages.push(Message::new(message.role, content));
+            }
+            ResponsesInputItem::FunctionCall(call) => {
+                let tool_call = ToolCall::new(call.call_id, call.name, call.arguments);
+                match messages.last_mut() {
+                    Some(last) if last.role == Role::Assistant => {
+                        last.tool_calls.get_or_insert_with(Vec::new).push(tool_call);
+                    }
+                    _ => messages.push(Message::assistant_with_tool_calls("", vec![tool_call])),
+                }
+            }
+            ResponsesInputItem::FunctionCallOutput(output) => {
+                messages.push(Message::tool_response(&output.call_id, output.output));
+            }
+            ResponsesInputItem::Reasoning(_) => {
+                report.push(path, "reasoning items have no chat message equivalent");
+            }
+            ResponsesInputItem::Other(value) => report.push(
+                path,
+                format!(
+                    "`{}` items have no chat message equivalent",
+                    type_name(&value)
+                ),
+            ),
+        }
+    }
+    messages
+}
+
+fn responses_part_to_chat(
+    part: ResponsesContentPart,
+    path: &str,
+    report: &mut ConversionReport,
+) -> Option<ContentPart> {
+    match part {
+        ResponsesContentPart::InputText { text } => Some(ContentPart::text(text)),
+        ResponsesContentPart::OutputText {
+            text,
+            annotations,
+            logprobs,
+        } => {
+            if !annotations.is_empty() {
+                report.push(
+                    format!("{path}.annotations"),
+                    "chat content parts have no annotations",
+                );
+            }
+            if logprobs.is_some() {
+                report.push(
+                    format!("{path}.logprobs"),
+                    "chat content parts have no logprobs",
+                );
+            }
+            Some(ContentPart::text(text))
+        }
+        ResponsesContentPart::InputImage {
+            image_url: Some(url),
+            detail,
+            ..
+        } => Some(ContentPart::ImageUrl {
+            image_url: ImageUrl { url, detail },
+        }),
+        ResponsesContentPart::InputFile {
+            file_id,
+            file_data,
+            file_url,
+            filename,
+        } => {
+            if file_data.is_some() && file_url.is_some() {
+                report.push(
+                    format!("{path}.file_url"),
+                    "chat file parts carry either inline data or a URL",
+                );
+            }
+            Some(ContentPart::File {
+                file: FileInput {
+                    file_data: file_data.or(file_url),
+                    file_id,
+                    filename,
+                },
+            })
+        }
+        other => {
+            report.push(
+                path,
+                format!(
+                    "`{}` parts have no chat content equivalent",
+                    type_name(&other)
+                ),
+            );
+            None
+        }
+    }
+}
+
+fn responses_tools_to_chat(tools: Vec<ResponsesTool>, report: &mut ConversionReport) -> Vec<Tool> {
+    let mut converted = Vec::new();
+    for (index, tool) in tools.into_iter().enumerate() {
+        match tool {
+            ResponsesTool::Function(function) => {
+                if function.strict.is_some() {
+                    report.push(
+                        format!("tools[{index}].strict"),
+                        "chat function definitions have no strict flag",
+                    );
+                }
+                converted.push(Tool::new(
+                    &function.name,
+                    function.description.as_deref().unwrap_or_default(),
+                    function.parameters.unwrap_or_else(empty_schema),
+                ));
+            }
+            other => report.push(
+                format!("tools[{index}]"),
+                format!("`{}` tools have no chat equivalent", type_name(&other)),
+            ),
+        }
+    }
+    converted
+}
+
+fn chat_format_to_responses(
+    format: ResponseFormat,
+    report: &mut ConversionReport,
+) -> Option<ResponsesTextFormat> {
+    let kind = match format {
+        ResponseFormat::JsonSchema { json_schema, .. } => return Some(json_schema.into()),
+        ResponseFormat::TypeOnly(kind) | ResponseFormat::Typed { type_: kind } => kind,
+        ResponseFormat::Grammar { type_, .. } => type_,
+    };
+    match kind {
+        ResponseFormatType::Text => Some(ResponsesTextFormat::Text),
+        ResponseFormatType::JsonObject => Some(ResponsesTextFormat::JsonObject),
+        other => {
+            report.push(
+                "response_format",
+                format!(
+                    "`{}` formats have no Responses API equivalent",
+                    type_name(&other)
+                ),
+            );
+            None
+        }
+    }
+}
+
+fn responses_format_to_chat(
+    format: ResponsesTextFormat,
+    report: &mut ConversionReport,
+) -> Option<ResponseFormat> {
+    match format {
+        ResponsesTextFormat::Text => Some(ResponseFormat::text()),
+        ResponsesTextFormat::JsonObject => Some(ResponseFormat::json_object()),
+        ResponsesTextFormat::JsonSchema {
+            name,
+            schema,
+            description,
+            strict,
+        } => {
+            if description.is_some() {
+                report.push(
+                    "text.format.description",
+                    "chat JSON schema formats have no description",
+                );
+            }
+            Some(ResponseFormat::json_schema(
+                name,
+                strict.unwrap_or_default(),
+                schema,
+            ))
+        }
+        ResponsesTextFormat::Other(value) => {
+            report.push(
+                "text.format",
+                format!("`{}` formats have no chat equivalent", type_name(&value)),
+            );
+            None
+        }
+    }
+}
+
+fn chat_to_anthropic_messages(
+    messages: &[Message],
+    report: &mut ConversionReport,
+) -> (Option<AnthropicSystemPrompt>, Vec<AnthropicMessage>) {
+    let leading = messages
+        .iter()
+        .take_while(|message| matches!(message.role, Role::System | Role::Developer))
+        .count();
+
+    let mut blocks = Vec::new();
+    for (index, message) in messages[..leading].iter().enumerate() {
+        let path = format!("messages[{index}]");
+        if message.name.is_some() {
+            report.push(format!("{path}.name"), "system prompts have no name");
+        }
+        match &message.content {
+            Content::Text(text) => blocks.push(AnthropicSystemTextBlock::text(text)),
+            Content::Parts(parts) => {
+                for (part_index, part) in parts.iter().enumerate() {
+                    match part {
+                        ContentPart::Text {
+                            text,
+                            cache_control,
+                        } => {
+                            let mut block = AnthropicSystemTextBlock::text(text);
+                            block.cache_control = cache_control.clone();
+                            blocks.push(block);
+                        }
+                        other => report.push(
+                            format!("{path}.content[{part_index}]"),
+                            format!(
+                                "`{}` parts are not allowed in a system prompt",
+                                type_name(other)
+                            ),
+                        ),
+                    }
+                }
+            }
+        }
+    }
+    let system = match blocks.len() {
+        0 => None,
+        1 if blocks[0].cache_control.is_none() => {
+            Some(AnthropicSystemPrompt::Text(blocks.remove(0).text))
+        }
+        _ => Some(AnthropicSystemPrompt::Blocks(blocks)),
+    };
+
+    let mut converted: Vec<AnthropicMessage> = Vec::new();
+    for (index, message) in messages.iter().enumerate().skip(leading) {
+        let path = format!("messages[{index}]");
+        if message.name.is_some() {
+            report.push(format!("{path}.name"), "Messages API messages have no name");
+        }
+        let content = chat_content_to_anthropic(&message.content, &path, report);
+        match message.role {
+            Role::Tool => {
+                let result = AnthropicContentPart::tool_result(
+                    message.tool_call_id.clone().unwrap_or_default(),
+                    content,
+                );
+                match converted.last_mut() {
+                    Some(AnthropicMessage {
+                        role: AnthropicRole::User,
+                        content: AnthropicMessageContent::Parts(parts),
+                    }) if parts
+                        .iter()
+                        .all(|part| matches!(part, AnthropicContentPart::ToolResult { .. })) =>
+                    {
+                        parts.push(result)
+                    }
+                    _ => converted.push(AnthropicMessage::with_parts(
+                        AnthropicRole::User,
+                        vec![result],
+                    )),
+                }
+            }
+            Role::Assistant => {
+                let calls = message.tool_calls.as_deref().unwrap_or_default();
+                if calls.is_empty() {
+                    converted.push(AnthropicMessage::assistant(content));
+                    continue;
+                }
+                let mut parts = match content {
+                    AnthropicMessageContent::Text(text) if text.is_empty() => Vec::new(),
+                    AnthropicMessageContent::Text(text) => vec![AnthropicContentPart::text(text)],
+                    AnthropicMessageContent::Parts(parts) => parts,
+                };
+                for (call_index, call) in calls.iter().enumerate() {
+                    let input = parse_arguments(
+                        &call.function.arguments,
+                        format!("{path}.tool_calls[{call_index}].function.arguments"),
+                        report,
+                    );
+                    parts.push(AnthropicContentPart::tool_use(
+                        &call.id,
+                        &call.function.name,
+                        input,
+                    ));
+                }
+                converted.push(AnthropicMessage::with_parts(
+                    AnthropicRole::Assistant,
+                    parts,
+                ));
+            }
+            Role::User => converted.push(AnthropicMessage::user(content)),
+            Role::System | Role::Developer => converted.push(AnthropicMessage::system(content)),
+        }
+    }
+    (system, converted)
+}
+
+fn chat_content_to_anthropic(
+    content: &Content,
+    path: &str,
+    report: &mut ConversionReport,
+) -> AnthropicMessageContent {
+    match content {
+        Content::Text(text) => AnthropicMessageContent::Text(text.clone()),
+        Content::Parts(parts) => AnthropicMessageContent::Parts(
+            parts
+                .iter()
+                .enumerate()
+                .filter_map(|(index, part)| {
+                    let path = format!("{path}.content[{index}]");
+                    chat_part_to_anthropic(part, &path, report)
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn chat_part_to_anthropic(
+    part: &ContentPart,
+    path: &str,
+    report: &mut ConversionReport,
+) -> Option<AnthropicContentPart> {
+    match part {
+        ContentPart::Text {
+            text,
+            cache_control,
+        } => Some(AnthropicContentPart::Text {
+            text: text.clone(),
+            citations: None,
+            cache_control: cache_control.clone(),
+        }),
+        ContentPart::ImageUrl { image_url } => {
+            if image_url.detail.is_some() {
+                report.push(
+                    format!("{path}.image_url.detail"),
+                    "Messages API images have no detail level",
+                );
+            }
+            Some(match parse_data_url(&image_url.url) {
+                Some((media_type, data)) => AnthropicContentPart::image_base64(media_type, data),
+                None => AnthropicContentPart::image_url(&image_url.url),
+            })
+        }
+        ContentPart::File { file } => {
+            let source = match (&file.file_id, &file.file_data) {
+                (Some(file_id), _) => json!({ "type": "file", "file_id": file_id }),
+                (None, Some(data)) => match parse_data_url(data) {
+                    Some((media_type, data)) => {
+                        json!({ "type": "base64", "media_type": media_type, "data": data })
+                    }
+                    None => json!({ "type": "url", "url": data }),
+                },
+                (None, None) => {
+                    report.push(path, "file parts need a file_id or file_data");
+                    return None;
+                }
+            };
+            Some(AnthropicContentPart::Document {
+                source,
+                title: file.filename.clone(),
+                context: None,
+                citations: None,
+                cache_control: None,
+            })
+        }
+        ContentPart::InputAudio { .. }
+        | ContentPart::VideoUrl { .. }
+        | ContentPart::InputVideo { .. } => {
+            report.push(
+                path,
+                format!(
+                    "`{}` parts are not supported by the Messages API",
+                    type_name(part)
+                ),
+            );
+            None
+        }
+    }
+}
+
+fn anthropic_to_chat_messages(
+    system: Option<AnthropicSystemPrompt>,
+    messages: Vec<AnthropicMessage>,
+    report: &mut ConversionReport,
+) -> Vec<Message> {
+    let mut converted = Vec::new();
+    match system {
+        Some(AnthropicSystemPrompt::Text(text)) => {
+            converted.push(Message::new(Role::System, text));
+        }
+        Some(AnthropicSystemPrompt::Blocks(blocks)) => {
+            let mut parts = Vec::new();
+            for (index, block) in blocks.into_iter().enumerate() {
+                if block.citations.is_some() {
+                    report.push(
+                        format!("system[{index}].citations"),
+                        "chat text parts have no citations",
+                    );
+                }
+                for key in block.extra.keys() {
+                    report.push(
+                        format!("system[{index}].{key}"),
+                        "no equivalent in the chat completions API",
+                    );
+                }
+                parts.push(ContentPart::Text {
+                    text: block.text,
+                    cache_control: block.cache_control,
+                });
+            }
+            converted.push(Message::new(Role::System, collapse_parts(parts)));
+        }
+        None => {}
+    }
+
+    for (index, message) in messages.into_iter().enumerate() {
+        let path = format!("messages[{index}]");
+        let role = match message.role {
+            AnthropicRole::User => Role::User,
+            AnthropicRole::Assistant => Role::Assistant,
+            AnthropicRole::System => Role::System,
+        };
+        let parts = match message.content {
+            AnthropicMessageContent::Text(text) => {
+                converted.push(Message::new(role, text));
+                continue;
+            }
+            AnthropicMessageContent::Parts(parts) => parts,
+        };
+
+        let mut content = Vec::new();
+        let mut tool_calls = Vec::new();
+        for (part_index, part) in parts.into_iter().enumerate() {
+            let part_path = format!("{path}.content[{part_index}]");
+            match part {
+                AnthropicContentPart::ToolUse {
+                    id,
+                    name,
+                    input,
+                    cache_control,
+                } if role == Role::Assistant => {
+                    if cache_control.is_some() {
+                        report.push(
+                            format!("{part_path}.cache_control"),
+                            "chat tool calls have no cache_control",
+                        );
+                    }
+                    let arguments = input.map_or_else(|| "{}".to_string(), |v| v.to_string());
+                    tool_calls.push(ToolCall::new(id, name, arguments));
+                }
+                AnthropicContentPart::ToolResult {
+                    tool_use_id,
+                    content: result,
+                    is_error,
+                    cache_control,
+                } => {
+                    if is_error.is_some() {
+                        report.push(
+                            format!("{part_path}.is_error"),
+                            "chat tool messages have no error flag",
+                        );
+                    }
+                    if cache_control.is_some() {
+                        report.push(
+                            format!("{part_path}.cache_control"),
+                            "chat tool messages have no cache_control",
+                        );
+                    }
+                    let result = match result {
+                        None => Content::Text(String::new()),
+                        Some(AnthropicMessageContent::Text(text)) => Content::Text(text),
+                        Some(AnthropicMessageContent::Parts(parts)) => collapse_parts(
+                            parts
+                                .into_iter()
+                                .enumerate()
+                                .filter_map(|(result_index, part)| {
+                                    let path = format!("{part_path}.content[{result_index}]");
+                                    anthropic_part_to_chat(part, &path, report)
+                                })
+                                .collect(),
+                        ),
+                    };
+                    converted.push(Message::tool_response(&tool_use_id, result));
+                }
+                other => {
+                    if let Some(part) = anthropic_part_to_chat(other, &part_path, report) {
+                        content.push(part);
+                    }
+                }
+            }
+        }
+
+        if !tool_calls.is_empty() {
+            converted.push(Message::assistant_with_tool_calls(
+                collapse_parts(content),
+                tool_calls,
+            ));
+        } else if !content.is_empty() {
+            converted.push(Message::new(role, collapse_parts(content)));
+        }
+    }
+    converted
+}
+
+fn anthropic_part_to_chat(
+    part: AnthropicContentPart,
+    path: &str,
+    report: &mut ConversionReport,
+) -> Option<ContentPart> {
+    match part {
+        AnthropicContentPart::Text {
+            text,
+            citations,
+            cache_control,
+        } => {
+            if citations.is_some() {
+                report.push(
+                    format!("{path}.citations"),
+                    "chat text parts have no citations",
+                );
+            }
+            Some(ContentPart::Text {
+                text,
+                cache_control,
+            })
+        }
+        AnthropicContentPart::Image {
+            source,
+            cache_control,
+        } => {
+            if cache_control.is_some() {
+                report.push(
+                    format!("{path}.cache_control"),
+                    "chat image parts have no cache_control",
+                );
+            }
+            let url = source_url(&source);
+            if url.is_none() {
+                report.push(
+                    format!("{path}.source"),
+                    "only url and base64 image sources map to chat",
+                );
+            }
+            url.map(ContentPart::image_url)
+        }
+        AnthropicContentPart::Document {
+            source,
+            title,
+            context,
+            citations,
+            cache_control,
+        } => {
+            for (field, present) in [
+                ("context", context.is_some()),
+                ("citations", citations.is_some()),
+                ("cache_control", cache_control.is_some()),
+            ] {
+                if present {
+                    report.push(
+                        format!("{path}.{field}"),
+                        "chat file parts have no equivalent",
+                    );
+                }
+            }
+            let file = match source.get("type").and_then(Value::as_str) {
+                Some("file") => source
+                    .get("file_id")
+                    .and_then(Value::as_str)
+                    .map(FileInput::from_id),
+                _ => source_url(&source).map(FileInput::from_data),
+            };
+            let Some(mut file) = file else {
+                report.push(
+                    format!("{path}.source"),
+                    "only file, url, and base64 document sources map to chat",
+                );
+                return None;
+            };
+            file.filename = title;
+            Some(ContentPart::File { file })
+        }
+        other => {
+            report.push(
+                path,
+                format!(
+                    "`{}` blocks have no chat content equivalent",
+                    type_name(&other)
+                ),
+            );
+            None
+        }
+    }
+}
+
+fn anthropic_tools_to_chat(tools: Vec<AnthropicTool>, report: &mut ConversionReport) -> Vec<Tool> {
+    let mut converted = Vec::new();
+    for (index, tool) in tools.into_iter().enumerate() {
+        let path = format!("tools[{index}]");
+        if tool
+            .tool_type
+            .as_deref()
+            .is_some_and(|kind| kind != "custom")
+        {
+            report.push(
+                path,
+                format!(
+                    "`{}` tools have no chat equivalent",
+                    tool.tool_type.unwrap_or_default()
+                ),
+            );
+            continue;
+        }
+        if tool.cache_control.is_some() {
+            report.push(
+                format!("{path}.cache_control"),
+                "chat tools have no cache_control",
+            );
+        }
+        for key in tool.extra.keys() {
+            report.push(
+                format!("{path}.{key}"),
+                "no equivalent in the chat completions API",
+            );
+        }
+        converted.push(Tool::new(
+            &tool.name,
+            tool.description.as_deref().unwrap_or_default(),
+            tool.input_schema.unwrap_or_else(empty_schema),
+        ));
+    }
+    converted
+}
+
+fn chat_reasoning_to_anthropic(
+    reasoning: ReasoningConfig,
+    report: &mut ConversionReport,
+) -> (Option<AnthropicThinking>, Option<AnthropicOutputConfig>) {
+    if reasoning.exclude.is_some() {
+        report.push("reasoning.exclude", "the Messages API has no exclude flag");
+    }
+    let effort = match &reasoning.effort {
+        Some(Effort::Low) => Some(AnthropicOutputEffort::Low),
+        Some(Effort::Medium) => Some(AnthropicOutputEffort::Medium),
+        Some(Effort::High) => Some(AnthropicOutputEffort::High),
+        Some(Effort::Max) => Some(AnthropicOutputEffort::Max),
+        Some(Effort::None) | None => None,
+        Some(other) => {
+            report.push(
+                "reasoning.effort",
+                format!("`{other}` has no Messages API equivalent"),
+            );
+            None
+        }
+    };
+    let thinking = if reasoning.enabled == Some(false) || reasoning.effort == Some(Effort::None) {
+        Some(AnthropicThinking::disabled())
+    } else if let Some(budget_tokens) = reasoning.max_tokens {
+        Some(AnthropicThinking::enabled(budget_tokens))
+    } else if reasoning.enabled == Some(true) || effort.is_some() {
+        Some(AnthropicThinking::adaptive())
+    } else {
+        None
+    };
+    (thinking, effort.map(AnthropicOutputConfig::with_effort))
+}
+
+fn anthropic_reasoning_to_chat(
+    thinking: Option<AnthropicThinking>,
+    output_config: Option<AnthropicOutputConfig>,
+) -> Option<ReasoningConfig> {
+    let effort = output_config
+        .and_then(|config| config.effort)
+        .map(|effort| match effort {
+            AnthropicOutputEffort::Low => Effort::Low,
+            AnthropicOutputEffort::Medium => Effort::Medium,
+            AnthropicOutputEffort::High => Effort::High,
+            AnthropicOutputEffort::Max => Effort::Max,
+        });
+    let (max_tokens, enabled) = match thinking {
+        Some(AnthropicThinking::Enabled { budget_tokens }) => (Some(budget_tokens), None),
+        Some(AnthropicThinking::Disabled) => (None, Some(false)),
+        Some(AnthropicThinking::Adaptive) if effort.is_none() => (None, Some(true)),
+        _ => (None, None),
+    };
+    if effort.is_none() && max_tokens.is_none() && enabled.is_none() {
+        return None;
+    }
+    Some(ReasoningConfig {
+        effort,
+        max_tokens,
+        exclude: None,
+        enabled,
+    })
+}
+
+/// The first non-streaming choice; any other choice is reported.
+fn first_choice<'a>(
+    source: &'a CompletionsResponse,
+    report: &mut ConversionReport,
+) -> Option<&'a NonStreamingChoice> {
+    let mut first = None;
+    for (index, choice) in source.choices.iter().enumerate() {
+        match choice {
+            Choice::NonStreaming(choice) if index == 0 => first = Some(choice),
+            _ if index > 0 => report.push(
+                format!("choices[{index}]"),
+                "only the first choice is converted",
+            ),
+            _ => report.push(
+                format!("choices[{index}]"),
+                "only non-streaming chat choices are converted",
+            ),
+        }
+    }
+    first
+}
+
+fn report_chat_response_extras(source: &CompletionsResponse, report: &mut ConversionReport) {
+    for (field, present) in [
+        ("provider", source.provider.is_some()),
+        ("system_fingerprint", source.system_fingerprint.is_some()),
+        ("openrouter_metadata", source.openrouter_metadata.is_some()),
+    ] {
+        if present {
+            report.push(field, "no equivalent in the target response");
+        }
+    }
+}
+
+fn chat_reasoning_to_responses_item(choice: &NonStreamingChoice) -> Option<ResponsesReasoning> {
+    let message = &choice.message;
+    let details = message.reasoning_details.as_deref().unwrap_or_default();
+    if message.reasoning.is_none() && details.is_empty() {
+        return None;
+    }
+
+    let mut item = ResponsesReasoning::default();
+    let mut content = Vec::new();
+    for detail in details {
+        match detail.block_type.as_str() {
+            "reasoning.encrypted" => item.encrypted_content = detail.data.clone(),
+            "reasoning.summary" => {
+                if let Some(text) = detail.content() {
+                    item.summary.push(ResponsesReasoningPart::SummaryText {
+                        text: text.to_string(),
+                    });
+                }
+            }
+            _ => {
+                if let Some(text) = &detail.text {
+                    content.push(ResponsesReasoningPart::ReasoningText { text: text.clone() });
+                }
+            }
+        }
+        item.id = item.id.take().or_else(|| detail.id.clone());
+        item.signature = item.signature.take().or_else(|| detail.signature.clone());
+        item.format = item.format.take().or_else(|| detail.format.clone());
+    }
+    if content.is_empty() {
+        if let Some(text) = &message.reasoning {
+            content.push(ResponsesReasoningPart::ReasoningText { text: text.clone() });
+        }
+    }
+    if !content.is_empty() {
+        item.content = Some(content);
+    }
+    Some(item)
+}
+
+fn responses_reasoning_to_details(item: &ResponsesReasoning) -> Vec<Value> {
+    let mut details = Vec::new();
+    let with_metadata = |mut detail: Value| {
+        for (key, value) in [
+            ("id", &item.id),
+            ("format", &item.format),
+            ("signature", &item.signature),
+        ] {
+            if let Some(value) = value {
+                detail[key] = Value::from(value.clone());
+            }
+        }
+        detail
+    };
+    for part in &item.summary {
+        if let Some(text) = part.text() {
+            details.push(json!({ "type": "reasoning.summary", "text": text }));
+        }
+    }
+    for part in item.content.iter().flatten() {
+        if let Some(text) = part.text() {
+            details.push(with_metadata(
+                json!({ "type": "reasoning.text", "text": text }),
+            ));
+        }
+    }
+    if let Some(data) = &item.encrypted_content {
+        details.push(with_metadata(
+            json!({ "type": "reasoning.encrypted", "data": data }),
+        ));
+    }
+    details
+}
+
+fn responses_usage_to_chat(usage: &Value, report: &mut ConversionReport) -> Value {
+    let tokens = |key: &str| usage.get(key).and_then(Value::as_u64).unwrap_or_default();
+    let prompt_tokens = tokens("input_tokens");
+    let completion_tokens = tokens("output_tokens");
+    let total_tokens = usage
+        .get("total_tokens")
+        .and_then(Value::as_u64)
+        .unwrap_or(prompt_tokens + completion_tokens);
+
+    let mut converted = json!({
+        "prompt_tokens": prompt_tokens,
+        "completion_tokens": completion_tokens,
+        "total_tokens": total_tokens,
+    });
+    for (key, value) in usage.as_object().into_iter().flatten() {
+        match key.as_str() {
+            "input_tokens" | "output_tokens" | "total_tokens" => {}
+            "cost" | "cost_details" | "is_byok" | "server_tool_use_details" => {
+                converted[key] = value.clone();
+            }
+            _ => report.push(
+                format!("usage.{key}"),
+                "no equivalent in the chat completions API",
+            ),
+        }
+    }
+    converted
+}
+
+/// Chat content reduced to text; non-text parts are reported.
+fn chat_content_text(content: &Content, path: &str, report: &mut ConversionReport) -> String {
+    match content {
+        Content::Text(text) => text.clone(),
+        Content::Parts(parts) => {
+            let mut text = String::new();
+            for (index, part) in parts.iter().enumerate() {
+                match part {
+                    ContentPart::Text { text: part, .. } => text.push_str(part),
+                    other => report.push(
+                        format!("{path}.content[{index}]"),
+                        format!(
+                            "`{}` parts are not allowed in a function call output",
+                            type_name(other)
+                        ),
+                    ),
+                }
+            }
+            text
+        }
+    }
+}
+
+/// Plain text when `parts` is a single text part without cache control.
+fn collapse_parts(mut parts: Vec<ContentPart>) -> Content {
+    match parts.as_slice() {
+        [] => Content::Text(String::new()),
+        [
+            ContentPart::Text {
+                cache_control: None,
+                ..
+            },
+        ] => match parts.remove(0) {
+            ContentPart::Text { text, .. } => Content::Text(text),
+            part => Content::Parts(vec![part]),
+        },
+        _ => Content::Parts(parts),
+    }
+}
+
+fn append(target: &mut Option<String>, text: &str) {
+    target.get_or_insert_with(String::new).push_str(text);
+}
+
+fn parse_arguments(arguments: &str, path: String, report: &mut ConversionReport) -> Value {
+    serde_json::from_str(arguments).unwrap_or_else(|_| {
+        report.push(
+            path,
+            "arguments are not valid JSON and were sent as a string",
+        );
+        Value::String(arguments.to_string())
+    })
+}
+
+fn parse_data_url(url: &str) -> Option<(&str, &str)> {
+    url.strip_prefix("data:")?.split_once(";base64,")
+}
+
+fn is_remote_url(value: &str) -> bool {
+    value.starts_with("https://") || value.starts_with("http://")
+}
+
+/// URL for an Anthropic `url` or `base64` source, as a data URL for the latter.
+fn source_url(source: &Value) -> Option<String> {
+    let field = |key: &str| source.get(key).and_then(Value::as_str);
+    match field("type")? {
+        "url" => field("url").map(str::to_string),
+        "base64" => Some(format!(
+            "data:{};base64,{}",
+            field("media_type")?,
+            field("data")?
+        )),
+        _ => None,
+    }
+}
+
+fn empty_schema() -> Value {
+    json!({ "type": "object", "properties": {} })
+}
+
+/// The serialized `type` tag of a value, for report messages.
+fn type_name(value: &impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(kind)) => kind,
+        Ok(value) => value
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown")
+            .to_string(),
+        Err(_) => "unknown".to_string(),
+    }
+}
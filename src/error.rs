@@ -7,7 +7,8 @@
 //! ## 🎯 Error Categories
 //!
 //! ### HTTP Request Errors
-//! - **`HttpRequest`**: Network-level failures (connectivity, timeouts)
+//! - **`HttpRequest`**: Network-level failures (connection refused, DNS, TLS)
+//! - **`Timeout`**: Connect, request, stream-idle, or polling deadlines that elapsed
 //! - **`Api`**: Normalized API failures from the OpenRouter API
 //!
 //! ### OpenRouter API Errors
@@ -15,6 +16,9 @@
 //! - **`ApiErrorKind::Moderation`**: Content moderation violations
 //! - **`ApiErrorKind::Provider`**: Provider-specific upstream failures
 //!
+//! ### Budget Errors
+//! - **`BudgetExceeded`**: A [`BudgetGuard`](crate::budget::BudgetGuard) limit was reached before sending
+//!
 //! ### Validation Errors
 //! - **`ConfigError`**: Invalid SDK configuration or request/tool validation issues
 //! - **`KeyNotConfigured`**: Missing or invalid API keys
@@ -22,6 +26,11 @@
 //! ### Data Processing Errors
 //! - **`UninitializedFieldError`**: Builder pattern validation failures
 //! - **`Serialization`**: JSON serialization/deserialization errors
+//! - **`StructuredOutput`**: Model replies that do not parse into the requested type (keeps the raw text)
+//!
+//! ### Long-Running Jobs
+//! - **`JobFailed`**: A polled job (e.g. video generation) finished in a failure state
+//! - **`Cancelled`**: A wait was stopped through its cancellation token
 //!
 //! ### System Errors
 //! - **`Io`**: File system and I/O operations
@@ -44,6 +53,9 @@
 //!     Err(OpenRouterError::Api(api_error)) => {
 //!         eprintln!("API error {}: {}", api_error.status, api_error.message);
 //!     }
+//!     Err(OpenRouterError::Timeout(timeout)) => {
+//!         eprintln!("Timed out: {}", timeout);
+//!     }
 //!     Err(OpenRouterError::HttpRequest(e)) => {
 //!         eprintln!("Network error: {}", e);
 //!     }
@@ -100,11 +112,13 @@
 //!
 //! The SDK automatically converts common error types:
 //!
-//! - transport client errors → `OpenRouterError::HttpRequest`
+//! - transport client errors → `OpenRouterError::HttpRequest` (or `OpenRouterError::Timeout` for elapsed deadlines)
 //! - `serde_json::Error` → `OpenRouterError::Serialization`
 //! - `std::io::Error` → `OpenRouterError::Io`
 //! - `derive_builder::UninitializedFieldError` → `OpenRouterError::UninitializedFieldError`
 
+use std::time::Duration;
+
 use http::StatusCode;
 use serde_json::Value;
 use thiserror::Error;
@@ -114,18 +128,235 @@ use thiserror::Error;
 #[error("{message}")]
 pub struct HttpRequestError {
     message: String,
+    is_connect: bool,
 }
 
 impl HttpRequestError {
     pub fn new(message: impl Into<String>) -> Self {
         Self {
             message: message.into(),
+            is_connect: false,
+        }
+    }
+
+    /// An error raised while establishing the connection, which retry
+    /// policies and routers treat as a connection failure.
+    pub fn connect(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            is_connect: true,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns true if the request failed while establishing the connection.
+    pub fn is_connect(&self) -> bool {
+        self.is_connect
+    }
+}
+
+/// Which deadline elapsed for an [`OpenRouterError::Timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeoutKind {
+    /// Establishing the TCP/TLS connection took too long.
+    Connect,
+    /// The request (or a body read) exceeded its deadline.
+    Request,
+    /// A stream produced no SSE bytes within the idle window.
+    StreamIdle,
+    /// A polled job did not finish before the overall deadline.
+    Poll,
+}
+
+impl std::fmt::Display for TimeoutKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Connect => write!(f, "connect"),
+            Self::Request => write!(f, "request"),
+            Self::StreamIdle => write!(f, "stream idle"),
+            Self::Poll => write!(f, "poll"),
+        }
+    }
+}
+
+/// Timeout details used by [`OpenRouterError::Timeout`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub struct TimeoutError {
+    kind: TimeoutKind,
+    duration: Option<Duration>,
+}
+
+impl TimeoutError {
+    pub fn new(kind: TimeoutKind, duration: Option<Duration>) -> Self {
+        Self { kind, duration }
+    }
+
+    pub fn kind(&self) -> TimeoutKind {
+        self.kind
+    }
+
+    /// The configured deadline, when known.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+}
+
+impl std::fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.duration {
+            Some(duration) => write!(f, "{} timeout elapsed after {duration:?}", self.kind),
+            None => write!(f, "{} timeout elapsed", self.kind),
+        }
+    }
+}
+
+/// Details used by [`OpenRouterError::StructuredOutput`] when a reply cannot be
+/// deserialized into the requested type.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{message} (after {attempts} attempt(s))")]
+pub struct StructuredOutputError {
+    raw: String,
+    message: String,
+    attempts: usize,
+}
+
+impl StructuredOutputError {
+    pub fn new(raw: impl Into<String>, message: impl Into<String>, attempts: usize) -> Self {
+        Self {
+            raw: raw.into(),
+            message: message.into(),
+            attempts,
         }
     }
 
+    /// The raw model text of the last attempt.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// The deserialization error of the last attempt.
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Number of model calls made, including repair attempts.
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+}
+
+/// Details used by [`OpenRouterError::JobFailed`] when a polled job ends in a
+/// failure state.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("job {job_id} ended with status `{status}`{}", .message.as_deref().map(|message| format!(": {message}")).unwrap_or_default())]
+pub struct JobError {
+    job_id: String,
+    status: String,
+    message: Option<String>,
+}
+
+impl JobError {
+    pub fn new(
+        job_id: impl Into<String>,
+        status: impl Into<String>,
+        message: Option<String>,
+    ) -> Self {
+        Self {
+            job_id: job_id.into(),
+            status: status.into(),
+            message,
+        }
+    }
+
+    pub fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
+    /// The terminal status reported by the API (e.g. `failed`).
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /// The error message reported for the job, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+/// Which limit a [`BudgetGuard`](crate::budget::BudgetGuard) found crossed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BudgetKind {
+    /// Spend since the guard was created or last reset.
+    Session,
+    /// Spend since midnight UTC.
+    Daily,
+    /// Remaining account credits.
+    CreditFloor,
+}
+
+impl std::fmt::Display for BudgetKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Session => write!(f, "session budget"),
+            Self::Daily => write!(f, "daily budget"),
+            Self::CreditFloor => write!(f, "credit floor"),
+        }
+    }
+}
+
+/// Details used by [`OpenRouterError::BudgetExceeded`], and passed to
+/// budget warning handlers.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub struct BudgetError {
+    kind: BudgetKind,
+    limit: f64,
+    current: f64,
+}
+
+impl BudgetError {
+    pub fn new(kind: BudgetKind, limit: f64, current: f64) -> Self {
+        Self {
+            kind,
+            limit,
+            current,
+        }
+    }
+
+    pub fn kind(&self) -> BudgetKind {
+        self.kind
+    }
+
+    /// The configured budget or credit floor.
+    pub fn limit(&self) -> f64 {
+        self.limit
+    }
+
+    /// Spend so far, or remaining credits for [`BudgetKind::CreditFloor`].
+    pub fn current(&self) -> f64 {
+        self.current
+    }
+}
+
+impl std::fmt::Display for BudgetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            BudgetKind::CreditFloor => write!(
+                f,
+                "{} of {} reached ({} credits remaining)",
+                self.kind, self.limit, self.current
+            ),
+            _ => write!(
+                f,
+                "{} of {} reached ({} spent)",
+                self.kind, self.limit, self.current
+            ),
+        }
+    }
 }
 
 /// Normalized API error category.
@@ -235,10 +466,27 @@ pub enum OpenRouterError {
     #[error("HTTP request failed: {0}")]
     HttpRequest(HttpRequestError),
 
+    #[error("Timeout: {0}")]
+    Timeout(TimeoutError),
+
     // API response errors
     #[error("{0}")]
     Api(Box<ApiErrorContext>),
 
+    #[error("Structured output error: {0}")]
+    StructuredOutput(Box<StructuredOutputError>),
+
+    // Long-running job errors
+    #[error("Job failed: {0}")]
+    JobFailed(Box<JobError>),
+
+    #[error("Operation cancelled")]
+    Cancelled,
+
+    // Spend control errors
+    #[error("Budget exceeded: {0}")]
+    BudgetExceeded(Box<BudgetError>),
+
     // Configuration errors
     #[error("Config error: {0}")]
     ConfigError(String),
@@ -262,8 +510,62 @@ pub enum OpenRouterError {
     Unknown(String),
 }
 
+impl OpenRouterError {
+    /// A copy of this error for a second consumer, such as a stream
+    /// collector. Variants are kept; the `serde_json` and IO sources, which
+    /// cannot be cloned, are rebuilt from their message.
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Self::HttpRequest(error) => Self::HttpRequest(error.clone()),
+            Self::Timeout(error) => Self::Timeout(error.clone()),
+            Self::Api(context) => Self::Api(context.clone()),
+            Self::StructuredOutput(error) => Self::StructuredOutput(error.clone()),
+            Self::JobFailed(error) => Self::JobFailed(error.clone()),
+            Self::Cancelled => Self::Cancelled,
+            Self::BudgetExceeded(error) => Self::BudgetExceeded(error.clone()),
+            Self::ConfigError(message) => Self::ConfigError(message.clone()),
+            Self::KeyNotConfigured => Self::KeyNotConfigured,
+            Self::UninitializedFieldError(error) => Self::UninitializedFieldError(error.clone()),
+            Self::Serialization(error) => {
+                Self::Serialization(serde::de::Error::custom(error.to_string()))
+            }
+            Self::Io(error) => Self::Io(std::io::Error::new(error.kind(), error.to_string())),
+            Self::Unknown(message) => Self::Unknown(message.clone()),
+        }
+    }
+}
+
+impl From<StructuredOutputError> for OpenRouterError {
+    fn from(err: StructuredOutputError) -> Self {
+        OpenRouterError::StructuredOutput(Box::new(err))
+    }
+}
+
+impl From<JobError> for OpenRouterError {
+    fn from(err: JobError) -> Self {
+        OpenRouterError::JobFailed(Box::new(err))
+    }
+}
+
+impl From<BudgetError> for OpenRouterError {
+    fn from(err: BudgetError) -> Self {
+        OpenRouterError::BudgetExceeded(Box::new(err))
+    }
+}
+
 impl From<reqwest::Error> for OpenRouterError {
     fn from(err: reqwest::Error) -> Self {
-        OpenRouterError::HttpRequest(HttpRequestError::new(err.to_string()))
+        if err.is_timeout() {
+            let kind = if err.is_connect() {
+                TimeoutKind::Connect
+            } else {
+                TimeoutKind::Request
+            };
+            OpenRouterError::Timeout(TimeoutError::new(kind, None))
+        } else if err.is_connect() {
+            OpenRouterError::HttpRequest(HttpRequestError::connect(err.to_string()))
+        } else {
+            OpenRouterError::HttpRequest(HttpRequestError::new(err.to_string()))
+        }
     }
 }
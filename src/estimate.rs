@@ -0,0 +1,430 @@
+//! # Token and Cost Estimation
+//!
+//! Approximate the prompt size of a request and project its cost from a
+//! model's pricing before sending it.
+//!
+//! Estimates are heuristic, not tokenizer-exact:
+//!
+//! - text counts about four characters per token, plus a small per-message overhead
+//! - images count by `detail` level: [`LOW_DETAIL_IMAGE_TOKENS`] for `"low"`,
+//!   [`HIGH_DETAIL_IMAGE_TOKENS`] (a 1024×1024 image) for `"high"`, `"auto"`, or unset
+//! - tool definitions count as the text of their JSON schema
+//! - audio, video, and file parts are not estimated; they are counted in
+//!   [`TokenEstimate::unestimated_parts`] so callers can decide how to treat them
+//!
+//! The minimum cost covers the prompt, per-image, and per-request prices. The
+//! maximum adds the completion price for the largest possible completion:
+//! the request's max tokens, else the model's completion limit, else the
+//! remaining context window.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use openrouter_rs::{
+//!     OpenRouterClient,
+//!     api::chat::{ChatCompletionRequest, Message},
+//!     estimate::estimate_cost,
+//!     types::Role,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+//! let model = client.models().get("openai", "gpt-4.1-mini").await?;
+//! let request = ChatCompletionRequest::builder()
+//!     .model("openai/gpt-4.1-mini")
+//!     .messages(vec![Message::new(Role::User, "Summarize this report ...")])
+//!     .max_tokens(500)
+//!     .build()?;
+//!
+//! let estimate = estimate_cost(&request, &model)?;
+//! estimate.ensure_within(0.01)?;
+//! println!("~{} prompt tokens", estimate.tokens.prompt_tokens());
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    api::{
+        chat::{ChatCompletionRequest, Message},
+        messages::AnthropicMessagesRequest,
+        models::{Endpoint, Model},
+        responses::ResponsesRequest,
+    },
+    error::OpenRouterError,
+    types::Tool,
+};
+
+/// Tokens counted for a `"low"` detail image.
+pub const LOW_DETAIL_IMAGE_TOKENS: u32 = 85;
+/// Tokens counted for a `"high"`, `"auto"`, or unspecified detail image.
+pub const HIGH_DETAIL_IMAGE_TOKENS: u32 = 765;
+
+const MESSAGE_OVERHEAD_TOKENS: u32 = 4;
+const CHARS_PER_TOKEN: u32 = 4;
+
+/// Approximate prompt size of a request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TokenEstimate {
+    /// Text, tool-call, and per-message overhead tokens.
+    pub text_tokens: u32,
+    /// Tokens attributed to images.
+    pub image_tokens: u32,
+    /// Tokens attributed to tool definitions.
+    pub tool_tokens: u32,
+    /// Number of images in the prompt.
+    pub images: u32,
+    /// Content parts (audio, video, files, ...) that were not estimated.
+    pub unestimated_parts: u32,
+    /// The request's completion limit, if it sets one.
+    pub max_output_tokens: Option<u32>,
+}
+
+impl TokenEstimate {
+    /// Total estimated prompt tokens.
+    pub fn prompt_tokens(&self) -> u32 {
+        self.text_tokens + self.image_tokens + self.tool_tokens
+    }
+
+    fn add_text(&mut self, text: &str) {
+        self.text_tokens += text_tokens(text);
+    }
+
+    fn add_image(&mut self, detail: Option<&str>) {
+        self.images += 1;
+        self.image_tokens += match detail {
+            Some("low") => LOW_DETAIL_IMAGE_TOKENS,
+            _ => HIGH_DETAIL_IMAGE_TOKENS,
+        };
+    }
+
+    fn add_tools(&mut self, tools: Option<&Value>) {
+        if let Some(Value::Array(tools)) = tools {
+            for tool in tools {
+                self.tool_tokens += text_tokens(&tool.to_string());
+            }
+        }
+    }
+
+    /// Count one message-like value: its content plus any tool-call payloads.
+    fn add_message(&mut self, message: &Value) {
+        self.text_tokens += MESSAGE_OVERHEAD_TOKENS;
+        match message.get("content") {
+            Some(content) => self.add_content(content),
+            None => self.add_part(message),
+        }
+        for call in message
+            .get("tool_calls")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(function) = call.get("function") {
+                self.add_str_field(function, "name");
+                self.add_str_field(function, "arguments");
+            }
+        }
+    }
+
+    fn add_content(&mut self, content: &Value) {
+        match content {
+            Value::String(text) => self.add_text(text),
+            Value::Array(parts) => parts.iter().for_each(|part| self.add_part(part)),
+            Value::Null => {}
+            other => self.add_part(other),
+        }
+    }
+
+    fn add_part(&mut self, part: &Value) {
+        let part_type = part.get("type").and_then(Value::as_str).unwrap_or_default();
+        match part_type {
+            "text" | "input_text" | "output_text" => self.add_str_field(part, "text"),
+            "thinking" => self.add_str_field(part, "thinking"),
+            "image_url" => self.add_image(
+                part.get("image_url")
+                    .and_then(|image| image.get("detail"))
+                    .and_then(Value::as_str),
+            ),
+            "input_image" => self.add_image(part.get("detail").and_then(Value::as_str)),
+            "image" => self.add_image(None),
+            "tool_use" | "server_tool_use" => {
+                self.add_str_field(part, "name");
+                if let Some(input) = part.get("input") {
+                    self.add_text(&input.to_string());
+                }
+            }
+            "tool_result" => {
+                if let Some(content) = part.get("content") {
+                    self.add_content(content);
+                }
+            }
+            "function_call" => {
+                self.add_str_field(part, "name");
+                self.add_str_field(part, "arguments");
+            }
+            "function_call_output" => self.add_str_field(part, "output"),
+            "redacted_thinking" => {}
+            _ => self.unestimated_parts += 1,
+        }
+    }
+
+    fn add_str_field(&mut self, value: &Value, field: &str) {
+        if let Some(text) = value.get(field).and_then(Value::as_str) {
+            self.add_text(text);
+        }
+    }
+}
+
+fn text_tokens(text: &str) -> u32 {
+    (text.chars().count() as u32).div_ceil(CHARS_PER_TOKEN)
+}
+
+fn to_value<T: Serialize>(request: &T) -> Value {
+    serde_json::to_value(request).unwrap_or(Value::Null)
+}
+
+fn max_tokens(request: &Value, fields: &[&str]) -> Option<u32> {
+    fields.iter().find_map(|field| {
+        request
+            .get(*field)
+            .and_then(Value::as_u64)
+            .map(|tokens| tokens as u32)
+    })
+}
+
+/// Requests whose prompt size can be estimated.
+pub trait EstimateTokens {
+    fn estimate_tokens(&self) -> TokenEstimate;
+}
+
+/// Estimate a request body built around a `messages` array (chat
+/// completions and `/messages`), with an optional `system` prompt.
+fn estimate_messages_body(request: &Value, max_fields: &[&str]) -> TokenEstimate {
+    let mut estimate = TokenEstimate {
+        max_output_tokens: max_tokens(request, max_fields),
+        ..TokenEstimate::default()
+    };
+    if let Some(system) = request.get("system") {
+        estimate.text_tokens += MESSAGE_OVERHEAD_TOKENS;
+        estimate.add_content(system);
+    }
+    for message in request
+        .get("messages")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        estimate.add_message(message);
+    }
+    estimate.add_tools(request.get("tools"));
+    estimate
+}
+
+/// Estimate a Responses API request body.
+fn estimate_responses_body(request: &Value) -> TokenEstimate {
+    let mut estimate = TokenEstimate {
+        max_output_tokens: max_tokens(request, &["max_output_tokens"]),
+        ..TokenEstimate::default()
+    };
+    if let Some(instructions) = request.get("instructions").and_then(Value::as_str) {
+        estimate.text_tokens += MESSAGE_OVERHEAD_TOKENS;
+        estimate.add_text(instructions);
+    }
+    match request.get("input") {
+        Some(Value::String(text)) => {
+            estimate.text_tokens += MESSAGE_OVERHEAD_TOKENS;
+            estimate.add_text(text);
+        }
+        Some(Value::Array(items)) => items.iter().for_each(|item| estimate.add_message(item)),
+        _ => {}
+    }
+    estimate.add_tools(request.get("tools"));
+    estimate
+}
+
+/// Estimate a serialized chat, responses, or messages request body.
+pub(crate) fn estimate_request_body(request: &Value) -> TokenEstimate {
+    if request.get("messages").is_some() {
+        estimate_messages_body(request, &["max_completion_tokens", "max_tokens"])
+    } else {
+        estimate_responses_body(request)
+    }
+}
+
+impl EstimateTokens for ChatCompletionRequest {
+    fn estimate_tokens(&self) -> TokenEstimate {
+        estimate_messages_body(&to_value(self), &["max_completion_tokens", "max_tokens"])
+    }
+}
+
+impl EstimateTokens for ResponsesRequest {
+    fn estimate_tokens(&self) -> TokenEstimate {
+        estimate_responses_body(&to_value(self))
+    }
+}
+
+impl EstimateTokens for AnthropicMessagesRequest {
+    fn estimate_tokens(&self) -> TokenEstimate {
+        estimate_messages_body(&to_value(self), &["max_tokens"])
+    }
+}
+
+impl EstimateTokens for Message {
+    fn estimate_tokens(&self) -> TokenEstimate {
+        let mut estimate = TokenEstimate::default();
+        estimate.add_message(&to_value(self));
+        estimate
+    }
+}
+
+impl EstimateTokens for Tool {
+    fn estimate_tokens(&self) -> TokenEstimate {
+        TokenEstimate {
+            tool_tokens: text_tokens(&to_value(self).to_string()),
+            ..TokenEstimate::default()
+        }
+    }
+}
+
+/// Projected cost of a request, in the pricing's currency (USD credits).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CostEstimate {
+    pub tokens: TokenEstimate,
+    /// Largest completion the request can produce, when a limit is known.
+    pub max_completion_tokens: Option<u32>,
+    /// Cost of the prompt alone (zero completion tokens).
+    pub min_cost: f64,
+    /// Cost with the largest possible completion; `None` when no limit is known.
+    pub max_cost: Option<f64>,
+}
+
+impl CostEstimate {
+    /// Fail with [`OpenRouterError::ConfigError`] if the worst-case cost (or
+    /// the minimum, when no maximum is known) is above `cap`.
+    pub fn ensure_within(&self, cap: f64) -> Result<(), OpenRouterError> {
+        let projected = self.max_cost.unwrap_or(self.min_cost);
+        if projected > cap {
+            return Err(OpenRouterError::ConfigError(format!(
+                "estimated request cost {projected:.6} exceeds the cap of {cap:.6}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+struct PriceSheet {
+    prompt: f64,
+    completion: f64,
+    image: f64,
+    request: f64,
+}
+
+impl PriceSheet {
+    fn parse(
+        prompt: &str,
+        completion: &str,
+        image: Option<&str>,
+        request: Option<&str>,
+    ) -> Result<Self, OpenRouterError> {
+        Ok(Self {
+            prompt: parse_price("prompt", Some(prompt))?,
+            completion: parse_price("completion", Some(completion))?,
+            image: parse_price("image", image)?,
+            request: parse_price("request", request)?,
+        })
+    }
+
+    fn estimate(&self, tokens: TokenEstimate, max_completion_tokens: Option<u32>) -> CostEstimate {
+        let min_cost = f64::from(tokens.prompt_tokens()) * self.prompt
+            + f64::from(tokens.images) * self.image
+            + self.request;
+        CostEstimate {
+            tokens,
+            max_completion_tokens,
+            min_cost,
+            max_cost: max_completion_tokens
+                .map(|completion| min_cost + f64::from(completion) * self.completion),
+        }
+    }
+}
+
+fn parse_price(name: &str, price: Option<&str>) -> Result<f64, OpenRouterError> {
+    let Some(price) = price else {
+        return Ok(0.0);
+    };
+    match price.trim().parse::<f64>() {
+        Ok(value) if value >= 0.0 => Ok(value),
+        // Router models such as `openrouter/auto` report `-1` for variable pricing.
+        _ => Err(OpenRouterError::ConfigError(format!(
+            "cannot estimate cost: {name} price {price:?} is not a fixed per-unit price"
+        ))),
+    }
+}
+
+fn completion_limit(
+    tokens: &TokenEstimate,
+    model_limit: Option<f64>,
+    context_length: Option<f64>,
+) -> Option<u32> {
+    tokens
+        .max_output_tokens
+        .or_else(|| {
+            model_limit
+                .filter(|limit| *limit > 0.0)
+                .map(|limit| limit as u32)
+        })
+        .or_else(|| {
+            context_length
+                .filter(|length| *length > 0.0)
+                .map(|length| (length as u32).saturating_sub(tokens.prompt_tokens()))
+        })
+}
+
+/// Estimate the cost of sending `request` to `model`.
+pub fn estimate_cost<R>(request: &R, model: &Model) -> Result<CostEstimate, OpenRouterError>
+where
+    R: EstimateTokens + ?Sized,
+{
+    let pricing = &model.pricing;
+    let prices = PriceSheet::parse(
+        &pricing.prompt,
+        &pricing.completion,
+        pricing.image.as_deref(),
+        pricing.request.as_deref(),
+    )?;
+    let tokens = request.estimate_tokens();
+    let limit = completion_limit(
+        &tokens,
+        model.top_provider.max_completion_tokens,
+        model.context_length.or(model.top_provider.context_length),
+    );
+    Ok(prices.estimate(tokens, limit))
+}
+
+/// Estimate the cost of sending `request` to a specific provider endpoint.
+pub fn estimate_endpoint_cost<R>(
+    request: &R,
+    endpoint: &Endpoint,
+) -> Result<CostEstimate, OpenRouterError>
+where
+    R: EstimateTokens + ?Sized,
+{
+    let pricing = &endpoint.pricing;
+    let prices = PriceSheet::parse(
+        &pricing.prompt,
+        &pricing.completion,
+        pricing.image.as_deref(),
+        pricing.request.as_deref(),
+    )?;
+    let tokens = request.estimate_tokens();
+    let limit = completion_limit(
+        &tokens,
+        endpoint.max_completion_tokens,
+        Some(endpoint.context_length),
+    );
+    Ok(prices.estimate(tokens, limit))
+}
@@ -153,6 +153,14 @@
 //! | Model Information | ✅ | [`api::models`] |
 //! | Streaming | ✅ | [`api::chat`] |
 //! | Unified Streaming Events | ✅ | [`types::stream`] |
+//! | Conversation State | ✅ | [`conversation`] |
+//! | Chat / Responses / Messages Conversions | ✅ | [`convert`] |
+//! | Token and Cost Estimation | ✅ | [`estimate`] |
+//! | Batch Runner | ✅ | [`batch`] |
+//! | Client-Side Model Fallback | ✅ | [`router`] |
+//! | Response Cache | ✅ | [`cache`] |
+//! | Budget Guard | ✅ | [`budget`] |
+//! | Tracing Spans and Metrics (`tracing`, `metrics`) | ✅ | `telemetry` |
 //! | Reasoning Tokens | ✅ | [`api::chat`] |
 //! | API Key Management | ✅ | [`api::api_keys`] |
 //! | Organization Members | ✅ | [`api::organization`] |
@@ -162,6 +170,7 @@
 //! | Authentication | ✅ | [`api::auth`] |
 //! | Guardrails | ✅ | [`api::guardrails`] |
 //! | Workspaces | ✅ | [`api::workspaces`] |
+//! | Mock Transport and Cassettes (`test-support`) | ✅ | `testing` |
 //!
 //! ## 📖 Examples
 //!
@@ -181,9 +190,21 @@
 //! [GitHub repository](https://github.com/realmorrisliu/openrouter-rs) for issues and pull requests.
 
 pub mod api;
+pub mod batch;
+pub mod budget;
+pub mod cache;
 pub mod client;
+pub mod conversation;
+pub mod convert;
 pub mod error;
+pub mod estimate;
 mod generated;
+pub mod router;
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+pub mod telemetry;
+#[cfg(feature = "test-support")]
+pub mod testing;
+pub mod tool_runner;
 mod transport;
 pub mod types;
 pub mod utils;
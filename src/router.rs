@@ -0,0 +1,551 @@
+//! # Client-Side Routing
+//!
+//! [`Router`] retries a whole request on other models when it fails. It
+//! complements the server-side `models` list and [`ProviderPreferences`],
+//! which only fall back inside one OpenRouter request: a moderation
+//! rejection, a `5xx`, or a timeout that ends the request can still be
+//! answered by the next model in the chain.
+//!
+//! Each call orders the configured models by the [`RoutingStrategy`], then
+//! tries them in turn, replacing the request's own `model` each time. A failure falls through to the next model only when
+//! its [`ErrorClass`] is one of the router's fallback classes; any other
+//! error (such as a malformed request) is returned immediately.
+//!
+//! A per-model circuit breaker trips after a run of consecutive health
+//! failures (provider errors, rate limits, server errors, timeouts, and
+//! connection failures). While open, the model is skipped; once the
+//! cooldown passes, one request is let through as a trial and its outcome
+//! closes or re-opens the circuit; concurrent requests keep skipping the
+//! model until the trial ends. Breaker state and latency statistics are
+//! shared by clones of the router.
+//!
+//! A failed route returns a [`RouteError`], which carries the final error
+//! and every attempt made. It converts into [`OpenRouterError`] with `?`.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use openrouter_rs::{
+//!     OpenRouterClient,
+//!     api::chat::{ChatCompletionRequest, Message},
+//!     router::{Router, RoutingStrategy},
+//!     types::Role,
+//! };
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+//! let router = Router::new(["openai/gpt-4.1-mini", "anthropic/claude-sonnet-4"])
+//!     .strategy(RoutingStrategy::LowestLatency)
+//!     .circuit_breaker(3, Duration::from_secs(60));
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("openai/gpt-4.1-mini")
+//!     .messages(vec![Message::new(Role::User, "Hello!")])
+//!     .build()?;
+//!
+//! let routed = router.chat(&client, &request).await?;
+//! println!("answered by {} after {} attempt(s)", routed.model, routed.attempts.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`ProviderPreferences`]: crate::types::ProviderPreferences
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use http::StatusCode;
+use thiserror::Error;
+
+use crate::{
+    OpenRouterClient,
+    api::{
+        chat::ChatCompletionRequest,
+        messages::{AnthropicMessagesRequest, AnthropicMessagesResponse},
+        responses::{ResponsesRequest, ResponsesResponse},
+    },
+    error::{ApiErrorKind, OpenRouterError},
+    types::completion::CompletionsResponse,
+};
+
+/// Broad category of a request failure, used to decide whether to fall
+/// back to another model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorClass {
+    /// The model's moderation rejected the input ([`ApiErrorKind::Moderation`]).
+    Moderation,
+    /// The upstream provider failed ([`ApiErrorKind::Provider`]).
+    Provider,
+    /// `429 Too Many Requests`.
+    RateLimited,
+    /// Any other `5xx` response.
+    ServerError,
+    /// A connect, read, or request deadline elapsed.
+    Timeout,
+    /// The connection could not be established.
+    Connection,
+    /// Any other `4xx` response, usually a problem with the request itself.
+    ClientError,
+    /// Errors raised before or after the HTTP exchange.
+    Other,
+}
+
+impl ErrorClass {
+    /// Classify `error`.
+    pub fn of(error: &OpenRouterError) -> Self {
+        match error {
+            OpenRouterError::Api(context) => match &context.kind {
+                ApiErrorKind::Moderation { .. } => Self::Moderation,
+                ApiErrorKind::Provider { .. } => Self::Provider,
+                ApiErrorKind::Generic if context.status == StatusCode::TOO_MANY_REQUESTS => {
+                    Self::RateLimited
+                }
+                ApiErrorKind::Generic if context.is_server_error() => Self::ServerError,
+                ApiErrorKind::Generic if context.is_client_error() => Self::ClientError,
+                ApiErrorKind::Generic => Self::Other,
+            },
+            OpenRouterError::Timeout(_) => Self::Timeout,
+            OpenRouterError::HttpRequest(error) if error.is_connect() => Self::Connection,
+            _ => Self::Other,
+        }
+    }
+
+    /// Whether this failure says something about the model's health, and so
+    /// counts toward its circuit breaker.
+    pub fn is_health_failure(self) -> bool {
+        matches!(
+            self,
+            Self::Provider
+                | Self::RateLimited
+                | Self::ServerError
+                | Self::Timeout
+                | Self::Connection
+        )
+    }
+}
+
+/// How a [`Router`] orders its models for each request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum RoutingStrategy {
+    /// Try models in the order they were configured.
+    #[default]
+    Ordered,
+    /// Draw the order at random in proportion to each model's weight.
+    /// Models with weight `0` are only used as a last resort.
+    Weighted,
+    /// Prefer the model with the lowest recent latency. Models without a
+    /// successful request yet are tried first so they get measured.
+    LowestLatency,
+}
+
+/// Circuit breaker state of one model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CircuitState {
+    /// Requests flow normally.
+    Closed,
+    /// The model is skipped until the cooldown ends.
+    Open,
+    /// The cooldown ended; the next request is a trial.
+    HalfOpen,
+}
+
+/// One model tried while routing a request.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RouteAttempt {
+    pub model: String,
+    /// Time spent on this attempt.
+    pub elapsed: Duration,
+    /// Class of the failure, or `None` for the attempt that succeeded.
+    pub error_class: Option<ErrorClass>,
+    /// Failure message, or `None` for the attempt that succeeded.
+    pub error: Option<String>,
+}
+
+/// A response together with the model that produced it and every attempt
+/// made, including the successful one.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Routed<T> {
+    pub response: T,
+    pub model: String,
+    pub attempts: Vec<RouteAttempt>,
+}
+
+/// A routed request that failed, with every attempt made before giving up.
+#[derive(Debug, Error)]
+#[error("{error}")]
+pub struct RouteError {
+    error: OpenRouterError,
+    attempts: Vec<RouteAttempt>,
+}
+
+impl RouteError {
+    /// The error that ended routing: the last model's failure, one that
+    /// does not fall back, or a `ConfigError` when no model was available.
+    pub fn error(&self) -> &OpenRouterError {
+        &self.error
+    }
+
+    /// Every model tried, in order. Empty when no model was available.
+    pub fn attempts(&self) -> &[RouteAttempt] {
+        &self.attempts
+    }
+
+    pub fn into_error(self) -> OpenRouterError {
+        self.error
+    }
+}
+
+impl From<RouteError> for OpenRouterError {
+    fn from(error: RouteError) -> Self {
+        error.error
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Route {
+    model: String,
+    weight: u32,
+}
+
+#[derive(Debug, Default)]
+struct ModelHealth {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+    /// Whether a request is probing the half-open circuit.
+    trial_in_flight: bool,
+    latency: Option<Duration>,
+}
+
+impl ModelHealth {
+    /// Whether a request may be sent to the model at `now`.
+    fn is_available(&self, now: Instant) -> bool {
+        self.open_until
+            .is_none_or(|open_until| now >= open_until && !self.trial_in_flight)
+    }
+}
+
+/// Ends a half-open trial when the attempt finishes or is dropped, so a
+/// cancelled trial does not keep the model locked out.
+struct Trial<'a> {
+    router: &'a Router,
+    model: &'a str,
+}
+
+impl Drop for Trial<'_> {
+    fn drop(&mut self) {
+        if let Some(health) = self.router.lock().get_mut(self.model) {
+            health.trial_in_flight = false;
+        }
+    }
+}
+
+/// Smoothing factor for the moving average of each model's latency.
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+/// Client-side fallback across models for chat, responses, and messages
+/// requests.
+///
+/// Clones share circuit breaker and latency state.
+#[derive(Debug, Clone)]
+pub struct Router {
+    routes: Vec<Route>,
+    strategy: RoutingStrategy,
+    fallback_on: Vec<ErrorClass>,
+    failure_threshold: u32,
+    cooldown: Duration,
+    health: Arc<Mutex<HashMap<String, ModelHealth>>>,
+}
+
+impl Router {
+    /// Route across `models`, in order, each with weight `1`.
+    ///
+    /// By default a request falls back on moderation, provider, rate-limit,
+    /// server, timeout, and connection errors, and a model's circuit opens
+    /// for 30 seconds after 5 consecutive health failures.
+    pub fn new<I, S>(models: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            routes: models
+                .into_iter()
+                .map(|model| Route {
+                    model: model.into(),
+                    weight: 1,
+                })
+                .collect(),
+            strategy: RoutingStrategy::default(),
+            fallback_on: vec![
+                ErrorClass::Moderation,
+                ErrorClass::Provider,
+                ErrorClass::RateLimited,
+                ErrorClass::ServerError,
+                ErrorClass::Timeout,
+                ErrorClass::Connection,
+            ],
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+            health: Arc::default(),
+        }
+    }
+
+    /// Set how models are ordered for each request.
+    pub fn strategy(mut self, strategy: RoutingStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set the weight of `model` for [`RoutingStrategy::Weighted`], adding it
+    /// to the end of the chain if it is not routed yet.
+    pub fn weight(mut self, model: impl Into<String>, weight: u32) -> Self {
+        let model = model.into();
+        match self.routes.iter_mut().find(|route| route.model == model) {
+            Some(route) => route.weight = weight,
+            None => self.routes.push(Route { model, weight }),
+        }
+        self
+    }
+
+    /// Replace the error classes that fall through to the next model.
+    pub fn fallback_on(mut self, classes: impl IntoIterator<Item = ErrorClass>) -> Self {
+        self.fallback_on = classes.into_iter().collect();
+        self
+    }
+
+    /// Open a model's circuit for `cooldown` after `failure_threshold`
+    /// consecutive health failures. A threshold of `0` disables the breaker.
+    pub fn circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.failure_threshold = failure_threshold;
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Current circuit breaker state of `model`.
+    pub fn circuit_state(&self, model: &str) -> CircuitState {
+        let health = self.lock();
+        match health.get(model).and_then(|health| health.open_until) {
+            Some(open_until) if Instant::now() < open_until => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+            None => CircuitState::Closed,
+        }
+    }
+
+    /// Send a chat completion, falling back across the routed models.
+    pub async fn chat(
+        &self,
+        client: &OpenRouterClient,
+        request: &ChatCompletionRequest,
+    ) -> Result<Routed<CompletionsResponse>, RouteError> {
+        self.route(|model| {
+            let request = request.with_model(model);
+            async move { client.chat().create(&request).await }
+        })
+        .await
+    }
+
+    /// Create a response, falling back across the routed models.
+    pub async fn responses(
+        &self,
+        client: &OpenRouterClient,
+        request: &ResponsesRequest,
+    ) -> Result<Routed<ResponsesResponse>, RouteError> {
+        self.route(|model| {
+            let request = request.with_model(model);
+            async move { client.responses().create(&request).await }
+        })
+        .await
+    }
+
+    /// Create a message, falling back across the routed models.
+    pub async fn messages(
+        &self,
+        client: &OpenRouterClient,
+        request: &AnthropicMessagesRequest,
+    ) -> Result<Routed<AnthropicMessagesResponse>, RouteError> {
+        self.route(|model| {
+            let request = request.with_model(model);
+            async move { client.messages().create(&request).await }
+        })
+        .await
+    }
+
+    async fn route<T, F, Fut>(&self, mut send: F) -> Result<Routed<T>, RouteError>
+    where
+        F: FnMut(&str) -> Fut,
+        Fut: Future<Output = Result<T, OpenRouterError>>,
+    {
+        let mut attempts = Vec::new();
+        let mut last_error = None;
+
+        for model in self.candidates() {
+            // Re-check now: the circuit may have opened, or another request
+            // may have started its trial, since the order was drawn.
+            let Some(is_trial) = self.admit(&model) else {
+                continue;
+            };
+            let trial = is_trial.then(|| Trial {
+                router: self,
+                model: &model,
+            });
+            let started = Instant::now();
+            let result = send(&model).await;
+            let elapsed = started.elapsed();
+            drop(trial);
+
+            match result {
+                Ok(response) => {
+                    self.record_success(&model, elapsed);
+                    attempts.push(RouteAttempt {
+                        model: model.clone(),
+                        elapsed,
+                        error_class: None,
+                        error: None,
+                    });
+                    return Ok(Routed {
+                        response,
+                        model,
+                        attempts,
+                    });
+                }
+                Err(error) => {
+                    let class = ErrorClass::of(&error);
+                    if class.is_health_failure() {
+                        self.record_failure(&model);
+                    }
+                    attempts.push(RouteAttempt {
+                        model,
+                        elapsed,
+                        error_class: Some(class),
+                        error: Some(error.to_string()),
+                    });
+                    if !self.fallback_on.contains(&class) {
+                        return Err(RouteError { error, attempts });
+                    }
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        let error = last_error.unwrap_or_else(|| {
+            OpenRouterError::ConfigError(
+                "no route available: no models configured or every circuit breaker is open"
+                    .to_string(),
+            )
+        });
+        Err(RouteError { error, attempts })
+    }
+
+    /// Claim `model` for an attempt: `None` while its circuit is open or
+    /// another request is probing it, `Some(true)` when this attempt is the
+    /// half-open trial.
+    fn admit(&self, model: &str) -> Option<bool> {
+        let mut health = self.lock();
+        let Some(health) = health.get_mut(model) else {
+            return Some(false);
+        };
+        if !health.is_available(Instant::now()) {
+            return None;
+        }
+        let is_trial = health.open_until.is_some();
+        health.trial_in_flight = is_trial;
+        Some(is_trial)
+    }
+
+    /// Routed models in the order to try them, without open circuits or
+    /// circuits already being probed.
+    fn candidates(&self) -> Vec<String> {
+        let health = self.lock();
+        let now = Instant::now();
+        let mut routes: Vec<&Route> = self
+            .routes
+            .iter()
+            .filter(|route| {
+                health
+                    .get(&route.model)
+                    .is_none_or(|health| health.is_available(now))
+            })
+            .collect();
+
+        match self.strategy {
+            RoutingStrategy::Ordered => {}
+            RoutingStrategy::Weighted => routes = weighted_order(routes),
+            RoutingStrategy::LowestLatency => routes.sort_by_key(|route| {
+                health
+                    .get(&route.model)
+                    .and_then(|health| health.latency)
+                    .unwrap_or_default()
+            }),
+        }
+        routes
+            .into_iter()
+            .map(|route| route.model.clone())
+            .collect()
+    }
+
+    fn record_success(&self, model: &str, elapsed: Duration) {
+        let mut health = self.lock();
+        let health = health.entry(model.to_string()).or_default();
+        health.consecutive_failures = 0;
+        health.open_until = None;
+        health.latency = Some(match health.latency {
+            Some(average) => {
+                average.mul_f64(1.0 - LATENCY_SMOOTHING) + elapsed.mul_f64(LATENCY_SMOOTHING)
+            }
+            None => elapsed,
+        });
+    }
+
+    fn record_failure(&self, model: &str) {
+        let mut health = self.lock();
+        let health = health.entry(model.to_string()).or_default();
+        health.consecutive_failures += 1;
+        if self.failure_threshold > 0 && health.consecutive_failures >= self.failure_threshold {
+            health.open_until = Some(Instant::now() + self.cooldown);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, ModelHealth>> {
+        self.health
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Weighted random order without replacement; zero-weight routes go last.
+fn weighted_order(mut routes: Vec<&Route>) -> Vec<&Route> {
+    let mut ordered = Vec::with_capacity(routes.len());
+    loop {
+        let total: u64 = routes.iter().map(|route| u64::from(route.weight)).sum();
+        if total == 0 {
+            break;
+        }
+        let mut pick = fastrand::u64(0..total);
+        let index = routes
+            .iter()
+            .position(|route| {
+                let weight = u64::from(route.weight);
+                if pick < weight {
+                    true
+                } else {
+                    pick -= weight;
+                    false
+                }
+            })
+            .expect("pick is below the total weight");
+        ordered.push(routes.remove(index));
+    }
+    ordered.extend(routes);
+    ordered
+}
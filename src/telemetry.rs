@@ -0,0 +1,455 @@
+//! # Telemetry
+//!
+//! Client-side spans and metrics for every request an
+//! [`OpenRouterClient`](crate::OpenRouterClient) sends, following the
+//! OpenTelemetry GenAI semantic conventions. Enable the `tracing` feature
+//! for spans and the `metrics` feature for counters and histograms; either
+//! works on its own.
+//!
+//! ```toml
+//! [dependencies]
+//! openrouter-rs = { version = "0.11.1", features = ["tracing", "metrics"] }
+//! ```
+//!
+//! ## Spans
+//!
+//! Each logical call (retries included) gets one `openrouter.request` span
+//! at `INFO` level with target `openrouter_rs`. Its `otel.name` is
+//! `"{operation} {model}"` for model calls (for example
+//! `chat openai/gpt-4.1-mini`) and `"{method} {path}"` otherwise. The span
+//! stays open until the response body has been read or dropped, so for
+//! streams it covers the whole SSE stream, not only the initial HTTP call.
+//! Response fields are read only from the JSON or event-stream bodies of
+//! model calls; file and video downloads stream through untouched.
+//!
+//! Fields recorded, when known:
+//!
+//! - `gen_ai.operation.name` (`chat`, `embeddings`, or `text_completion`),
+//!   `gen_ai.provider.name` (`openrouter`)
+//! - `gen_ai.request.model`, `gen_ai.request.max_tokens`,
+//!   `gen_ai.request.temperature`, `gen_ai.request.top_p`
+//! - `gen_ai.response.model`, `gen_ai.response.id`,
+//!   `gen_ai.response.finish_reasons` (comma-separated)
+//! - `gen_ai.usage.input_tokens`, `gen_ai.usage.output_tokens`
+//! - `gen_ai.server.time_to_first_token`, in seconds until the first stream
+//!   event
+//! - `openrouter.provider` (the upstream provider that served the call),
+//!   `openrouter.cost`, `openrouter.request_id` (from the `x-request-id`
+//!   response header), `openrouter.stream`
+//! - `http.request.method`, `url.path`, `server.address`,
+//!   `http.response.status_code`, `error.type`, `otel.status_code`
+//!
+//! ## Metrics
+//!
+//! Recorded through the [`metrics`](https://docs.rs/metrics) facade once a
+//! call finishes, labelled with `gen_ai.operation.name`,
+//! `gen_ai.provider.name`, `gen_ai.request.model`, and, when known,
+//! `gen_ai.response.model` and `error.type`:
+//!
+//! - [`OPERATION_DURATION`] histogram, in seconds
+//! - [`TOKEN_USAGE`] histogram, with `gen_ai.token.type` `input` or `output`
+//! - [`TIME_TO_FIRST_TOKEN`] histogram, in seconds, for streams
+//! - [`REQUESTS`] counter
+//! - [`TOKENS`] counter, with `gen_ai.token.type`
+//! - [`COST`] histogram, in credits
+//!
+//! Call [`describe_metrics`] once after installing a recorder to register
+//! units and descriptions.
+
+use std::time::{Duration, Instant};
+
+use reqwest::{Request, Response};
+use serde_json::Value;
+
+use crate::{
+    error::OpenRouterError,
+    transport::{inspect_body, response::response_request_id, sse::JsonEvents},
+};
+
+/// Duration of each call, in seconds.
+pub const OPERATION_DURATION: &str = "gen_ai.client.operation.duration";
+/// Tokens per call, by `gen_ai.token.type`.
+pub const TOKEN_USAGE: &str = "gen_ai.client.token.usage";
+/// Time from sending a streaming request to its first event, in seconds.
+pub const TIME_TO_FIRST_TOKEN: &str = "gen_ai.server.time_to_first_token";
+/// Number of calls.
+pub const REQUESTS: &str = "openrouter.client.requests";
+/// Total tokens, by `gen_ai.token.type`.
+pub const TOKENS: &str = "openrouter.client.tokens";
+/// Reported cost per call, in credits.
+pub const COST: &str = "openrouter.client.cost";
+
+const PROVIDER_NAME: &str = "openrouter";
+
+/// Register units and descriptions for the SDK's metrics with the installed
+/// recorder.
+#[cfg(feature = "metrics")]
+pub fn describe_metrics() {
+    use metrics::{Unit, describe_counter, describe_histogram};
+
+    describe_histogram!(
+        OPERATION_DURATION,
+        Unit::Seconds,
+        "Duration of OpenRouter client operations."
+    );
+    describe_histogram!(TOKEN_USAGE, "Tokens used per OpenRouter call.");
+    describe_histogram!(
+        TIME_TO_FIRST_TOKEN,
+        Unit::Seconds,
+        "Time to the first event of OpenRouter streams."
+    );
+    describe_counter!(REQUESTS, "OpenRouter calls sent.");
+    describe_counter!(TOKENS, "Tokens used by OpenRouter calls.");
+    describe_histogram!(COST, "Reported cost of OpenRouter calls, in credits.");
+}
+
+/// Span and metrics for one logical call, completed when dropped.
+pub(crate) struct RequestTelemetry {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    operation: Option<&'static str>,
+    #[cfg(feature = "metrics")]
+    request_model: Option<String>,
+    streaming: bool,
+    started: Instant,
+    events: Option<JsonEvents>,
+    time_to_first_event: Option<Duration>,
+    status: Option<u16>,
+    request_id: Option<String>,
+    response: ResponseFacts,
+    error_type: Option<String>,
+}
+
+/// Details read from the response body or stream events.
+#[derive(Debug, Default)]
+struct ResponseFacts {
+    model: Option<String>,
+    id: Option<String>,
+    provider: Option<String>,
+    finish_reasons: Vec<String>,
+    input_tokens: Option<u64>,
+    output_tokens: Option<u64>,
+    cost: Option<f64>,
+}
+
+impl RequestTelemetry {
+    pub(crate) fn start(request: &Request, streaming: bool) -> Self {
+        let body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .and_then(|body| serde_json::from_slice::<Value>(body).ok())
+            .unwrap_or_default();
+        let request_model = body
+            .get("model")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let operation = request_model
+            .as_ref()
+            .and_then(|_| operation_name(request.url().path()));
+
+        #[cfg(feature = "tracing")]
+        let span = {
+            use tracing::field::Empty;
+
+            let max_tokens = max_tokens(&body);
+            let temperature = body.get("temperature").and_then(Value::as_f64);
+            let top_p = body.get("top_p").and_then(Value::as_f64);
+            let name = match (operation, &request_model) {
+                (Some(operation), Some(model)) => format!("{operation} {model}"),
+                _ => format!("{} {}", request.method(), request.url().path()),
+            };
+            let span = tracing::info_span!(
+                target: "openrouter_rs",
+                "openrouter.request",
+                otel.name = %name,
+                otel.kind = "client",
+                otel.status_code = Empty,
+                gen_ai.operation.name = operation,
+                gen_ai.provider.name = PROVIDER_NAME,
+                gen_ai.request.model = request_model.as_deref(),
+                gen_ai.request.max_tokens = max_tokens,
+                gen_ai.request.temperature = temperature,
+                gen_ai.request.top_p = top_p,
+                gen_ai.response.model = Empty,
+                gen_ai.response.id = Empty,
+                gen_ai.response.finish_reasons = Empty,
+                gen_ai.usage.input_tokens = Empty,
+                gen_ai.usage.output_tokens = Empty,
+                gen_ai.server.time_to_first_token = Empty,
+                openrouter.provider = Empty,
+                openrouter.cost = Empty,
+                openrouter.request_id = Empty,
+                openrouter.stream = streaming,
+                http.request.method = %request.method(),
+                url.path = request.url().path(),
+                server.address = request.url().host_str(),
+                http.response.status_code = Empty,
+                error.type = Empty,
+            );
+            span
+        };
+
+        Self {
+            #[cfg(feature = "tracing")]
+            span,
+            operation,
+            #[cfg(feature = "metrics")]
+            request_model,
+            streaming,
+            started: Instant::now(),
+            events: None,
+            time_to_first_event: None,
+            status: None,
+            request_id: None,
+            response: ResponseFacts::default(),
+            error_type: None,
+        }
+    }
+
+    /// Run `future` inside this call's span.
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Instrument::instrument(future, self.span.clone())
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            future
+        }
+    }
+
+    /// Record the call's outcome. Successful responses keep the telemetry
+    /// alive in their body until it has been read or dropped.
+    pub(crate) fn finish(
+        mut self,
+        result: Result<Response, OpenRouterError>,
+    ) -> Result<Response, OpenRouterError> {
+        let response = match result {
+            Ok(response) => response,
+            Err(error) => {
+                self.error_type = Some(error_type(&error));
+                return Err(error);
+            }
+        };
+
+        self.status = Some(response.status().as_u16());
+        self.request_id = response_request_id(&response);
+        if !response.status().is_success() {
+            self.error_type = Some(response.status().as_str().to_string());
+            return Ok(response);
+        }
+
+        // Only model calls carry response facts; downloads and other
+        // endpoints keep streaming without their bodies being buffered.
+        if self.operation.is_some() {
+            self.events = JsonEvents::new(&response);
+        }
+        Ok(inspect_body(response, move |chunk| match chunk {
+            Ok(chunk) => self.observe(chunk),
+            Err(_) => self.error_type = Some("http_request".to_string()),
+        }))
+    }
+
+    fn observe(&mut self, chunk: &[u8]) {
+        let Some(events) = &mut self.events else {
+            return;
+        };
+        events.push(chunk, |event| {
+            if self.streaming && self.time_to_first_event.is_none() {
+                self.time_to_first_event = Some(self.started.elapsed());
+            }
+            self.response.observe(&event);
+        });
+    }
+
+    #[cfg(feature = "metrics")]
+    fn labels(&self) -> Vec<(&'static str, String)> {
+        let mut labels = vec![
+            (
+                "gen_ai.operation.name",
+                self.operation.unwrap_or("http").to_string(),
+            ),
+            ("gen_ai.provider.name", PROVIDER_NAME.to_string()),
+        ];
+        if let Some(model) = &self.request_model {
+            labels.push(("gen_ai.request.model", model.clone()));
+        }
+        if let Some(model) = &self.response.model {
+            labels.push(("gen_ai.response.model", model.clone()));
+        }
+        if let Some(error_type) = &self.error_type {
+            labels.push(("error.type", error_type.clone()));
+        }
+        labels
+    }
+
+    #[cfg(feature = "tracing")]
+    fn record_span(&self) {
+        let span = &self.span;
+        let response = &self.response;
+        if let Some(status) = self.status {
+            span.record("http.response.status_code", status);
+        }
+        if let Some(request_id) = &self.request_id {
+            span.record("openrouter.request_id", request_id.as_str());
+        }
+        if let Some(model) = &response.model {
+            span.record("gen_ai.response.model", model.as_str());
+        }
+        if let Some(id) = &response.id {
+            span.record("gen_ai.response.id", id.as_str());
+        }
+        if !response.finish_reasons.is_empty() {
+            span.record(
+                "gen_ai.response.finish_reasons",
+                response.finish_reasons.join(","),
+            );
+        }
+        if let Some(tokens) = response.input_tokens {
+            span.record("gen_ai.usage.input_tokens", tokens);
+        }
+        if let Some(tokens) = response.output_tokens {
+            span.record("gen_ai.usage.output_tokens", tokens);
+        }
+        if let Some(elapsed) = self.time_to_first_event {
+            span.record("gen_ai.server.time_to_first_token", elapsed.as_secs_f64());
+        }
+        if let Some(provider) = &response.provider {
+            span.record("openrouter.provider", provider.as_str());
+        }
+        if let Some(cost) = response.cost {
+            span.record("openrouter.cost", cost);
+        }
+        match &self.error_type {
+            Some(error_type) => {
+                span.record("error.type", error_type.as_str());
+                span.record("otel.status_code", "ERROR");
+            }
+            None => {
+                span.record("otel.status_code", "OK");
+            }
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    fn record_metrics(&self) {
+        use metrics::{counter, histogram};
+
+        let labels = self.labels();
+        histogram!(OPERATION_DURATION, &labels).record(self.started.elapsed().as_secs_f64());
+        counter!(REQUESTS, &labels).increment(1);
+        if let Some(elapsed) = self.time_to_first_event {
+            histogram!(TIME_TO_FIRST_TOKEN, &labels).record(elapsed.as_secs_f64());
+        }
+        for (token_type, tokens) in [
+            ("input", self.response.input_tokens),
+            ("output", self.response.output_tokens),
+        ] {
+            let Some(tokens) = tokens else {
+                continue;
+            };
+            let mut labels = labels.clone();
+            labels.push(("gen_ai.token.type", token_type.to_string()));
+            histogram!(TOKEN_USAGE, &labels).record(tokens as f64);
+            counter!(TOKENS, &labels).increment(tokens);
+        }
+        if let Some(cost) = self.response.cost {
+            histogram!(COST, &labels).record(cost);
+        }
+    }
+}
+
+impl Drop for RequestTelemetry {
+    fn drop(&mut self) {
+        if let Some(events) = &mut self.events {
+            events.finish(|body| self.response.observe(&body));
+        }
+        #[cfg(feature = "tracing")]
+        self.record_span();
+        #[cfg(feature = "metrics")]
+        self.record_metrics();
+    }
+}
+
+impl ResponseFacts {
+    /// Read what a response body or stream event says about the call. Chat
+    /// chunks and bodies carry fields at the top level, Responses API events
+    /// under `response`, and `/messages` `message_start` under `message`.
+    fn observe(&mut self, event: &Value) {
+        let bodies = [Some(event), event.get("response"), event.get("message")];
+        for body in bodies.into_iter().flatten().filter(|body| body.is_object()) {
+            let text = |field: &str| body.get(field).and_then(Value::as_str).map(str::to_string);
+            self.model = text("model").or(self.model.take());
+            self.id = text("id").or(self.id.take());
+            self.provider = text("provider").or(self.provider.take());
+
+            if let Some(usage) = body.get("usage").filter(|usage| usage.is_object()) {
+                let tokens = |fields: [&str; 2]| {
+                    fields
+                        .iter()
+                        .find_map(|field| usage.get(field).and_then(Value::as_u64))
+                };
+                self.input_tokens = tokens(["prompt_tokens", "input_tokens"]).or(self.input_tokens);
+                self.output_tokens =
+                    tokens(["completion_tokens", "output_tokens"]).or(self.output_tokens);
+                self.cost = usage.get("cost").and_then(Value::as_f64).or(self.cost);
+            }
+
+            let choices = body.get("choices").and_then(Value::as_array);
+            let reasons = choices
+                .into_iter()
+                .flatten()
+                .filter_map(|choice| choice.get("finish_reason"))
+                .chain(body.get("stop_reason"))
+                .chain(body.get("delta").and_then(|delta| delta.get("stop_reason")))
+                .chain(
+                    body.get("status")
+                        .filter(|_| body.get("object").and_then(Value::as_str) == Some("response"))
+                        .filter(|status| {
+                            !matches!(status.as_str(), Some("queued" | "in_progress"))
+                        }),
+                );
+            for reason in reasons.filter_map(Value::as_str) {
+                if !self.finish_reasons.iter().any(|known| known == reason) {
+                    self.finish_reasons.push(reason.to_string());
+                }
+            }
+        }
+    }
+}
+
+/// GenAI operation name for a model call to `path`.
+fn operation_name(path: &str) -> Option<&'static str> {
+    if path.ends_with("/chat/completions")
+        || path.ends_with("/responses")
+        || path.ends_with("/messages")
+    {
+        Some("chat")
+    } else if path.ends_with("/embeddings") {
+        Some("embeddings")
+    } else if path.ends_with("/completions") {
+        Some("text_completion")
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn max_tokens(body: &Value) -> Option<u64> {
+    ["max_completion_tokens", "max_tokens", "max_output_tokens"]
+        .iter()
+        .find_map(|field| body.get(field).and_then(Value::as_u64))
+}
+
+/// `error.type` for a failed call: the HTTP status for API errors, or a
+/// short error class.
+fn error_type(error: &OpenRouterError) -> String {
+    match error {
+        OpenRouterError::Api(api_error) => api_error.status.as_str().to_string(),
+        OpenRouterError::HttpRequest(_) => "http_request".to_string(),
+        OpenRouterError::Timeout(_) => "timeout".to_string(),
+        OpenRouterError::BudgetExceeded(_) => "budget_exceeded".to_string(),
+        OpenRouterError::Cancelled => "cancelled".to_string(),
+        _ => "_OTHER".to_string(),
+    }
+}
@@ -0,0 +1,280 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use http::{
+    HeaderMap, HeaderName, HeaderValue, StatusCode,
+    header::{CONNECTION, CONTENT_LENGTH, SET_COOKIE, TRANSFER_ENCODING},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client::{MiddlewareFuture, MiddlewareRequest, MiddlewareResponse, Network, Transport},
+    error::OpenRouterError,
+};
+
+use super::{canned_response, is_event_stream, request_path, sse_chunks};
+
+/// Whether a [`Cassette`] captures live traffic or serves it back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests to the network and append each interaction to the cassette file.
+    Record,
+    /// Serve recorded interactions without touching the network.
+    Replay,
+}
+
+/// Response headers that are connection-specific or sensitive and never recorded.
+const SKIPPED_HEADERS: [HeaderName; 4] =
+    [CONNECTION, CONTENT_LENGTH, SET_COOKIE, TRANSFER_ENCODING];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<Value>,
+}
+
+impl RecordedRequest {
+    fn from_request(request: &MiddlewareRequest) -> Self {
+        Self {
+            method: request.method().to_string(),
+            path: request_path(request.url()),
+            body: request.json_body(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: BTreeMap<String, String>,
+    body: RecordedBody,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RecordedBody {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+impl RecordedResponse {
+    fn capture(status: StatusCode, headers: &HeaderMap, body: Bytes) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| !SKIPPED_HEADERS.contains(name))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let body = match String::from_utf8(body.to_vec()) {
+            Ok(text) => RecordedBody::Text(text),
+            Err(error) => RecordedBody::Binary(error.into_bytes()),
+        };
+
+        Self {
+            status: status.as_u16(),
+            headers,
+            body,
+        }
+    }
+
+    fn to_middleware_response(&self) -> Result<MiddlewareResponse, OpenRouterError> {
+        let status = StatusCode::from_u16(self.status)
+            .map_err(|error| OpenRouterError::ConfigError(format!("cassette: {error}")))?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|error| OpenRouterError::ConfigError(format!("cassette: {error}")))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|error| OpenRouterError::ConfigError(format!("cassette: {error}")))?;
+            headers.append(name, value);
+        }
+
+        let chunks = match &self.body {
+            RecordedBody::Text(text) if is_event_stream(&headers) => sse_chunks(text),
+            RecordedBody::Text(text) => vec![Bytes::copy_from_slice(text.as_bytes())],
+            RecordedBody::Binary(bytes) => vec![Bytes::copy_from_slice(bytes)],
+        };
+
+        Ok(canned_response(status, headers, chunks))
+    }
+}
+
+#[derive(Debug)]
+struct CassetteState {
+    file: CassetteFile,
+    replayed: Vec<bool>,
+}
+
+/// Record/replay transport backed by a JSON cassette file.
+///
+/// Register it with [`OpenRouterClientBuilder::transport`](crate::client::OpenRouterClientBuilder::transport).
+/// It sits below the retry policy, so every attempt is recorded and replayed
+/// separately.
+///
+/// In [`CassetteMode::Record`] every request is sent to the network and the
+/// interaction (method, path, JSON body, status, headers, and full response
+/// body) is written to the cassette. Streaming responses are buffered while
+/// recording. Request headers are never stored, so API keys stay out of the
+/// file; `Set-Cookie` and connection-level response headers are dropped as well.
+///
+/// In [`CassetteMode::Replay`] requests are matched against unused recorded
+/// interactions by method, path, and JSON body, and served back without
+/// network access. SSE bodies are replayed one event per chunk.
+///
+/// # Examples
+///
+/// ```no_run
+/// use openrouter_rs::{OpenRouterClient, testing::Cassette};
+///
+/// // Records on the first run (when the file is missing), replays afterwards.
+/// let cassette = Cassette::record_or_replay("tests/cassettes/chat.json")?;
+/// let client = OpenRouterClient::builder()
+///     .api_key(std::env::var("OPENROUTER_API_KEY").unwrap_or_default())
+///     .transport(cassette)
+///     .build()?;
+/// # Ok::<(), openrouter_rs::error::OpenRouterError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Arc<Mutex<CassetteState>>,
+    /// Serializes cassette file writes so a later snapshot always lands last.
+    writer: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Cassette {
+    /// Record live traffic to `path`, replacing any existing cassette.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::with_file(path, CassetteMode::Record, CassetteFile::default())
+    }
+
+    /// Replay the cassette stored at `path`.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, OpenRouterError> {
+        let contents = fs::read(path.as_ref())?;
+        let file = serde_json::from_slice(&contents)?;
+        Ok(Self::with_file(path, CassetteMode::Replay, file))
+    }
+
+    /// Replay `path` if it exists, otherwise record to it.
+    pub fn record_or_replay(path: impl AsRef<Path>) -> Result<Self, OpenRouterError> {
+        if path.as_ref().exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path))
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn with_file(path: impl AsRef<Path>, mode: CassetteMode, file: CassetteFile) -> Self {
+        let replayed = vec![false; file.interactions.len()];
+        Self {
+            path: path.as_ref().to_path_buf(),
+            mode,
+            state: Arc::new(Mutex::new(CassetteState { file, replayed })),
+            writer: Arc::default(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CassetteState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn replay_response(
+        &self,
+        request: &RecordedRequest,
+    ) -> Result<MiddlewareResponse, OpenRouterError> {
+        let mut state = self.lock();
+        let CassetteState { file, replayed } = &mut *state;
+        let index = file
+            .interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, used)| !used && interaction.request == *request)
+            .ok_or_else(|| {
+                OpenRouterError::ConfigError(format!(
+                    "cassette {}: no recorded interaction for {} {}",
+                    self.path.display(),
+                    request.method,
+                    request.path
+                ))
+            })?;
+
+        replayed[index] = true;
+        file.interactions[index].response.to_middleware_response()
+    }
+
+    async fn save(&self, interaction: Interaction) -> Result<(), OpenRouterError> {
+        let _writer = self.writer.lock().await;
+        let contents = {
+            let mut state = self.lock();
+            state.file.interactions.push(interaction);
+            state.replayed.push(true);
+            serde_json::to_vec_pretty(&state.file)?
+        };
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&self.path, contents).await?;
+        Ok(())
+    }
+}
+
+impl Transport for Cassette {
+    fn send<'a>(
+        &'a self,
+        request: MiddlewareRequest,
+        network: Network<'a>,
+    ) -> MiddlewareFuture<'a> {
+        let recorded = RecordedRequest::from_request(&request);
+        match self.mode {
+            CassetteMode::Replay => {
+                let outcome = self.replay_response(&recorded);
+                Box::pin(async move { outcome })
+            }
+            CassetteMode::Record => Box::pin(async move {
+                let mut response = network.send(request).await?;
+                let body = response.body().await?;
+                self.save(Interaction {
+                    request: recorded,
+                    response: RecordedResponse::capture(
+                        response.status(),
+                        response.headers(),
+                        body,
+                    ),
+                })
+                .await?;
+                Ok(response)
+            }),
+        }
+    }
+}
@@ -0,0 +1,336 @@
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header::CONTENT_TYPE};
+use serde_json::{Value, json};
+
+use crate::{
+    client::{MiddlewareFuture, MiddlewareRequest, Network, Transport},
+    error::OpenRouterError,
+};
+
+use super::{canned_response, request_path};
+
+/// Request predicate for [`MockTransport`].
+///
+/// Every condition that is set must hold. Paths are matched as a suffix of the
+/// request path, so `Matcher::post("/chat/completions")` matches regardless of
+/// the configured base URL. Include a `?` to also match the query string.
+#[derive(Debug, Clone, Default)]
+pub struct Matcher {
+    method: Option<Method>,
+    path: Option<String>,
+    body: Option<Value>,
+    headers: Vec<(String, String)>,
+    streaming: Option<bool>,
+}
+
+impl Matcher {
+    /// Match every request.
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Match requests with the given method and path suffix.
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method: Some(method),
+            path: Some(path.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn get(path: impl Into<String>) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    pub fn post(path: impl Into<String>) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    pub fn put(path: impl Into<String>) -> Self {
+        Self::new(Method::PUT, path)
+    }
+
+    pub fn patch(path: impl Into<String>) -> Self {
+        Self::new(Method::PATCH, path)
+    }
+
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    /// Require the JSON body to contain `expected` (objects match recursively by subset).
+    pub fn body_partial_json(mut self, expected: Value) -> Self {
+        self.body = Some(expected);
+        self
+    }
+
+    /// Require a request header with exactly this value.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Require a streaming (`true`) or non-streaming (`false`) call.
+    pub fn streaming(mut self, streaming: bool) -> Self {
+        self.streaming = Some(streaming);
+        self
+    }
+
+    fn matches(&self, request: &MiddlewareRequest) -> bool {
+        if self
+            .method
+            .as_ref()
+            .is_some_and(|method| method != request.method())
+        {
+            return false;
+        }
+
+        if let Some(expected) = &self.path {
+            let path = request_path(request.url());
+            let path = if expected.contains('?') {
+                path.as_str()
+            } else {
+                path.split('?').next().unwrap_or_default()
+            };
+            if !path.ends_with(expected.as_str()) {
+                return false;
+            }
+        }
+
+        if self
+            .streaming
+            .is_some_and(|streaming| streaming != request.is_streaming())
+        {
+            return false;
+        }
+
+        let headers_match = self.headers.iter().all(|(name, value)| {
+            request
+                .headers()
+                .get(name.as_str())
+                .and_then(|actual| actual.to_str().ok())
+                == Some(value.as_str())
+        });
+        if !headers_match {
+            return false;
+        }
+
+        match &self.body {
+            Some(expected) => request
+                .json_body()
+                .is_some_and(|actual| json_contains(&actual, expected)),
+            None => true,
+        }
+    }
+}
+
+fn json_contains(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Object(actual), Value::Object(expected)) => expected.iter().all(|(key, value)| {
+            actual
+                .get(key)
+                .is_some_and(|actual| json_contains(actual, value))
+        }),
+        _ => actual == expected,
+    }
+}
+
+/// Canned response served by [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    chunks: Vec<Bytes>,
+}
+
+impl MockResponse {
+    /// Build a `200 OK` response with a raw body and content type.
+    pub fn bytes(content_type: &str, body: impl Into<Bytes>) -> Self {
+        Self {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            chunks: vec![body.into()],
+        }
+        .header(CONTENT_TYPE.as_str(), content_type)
+    }
+
+    /// Build a `200 OK` JSON response.
+    pub fn json(body: Value) -> Self {
+        Self::bytes("application/json", body.to_string())
+    }
+
+    /// Build a `200 OK` SSE response with one `data:` event per value, followed by `[DONE]`.
+    pub fn sse(events: impl IntoIterator<Item = Value>) -> Self {
+        let frames = events
+            .into_iter()
+            .map(|event| format!("data: {event}\n\n"))
+            .chain(std::iter::once("data: [DONE]\n\n".to_string()));
+        Self::sse_raw(frames)
+    }
+
+    /// Build a `200 OK` SSE response from raw frames, each delivered as its own chunk.
+    ///
+    /// Frames are sent verbatim, so they must include their `\n\n` terminator.
+    pub fn sse_raw(frames: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut response = Self::bytes("text/event-stream", Bytes::new());
+        response.chunks = frames
+            .into_iter()
+            .map(|frame| Bytes::from(frame.into()))
+            .collect();
+        response
+    }
+
+    /// Build an OpenRouter-style error response.
+    pub fn error(status: StatusCode, message: impl Into<String>) -> Self {
+        Self::json(json!({
+            "error": {
+                "code": status.as_u16(),
+                "message": message.into(),
+            }
+        }))
+        .status(status)
+    }
+
+    pub fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Add a response header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name or value is not a valid HTTP header.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        let name = HeaderName::from_bytes(name.as_bytes()).expect("invalid mock header name");
+        let value = HeaderValue::from_str(value).expect("invalid mock header value");
+        self.headers.insert(name, value);
+        self
+    }
+}
+
+/// Request observed by [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<Value>,
+    pub streaming: bool,
+}
+
+#[derive(Debug)]
+struct Mock {
+    matcher: Matcher,
+    response: MockResponse,
+    remaining: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    mocks: Vec<Mock>,
+    received: Vec<ReceivedRequest>,
+}
+
+/// In-memory transport that serves canned responses instead of sending requests.
+///
+/// Register it with [`OpenRouterClientBuilder::transport`](crate::client::OpenRouterClientBuilder::transport).
+/// Each attempt the client makes is answered separately, so retry policies,
+/// rate limits, and timeouts run against mocked responses too.
+/// Mocks are checked in registration order and the first active match wins.
+/// Requests that match no mock fail with [`OpenRouterError::ConfigError`].
+/// Clones share the same mocks and request log.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve `response` for every request that matches `matcher`.
+    pub fn on(&self, matcher: Matcher, response: MockResponse) -> &Self {
+        self.register(matcher, response, None)
+    }
+
+    /// Serve `response` for the next matching request only.
+    pub fn on_once(&self, matcher: Matcher, response: MockResponse) -> &Self {
+        self.register(matcher, response, Some(1))
+    }
+
+    /// Requests received so far, in order.
+    pub fn received_requests(&self) -> Vec<ReceivedRequest> {
+        self.lock().received.clone()
+    }
+
+    /// Remove all mocks and clear the request log.
+    pub fn reset(&self) {
+        *self.lock() = MockState::default();
+    }
+
+    fn register(
+        &self,
+        matcher: Matcher,
+        response: MockResponse,
+        remaining: Option<usize>,
+    ) -> &Self {
+        self.lock().mocks.push(Mock {
+            matcher,
+            response,
+            remaining,
+        });
+        self
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn respond(&self, request: &MiddlewareRequest) -> Option<MockResponse> {
+        let mut state = self.lock();
+        state.received.push(ReceivedRequest {
+            method: request.method().clone(),
+            url: request.url().to_string(),
+            headers: request.headers().clone(),
+            body: request.json_body(),
+            streaming: request.is_streaming(),
+        });
+
+        let mock = state
+            .mocks
+            .iter_mut()
+            .find(|mock| mock.remaining != Some(0) && mock.matcher.matches(request))?;
+        if let Some(remaining) = &mut mock.remaining {
+            *remaining -= 1;
+        }
+        Some(mock.response.clone())
+    }
+}
+
+impl Transport for MockTransport {
+    fn send<'a>(
+        &'a self,
+        request: MiddlewareRequest,
+        _network: Network<'a>,
+    ) -> MiddlewareFuture<'a> {
+        let outcome = match self.respond(&request) {
+            Some(response) => Ok(canned_response(
+                response.status,
+                response.headers,
+                response.chunks,
+            )),
+            None => Err(OpenRouterError::ConfigError(format!(
+                "mock transport: no mock matched {} {}",
+                request.method(),
+                request.url()
+            ))),
+        };
+        Box::pin(async move { outcome })
+    }
+}
@@ -0,0 +1,119 @@
+//! # Test Support
+//!
+//! Offline testing helpers for code built on top of `openrouter-rs`, enabled
+//! with the `test-support` cargo feature.
+//!
+//! Both helpers plug into [`OpenRouterClient`](crate::OpenRouterClient) as a
+//! [`Transport`](crate::client::Transport), so they replace the HTTP layer
+//! for every endpoint, including streaming endpoints such as
+//! `client.chat().stream(...)` and `client.images().stream(...)`. They sit
+//! below the client's retry policy, rate limiter, and timeouts, which
+//! therefore behave as they would against the real API:
+//!
+//! - [`MockTransport`]: programmable in-memory mock. Register [`Matcher`]s
+//!   with canned JSON or SSE [`MockResponse`]s and inspect the requests the
+//!   SDK sent.
+//! - [`Cassette`]: record real traffic to a JSON cassette file once, then
+//!   replay it in later runs without network access.
+//!
+//! ```toml
+//! [dev-dependencies]
+//! openrouter-rs = { version = "*", features = ["test-support"] }
+//! ```
+//!
+//! ## Example
+//!
+//! ```rust
+//! use openrouter_rs::{
+//!     OpenRouterClient,
+//!     api::chat::{ChatCompletionRequest, Message},
+//!     testing::{Matcher, MockResponse, MockTransport},
+//!     types::Role,
+//! };
+//! use serde_json::json;
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mock = MockTransport::new();
+//! mock.on(
+//!     Matcher::post("/chat/completions"),
+//!     MockResponse::json(json!({
+//!         "id": "gen-1",
+//!         "object": "chat.completion",
+//!         "created": 1700000000,
+//!         "model": "openai/gpt-4.1-mini",
+//!         "choices": [{
+//!             "index": 0,
+//!             "message": {"role": "assistant", "content": "Hello!"},
+//!             "finish_reason": "stop"
+//!         }]
+//!     })),
+//! );
+//!
+//! let client = OpenRouterClient::builder()
+//!     .api_key("test-key")
+//!     .transport(mock.clone())
+//!     .build()?;
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("openai/gpt-4.1-mini")
+//!     .messages(vec![Message::new(Role::User, "Hi")])
+//!     .build()?;
+//! let response = client.chat().create(&request).await?;
+//!
+//! assert_eq!(response.choices[0].content(), Some("Hello!"));
+//! assert_eq!(mock.received_requests().len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+mod cassette;
+mod mock;
+
+use bytes::Bytes;
+use futures_util::{StreamExt, stream};
+use http::{HeaderMap, StatusCode, header::CONTENT_TYPE};
+
+use crate::client::MiddlewareResponse;
+
+pub use cassette::{Cassette, CassetteMode};
+pub use mock::{Matcher, MockResponse, MockTransport, ReceivedRequest};
+
+/// Path and query of a request URL, independent of the configured base URL host.
+fn request_path(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(url) => match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+fn is_event_stream(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/event-stream"))
+}
+
+/// Build a middleware response, delivering SSE bodies chunk by chunk.
+fn canned_response(
+    status: StatusCode,
+    headers: HeaderMap,
+    chunks: Vec<Bytes>,
+) -> MiddlewareResponse {
+    if is_event_stream(&headers) {
+        let body = stream::iter(chunks.into_iter().map(Ok)).boxed();
+        MiddlewareResponse::stream(status, headers, body)
+    } else {
+        MiddlewareResponse::new(status, headers, chunks.concat())
+    }
+}
+
+/// Split an SSE body into one chunk per event so replays stream incrementally.
+fn sse_chunks(body: &str) -> Vec<Bytes> {
+    body.split_inclusive("\n\n")
+        .map(|frame| Bytes::copy_from_slice(frame.as_bytes()))
+        .collect()
+}
@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+
+use futures_util::{StreamExt, stream::BoxStream};
+
+use crate::{
+    OpenRouterClient,
+    api::chat::{ChatCompletionRequest, Message},
+    error::OpenRouterError,
+    types::{
+        CompletionsResponse, Role, ToolCall,
+        stream::{StreamEvent, ToolAwareStream},
+    },
+};
+
+use super::{ToolOutput, ToolRunStop, ToolRunner};
+
+/// Result of a non-streaming chat tool loop.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ToolRunOutcome {
+    /// The last model response. Holds the final answer, or the unexecuted
+    /// tool calls when the loop stopped at the iteration limit.
+    pub response: CompletionsResponse,
+    /// Full conversation: the request messages plus every assistant turn and tool result.
+    pub messages: Vec<Message>,
+    /// Number of model calls made.
+    pub iterations: usize,
+    pub stop: ToolRunStop,
+}
+
+/// Events emitted by [`ToolRunner::run_streaming`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ToolRunEvent {
+    /// An event from the current model turn, including its [`StreamEvent::Done`].
+    Model(StreamEvent),
+    /// A tool call was executed and its result appended to the conversation.
+    ToolResult { call: ToolCall, output: ToolOutput },
+    /// The loop finished. Always the last event of a successful run.
+    Finished {
+        messages: Vec<Message>,
+        iterations: usize,
+        stop: ToolRunStop,
+    },
+}
+
+/// Stream returned by [`ToolRunner::run_streaming`].
+pub type ToolRunStream = BoxStream<'static, Result<ToolRunEvent, OpenRouterError>>;
+
+impl ToolRunner {
+    /// Run the tool loop against `POST /chat/completions`.
+    ///
+    /// Registered tools missing from `request` are declared automatically.
+    pub async fn run(
+        &self,
+        client: &OpenRouterClient,
+        request: &ChatCompletionRequest,
+    ) -> Result<ToolRunOutcome, OpenRouterError> {
+        let mut request = request.with_missing_tools(&self.definitions());
+        let mut messages = request.messages().to_vec();
+        let mut iterations = 0;
+
+        loop {
+            iterations += 1;
+            let response = client.chat().create(&request).await?;
+            let choice = response.choices.first();
+            let content = choice
+                .and_then(|choice| choice.content())
+                .unwrap_or_default();
+            let tool_calls = choice
+                .and_then(|choice| choice.tool_calls())
+                .map(<[ToolCall]>::to_vec)
+                .unwrap_or_default();
+
+            let stop = if tool_calls.is_empty() {
+                messages.push(Message::new(Role::Assistant, content));
+                Some(ToolRunStop::FinalAnswer)
+            } else if iterations >= self.limit() {
+                Some(ToolRunStop::MaxIterations)
+            } else {
+                None
+            };
+            if let Some(stop) = stop {
+                return Ok(ToolRunOutcome {
+                    response,
+                    messages,
+                    iterations,
+                    stop,
+                });
+            }
+
+            messages.push(Message::assistant_with_tool_calls(
+                content,
+                tool_calls.clone(),
+            ));
+            let outputs = self.execute_all(&tool_calls).await;
+            messages.extend(
+                tool_calls
+                    .iter()
+                    .zip(outputs)
+                    .map(|(call, output)| tool_message(call, &output)),
+            );
+            request = request.with_messages(messages.clone());
+        }
+    }
+
+    /// Run the tool loop over `stream_tool_aware`, forwarding model deltas as they arrive.
+    ///
+    /// Each turn is streamed; once its [`StreamEvent::Done`] carries tool
+    /// calls, they are executed concurrently and the next turn is started.
+    pub fn run_streaming(
+        &self,
+        client: &OpenRouterClient,
+        request: &ChatCompletionRequest,
+    ) -> ToolRunStream {
+        let request = request.with_missing_tools(&self.definitions());
+        let state = StreamingRun {
+            runner: self.clone(),
+            client: client.clone(),
+            messages: request.messages().to_vec(),
+            request,
+            iterations: 0,
+            turn: None,
+            content: String::new(),
+            pending: VecDeque::new(),
+            finished: false,
+        };
+
+        futures_util::stream::unfold(state, |mut state| async move {
+            let item = state.next_event().await?;
+            Some((item, state))
+        })
+        .boxed()
+    }
+}
+
+fn tool_message(call: &ToolCall, output: &ToolOutput) -> Message {
+    Message::tool_response_named(&call.id, &call.function.name, output.content.as_str())
+}
+
+struct StreamingRun {
+    runner: ToolRunner,
+    client: OpenRouterClient,
+    request: ChatCompletionRequest,
+    messages: Vec<Message>,
+    iterations: usize,
+    turn: Option<ToolAwareStream>,
+    content: String,
+    pending: VecDeque<ToolRunEvent>,
+    finished: bool,
+}
+
+impl StreamingRun {
+    async fn next_event(&mut self) -> Option<Result<ToolRunEvent, OpenRouterError>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok(event));
+            }
+            if self.finished {
+                return None;
+            }
+
+            let turn = match &mut self.turn {
+                Some(turn) => turn,
+                None => {
+                    self.iterations += 1;
+                    match self.client.chat().stream_tool_aware(&self.request).await {
+                        Ok(turn) => self.turn.insert(turn),
+                        Err(error) => {
+                            self.finished = true;
+                            return Some(Err(error));
+                        }
+                    }
+                }
+            };
+
+            match turn.next().await {
+                Some(StreamEvent::ContentDelta(delta)) => {
+                    self.content.push_str(&delta);
+                    return Some(Ok(ToolRunEvent::Model(StreamEvent::ContentDelta(delta))));
+                }
+                Some(StreamEvent::Error(error)) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+                Some(StreamEvent::Done {
+                    tool_calls,
+                    finish_reason,
+                    usage,
+                    id,
+                    model,
+                }) => {
+                    self.turn = None;
+                    self.pending
+                        .push_back(ToolRunEvent::Model(StreamEvent::Done {
+                            tool_calls: tool_calls.clone(),
+                            finish_reason,
+                            usage,
+                            id,
+                            model,
+                        }));
+                    self.finish_turn(tool_calls).await;
+                }
+                Some(event) => return Some(Ok(ToolRunEvent::Model(event))),
+                None => {
+                    self.turn = None;
+                    self.finish_turn(Vec::new()).await;
+                }
+            }
+        }
+    }
+
+    async fn finish_turn(&mut self, tool_calls: Vec<ToolCall>) {
+        let content = std::mem::take(&mut self.content);
+
+        let stop = if tool_calls.is_empty() {
+            self.messages.push(Message::new(Role::Assistant, content));
+            ToolRunStop::FinalAnswer
+        } else if self.iterations >= self.runner.limit() {
+            ToolRunStop::MaxIterations
+        } else {
+            self.messages.push(Message::assistant_with_tool_calls(
+                content,
+                tool_calls.clone(),
+            ));
+            let outputs = self.runner.execute_all(&tool_calls).await;
+            for (call, output) in tool_calls.into_iter().zip(outputs) {
+                self.messages.push(tool_message(&call, &output));
+                self.pending
+                    .push_back(ToolRunEvent::ToolResult { call, output });
+            }
+            self.request = self.request.with_messages(self.messages.clone());
+            return;
+        };
+
+        self.finished = true;
+        self.pending.push_back(ToolRunEvent::Finished {
+            messages: self.messages.clone(),
+            iterations: self.iterations,
+            stop,
+        });
+    }
+}
@@ -0,0 +1,126 @@
+use futures_util::future::join_all;
+
+use crate::{
+    OpenRouterClient,
+    api::messages::{
+        AnthropicContentPart, AnthropicMessage, AnthropicMessagesRequest,
+        AnthropicMessagesResponse, AnthropicRole, AnthropicTool,
+    },
+    error::OpenRouterError,
+};
+
+use super::{ToolOutput, ToolRunStop, ToolRunner};
+
+/// Result of a Messages API tool loop.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MessagesToolRunOutcome {
+    /// The last model response. Holds the final answer, or the unexecuted
+    /// `tool_use` blocks when the loop stopped at the iteration limit.
+    pub response: AnthropicMessagesResponse,
+    /// Full conversation: the request messages plus every assistant turn and
+    /// `tool_result` message.
+    pub messages: Vec<AnthropicMessage>,
+    /// Number of model calls made.
+    pub iterations: usize,
+    pub stop: ToolRunStop,
+}
+
+impl ToolRunner {
+    /// Registered tools as Messages API custom tools, in registration order.
+    pub fn anthropic_definitions(&self) -> Vec<AnthropicTool> {
+        self.definitions()
+            .into_iter()
+            .map(AnthropicTool::from)
+            .collect()
+    }
+
+    /// Run the tool loop against `POST /messages`.
+    ///
+    /// The loop continues while the response has `stop_reason == "tool_use"`.
+    /// Each assistant turn is replayed with all of its content blocks, so
+    /// `thinking` and `redacted_thinking` blocks (and their signatures) are
+    /// preserved ahead of the `tool_use` blocks as the API requires. Tool
+    /// results of one turn are sent back together in a single user message.
+    pub async fn run_messages(
+        &self,
+        client: &OpenRouterClient,
+        request: &AnthropicMessagesRequest,
+    ) -> Result<MessagesToolRunOutcome, OpenRouterError> {
+        let mut request = request.with_missing_tools(&self.anthropic_definitions());
+        let mut messages = request.messages().to_vec();
+        let mut iterations = 0;
+
+        loop {
+            iterations += 1;
+            let response = client.messages().create(&request).await?;
+            let tool_uses: Vec<(String, String, String)> =
+                if response.stop_reason.as_deref() == Some("tool_use") {
+                    response.content.iter().filter_map(tool_use).collect()
+                } else {
+                    Vec::new()
+                };
+
+            let stop = if tool_uses.is_empty() {
+                messages.push(assistant_turn(&response));
+                Some(ToolRunStop::FinalAnswer)
+            } else if iterations >= self.limit() {
+                Some(ToolRunStop::MaxIterations)
+            } else {
+                None
+            };
+            if let Some(stop) = stop {
+                return Ok(MessagesToolRunOutcome {
+                    response,
+                    messages,
+                    iterations,
+                    stop,
+                });
+            }
+
+            messages.push(assistant_turn(&response));
+            let outputs = join_all(
+                tool_uses
+                    .iter()
+                    .map(|(_, name, input)| self.execute_raw(name, input)),
+            )
+            .await;
+            let results = tool_uses
+                .iter()
+                .zip(outputs)
+                .map(|((id, _, _), output)| tool_result(id, output))
+                .collect();
+            messages.push(AnthropicMessage::with_parts(AnthropicRole::User, results));
+            request = request.with_messages(messages.clone());
+        }
+    }
+}
+
+/// `(id, name, serialized input)` of a `tool_use` block.
+fn tool_use(part: &AnthropicContentPart) -> Option<(String, String, String)> {
+    match part {
+        AnthropicContentPart::ToolUse {
+            id, name, input, ..
+        } => Some((
+            id.clone(),
+            name.clone(),
+            input
+                .as_ref()
+                .map(|input| input.to_string())
+                .unwrap_or_default(),
+        )),
+        _ => None,
+    }
+}
+
+fn assistant_turn(response: &AnthropicMessagesResponse) -> AnthropicMessage {
+    AnthropicMessage::with_parts(AnthropicRole::Assistant, response.content.clone())
+}
+
+fn tool_result(tool_use_id: &str, output: ToolOutput) -> AnthropicContentPart {
+    if output.is_error {
+        AnthropicContentPart::tool_error(tool_use_id, output.content)
+    } else {
+        AnthropicContentPart::tool_result(tool_use_id, output.content)
+    }
+}
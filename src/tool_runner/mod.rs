@@ -0,0 +1,260 @@
+//! # Tool Runner
+//!
+//! Automatic multi-turn tool execution on top of [`TypedTool`] definitions.
+//!
+//! Register an async handler per tool with [`ToolRunner::tool`]. The runner
+//! declares the tools on the request, sends it, deserializes every tool call
+//! into the typed parameter struct, calls [`TypedToolParams::validate`], runs
+//! the handlers for one turn concurrently, feeds the results back, and
+//! repeats until the model produces a final answer or
+//! [`ToolRunner::max_iterations`] model calls have been made.
+//!
+//! [`ToolRunner::run`] and [`ToolRunner::run_streaming`] drive
+//! `/chat/completions`; [`ToolRunner::run_messages`] drives the
+//! Anthropic-compatible `/messages` API with the same registered handlers.
+//!
+//! Invalid arguments, failed validation, unknown tool names, and handler
+//! errors do not abort the loop: they are returned to the model as error tool
+//! results so it can correct itself.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use openrouter_rs::{
+//!     OpenRouterClient,
+//!     api::chat::{ChatCompletionRequest, Message},
+//!     tool_runner::ToolRunner,
+//!     types::{Role, typed_tool::TypedTool},
+//! };
+//! use schemars::JsonSchema;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, JsonSchema)]
+//! struct WeatherParams {
+//!     location: String,
+//! }
+//!
+//! impl TypedTool for WeatherParams {
+//!     fn name() -> &'static str {
+//!         "get_weather"
+//!     }
+//!
+//!     fn description() -> &'static str {
+//!         "Get the current weather for a location"
+//!     }
+//! }
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = OpenRouterClient::builder().api_key("your_api_key").build()?;
+//! let runner = ToolRunner::new()
+//!     .tool(|params: WeatherParams| async move {
+//!         Ok::<_, String>(format!("Sunny in {}", params.location))
+//!     })
+//!     .max_iterations(5);
+//!
+//! let request = ChatCompletionRequest::builder()
+//!     .model("openai/gpt-4.1-mini")
+//!     .messages(vec![Message::new(Role::User, "What's the weather in Paris?")])
+//!     .build()?;
+//!
+//! let outcome = runner.run(&client, &request).await?;
+//! println!("{:?}", outcome.response.choices[0].content());
+//! # Ok(())
+//! # }
+//! ```
+
+mod chat;
+mod messages;
+
+use std::{collections::HashMap, fmt, future::Future, sync::Arc};
+
+use futures_util::future::{BoxFuture, join_all};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::types::{Tool, ToolCall, TypedTool, TypedToolParams};
+
+pub use chat::{ToolRunEvent, ToolRunOutcome, ToolRunStream};
+pub use messages::MessagesToolRunOutcome;
+
+/// Result of executing one tool call, as fed back to the model.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolOutput {
+    /// Text returned to the model as the tool result.
+    pub content: String,
+    /// True when the call failed (bad arguments, validation, unknown tool, or handler error).
+    pub is_error: bool,
+}
+
+impl ToolOutput {
+    pub fn success(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            is_error: false,
+        }
+    }
+
+    pub fn error(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            is_error: true,
+        }
+    }
+}
+
+/// Why a tool loop stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ToolRunStop {
+    /// The model answered without requesting more tool calls.
+    FinalAnswer,
+    /// The model still requested tools when the iteration limit was reached.
+    /// The pending calls were not executed.
+    MaxIterations,
+}
+
+type ToolHandler = dyn Fn(String) -> BoxFuture<'static, ToolOutput> + Send + Sync;
+
+#[derive(Clone)]
+struct RegisteredTool {
+    definition: Tool,
+    handler: Arc<ToolHandler>,
+}
+
+/// Multi-turn tool execution loop driven by registered [`TypedTool`] handlers.
+///
+/// Clones share the registered handlers.
+#[derive(Clone)]
+pub struct ToolRunner {
+    tools: HashMap<String, RegisteredTool>,
+    order: Vec<String>,
+    max_iterations: usize,
+}
+
+impl fmt::Debug for ToolRunner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToolRunner")
+            .field("tools", &self.order)
+            .field("max_iterations", &self.max_iterations)
+            .finish()
+    }
+}
+
+impl Default for ToolRunner {
+    fn default() -> Self {
+        Self {
+            tools: HashMap::new(),
+            order: Vec::new(),
+            max_iterations: 10,
+        }
+    }
+}
+
+impl ToolRunner {
+    /// Create a runner with no tools and a limit of 10 model calls.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an async handler for the tool `T`, keyed by [`TypedTool::name`].
+    ///
+    /// Successful results are serialized to JSON; string results are passed
+    /// through verbatim. Errors are reported to the model as error results.
+    /// Registering the same tool name again replaces the previous handler.
+    pub fn tool<T, F, Fut, R, E>(mut self, handler: F) -> Self
+    where
+        T: TypedTool + Send + 'static,
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R, E>> + Send + 'static,
+        R: Serialize,
+        E: fmt::Display,
+    {
+        let handler = Arc::new(handler);
+        let erased: Arc<ToolHandler> = Arc::new(move |arguments: String| {
+            let handler = Arc::clone(&handler);
+            Box::pin(async move {
+                let params = match parse_arguments::<T>(&arguments) {
+                    Ok(params) => params,
+                    Err(output) => return output,
+                };
+                match handler(params).await {
+                    Ok(result) => render_output(&result),
+                    Err(error) => ToolOutput::error(error.to_string()),
+                }
+            }) as BoxFuture<'static, ToolOutput>
+        });
+
+        let name = T::name().to_string();
+        if !self.tools.contains_key(&name) {
+            self.order.push(name.clone());
+        }
+        self.tools.insert(
+            name,
+            RegisteredTool {
+                definition: T::create_tool(),
+                handler: erased,
+            },
+        );
+        self
+    }
+
+    /// Set the maximum number of model calls per run (at least 1).
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations.max(1);
+        self
+    }
+
+    /// Tool definitions for every registered handler, in registration order.
+    pub fn definitions(&self) -> Vec<Tool> {
+        self.order
+            .iter()
+            .filter_map(|name| self.tools.get(name))
+            .map(|tool| tool.definition.clone())
+            .collect()
+    }
+
+    /// Execute a single tool call with the matching handler.
+    pub async fn execute(&self, call: &ToolCall) -> ToolOutput {
+        self.execute_raw(&call.function.name, &call.function.arguments)
+            .await
+    }
+
+    /// Execute all tool calls of one turn concurrently, preserving their order.
+    pub async fn execute_all(&self, calls: &[ToolCall]) -> Vec<ToolOutput> {
+        join_all(calls.iter().map(|call| self.execute(call))).await
+    }
+
+    pub(crate) async fn execute_raw(&self, name: &str, arguments: &str) -> ToolOutput {
+        match self.tools.get(name) {
+            Some(tool) => (tool.handler)(arguments.to_string()).await,
+            None => ToolOutput::error(format!("Unknown tool: {name}")),
+        }
+    }
+
+    pub(crate) fn limit(&self) -> usize {
+        self.max_iterations
+    }
+}
+
+fn parse_arguments<T: TypedTool>(arguments: &str) -> Result<T, ToolOutput> {
+    let arguments = if arguments.trim().is_empty() {
+        "{}"
+    } else {
+        arguments
+    };
+    let params: T = serde_json::from_str(arguments).map_err(|error| {
+        ToolOutput::error(format!("Invalid arguments for {}: {error}", T::name()))
+    })?;
+    params.validate().map_err(|error| {
+        ToolOutput::error(format!("Invalid arguments for {}: {error}", T::name()))
+    })?;
+    Ok(params)
+}
+
+fn render_output<R: Serialize>(result: &R) -> ToolOutput {
+    match serde_json::to_value(result) {
+        Ok(Value::String(text)) => ToolOutput::success(text),
+        Ok(value) => ToolOutput::success(value.to_string()),
+        Err(error) => ToolOutput::error(format!("Failed to serialize tool result: {error}")),
+    }
+}
@@ -0,0 +1,107 @@
+use std::{fmt, sync::Arc};
+
+use reqwest::{Request, Response};
+
+use crate::{
+    error::OpenRouterError,
+    transport::{
+        middleware::{MiddlewareFuture, MiddlewareRequest, MiddlewareResponse},
+        timeout,
+    },
+};
+
+/// Replacement for the network at the bottom of the transport stack.
+///
+/// A transport receives each HTTP attempt after the client's retry policy,
+/// rate limiter, and request timeout have been applied, so those policies
+/// behave against it as they do against a real server: a retried call
+/// reaches the transport once per attempt, and a transport that answers too
+/// slowly times out. Responses it returns still pass back through any
+/// [`Middleware`](crate::client::Middleware) and the budget guard.
+///
+/// Register one with
+/// [`OpenRouterClientBuilder::transport`](crate::client::OpenRouterClientBuilder::transport).
+/// Use [`Network::send`] to perform the real request, for example to record
+/// it.
+///
+/// # Examples
+///
+/// ```rust
+/// use http::StatusCode;
+/// use openrouter_rs::{
+///     OpenRouterClient,
+///     client::{MiddlewareFuture, MiddlewareRequest, MiddlewareResponse, Network, Transport},
+/// };
+///
+/// struct Offline;
+///
+/// impl Transport for Offline {
+///     fn send<'a>(&'a self, _request: MiddlewareRequest, _network: Network<'a>) -> MiddlewareFuture<'a> {
+///         Box::pin(async {
+///             MiddlewareResponse::json(StatusCode::SERVICE_UNAVAILABLE, &serde_json::json!({}))
+///         })
+///     }
+/// }
+///
+/// let client = OpenRouterClient::builder()
+///     .api_key("your_api_key")
+///     .transport(Offline)
+///     .build()?;
+/// # Ok::<(), openrouter_rs::error::OpenRouterError>(())
+/// ```
+pub trait Transport: Send + Sync + 'static {
+    /// Send one attempt of `request`, or answer it without the network.
+    fn send<'a>(&'a self, request: MiddlewareRequest, network: Network<'a>)
+    -> MiddlewareFuture<'a>;
+}
+
+/// The HTTP client beneath a [`Transport`].
+pub struct Network<'a> {
+    client: &'a reqwest::Client,
+}
+
+impl<'a> Network<'a> {
+    /// Send `request` over the network.
+    pub fn send(self, request: MiddlewareRequest) -> MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let deadline = request.inner.timeout().copied();
+            let response = self
+                .client
+                .execute(request.inner)
+                .await
+                .map_err(|error| timeout::from_reqwest(error, deadline, None))?;
+            Ok(MiddlewareResponse::from_transport(response))
+        })
+    }
+}
+
+/// The registered [`Transport`], if any.
+#[derive(Clone)]
+pub(crate) struct TransportHook(Arc<dyn Transport>);
+
+impl TransportHook {
+    pub(crate) fn new(transport: impl Transport) -> Self {
+        Self(Arc::new(transport))
+    }
+
+    pub(crate) async fn send(
+        &self,
+        client: &reqwest::Client,
+        request: Request,
+        streaming: bool,
+    ) -> Result<Response, OpenRouterError> {
+        self.0
+            .send(
+                MiddlewareRequest::new(request, streaming),
+                Network { client },
+            )
+            .await
+            .map(MiddlewareResponse::into_response)
+    }
+}
+
+impl fmt::Debug for TransportHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransportHook").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,332 @@
+use std::{fmt, io, sync::Arc};
+
+use bytes::Bytes;
+use futures_util::{
+    StreamExt, TryStreamExt,
+    future::BoxFuture,
+    stream::{self, BoxStream},
+};
+use http::{HeaderMap, HeaderValue, Method, StatusCode, header::CONTENT_TYPE};
+use reqwest::{Request, Response};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    error::OpenRouterError,
+    transport::{HttpClient, response::read_bytes},
+};
+
+/// Future returned by [`Middleware::handle`] and [`Next::run`].
+pub type MiddlewareFuture<'a> = BoxFuture<'a, Result<MiddlewareResponse, OpenRouterError>>;
+
+/// Byte stream handle for streaming (SSE) response bodies.
+pub type ByteStream = BoxStream<'static, io::Result<Bytes>>;
+
+/// Interceptor around every outgoing request sent by an
+/// [`OpenRouterClient`](crate::OpenRouterClient).
+///
+/// Middleware runs in registration order. Each layer receives the request
+/// (method, URL, headers, and body) and a [`Next`] handle. Call
+/// [`Next::run`] to continue the chain, or return a [`MiddlewareResponse`]
+/// directly to short-circuit without touching the network.
+///
+/// The chain wraps the whole logical call: retries and timeouts configured on
+/// the client happen inside [`Next::run`].
+///
+/// # Examples
+///
+/// ```rust
+/// use http::HeaderValue;
+/// use openrouter_rs::{
+///     OpenRouterClient,
+///     client::{Middleware, MiddlewareFuture, MiddlewareRequest, Next},
+/// };
+///
+/// struct TenantHeader(&'static str);
+///
+/// impl Middleware for TenantHeader {
+///     fn handle<'a>(&'a self, mut request: MiddlewareRequest, next: Next<'a>) -> MiddlewareFuture<'a> {
+///         request
+///             .headers_mut()
+///             .insert("x-tenant-id", HeaderValue::from_static(self.0));
+///         next.run(request)
+///     }
+/// }
+///
+/// let client = OpenRouterClient::builder()
+///     .api_key("your_api_key")
+///     .middleware(TenantHeader("acme"))
+///     .build()?;
+/// # Ok::<(), openrouter_rs::error::OpenRouterError>(())
+/// ```
+pub trait Middleware: Send + Sync + 'static {
+    /// Handle one request, delegating to `next` to continue the chain.
+    fn handle<'a>(&'a self, request: MiddlewareRequest, next: Next<'a>) -> MiddlewareFuture<'a>;
+}
+
+/// Ordered set of registered middleware.
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareStack(Vec<Arc<dyn Middleware>>);
+
+impl MiddlewareStack {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn layers(&self) -> &[Arc<dyn Middleware>] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for MiddlewareStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MiddlewareStack")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+/// Remaining middleware plus the transport at the end of the chain.
+pub struct Next<'a> {
+    layers: &'a [Arc<dyn Middleware>],
+    http_client: &'a HttpClient,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(layers: &'a [Arc<dyn Middleware>], http_client: &'a HttpClient) -> Self {
+        Self {
+            layers,
+            http_client,
+        }
+    }
+
+    /// Pass the request to the next middleware, or send it if none are left.
+    pub fn run(self, request: MiddlewareRequest) -> MiddlewareFuture<'a> {
+        match self.layers.split_first() {
+            Some((layer, rest)) => layer.handle(
+                request,
+                Next {
+                    layers: rest,
+                    http_client: self.http_client,
+                },
+            ),
+            None => Box::pin(async move {
+                let streaming = request.streaming;
+                self.http_client
+                    .dispatch(request.inner, streaming)
+                    .await
+                    .map(MiddlewareResponse::from_transport)
+            }),
+        }
+    }
+}
+
+/// Outgoing request as seen by [`Middleware`].
+#[derive(Debug)]
+pub struct MiddlewareRequest {
+    pub(super) inner: Request,
+    streaming: bool,
+}
+
+impl MiddlewareRequest {
+    pub(crate) fn new(inner: Request, streaming: bool) -> Self {
+        Self { inner, streaming }
+    }
+
+    pub fn method(&self) -> &Method {
+        self.inner.method()
+    }
+
+    pub fn url(&self) -> &str {
+        self.inner.url().as_str()
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        self.inner.headers()
+    }
+
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        self.inner.headers_mut()
+    }
+
+    /// Returns true when the caller will consume the response as an SSE stream.
+    pub fn is_streaming(&self) -> bool {
+        self.streaming
+    }
+
+    /// Raw request body bytes, when the body is buffered (JSON requests always are).
+    pub fn body(&self) -> Option<&[u8]> {
+        self.inner.body().and_then(|body| body.as_bytes())
+    }
+
+    /// Request body parsed as JSON, when present and valid.
+    pub fn json_body(&self) -> Option<Value> {
+        self.body()
+            .and_then(|body| serde_json::from_slice(body).ok())
+    }
+
+    /// Replace the request body with raw bytes.
+    pub fn set_body(&mut self, body: impl Into<Bytes>) {
+        *self.inner.body_mut() = Some(reqwest::Body::from(body.into()));
+    }
+
+    /// Replace the request body with a JSON payload.
+    pub fn set_json_body<T: Serialize + ?Sized>(
+        &mut self,
+        body: &T,
+    ) -> Result<(), OpenRouterError> {
+        self.set_body(serde_json::to_vec(body)?);
+        self.headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        Ok(())
+    }
+}
+
+enum ResponseBody {
+    Transport(Response),
+    Buffered(Bytes),
+    Stream(ByteStream),
+}
+
+/// Response as seen by [`Middleware`] after the request has been sent, or a
+/// synthetic response produced by a middleware that short-circuits the chain.
+pub struct MiddlewareResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: ResponseBody,
+}
+
+impl fmt::Debug for MiddlewareResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let body = match &self.body {
+            ResponseBody::Transport(_) => "transport",
+            ResponseBody::Buffered(_) => "buffered",
+            ResponseBody::Stream(_) => "stream",
+        };
+        f.debug_struct("MiddlewareResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .field("body", &body)
+            .finish()
+    }
+}
+
+impl MiddlewareResponse {
+    /// Build a synthetic response with a fully buffered body.
+    pub fn new(status: StatusCode, headers: HeaderMap, body: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers,
+            body: ResponseBody::Buffered(body.into()),
+        }
+    }
+
+    /// Build a synthetic JSON response.
+    pub fn json<T: Serialize + ?Sized>(
+        status: StatusCode,
+        body: &T,
+    ) -> Result<Self, OpenRouterError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        Ok(Self::new(status, headers, serde_json::to_vec(body)?))
+    }
+
+    /// Build a synthetic streaming response (for example `text/event-stream`).
+    pub fn stream(status: StatusCode, headers: HeaderMap, body: ByteStream) -> Self {
+        Self {
+            status,
+            headers,
+            body: ResponseBody::Stream(body),
+        }
+    }
+
+    pub(crate) fn from_transport(response: Response) -> Self {
+        Self {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: ResponseBody::Transport(response),
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: StatusCode) {
+        self.status = status;
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        &mut self.headers
+    }
+
+    /// Returns true when the response is a server-sent event stream.
+    pub fn is_event_stream(&self) -> bool {
+        self.headers
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/event-stream"))
+    }
+
+    /// Buffer and return the full response body.
+    ///
+    /// The buffered bytes remain available to the rest of the chain and to the
+    /// SDK. Avoid calling this on event streams you want delivered incrementally;
+    /// use [`inspect_stream`](Self::inspect_stream) instead.
+    pub async fn body(&mut self) -> Result<Bytes, OpenRouterError> {
+        let bytes = match std::mem::replace(&mut self.body, ResponseBody::Buffered(Bytes::new())) {
+            ResponseBody::Transport(response) => read_bytes(response).await?,
+            ResponseBody::Buffered(bytes) => bytes,
+            ResponseBody::Stream(stream) => {
+                let chunks: Vec<Bytes> = stream.try_collect().await?;
+                Bytes::from(chunks.concat())
+            }
+        };
+        self.body = ResponseBody::Buffered(bytes.clone());
+        Ok(bytes)
+    }
+
+    /// Observe body chunks as they are delivered, without buffering them.
+    pub fn inspect_stream(mut self, mut inspect: impl FnMut(&Bytes) + Send + 'static) -> Self {
+        let stream = self.take_byte_stream();
+        self.body = ResponseBody::Stream(
+            stream
+                .inspect(move |chunk| {
+                    if let Ok(chunk) = chunk {
+                        inspect(chunk);
+                    }
+                })
+                .boxed(),
+        );
+        self
+    }
+
+    fn take_byte_stream(&mut self) -> ByteStream {
+        match std::mem::replace(&mut self.body, ResponseBody::Buffered(Bytes::new())) {
+            ResponseBody::Transport(response) => {
+                response.bytes_stream().map_err(io::Error::other).boxed()
+            }
+            ResponseBody::Buffered(bytes) => stream::once(async move { Ok(bytes) }).boxed(),
+            ResponseBody::Stream(stream) => stream,
+        }
+    }
+
+    pub(crate) fn into_response(self) -> Response {
+        let mut response = match self.body {
+            ResponseBody::Transport(response) => http::Response::from(response),
+            ResponseBody::Buffered(bytes) => http::Response::new(reqwest::Body::from(bytes)),
+            ResponseBody::Stream(stream) => http::Response::new(reqwest::Body::wrap_stream(stream)),
+        };
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        Response::from(response)
+    }
+}
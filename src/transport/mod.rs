@@ -1,13 +1,339 @@
+pub(crate) mod hook;
+pub(crate) mod middleware;
+pub(crate) mod rate_limit;
 pub(crate) mod request;
 pub(crate) mod response;
+pub(crate) mod retry;
 pub(crate) mod sse;
+pub(crate) mod timeout;
 
-use reqwest::Client;
+use std::{
+    pin::Pin,
+    sync::OnceLock,
+    task::{Context, Poll, ready},
+};
 
-use crate::error::OpenRouterError;
+use bytes::Bytes;
+use reqwest::{Request, Response};
 
-pub(crate) fn new_client() -> Result<Client, OpenRouterError> {
-    reqwest::Client::builder()
-        .build()
-        .map_err(OpenRouterError::from)
+use crate::{
+    budget::BoundBudgetGuard,
+    error::{OpenRouterError, TimeoutKind},
+};
+use hook::TransportHook;
+use middleware::{MiddlewareRequest, MiddlewareStack, Next};
+
+pub use rate_limit::RateLimiter;
+pub use retry::RetryPolicy;
+pub use timeout::Timeouts;
+
+/// Crate-internal HTTP handle shared by every endpoint module.
+///
+/// Wraps the configured `reqwest::Client` together with the client-wide
+/// transport policies so that every request path (chat, responses, messages,
+/// embeddings, management, ...) is executed the same way.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpClient {
+    client: reqwest::Client,
+    retry_policy: Option<RetryPolicy>,
+    timeouts: Timeouts,
+    middleware: MiddlewareStack,
+    transport: Option<TransportHook>,
+    rate_limiter: Option<RateLimiter>,
+    budget_guard: Option<BoundBudgetGuard>,
+}
+
+impl HttpClient {
+    pub(crate) fn new(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            retry_policy: None,
+            timeouts: Timeouts::default(),
+            middleware: MiddlewareStack::default(),
+            transport: None,
+            rate_limiter: None,
+            budget_guard: None,
+        }
+    }
+
+    pub(crate) fn with_retry_policy(mut self, retry_policy: Option<RetryPolicy>) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub(crate) fn with_timeouts(mut self, timeouts: Timeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    pub(crate) fn with_middleware(mut self, middleware: MiddlewareStack) -> Self {
+        self.middleware = middleware;
+        self
+    }
+
+    pub(crate) fn with_transport(mut self, transport: Option<TransportHook>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub(crate) fn with_rate_limiter(mut self, rate_limiter: Option<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    pub(crate) fn with_budget_guard(mut self, budget_guard: Option<BoundBudgetGuard>) -> Self {
+        self.budget_guard = budget_guard;
+        self
+    }
+
+    pub(crate) fn inner(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    pub(crate) fn timeouts(&self) -> &Timeouts {
+        &self.timeouts
+    }
+
+    /// Execute a built request through the middleware chain and transport policies.
+    ///
+    /// Middleware wraps the whole logical call; retries and timeouts apply
+    /// inside it. The budget guard checks the call before the chain runs and
+    /// charges the final successful response, and with the `tracing` or
+    /// `metrics` features the call is recorded until its body is read. For
+    /// streaming requests the stream idle timeout is attached to the final
+    /// response for the SSE reader, including synthetic responses returned
+    /// by middleware.
+    pub(crate) async fn execute(
+        &self,
+        request: Request,
+        streaming: bool,
+    ) -> Result<Response, OpenRouterError> {
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        let telemetry = crate::telemetry::RequestTelemetry::start(&request, streaming);
+        let result = self.execute_guarded(request, streaming);
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        let result = telemetry.instrument(result);
+        let result = result.await;
+        #[cfg(any(feature = "tracing", feature = "metrics"))]
+        let result = telemetry.finish(result);
+
+        let mut response = result?;
+        if let Some(idle_timeout) = self.timeouts.stream_idle_timeout().filter(|_| streaming) {
+            response
+                .extensions_mut()
+                .insert(timeout::StreamIdleTimeout(idle_timeout));
+        }
+
+        Ok(response)
+    }
+
+    async fn execute_guarded(
+        &self,
+        request: Request,
+        streaming: bool,
+    ) -> Result<Response, OpenRouterError> {
+        let charge = match &self.budget_guard {
+            Some(budget_guard) => budget_guard.admit(&request, self).await?,
+            None => None,
+        };
+
+        let response = if self.middleware.is_empty() {
+            self.dispatch(request, streaming).await?
+        } else {
+            Next::new(self.middleware.layers(), self)
+                .run(MiddlewareRequest::new(request, streaming))
+                .await?
+                .into_response()
+        };
+
+        Ok(match charge.filter(|_| response.status().is_success()) {
+            Some(charge) => charge.track(response),
+            None => response,
+        })
+    }
+
+    /// Send a request, applying the configured retry policy.
+    ///
+    /// Retries only happen while waiting for response headers, so streaming
+    /// endpoints are never retried once the SSE body has been handed out.
+    /// For streaming requests the request timeout bounds each attempt until
+    /// headers arrive.
+    pub(crate) async fn dispatch(
+        &self,
+        request: Request,
+        streaming: bool,
+    ) -> Result<Response, OpenRouterError> {
+        let headers_timeout = if streaming {
+            self.timeouts.request_timeout()
+        } else {
+            None
+        };
+        let send = |request: Request| self.send_once(request, streaming, headers_timeout);
+
+        match &self.retry_policy {
+            Some(policy) => retry::execute_with_retry(policy, request, send).await,
+            None => send(request).await,
+        }
+    }
+
+    async fn send_once(
+        &self,
+        request: Request,
+        streaming: bool,
+        headers_timeout: Option<std::time::Duration>,
+    ) -> Result<Response, OpenRouterError> {
+        let permit = match &self.rate_limiter {
+            Some(rate_limiter) => rate_limiter.acquire(&request).await?,
+            None => None,
+        };
+        let response = self
+            .send_unlimited(request, streaming, headers_timeout)
+            .await?;
+        Ok(match permit {
+            Some(permit) => rate_limit::hold_until_read(response, permit),
+            None => response,
+        })
+    }
+
+    /// Send one attempt, through the registered [`Transport`] if any.
+    ///
+    /// A transport is bounded by the request timeout the same way the
+    /// network is: for a non-streaming request the deadline reqwest would
+    /// enforce covers the transport's whole answer.
+    ///
+    /// [`Transport`]: hook::Transport
+    async fn send_unlimited(
+        &self,
+        request: Request,
+        streaming: bool,
+        headers_timeout: Option<std::time::Duration>,
+    ) -> Result<Response, OpenRouterError> {
+        if let Some(transport) = &self.transport {
+            let deadline = headers_timeout.or_else(|| request.timeout().copied());
+            let send = transport.send(&self.client, request, streaming);
+            return match deadline {
+                Some(duration) => tokio::time::timeout(duration, send)
+                    .await
+                    .map_err(|_| timeout::elapsed(TimeoutKind::Request, duration))?,
+                None => send.await,
+            };
+        }
+
+        // Non-streaming requests carry their request timeout; reqwest also
+        // enforces the client's read timeout on the body.
+        let deadline = request.timeout().copied().or(self.timeouts.read_timeout());
+        let connect = self.timeouts.connect_timeout();
+        let response = match headers_timeout {
+            Some(duration) => tokio::time::timeout(duration, self.client.execute(request))
+                .await
+                .map_err(|_| timeout::elapsed(TimeoutKind::Request, duration))?,
+            None => self.client.execute(request).await,
+        };
+        let mut response =
+            response.map_err(|error| timeout::from_reqwest(error, deadline, connect))?;
+        if let Some(deadline) = deadline.filter(|_| !streaming) {
+            response
+                .extensions_mut()
+                .insert(timeout::BodyDeadline(deadline));
+        }
+        Ok(response)
+    }
+}
+
+/// Rebuild `response` so `inspect` sees each body chunk, or the body
+/// error, as it is read.
+///
+/// Status, headers, extensions, and the body's size hint are kept.
+/// `inspect` is dropped together with the body, so state it owns lives
+/// until the body is read or dropped.
+pub(crate) fn inspect_body<F>(response: Response, inspect: F) -> Response
+where
+    F: FnMut(Result<&Bytes, &reqwest::Error>) + Send + Sync + Unpin + 'static,
+{
+    let (parts, inner) = http::Response::from(response).into_parts();
+    let body = reqwest::Body::wrap(InspectedBody { inner, inspect });
+    Response::from(http::Response::from_parts(parts, body))
+}
+
+struct InspectedBody<F> {
+    inner: reqwest::Body,
+    inspect: F,
+}
+
+impl<F> http_body::Body for InspectedBody<F>
+where
+    F: FnMut(Result<&Bytes, &reqwest::Error>) + Unpin,
+{
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<http_body::Frame<Bytes>, reqwest::Error>>> {
+        let this = self.get_mut();
+        let frame = ready!(Pin::new(&mut this.inner).poll_frame(cx));
+        match &frame {
+            Some(Ok(frame)) => {
+                if let Some(chunk) = frame.data_ref() {
+                    (this.inspect)(Ok(chunk));
+                }
+            }
+            Some(Err(error)) => (this.inspect)(Err(error)),
+            None => {}
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// Process-wide client behind the free functions in [`crate::api`] and every
+/// [`OpenRouterClient`](crate::OpenRouterClient) built without connect/read
+/// timeouts or a custom `http_client`.
+///
+/// `reqwest::Client` is a handle to a shared connection pool, so cloning it
+/// lets repeated calls reuse open connections instead of paying for a new
+/// TCP and TLS handshake each time.
+static SHARED_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Client for the given timeouts. Connect and read timeouts are pool-wide
+/// settings in `reqwest`, so only the default configuration uses the shared pool.
+pub(crate) fn new_reqwest_client(timeouts: &Timeouts) -> Result<reqwest::Client, OpenRouterError> {
+    if timeouts.connect_timeout().is_none() && timeouts.read_timeout().is_none() {
+        return shared_reqwest_client();
+    }
+    build_reqwest_client(timeouts)
+}
+
+fn shared_reqwest_client() -> Result<reqwest::Client, OpenRouterError> {
+    if let Some(client) = SHARED_CLIENT.get() {
+        return Ok(client.clone());
+    }
+    let client = build_reqwest_client(&Timeouts::default())?;
+    // A concurrent caller may have won the race; either way use the stored client.
+    Ok(SHARED_CLIENT.get_or_init(|| client).clone())
+}
+
+fn build_reqwest_client(timeouts: &Timeouts) -> Result<reqwest::Client, OpenRouterError> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(connect_timeout) = timeouts.connect_timeout() {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    if let Some(read_timeout) = timeouts.read_timeout() {
+        builder = builder.read_timeout(read_timeout);
+    }
+
+    builder.build().map_err(OpenRouterError::from)
+}
+
+/// Transport for the free functions, backed by the shared connection pool.
+pub(crate) fn default_client() -> Result<HttpClient, OpenRouterError> {
+    shared_reqwest_client().map(HttpClient::new)
 }
@@ -0,0 +1,285 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use reqwest::{Request, Response};
+use serde_json::Value;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{api::api_keys::RateLimit, error::OpenRouterError};
+
+/// Client-side request and token rate limits, plus an in-flight cap, for
+/// requests sent by an [`OpenRouterClient`](crate::OpenRouterClient).
+///
+/// Limits are token buckets: each holds up to `limit` units and refills at
+/// `limit / per`, so short bursts up to `limit` pass immediately. Requests
+/// that would overdraw a bucket wait for it to refill instead of failing.
+///
+/// - Key-wide buckets apply to every request from the client.
+/// - Model buckets apply to requests whose JSON body names that `model`.
+/// - Token buckets are charged the estimated prompt tokens plus the
+///   requested completion limit (`max_tokens` and friends), so set a
+///   completion limit on requests for accurate accounting.
+/// - [`max_in_flight`](Self::max_in_flight) caps concurrent requests; a slot
+///   stays taken until the response body, or stream, is fully read.
+///
+/// Each retry attempt is limited like a new request. Clones share the same
+/// buckets, so one limiter can govern several clients using the same key.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use openrouter_rs::{OpenRouterClient, client::RateLimiter};
+///
+/// let limiter = RateLimiter::new()
+///     .requests(20, Duration::from_secs(10))
+///     .model_tokens("openai/gpt-4.1", 30_000, Duration::from_secs(60))
+///     .max_in_flight(8);
+///
+/// let client = OpenRouterClient::builder()
+///     .api_key("your_api_key")
+///     .rate_limiter(limiter)
+///     .build()?;
+/// # Ok::<(), openrouter_rs::error::OpenRouterError>(())
+/// ```
+///
+/// Limits can also follow the key's own rate limit:
+///
+/// ```rust,no_run
+/// # use openrouter_rs::{OpenRouterClient, client::RateLimiter};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let limiter = RateLimiter::new().max_in_flight(8);
+/// let client = OpenRouterClient::builder()
+///     .api_key("your_api_key")
+///     .rate_limiter(limiter.clone())
+///     .build()?;
+///
+/// let key = client.management().get_current_api_key_info().await?;
+/// limiter.apply_key_rate_limit(&key.rate_limit)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    limits: Arc<Mutex<Limits>>,
+}
+
+#[derive(Debug, Default)]
+struct Limits {
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+    model_requests: HashMap<String, Bucket>,
+    model_tokens: HashMap<String, Bucket>,
+    in_flight: Option<Arc<Semaphore>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter with no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow at most `limit` requests per `per` across the whole key.
+    pub fn requests(self, limit: u32, per: Duration) -> Self {
+        self.lock().requests = Some(Bucket::new(limit, per));
+        self
+    }
+
+    /// Allow at most `limit` tokens per `per` across the whole key.
+    pub fn tokens(self, limit: u32, per: Duration) -> Self {
+        self.lock().tokens = Some(Bucket::new(limit, per));
+        self
+    }
+
+    /// Allow at most `limit` requests per `per` to `model`.
+    pub fn model_requests(self, model: impl Into<String>, limit: u32, per: Duration) -> Self {
+        self.lock()
+            .model_requests
+            .insert(model.into(), Bucket::new(limit, per));
+        self
+    }
+
+    /// Allow at most `limit` tokens per `per` to `model`.
+    pub fn model_tokens(self, model: impl Into<String>, limit: u32, per: Duration) -> Self {
+        self.lock()
+            .model_tokens
+            .insert(model.into(), Bucket::new(limit, per));
+        self
+    }
+
+    /// Allow at most `limit` requests in flight at once.
+    pub fn max_in_flight(self, limit: usize) -> Self {
+        self.lock().in_flight = Some(Arc::new(Semaphore::new(limit.max(1))));
+        self
+    }
+
+    /// Set the key-wide request limit from the key's reported rate limit
+    /// (for example [`ApiKeyDetails::rate_limit`](crate::api::api_keys::ApiKeyDetails::rate_limit)).
+    pub fn key_rate_limit(self, rate_limit: &RateLimit) -> Result<Self, OpenRouterError> {
+        self.apply_key_rate_limit(rate_limit)?;
+        Ok(self)
+    }
+
+    /// Replace the key-wide request limit on a limiter already in use.
+    ///
+    /// A non-positive request count means the key is unlimited and clears
+    /// the limit.
+    pub fn apply_key_rate_limit(&self, rate_limit: &RateLimit) -> Result<(), OpenRouterError> {
+        let per = parse_interval(&rate_limit.interval)?;
+        let limit = rate_limit.requests.floor();
+        self.lock().requests = if limit >= 1.0 {
+            Some(Bucket::new(limit.min(f64::from(u32::MAX)) as u32, per))
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    /// Wait until `request` may be sent, returning the in-flight slot to hold
+    /// while its response is read.
+    ///
+    /// Fails with `ConfigError` when the wait does not fit in a `Duration`,
+    /// which only happens with an absurdly long interval.
+    pub(crate) async fn acquire(
+        &self,
+        request: &Request,
+    ) -> Result<Option<OwnedSemaphorePermit>, OpenRouterError> {
+        let (wait, in_flight) = {
+            let mut limits = self.lock();
+            let body = limits
+                .needs_body()
+                .then(|| request.body().and_then(|body| body.as_bytes()))
+                .flatten()
+                .and_then(|body| serde_json::from_slice::<Value>(body).ok());
+            let model = body
+                .as_ref()
+                .and_then(|body| body.get("model"))
+                .and_then(Value::as_str);
+            let tokens = body.as_ref().map(estimated_tokens).unwrap_or_default();
+            (limits.reserve(model, tokens)?, limits.in_flight.clone())
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        Ok(match in_flight {
+            Some(semaphore) => semaphore.acquire_owned().await.ok(),
+            None => None,
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Limits> {
+        self.limits
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Limits {
+    fn needs_body(&self) -> bool {
+        self.tokens.is_some() || !self.model_requests.is_empty() || !self.model_tokens.is_empty()
+    }
+
+    /// Charge every bucket that applies and return how long to wait until
+    /// none of them is overdrawn.
+    fn reserve(&mut self, model: Option<&str>, tokens: u32) -> Result<Duration, OpenRouterError> {
+        let now = Instant::now();
+        let tokens = f64::from(tokens);
+        let mut wait = Duration::ZERO;
+        let mut charge = |bucket: Option<&mut Bucket>, amount: f64| {
+            if let Some(bucket) = bucket {
+                wait = wait.max(bucket.reserve(amount, now)?);
+            }
+            Ok::<_, OpenRouterError>(())
+        };
+
+        charge(self.requests.as_mut(), 1.0)?;
+        charge(self.tokens.as_mut(), tokens)?;
+        if let Some(model) = model {
+            charge(self.model_requests.get_mut(model), 1.0)?;
+            charge(self.model_tokens.get_mut(model), tokens)?;
+        }
+        Ok(wait)
+    }
+}
+
+/// Token bucket that may go into debt: a reservation is always granted, and
+/// the caller waits until the balance is back to zero.
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    refill_per_second: f64,
+    available: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: u32, per: Duration) -> Self {
+        let capacity = f64::from(limit.max(1));
+        Self {
+            capacity,
+            refill_per_second: capacity / per.as_secs_f64().max(f64::EPSILON),
+            available: capacity,
+            updated: Instant::now(),
+        }
+    }
+
+    fn reserve(&mut self, amount: f64, now: Instant) -> Result<Duration, OpenRouterError> {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.available = (self.available + elapsed * self.refill_per_second).min(self.capacity);
+        self.updated = now;
+
+        // A single request larger than the bucket would otherwise never fit.
+        self.available -= amount.min(self.capacity);
+        if self.available >= 0.0 {
+            return Ok(Duration::ZERO);
+        }
+        Duration::try_from_secs_f64(-self.available / self.refill_per_second).map_err(|error| {
+            OpenRouterError::ConfigError(format!("rate limit wait is out of range: {error}"))
+        })
+    }
+}
+
+fn estimated_tokens(body: &Value) -> u32 {
+    let estimate = crate::estimate::estimate_request_body(body);
+    estimate
+        .prompt_tokens()
+        .saturating_add(estimate.max_output_tokens.unwrap_or_default())
+}
+
+/// Parse a rate limit interval such as `10s`, `1m`, `1h`, or `500ms`.
+fn parse_interval(interval: &str) -> Result<Duration, OpenRouterError> {
+    let interval = interval.trim();
+    let split = interval
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(interval.len());
+    let (amount, unit) = interval.split_at(split);
+    let amount: f64 = amount.parse().map_err(|_| invalid_interval(interval))?;
+    let seconds = match unit.trim() {
+        "ms" => amount / 1000.0,
+        "s" | "" => amount,
+        "m" => amount * 60.0,
+        "h" => amount * 3600.0,
+        "d" => amount * 86_400.0,
+        _ => return Err(invalid_interval(interval)),
+    };
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| invalid_interval(interval))
+}
+
+fn invalid_interval(interval: &str) -> OpenRouterError {
+    OpenRouterError::ConfigError(format!("invalid rate limit interval `{interval}`"))
+}
+
+/// Keep `permit` until `response`'s body has been read to the end or dropped.
+pub(crate) fn hold_until_read(response: Response, permit: OwnedSemaphorePermit) -> Response {
+    super::inspect_body(response, move |_| {
+        let _ = &permit;
+    })
+}
@@ -1,28 +1,110 @@
-use reqwest::{Client, Method, RequestBuilder};
+use std::fmt::Display;
 
-use crate::types::OpenRouterExperimentalMetadata;
+use http::{HeaderName, HeaderValue};
+use reqwest::{Method, Response, multipart::Form};
+use serde::Serialize;
 
-pub(crate) fn request(client: &Client, method: Method, url: &str) -> RequestBuilder {
-    client.request(method, url)
+use crate::{error::OpenRouterError, transport::HttpClient, types::OpenRouterExperimentalMetadata};
+
+/// Request builder that dispatches through the crate's [`HttpClient`] policies.
+#[derive(Debug)]
+pub(crate) struct RequestBuilder {
+    http_client: HttpClient,
+    inner: reqwest::RequestBuilder,
+}
+
+impl RequestBuilder {
+    fn map(self, f: impl FnOnce(reqwest::RequestBuilder) -> reqwest::RequestBuilder) -> Self {
+        Self {
+            http_client: self.http_client,
+            inner: f(self.inner),
+        }
+    }
+
+    pub(crate) fn header<K, V>(self, key: K, value: V) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<http::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+    {
+        self.map(|inner| inner.header(key, value))
+    }
+
+    pub(crate) fn bearer_auth<T: Display>(self, token: T) -> Self {
+        self.map(|inner| inner.bearer_auth(token))
+    }
+
+    pub(crate) fn json<T: Serialize + ?Sized>(self, json: &T) -> Self {
+        self.map(|inner| inner.json(json))
+    }
+
+    pub(crate) fn query<T: Serialize + ?Sized>(self, query: &T) -> Self {
+        self.map(|inner| inner.query(query))
+    }
+
+    pub(crate) fn multipart(self, form: Form) -> Self {
+        self.map(|inner| inner.multipart(form))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn build(self) -> Result<reqwest::Request, OpenRouterError> {
+        Ok(self.inner.build()?)
+    }
+
+    /// Send a request whose full response body is read by the caller.
+    pub(crate) async fn send(self) -> Result<Response, OpenRouterError> {
+        let http_client = self.http_client;
+        let mut inner = self.inner;
+        if let Some(request_timeout) = http_client.timeouts().request_timeout() {
+            inner = inner.timeout(request_timeout);
+        }
+
+        http_client.execute(inner.build()?, false).await
+    }
+
+    /// Send a request with a streamed upload body, whose response is read
+    /// whole by the caller.
+    ///
+    /// The request timeout is not applied, since a large upload may outlast
+    /// it without stalling; the connect and read timeouts still bound the
+    /// connection.
+    pub(crate) async fn send_upload(self) -> Result<Response, OpenRouterError> {
+        let http_client = self.http_client;
+        http_client.execute(self.inner.build()?, false).await
+    }
+
+    /// Send a request whose response body is consumed as an SSE stream.
+    pub(crate) async fn send_streaming(self) -> Result<Response, OpenRouterError> {
+        let http_client = self.http_client;
+        http_client.execute(self.inner.build()?, true).await
+    }
+}
+
+pub(crate) fn request(client: &HttpClient, method: Method, url: &str) -> RequestBuilder {
+    RequestBuilder {
+        http_client: client.clone(),
+        inner: client.inner().request(method, url),
+    }
 }
 
-pub(crate) fn get(client: &Client, url: &str) -> RequestBuilder {
+pub(crate) fn get(client: &HttpClient, url: &str) -> RequestBuilder {
     request(client, Method::GET, url)
 }
 
-pub(crate) fn post(client: &Client, url: &str) -> RequestBuilder {
+pub(crate) fn post(client: &HttpClient, url: &str) -> RequestBuilder {
     request(client, Method::POST, url)
 }
 
-pub(crate) fn put(client: &Client, url: &str) -> RequestBuilder {
+pub(crate) fn put(client: &HttpClient, url: &str) -> RequestBuilder {
     request(client, Method::PUT, url)
 }
 
-pub(crate) fn patch(client: &Client, url: &str) -> RequestBuilder {
+pub(crate) fn patch(client: &HttpClient, url: &str) -> RequestBuilder {
     request(client, Method::PATCH, url)
 }
 
-pub(crate) fn delete(client: &Client, url: &str) -> RequestBuilder {
+pub(crate) fn delete(client: &HttpClient, url: &str) -> RequestBuilder {
     request(client, Method::DELETE, url)
 }
 
@@ -124,13 +206,13 @@ fn serialize_app_categories(
 mod tests {
     use reqwest::header::AUTHORIZATION;
 
-    use crate::types::OpenRouterExperimentalMetadata;
+    use crate::{transport::HttpClient, types::OpenRouterExperimentalMetadata};
 
     use super::{post, with_client_request_headers, with_experimental_metadata_header};
 
     #[test]
     fn test_with_client_request_headers_sets_auth_and_metadata() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::new(reqwest::Client::new());
         let request = with_client_request_headers(
             post(&client, "http://example.com/test"),
             "test-key",
@@ -181,7 +263,7 @@ mod tests {
 
     #[test]
     fn test_with_client_request_headers_rejects_invalid_app_categories() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::new(reqwest::Client::new());
         let error = with_client_request_headers(
             post(&client, "http://example.com/test"),
             "test-key",
@@ -199,7 +281,7 @@ mod tests {
 
     #[test]
     fn test_with_experimental_metadata_header_sets_level() {
-        let client = reqwest::Client::new();
+        let client = HttpClient::new(reqwest::Client::new());
         let request = with_experimental_metadata_header(
             post(&client, "http://example.com/test"),
             &Some(OpenRouterExperimentalMetadata::Enabled),
@@ -1,3 +1,4 @@
+use bytes::Bytes;
 use http::StatusCode;
 use reqwest::Response;
 use serde::de::DeserializeOwned;
@@ -6,6 +7,7 @@ use serde_json::Value;
 use crate::{
     api::errors::{parse_api_error, unreadable_error_response},
     error::OpenRouterError,
+    transport::timeout::{self, BodyDeadline},
 };
 
 fn body_preview(body_text: &str, limit: usize) -> String {
@@ -27,7 +29,7 @@ fn body_preview(body_text: &str, limit: usize) -> String {
     preview
 }
 
-fn response_request_id(response: &Response) -> Option<String> {
+pub(crate) fn response_request_id(response: &Response) -> Option<String> {
     response
         .headers()
         .get("x-request-id")
@@ -61,13 +63,38 @@ pub(crate) fn response_deserialization_error(
     ))
 }
 
+fn body_deadline(response: &Response) -> Option<std::time::Duration> {
+    response
+        .extensions()
+        .get::<BodyDeadline>()
+        .map(|deadline| deadline.0)
+}
+
+/// Read the whole body as text; a read timeout reports the request deadline.
+pub(crate) async fn read_text(response: Response) -> Result<String, OpenRouterError> {
+    let deadline = body_deadline(&response);
+    response
+        .text()
+        .await
+        .map_err(|error| timeout::from_reqwest(error, deadline, None))
+}
+
+/// Read the whole body; a read timeout reports the request deadline.
+pub(crate) async fn read_bytes(response: Response) -> Result<Bytes, OpenRouterError> {
+    let deadline = body_deadline(&response);
+    response
+        .bytes()
+        .await
+        .map_err(|error| timeout::from_reqwest(error, deadline, None))
+}
+
 pub(crate) async fn parse_json_response<T: DeserializeOwned>(
     response: Response,
     context: &str,
 ) -> Result<T, OpenRouterError> {
     let status = response.status();
     let request_id = response_request_id(&response);
-    let body_text = response.text().await?;
+    let body_text = read_text(response).await?;
 
     match serde_json::from_str(&body_text) {
         Ok(parsed) => Ok(parsed),
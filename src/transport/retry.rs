@@ -0,0 +1,327 @@
+use std::time::{Duration, SystemTime};
+
+use http::{HeaderMap, StatusCode, header::RETRY_AFTER};
+use reqwest::{Request, Response};
+
+use crate::error::OpenRouterError;
+
+/// Client-wide retry policy applied centrally by the transport layer.
+///
+/// Every endpoint executed through [`OpenRouterClient`](crate::OpenRouterClient)
+/// shares the same policy. Retries happen only before response headers have
+/// been accepted, so streaming endpoints are never retried after the first SSE
+/// byte has been handed to the caller.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use openrouter_rs::{OpenRouterClient, client::RetryPolicy};
+///
+/// let client = OpenRouterClient::builder()
+///     .api_key("your_api_key")
+///     .retry_policy(
+///         RetryPolicy::new(5)
+///             .initial_backoff(Duration::from_millis(250))
+///             .max_backoff(Duration::from_secs(10)),
+///     )
+///     .build()?;
+/// # Ok::<(), openrouter_rs::error::OpenRouterError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    backoff_multiplier: f64,
+    jitter: bool,
+    respect_retry_after: bool,
+    retry_on_status: Vec<StatusCode>,
+    retry_on_connection_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            respect_retry_after: true,
+            retry_on_status: vec![
+                StatusCode::REQUEST_TIMEOUT,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_on_connection_errors: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with default backoff settings and the given total attempt count.
+    pub fn new(max_attempts: u32) -> Self {
+        Self::default().max_attempts(max_attempts)
+    }
+
+    /// Create a policy that sends every request exactly once.
+    pub fn disabled() -> Self {
+        Self::new(1)
+    }
+
+    /// Set the total number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the upper bound for computed backoff delays and `Retry-After` waits.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Set the exponential growth factor applied after each failed attempt.
+    pub fn backoff_multiplier(mut self, backoff_multiplier: f64) -> Self {
+        self.backoff_multiplier = backoff_multiplier.max(1.0);
+        self
+    }
+
+    /// Enable or disable randomized jitter on computed backoff delays.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Honor the server's `Retry-After` header, capped at `max_backoff`, instead of
+    /// the computed backoff.
+    pub fn respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// Replace the set of HTTP status codes that trigger a retry.
+    pub fn retry_on_status(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.retry_on_status = statuses.into_iter().collect();
+        self
+    }
+
+    /// Retry connection failures and transport timeouts.
+    pub fn retry_on_connection_errors(mut self, retry_on_connection_errors: bool) -> Self {
+        self.retry_on_connection_errors = retry_on_connection_errors;
+        self
+    }
+
+    /// Returns true if a response with this status should be retried.
+    pub fn should_retry_status(&self, status: StatusCode) -> bool {
+        self.retry_on_status.contains(&status)
+    }
+
+    /// Deterministic exponential backoff for the given 1-based attempt, before jitter.
+    pub(crate) fn backoff_for_attempt(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let scaled = self.initial_backoff.as_secs_f64() * self.backoff_multiplier.powi(exponent);
+        let capped = scaled.min(self.max_backoff.as_secs_f64());
+
+        if capped.is_finite() && capped > 0.0 {
+            Duration::from_secs_f64(capped)
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// Delay before the retry that follows `attempt`, honoring `Retry-After`
+    /// when enabled.
+    pub(crate) fn retry_delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+        if let Some(delay) = headers
+            .filter(|_| self.respect_retry_after)
+            .and_then(retry_after)
+        {
+            return delay.min(self.max_backoff);
+        }
+
+        let backoff = self.backoff_for_attempt(attempt);
+        if self.jitter {
+            // Equal jitter: keep half of the delay and randomize the other half.
+            let half = backoff / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            backoff
+        }
+    }
+
+    fn should_retry_error(&self, error: &OpenRouterError) -> bool {
+        self.retry_on_connection_errors
+            && match error {
+                OpenRouterError::Timeout(_) => true,
+                OpenRouterError::HttpRequest(error) => error.is_connect(),
+                _ => false,
+            }
+    }
+}
+
+/// Parse a `Retry-After` header given either as delay seconds or an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<f64>() {
+        return Duration::try_from_secs_f64(seconds).ok();
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+pub(crate) async fn execute_with_retry<F, Fut>(
+    policy: &RetryPolicy,
+    request: Request,
+    send: F,
+) -> Result<Response, OpenRouterError>
+where
+    F: Fn(Request) -> Fut,
+    Fut: Future<Output = Result<Response, OpenRouterError>>,
+{
+    let mut attempt = 1;
+    let mut pending = request;
+
+    loop {
+        // Requests with streaming bodies cannot be cloned and are sent only once.
+        let next = if attempt < policy.max_attempts {
+            pending.try_clone()
+        } else {
+            None
+        };
+        let outcome = send(pending).await;
+
+        let Some(next) = next else {
+            return outcome;
+        };
+
+        let delay = match &outcome {
+            Ok(response) if policy.should_retry_status(response.status()) => {
+                policy.retry_delay(attempt, Some(response.headers()))
+            }
+            Err(error) if policy.should_retry_error(error) => policy.retry_delay(attempt, None),
+            _ => return outcome,
+        };
+
+        drop(outcome);
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+        pending = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use http::{HeaderMap, HeaderValue, StatusCode, header::RETRY_AFTER};
+
+    use super::{RetryPolicy, retry_after};
+
+    #[test]
+    fn test_backoff_grows_exponentially_and_caps() {
+        let policy = RetryPolicy::new(5)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350))
+            .jitter(false);
+
+        assert_eq!(policy.backoff_for_attempt(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff_for_attempt(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff_for_attempt(3), Duration::from_millis(350));
+        assert_eq!(policy.retry_delay(4, None), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn test_jittered_delay_stays_within_bounds() {
+        let policy = RetryPolicy::new(3).initial_backoff(Duration::from_millis(200));
+
+        for _ in 0..32 {
+            let delay = policy.retry_delay(1, None);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_parses_seconds_and_http_dates() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("2"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_retry_after_rejects_out_of_range_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("99999999999999999999"),
+        );
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("-1"));
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_retry_after_is_clamped_to_max_backoff() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("86400"));
+        let policy = RetryPolicy::new(2)
+            .max_backoff(Duration::from_secs(10))
+            .jitter(false);
+
+        assert_eq!(
+            policy.retry_delay(1, Some(&headers)),
+            Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn test_retry_after_overrides_backoff_only_when_respected() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("0"));
+        let policy = RetryPolicy::new(2)
+            .initial_backoff(Duration::from_secs(1))
+            .jitter(false);
+
+        assert_eq!(policy.retry_delay(1, Some(&headers)), Duration::ZERO);
+        assert_eq!(
+            policy
+                .respect_retry_after(false)
+                .retry_delay(1, Some(&headers)),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn test_retry_on_status_replaces_default_set() {
+        let policy = RetryPolicy::default().retry_on_status([StatusCode::CONFLICT]);
+
+        assert!(policy.should_retry_status(StatusCode::CONFLICT));
+        assert!(!policy.should_retry_status(StatusCode::TOO_MANY_REQUESTS));
+    }
+}
@@ -1,12 +1,24 @@
 use std::io::Error;
 
 use futures_util::{StreamExt, TryStreamExt, stream, stream::BoxStream};
+use http::header::CONTENT_TYPE;
 use reqwest::Response;
+use serde_json::Value;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio_util::io::StreamReader;
 
+use crate::transport::timeout::{StreamIdleTimeout, with_idle_timeout};
+
 pub(crate) fn response_lines(response: Response) -> BoxStream<'static, std::io::Result<String>> {
-    let byte_stream = response.bytes_stream().map_err(Error::other);
+    let idle_timeout = response
+        .extensions()
+        .get::<StreamIdleTimeout>()
+        .map(|timeout| timeout.0);
+    let byte_stream = response.bytes_stream().map_err(Error::other).boxed();
+    let byte_stream = match idle_timeout {
+        Some(idle_timeout) => with_idle_timeout(byte_stream, idle_timeout).boxed(),
+        None => byte_stream,
+    };
     let lines = BufReader::new(StreamReader::new(byte_stream)).lines();
 
     stream::unfold(lines, |mut lines| async move {
@@ -18,3 +30,62 @@ pub(crate) fn response_lines(response: Response) -> BoxStream<'static, std::io::
     })
     .boxed()
 }
+
+/// JSON payloads of a response body as it is read: each SSE `data:` event
+/// of an event stream, or the whole body of a JSON response.
+#[derive(Debug)]
+pub(crate) struct JsonEvents {
+    streaming: bool,
+    buffer: Vec<u8>,
+}
+
+impl JsonEvents {
+    /// Events of `response`, or `None` when it is neither JSON nor an event
+    /// stream, so binary downloads are never buffered.
+    pub(crate) fn new(response: &Response) -> Option<Self> {
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        let media_type = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let streaming = media_type == "text/event-stream";
+        let json = media_type == "application/json" || media_type.ends_with("+json");
+        (streaming || json).then(|| Self {
+            streaming,
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Feed a body chunk, passing each complete SSE event to `on_event`.
+    pub(crate) fn push(&mut self, chunk: &[u8], mut on_event: impl FnMut(Value)) {
+        self.buffer.extend_from_slice(chunk);
+        if !self.streaming {
+            return;
+        }
+        while let Some(end) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            if let Some(event) = line
+                .strip_prefix(b"data:")
+                .and_then(|data| serde_json::from_slice(data.trim_ascii()).ok())
+            {
+                on_event(event);
+            }
+        }
+    }
+
+    /// Mark the body as read, passing a buffered JSON body to `on_event`.
+    pub(crate) fn finish(&mut self, on_event: impl FnOnce(Value)) {
+        if self.streaming {
+            return;
+        }
+        if let Ok(body) = serde_json::from_slice(&std::mem::take(&mut self.buffer)) {
+            on_event(body);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::{io, time::Duration};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::error::{OpenRouterError, TimeoutError, TimeoutKind};
+
+/// Timeout settings for requests sent by [`OpenRouterClient`](crate::OpenRouterClient).
+///
+/// `connect` and `read` configure the default HTTP client and therefore only
+/// apply client-wide when no custom `http_client` is injected. `request` and
+/// `stream_idle` are enforced by the SDK for every request, including requests
+/// sent through a custom `http_client`, and can be overridden per call with
+/// [`OpenRouterClient::with_timeouts`](crate::OpenRouterClient::with_timeouts).
+///
+/// - `request` bounds each attempt of a non-streaming call, including reading
+///   the response body. For streaming calls it bounds the wait for response
+///   headers only. Streamed file uploads are not bounded by it.
+/// - `stream_idle` bounds the gap between two SSE chunks once a stream is open.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use openrouter_rs::{OpenRouterClient, client::Timeouts};
+///
+/// let client = OpenRouterClient::builder()
+///     .api_key("your_api_key")
+///     .timeouts(
+///         Timeouts::new()
+///             .connect(Duration::from_secs(5))
+///             .request(Duration::from_secs(60))
+///             .stream_idle(Duration::from_secs(30)),
+///     )
+///     .build()?;
+/// # Ok::<(), openrouter_rs::error::OpenRouterError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    connect: Option<Duration>,
+    read: Option<Duration>,
+    request: Option<Duration>,
+    stream_idle: Option<Duration>,
+}
+
+impl Timeouts {
+    /// Create an empty timeout configuration (no deadlines).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the connection establishment timeout for the default HTTP client.
+    pub fn connect(mut self, timeout: Duration) -> Self {
+        self.connect = Some(timeout);
+        self
+    }
+
+    /// Set the per-read socket timeout for the default HTTP client.
+    pub fn read(mut self, timeout: Duration) -> Self {
+        self.read = Some(timeout);
+        self
+    }
+
+    /// Set the total deadline for each request attempt.
+    pub fn request(mut self, timeout: Duration) -> Self {
+        self.request = Some(timeout);
+        self
+    }
+
+    /// Set the maximum gap between SSE chunks on streaming endpoints.
+    pub fn stream_idle(mut self, timeout: Duration) -> Self {
+        self.stream_idle = Some(timeout);
+        self
+    }
+
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect
+    }
+
+    pub fn read_timeout(&self) -> Option<Duration> {
+        self.read
+    }
+
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request
+    }
+
+    pub fn stream_idle_timeout(&self) -> Option<Duration> {
+        self.stream_idle
+    }
+
+    /// Overlay values set in `overrides` on top of `self`.
+    pub(crate) fn merge(self, overrides: Timeouts) -> Self {
+        Self {
+            connect: overrides.connect.or(self.connect),
+            read: overrides.read.or(self.read),
+            request: overrides.request.or(self.request),
+            stream_idle: overrides.stream_idle.or(self.stream_idle),
+        }
+    }
+}
+
+/// Response extension carrying the stream idle timeout for SSE readers.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StreamIdleTimeout(pub(crate) Duration);
+
+/// Response extension carrying the deadline that bounds reading a
+/// non-streaming body, so a read timeout can report it.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BodyDeadline(pub(crate) Duration);
+
+/// Convert a `reqwest` error. A timeout records `connect` when the
+/// connection timed out and `deadline` otherwise.
+pub(crate) fn from_reqwest(
+    error: reqwest::Error,
+    deadline: Option<Duration>,
+    connect: Option<Duration>,
+) -> OpenRouterError {
+    match OpenRouterError::from(error) {
+        OpenRouterError::Timeout(timeout) if timeout.duration().is_none() => {
+            let duration = match timeout.kind() {
+                TimeoutKind::Connect => connect,
+                _ => deadline,
+            };
+            OpenRouterError::Timeout(TimeoutError::new(timeout.kind(), duration))
+        }
+        other => other,
+    }
+}
+
+pub(crate) fn elapsed(kind: TimeoutKind, duration: Duration) -> OpenRouterError {
+    OpenRouterError::Timeout(TimeoutError::new(kind, Some(duration)))
+}
+
+/// Fail a byte stream with a stream-idle timeout if no chunk arrives in time.
+pub(crate) fn with_idle_timeout<S>(
+    bytes: S,
+    idle_timeout: Duration,
+) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static
+where
+    S: Stream<Item = io::Result<Bytes>> + Send + Unpin + 'static,
+{
+    stream::unfold(Some(bytes), move |state| async move {
+        let mut bytes = state?;
+        match tokio::time::timeout(idle_timeout, bytes.next()).await {
+            Ok(Some(item)) => Some((item, Some(bytes))),
+            Ok(None) => None,
+            Err(_) => Some((
+                Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    TimeoutError::new(TimeoutKind::StreamIdle, Some(idle_timeout)),
+                )),
+                None,
+            )),
+        }
+    })
+}
+
+/// Convert an SSE read error into an SDK error, preserving stream-idle timeouts.
+pub(crate) fn stream_read_error(error: io::Error) -> OpenRouterError {
+    if error.kind() == io::ErrorKind::TimedOut {
+        if let Some(timeout) = error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<TimeoutError>())
+        {
+            return OpenRouterError::Timeout(timeout.clone());
+        }
+    }
+
+    OpenRouterError::Io(error)
+}
@@ -22,6 +22,14 @@
 //! - **Content Types**: Different response content formats
 //! - **Validation**: Response format validation rules
 //!
+//! ### Partial JSON Streaming ([`partial_json`])
+//! - **Tolerant Parsing**: Snapshots of incomplete JSON while it streams
+//! - **Stream Adapters**: Partial objects, tool argument snapshots, and a typed final value
+//!
+//! ### Pagination and Polling ([`pagination`], [`poll`])
+//! - **Paginator**: Lazy item streams over offset- and cursor-paginated list endpoints
+//! - **PollOptions**: Interval, backoff, deadline, and cancellation for long-running jobs
+//!
 //! ### Tool Support ([`tool`])
 //! - **Tool Definitions**: Function calling definitions and schemas
 //! - **Tool Choice**: Control over tool usage behavior
@@ -141,6 +149,8 @@
 
 pub mod completion;
 pub mod pagination;
+pub mod partial_json;
+pub mod poll;
 pub mod provider;
 pub mod response_format;
 pub mod stream;
@@ -152,7 +162,7 @@ use std::fmt::Display;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 pub use {
-    completion::*, pagination::*, provider::*, response_format::*, stream::*, tool::*,
+    completion::*, pagination::*, poll::*, provider::*, response_format::*, stream::*, tool::*,
     typed_tool::*,
 };
 
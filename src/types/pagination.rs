@@ -1,5 +1,17 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::{
+    Stream, StreamExt, TryStreamExt, future,
+    stream::{self, BoxStream},
+};
 use serde::{Deserialize, Serialize};
 
+use crate::error::OpenRouterError;
+
 /// Common pagination input used by paginated OpenRouter endpoints.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -50,3 +62,115 @@ impl PaginationOptions {
         pairs
     }
 }
+
+/// Stream of items from a paginated list endpoint, fetching pages on demand.
+///
+/// Offset-paginated endpoints advance `offset` by the number of items
+/// received and stop at `total_count`, on a short page, or on an empty page.
+/// Cursor-paginated endpoints follow the returned cursor until the server
+/// reports no more results.
+///
+/// Pages are only requested as the stream is polled, so combinators such as
+/// [`StreamExt::take`] or [`StreamExt::take_while`] end pagination early.
+/// A request error is yielded once and ends the stream.
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use openrouter_rs::OpenRouterClient;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OpenRouterClient::builder()
+///     .management_key("your_management_key")
+///     .build()?;
+///
+/// let workspaces = client.management().paginate_workspaces(Some(50)).collect_all().await?;
+///
+/// let mut keys = client.management().paginate_api_keys(Some(100), None).take(10);
+/// while let Some(key) = keys.next().await {
+///     println!("{:?}", key?.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<'a, T> {
+    inner: BoxStream<'a, Result<T, OpenRouterError>>,
+}
+
+impl<'a, T: Send + 'a> Paginator<'a, T> {
+    /// Paginate an offset-based endpoint. `fetch` returns one page of items
+    /// and the endpoint's `total_count`, when it reports one.
+    pub(crate) fn offset<F, Fut>(page_size: Option<u32>, fetch: F) -> Self
+    where
+        F: FnMut(PaginationOptions) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<(Vec<T>, Option<u64>), OpenRouterError>> + Send + 'a,
+    {
+        let pages = stream::unfold(Some((0_u32, fetch)), move |state| async move {
+            let (offset, mut fetch) = state?;
+            let options = PaginationOptions {
+                offset: Some(offset),
+                limit: page_size,
+            };
+            match fetch(options).await {
+                Ok((items, total_count)) => {
+                    let received = items.len() as u32;
+                    let next_offset = offset + received;
+                    let done = received == 0
+                        || total_count.is_some_and(|total| u64::from(next_offset) >= total)
+                        || page_size.is_some_and(|size| received < size);
+                    Some((Ok(items), (!done).then_some((next_offset, fetch))))
+                }
+                Err(error) => Some((Err(error), None)),
+            }
+        });
+        Self::from_pages(pages)
+    }
+
+    /// Paginate a cursor-based endpoint. `fetch` returns one page of items
+    /// and the cursor for the next page, or `None` on the last page.
+    pub(crate) fn cursor<F, Fut>(fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<(Vec<T>, Option<String>), OpenRouterError>> + Send + 'a,
+    {
+        let pages = stream::unfold(Some((None, fetch)), |state| async move {
+            let (cursor, mut fetch) = state?;
+            match fetch(cursor).await {
+                Ok((items, next_cursor)) => {
+                    let next = next_cursor.map(|cursor| (Some(cursor), fetch));
+                    Some((Ok(items), next))
+                }
+                Err(error) => Some((Err(error), None)),
+            }
+        });
+        Self::from_pages(pages)
+    }
+
+    fn from_pages(pages: impl Stream<Item = Result<Vec<T>, OpenRouterError>> + Send + 'a) -> Self {
+        let inner = pages
+            .flat_map(|page| match page {
+                Ok(items) => stream::iter(items.into_iter().map(Ok)).left_stream(),
+                Err(error) => stream::once(future::ready(Err(error))).right_stream(),
+            })
+            .boxed();
+        Self { inner }
+    }
+
+    /// Fetch every remaining page and collect the items, stopping at the first error.
+    pub async fn collect_all(self) -> Result<Vec<T>, OpenRouterError> {
+        self.inner.try_collect().await
+    }
+}
+
+impl<T> Stream for Paginator<'_, T> {
+    type Item = Result<T, OpenRouterError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl<T> std::fmt::Debug for Paginator<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Paginator").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,468 @@
+//! # Partial JSON Streaming
+//!
+//! Tolerant parsing of incomplete JSON, for showing structured output while
+//! it is still streaming.
+//!
+//! [`parse_partial_json`] turns a JSON prefix into the most complete value it
+//! can: open strings, arrays, and objects are closed, and keys without a
+//! usable value yet are dropped. [`PartialJson`] accumulates fragments and
+//! reports a new snapshot whenever the parsed value changes.
+//!
+//! [`partial_json_stream`] and [`partial_json_unified_stream`] wrap a
+//! [`ToolAwareStream`] or [`UnifiedStream`] and interleave
+//! [`PartialJsonEvent::PartialObject`] snapshots of the content with the
+//! source events, followed by a typed [`PartialJsonEvent::Complete`] value
+//! before the source's `Done` event. The tool-aware variant also reports
+//! [`PartialJsonEvent::PartialToolArguments`] snapshots for each tool call.
+//!
+//! ```rust
+//! use openrouter_rs::types::partial_json::parse_partial_json;
+//! use serde_json::json;
+//!
+//! let value = parse_partial_json(r#"{"city": "Par"#).unwrap();
+//! assert_eq!(value, json!({"city": "Par"}));
+//! ```
+//!
+//! ```rust,no_run
+//! use futures_util::StreamExt;
+//! use openrouter_rs::types::partial_json::{PartialJsonEvent, partial_json_stream};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Answer {
+//!     city: String,
+//! }
+//!
+//! # async fn example(client: openrouter_rs::OpenRouterClient, request: openrouter_rs::api::chat::ChatCompletionRequest) -> Result<(), Box<dyn std::error::Error>> {
+//! let stream = client.chat().stream_tool_aware(&request).await?;
+//! let mut events = partial_json_stream::<Answer>(stream);
+//!
+//! while let Some(event) = events.next().await {
+//!     match event {
+//!         PartialJsonEvent::PartialObject(snapshot) => println!("so far: {snapshot}"),
+//!         PartialJsonEvent::Complete(answer) => println!("city: {}", answer.city),
+//!         PartialJsonEvent::Error(error) => eprintln!("error: {error}"),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{iter::Peekable, str::Chars};
+
+use futures_util::{StreamExt, stream::BoxStream};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Number, Value};
+
+use crate::{
+    error::{OpenRouterError, StructuredOutputError},
+    types::{
+        response_format::parse_structured,
+        stream::{StreamEvent, ToolAwareStream, UnifiedStream, UnifiedStreamEvent},
+    },
+};
+
+/// Parse a possibly truncated JSON document.
+///
+/// Returns `None` when no value can be recovered yet (empty input, or a
+/// partial literal such as `tru`) or when the text is not JSON. A leading
+/// Markdown code fence is skipped.
+pub fn parse_partial_json(text: &str) -> Option<Value> {
+    let mut parser = PartialParser {
+        chars: skip_fence(text).chars().peekable(),
+        truncated: false,
+    };
+    parser.value().ok().flatten()
+}
+
+fn skip_fence(text: &str) -> &str {
+    let trimmed = text.trim_start();
+    match trimmed.strip_prefix("```") {
+        Some(rest) => rest.split_once('\n').map_or("", |(_, body)| body),
+        None => trimmed,
+    }
+}
+
+/// Marker for input that cannot become valid JSON.
+struct Invalid;
+
+struct PartialParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    /// Set once the input ended inside a value; every caller then returns
+    /// what it has so far.
+    truncated: bool,
+}
+
+impl PartialParser<'_> {
+    fn value(&mut self) -> Result<Option<Value>, Invalid> {
+        self.skip_whitespace();
+        match self.chars.peek().copied() {
+            None => {
+                self.truncated = true;
+                Ok(None)
+            }
+            Some('{') => self.object().map(Some),
+            Some('[') => self.array().map(Some),
+            Some('"') => {
+                self.chars.next();
+                self.string().map(|text| Some(Value::String(text)))
+            }
+            Some('t') => self.literal("true", Value::Bool(true)),
+            Some('f') => self.literal("false", Value::Bool(false)),
+            Some('n') => self.literal("null", Value::Null),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(_) => Err(Invalid),
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, Invalid> {
+        self.chars.next();
+        let mut map = Map::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.next() {
+                None => {
+                    self.truncated = true;
+                    break;
+                }
+                Some('}') => break,
+                Some(',') => continue,
+                Some('"') => {
+                    let key = self.string()?;
+                    if self.truncated {
+                        break;
+                    }
+                    self.skip_whitespace();
+                    match self.chars.next() {
+                        Some(':') => {}
+                        None => {
+                            self.truncated = true;
+                            break;
+                        }
+                        Some(_) => return Err(Invalid),
+                    }
+                    if let Some(value) = self.value()? {
+                        map.insert(key, value);
+                    }
+                    if self.truncated {
+                        break;
+                    }
+                }
+                Some(_) => return Err(Invalid),
+            }
+        }
+        Ok(Value::Object(map))
+    }
+
+    fn array(&mut self) -> Result<Value, Invalid> {
+        self.chars.next();
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                None => {
+                    self.truncated = true;
+                    break;
+                }
+                Some(']') => {
+                    self.chars.next();
+                    break;
+                }
+                Some(',') => {
+                    self.chars.next();
+                    continue;
+                }
+                Some(_) => {
+                    if let Some(value) = self.value()? {
+                        items.push(value);
+                    }
+                    if self.truncated {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    /// Read a string body after its opening quote. A truncated string keeps
+    /// the text read so far, minus any incomplete escape sequence.
+    fn string(&mut self) -> Result<String, Invalid> {
+        let mut text = String::new();
+        loop {
+            match self.chars.next() {
+                None => {
+                    self.truncated = true;
+                    return Ok(text);
+                }
+                Some('"') => return Ok(text),
+                Some('\\') => match self.escape()? {
+                    Some(c) => text.push(c),
+                    None => {
+                        self.truncated = true;
+                        return Ok(text);
+                    }
+                },
+                Some(c) => text.push(c),
+            }
+        }
+    }
+
+    /// Decode one escape sequence; `None` when the input ends inside it.
+    fn escape(&mut self) -> Result<Option<char>, Invalid> {
+        let Some(c) = self.chars.next() else {
+            return Ok(None);
+        };
+        let decoded = match c {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let Some(high) = self.hex4()? else {
+                    return Ok(None);
+                };
+                if !(0xD800..0xDC00).contains(&high) {
+                    return Ok(Some(char::from_u32(high).unwrap_or('\u{fffd}')));
+                }
+                // High surrogate: the low half must follow as another `\u` escape.
+                match (self.chars.next(), self.chars.next()) {
+                    (Some('\\'), Some('u')) => {}
+                    (None, _) | (Some('\\'), None) => return Ok(None),
+                    _ => return Err(Invalid),
+                }
+                let Some(low) = self.hex4()? else {
+                    return Ok(None);
+                };
+                if !(0xDC00..0xE000).contains(&low) {
+                    return Ok(Some('\u{fffd}'));
+                }
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                char::from_u32(code).unwrap_or('\u{fffd}')
+            }
+            _ => return Err(Invalid),
+        };
+        Ok(Some(decoded))
+    }
+
+    fn hex4(&mut self) -> Result<Option<u32>, Invalid> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let Some(c) = self.chars.next() else {
+                return Ok(None);
+            };
+            code = code * 16 + c.to_digit(16).ok_or(Invalid)?;
+        }
+        Ok(Some(code))
+    }
+
+    fn literal(&mut self, literal: &str, value: Value) -> Result<Option<Value>, Invalid> {
+        for expected in literal.chars() {
+            match self.chars.next() {
+                Some(c) if c == expected => {}
+                Some(_) => return Err(Invalid),
+                None => {
+                    self.truncated = true;
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(Some(value))
+    }
+
+    /// Parse a number; a truncated one drops trailing `.`, `e`, or sign
+    /// characters that cannot stand on their own.
+    fn number(&mut self) -> Result<Option<Value>, Invalid> {
+        let mut text = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E') {
+                break;
+            }
+            text.push(c);
+            self.chars.next();
+        }
+        if self.chars.peek().is_none() {
+            self.truncated = true;
+        }
+
+        let mut candidate = text.as_str();
+        loop {
+            if let Ok(number) = serde_json::from_str::<Number>(candidate) {
+                return Ok(Some(Value::Number(number)));
+            }
+            if !self.truncated {
+                return Err(Invalid);
+            }
+            match candidate.strip_suffix(['.', 'e', 'E', '+', '-']) {
+                Some(shorter) if !shorter.is_empty() => candidate = shorter,
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+}
+
+/// Accumulates JSON text fragments and keeps a tolerant parse of the prefix.
+///
+/// Every [`push`](Self::push) re-parses the text received so far, which is
+/// cheap for the document sizes structured output typically produces.
+#[derive(Debug, Clone, Default)]
+pub struct PartialJson {
+    text: String,
+    snapshot: Option<Value>,
+}
+
+impl PartialJson {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a fragment and return the new snapshot if the parsed value changed.
+    pub fn push(&mut self, fragment: &str) -> Option<&Value> {
+        self.text.push_str(fragment);
+        let parsed = parse_partial_json(&self.text);
+        if parsed.is_none() || parsed == self.snapshot {
+            return None;
+        }
+        self.snapshot = parsed;
+        self.snapshot.as_ref()
+    }
+
+    /// All text received so far.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The latest parsed snapshot, if any.
+    pub fn snapshot(&self) -> Option<&Value> {
+        self.snapshot.as_ref()
+    }
+
+    /// Deserialize the complete text into `T`.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, StructuredOutputError> {
+        parse_structured(&self.text, 1)
+    }
+}
+
+/// Events emitted by [`partial_json_stream`] and [`partial_json_unified_stream`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum PartialJsonEvent<T, E> {
+    /// A new snapshot of the content parsed so far.
+    PartialObject(Value),
+    /// A new snapshot of one tool call's arguments (tool-aware streams only).
+    PartialToolArguments {
+        /// Tool call index within the turn.
+        index: u32,
+        id: Option<String>,
+        name: Option<String>,
+        arguments: Value,
+    },
+    /// The content deserialized into `T`, emitted right before the source's
+    /// `Done` event. Skipped when the turn produced no content.
+    Complete(T),
+    /// The final content could not be deserialized into `T`.
+    Error(OpenRouterError),
+    /// An event from the source stream, forwarded unchanged.
+    Source(E),
+}
+
+/// Stream returned by the partial JSON adapters.
+pub type PartialJsonStream<T, E> = BoxStream<'static, PartialJsonEvent<T, E>>;
+
+/// Add partial JSON snapshots and a typed final value to a [`ToolAwareStream`].
+///
+/// [`StreamEvent::PartialToolArguments`] events are turned into
+/// [`PartialJsonEvent::PartialToolArguments`]; every other event is
+/// forwarded as [`PartialJsonEvent::Source`].
+pub fn partial_json_stream<T>(stream: ToolAwareStream) -> PartialJsonStream<T, StreamEvent>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let mut content = PartialJson::new();
+    stream
+        .with_partial_tool_arguments()
+        .flat_map(move |event| {
+            let events = match event {
+                StreamEvent::ContentDelta(delta) => {
+                    let mut events = snapshot_event(&mut content, &delta);
+                    events.push(PartialJsonEvent::Source(StreamEvent::ContentDelta(delta)));
+                    events
+                }
+                StreamEvent::PartialToolArguments {
+                    index,
+                    id,
+                    name,
+                    arguments,
+                } => vec![PartialJsonEvent::PartialToolArguments {
+                    index,
+                    id,
+                    name,
+                    arguments,
+                }],
+                done @ StreamEvent::Done { .. } => {
+                    let mut events = complete_event(&content);
+                    events.push(PartialJsonEvent::Source(done));
+                    events
+                }
+                other => vec![PartialJsonEvent::Source(other)],
+            };
+            futures_util::stream::iter(events)
+        })
+        .boxed()
+}
+
+/// Add partial JSON snapshots and a typed final value to a [`UnifiedStream`].
+pub fn partial_json_unified_stream<T>(
+    stream: UnifiedStream,
+) -> PartialJsonStream<T, UnifiedStreamEvent>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let mut content = PartialJson::new();
+    stream
+        .flat_map(move |event| {
+            let events = match event {
+                UnifiedStreamEvent::ContentDelta(delta) => {
+                    let mut events = snapshot_event(&mut content, &delta);
+                    events.push(PartialJsonEvent::Source(UnifiedStreamEvent::ContentDelta(
+                        delta,
+                    )));
+                    events
+                }
+                done @ UnifiedStreamEvent::Done { .. } => {
+                    let mut events = complete_event(&content);
+                    events.push(PartialJsonEvent::Source(done));
+                    events
+                }
+                other => vec![PartialJsonEvent::Source(other)],
+            };
+            futures_util::stream::iter(events)
+        })
+        .boxed()
+}
+
+fn snapshot_event<T, E>(content: &mut PartialJson, delta: &str) -> Vec<PartialJsonEvent<T, E>> {
+    content
+        .push(delta)
+        .map(|snapshot| PartialJsonEvent::PartialObject(snapshot.clone()))
+        .into_iter()
+        .collect()
+}
+
+fn complete_event<T: DeserializeOwned, E>(content: &PartialJson) -> Vec<PartialJsonEvent<T, E>> {
+    if content.text().trim().is_empty() {
+        return Vec::new();
+    }
+    vec![match content.deserialize() {
+        Ok(value) => PartialJsonEvent::Complete(value),
+        Err(error) => PartialJsonEvent::Error(error.into()),
+    }]
+}
@@ -0,0 +1,174 @@
+use std::time::{Duration, Instant};
+
+pub use tokio_util::sync::CancellationToken;
+
+use crate::error::{OpenRouterError, TimeoutError, TimeoutKind};
+
+/// How to poll a long-running job (such as a video generation) until it
+/// reaches a final status.
+///
+/// The first status check happens immediately. Later checks wait `interval`,
+/// growing by `backoff_multiplier` after each check up to `max_interval`.
+/// An elapsed `deadline` ends the wait with [`OpenRouterError::Timeout`]
+/// ([`TimeoutKind::Poll`]); cancelling the token ends it with
+/// [`OpenRouterError::Cancelled`]. Both also interrupt a status check that
+/// is still in flight.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use openrouter_rs::types::{CancellationToken, PollOptions};
+///
+/// let cancel = CancellationToken::new();
+/// let options = PollOptions::new()
+///     .interval(Duration::from_secs(2))
+///     .max_interval(Duration::from_secs(20))
+///     .deadline(Duration::from_secs(15 * 60))
+///     .cancellation_token(cancel.clone());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    interval: Duration,
+    max_interval: Duration,
+    backoff_multiplier: f64,
+    deadline: Option<Duration>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(30),
+            backoff_multiplier: 1.5,
+            deadline: None,
+            cancellation: None,
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the delay before the second status check.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the upper bound for the delay between checks.
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Set the growth factor applied to the delay after each check. `1.0`
+    /// polls at a fixed interval.
+    pub fn backoff_multiplier(mut self, backoff_multiplier: f64) -> Self {
+        self.backoff_multiplier = backoff_multiplier.max(1.0);
+        self
+    }
+
+    /// Give up once this much time has passed since the wait started.
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stop waiting when `token` is cancelled.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+}
+
+/// Pacing state for one wait: the next delay and the time the wait started.
+#[derive(Debug)]
+pub(crate) struct Poller {
+    options: PollOptions,
+    started: Instant,
+    next_delay: Duration,
+}
+
+impl Poller {
+    pub(crate) fn new(options: PollOptions) -> Self {
+        Self {
+            next_delay: options.interval,
+            started: Instant::now(),
+            options,
+        }
+    }
+
+    /// Fail if the wait was cancelled or its deadline has passed.
+    pub(crate) fn check(&self) -> Result<(), OpenRouterError> {
+        if self
+            .options
+            .cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Err(OpenRouterError::Cancelled);
+        }
+        if let Some(deadline) = self.options.deadline {
+            if self.started.elapsed() >= deadline {
+                return Err(deadline_elapsed(deadline));
+            }
+        }
+        Ok(())
+    }
+
+    /// Sleep until the next check, waking early on cancellation or deadline.
+    pub(crate) async fn wait(&mut self) -> Result<(), OpenRouterError> {
+        self.check()?;
+        let delay = self.next_delay;
+        self.next_delay = self
+            .next_delay
+            .mul_f64(self.options.backoff_multiplier)
+            .min(self.options.max_interval);
+
+        self.bound(async {
+            tokio::time::sleep(delay).await;
+            Ok(())
+        })
+        .await?;
+        self.check()
+    }
+
+    /// Run one step of the wait, such as a status request, ending it early
+    /// on cancellation or deadline.
+    pub(crate) async fn bound<T>(
+        &self,
+        step: impl Future<Output = Result<T, OpenRouterError>>,
+    ) -> Result<T, OpenRouterError> {
+        let cancelled = async {
+            match &self.options.cancellation {
+                Some(token) => token.cancelled().await,
+                None => std::future::pending().await,
+            }
+        };
+        let deadline = async {
+            match self.options.deadline {
+                Some(deadline) => {
+                    tokio::time::sleep(deadline.saturating_sub(self.started.elapsed())).await;
+                    deadline
+                }
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+            _ = cancelled => Err(OpenRouterError::Cancelled),
+            deadline = deadline => Err(deadline_elapsed(deadline)),
+            result = step => result,
+        }
+    }
+}
+
+fn deadline_elapsed(deadline: Duration) -> OpenRouterError {
+    OpenRouterError::Timeout(TimeoutError::new(TimeoutKind::Poll, Some(deadline)))
+}
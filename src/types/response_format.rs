@@ -1,6 +1,9 @@
-use serde::{Deserialize, Serialize};
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use serde_json::Value;
 
+use crate::error::StructuredOutputError;
+
 /// Configuration for structured output responses
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -39,6 +42,18 @@ impl JsonSchemaConfig {
             schema,
         }
     }
+
+    /// Create a strict schema configuration generated from `T` via `schemars`.
+    ///
+    /// The name is the schema title of `T`, restricted to `[A-Za-z0-9_-]`.
+    /// The generated schema is rewritten into the form strict mode accepts:
+    /// every object sets `additionalProperties: false` and requires all of
+    /// its properties, and properties that were optional become nullable.
+    pub fn for_type<T: JsonSchema>() -> Self {
+        let mut schema = serde_json::to_value(schema_for!(T)).unwrap_or(Value::Null);
+        make_strict(&mut schema);
+        Self::new(schema_name::<T>(), true, schema)
+    }
 }
 
 /// Response format configuration
@@ -48,11 +63,6 @@ impl JsonSchemaConfig {
 pub enum ResponseFormat {
     /// Legacy shorthand format type specification (e.g. "text")
     TypeOnly(ResponseFormatType),
-    /// Canonical type envelope (e.g. {"type":"text"})
-    Typed {
-        #[serde(rename = "type")]
-        type_: ResponseFormatType,
-    },
     /// Full configuration for JSON Schema responses
     JsonSchema {
         #[serde(rename = "type")]
@@ -65,6 +75,14 @@ pub enum ResponseFormat {
         type_: ResponseFormatType,
         grammar: String,
     },
+    /// Canonical type envelope (e.g. {"type":"text"}).
+    ///
+    /// Listed after the richer shapes so deserialization does not match a
+    /// `json_schema` or `grammar` payload here and drop its configuration.
+    Typed {
+        #[serde(rename = "type")]
+        type_: ResponseFormatType,
+    },
 }
 
 impl ResponseFormat {
@@ -90,6 +108,26 @@ impl ResponseFormat {
         }
     }
 
+    /// Create a strict JSON Schema response format generated from `T`.
+    ///
+    /// ```rust
+    /// use openrouter_rs::types::ResponseFormat;
+    /// use schemars::JsonSchema;
+    ///
+    /// #[derive(JsonSchema)]
+    /// struct Answer {
+    ///     city: String,
+    /// }
+    ///
+    /// let format = ResponseFormat::json_schema_for::<Answer>();
+    /// ```
+    pub fn json_schema_for<T: JsonSchema>() -> Self {
+        ResponseFormat::JsonSchema {
+            type_: ResponseFormatType::JsonSchema,
+            json_schema: JsonSchemaConfig::for_type::<T>(),
+        }
+    }
+
     /// Create a grammar-constrained response format.
     pub fn grammar(grammar: impl Into<String>) -> Self {
         ResponseFormat::Grammar {
@@ -111,3 +149,134 @@ impl Default for ResponseFormat {
         ResponseFormat::text()
     }
 }
+
+fn schema_name<T: JsonSchema>() -> String {
+    let name: String = T::schema_name()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(64)
+        .collect();
+    if name.is_empty() {
+        "response".to_string()
+    } else {
+        name
+    }
+}
+
+/// Rewrite `schema` and every schema nested in it into strict form.
+fn make_strict(schema: &mut Value) {
+    let Value::Object(object) = schema else {
+        return;
+    };
+
+    let required = match object.get("required") {
+        Some(Value::Array(required)) => required.clone(),
+        _ => Vec::new(),
+    };
+    if let Some(Value::Object(properties)) = object.get_mut("properties") {
+        for (name, property) in properties.iter_mut() {
+            if !required.iter().any(|field| field == name) {
+                make_nullable(property);
+            }
+        }
+        let names = properties.keys().cloned().map(Value::String).collect();
+        object.insert("required".to_string(), Value::Array(names));
+    }
+    if object.contains_key("properties") || is_object_type(object.get("type")) {
+        object.insert("additionalProperties".to_string(), Value::Bool(false));
+    }
+
+    for (key, value) in object.iter_mut() {
+        match (key.as_str(), value) {
+            ("properties" | "$defs" | "definitions", Value::Object(schemas)) => {
+                schemas.values_mut().for_each(make_strict);
+            }
+            ("anyOf" | "oneOf" | "allOf" | "prefixItems", Value::Array(schemas)) => {
+                schemas.iter_mut().for_each(make_strict);
+            }
+            ("items", items) => make_strict(items),
+            _ => {}
+        }
+    }
+}
+
+fn is_object_type(schema_type: Option<&Value>) -> bool {
+    match schema_type {
+        Some(Value::String(schema_type)) => schema_type == "object",
+        Some(Value::Array(types)) => types.iter().any(|schema_type| schema_type == "object"),
+        _ => false,
+    }
+}
+
+/// Allow `null` for a property that strict mode now requires.
+fn make_nullable(property: &mut Value) {
+    let null = Value::String("null".to_string());
+    match property.get_mut("type") {
+        Some(Value::Array(types)) => {
+            if !types.contains(&null) {
+                types.push(null);
+            }
+            return;
+        }
+        Some(schema_type @ Value::String(_)) => {
+            if *schema_type != null {
+                *schema_type = Value::Array(vec![schema_type.take(), null]);
+            }
+            return;
+        }
+        _ => {}
+    }
+    let accepts_null = |schemas: &Value| {
+        schemas.as_array().is_some_and(|schemas| {
+            schemas
+                .iter()
+                .any(|schema| schema.get("type") == Some(&null))
+        })
+    };
+    if let Some(any_of) = property.get("anyOf") {
+        if accepts_null(any_of) {
+            return;
+        }
+    }
+    *property = serde_json::json!({ "anyOf": [property.take(), { "type": "null" }] });
+}
+
+/// Deserialize a structured reply, tolerating surrounding whitespace and a
+/// Markdown code fence around the JSON.
+pub(crate) fn parse_structured<T: DeserializeOwned>(
+    raw: &str,
+    attempts: usize,
+) -> Result<T, StructuredOutputError> {
+    serde_json::from_str(strip_code_fence(raw))
+        .map_err(|error| StructuredOutputError::new(raw, error.to_string(), attempts))
+}
+
+fn strip_code_fence(raw: &str) -> &str {
+    let trimmed = raw.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return trimmed;
+    };
+    let Some(rest) = rest.strip_suffix("```") else {
+        return trimmed;
+    };
+    // Drop the info string (e.g. `json`) on the opening fence line.
+    match rest.split_once('\n') {
+        Some((_, body)) => body.trim(),
+        None => rest.trim(),
+    }
+}
+
+/// Follow-up prompt asking the model to correct a reply that failed to parse.
+pub(crate) fn repair_prompt(error: &StructuredOutputError) -> String {
+    format!(
+        "Your previous reply could not be parsed against the requested JSON schema: {}. \
+         Reply again with only a JSON value that matches the schema.",
+        error.message()
+    )
+}
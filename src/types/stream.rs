@@ -49,21 +49,33 @@
 //! ```
 
 use std::collections::{BTreeMap, VecDeque};
+use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use futures_util::future::BoxFuture;
 use futures_util::stream::BoxStream;
-use futures_util::{Stream, StreamExt};
+use futures_util::{FutureExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use tokio::sync::oneshot;
 
 use crate::error::OpenRouterError;
 use crate::types::completion::{
-    CompletionsResponse, FunctionCall, PartialToolCall, ReasoningDetail, ResponseUsage, ToolCall,
+    Choice, CompletionsResponse, ErrorResponse, FunctionCall, Message, NonStreamingChoice,
+    ObjectType, PartialToolCall, ReasoningDetail, ResponseUsage, ToolCall,
 };
+use crate::types::partial_json::parse_partial_json;
 use crate::{
     api::{
-        messages::{AnthropicContentPart, AnthropicMessagesSseEvent, AnthropicMessagesStreamEvent},
-        responses::ResponsesStreamEvent,
+        messages::{
+            AnthropicContentPart, AnthropicMessagesResponse, AnthropicMessagesSseEvent,
+            AnthropicMessagesStreamAccumulator, AnthropicMessagesStreamEvent,
+        },
+        responses::{
+            ResponsesOutputItem, ResponsesResponse, ResponsesStreamAccumulator,
+            ResponsesStreamEvent,
+        },
     },
     types::completion::FinishReason,
 };
@@ -85,6 +97,18 @@ pub enum StreamEvent {
     /// Structured reasoning detail blocks (e.g., encrypted reasoning).
     ReasoningDetailsDelta(Vec<ReasoningDetail>),
 
+    /// A tolerant parse of one tool call's arguments received so far.
+    ///
+    /// Only emitted after [`ToolAwareStream::with_partial_tool_arguments`],
+    /// and only when the parsed value changed.
+    PartialToolArguments {
+        /// Tool call index within the turn.
+        index: u32,
+        id: Option<String>,
+        name: Option<String>,
+        arguments: Value,
+    },
+
     /// The stream has finished. Contains all accumulated data.
     ///
     /// `tool_calls` will be empty if the model did not invoke any tools.
@@ -114,6 +138,8 @@ struct ToolCallAccumulator {
     type_: Option<String>,
     name: Option<String>,
     arguments: String,
+    /// Last parsed arguments snapshot, tracked only when partial arguments are requested.
+    snapshot: Option<Value>,
 }
 
 impl ToolCallAccumulator {
@@ -198,6 +224,8 @@ pub struct ToolAwareStream {
     last_finish_reason: Option<FinishReason>,
     /// Whether the stream has completed.
     finished: bool,
+    /// Whether to emit [`StreamEvent::PartialToolArguments`] snapshots.
+    partial_tool_arguments: bool,
 }
 
 impl ToolAwareStream {
@@ -212,9 +240,17 @@ impl ToolAwareStream {
             last_usage: None,
             last_finish_reason: None,
             finished: false,
+            partial_tool_arguments: false,
         }
     }
 
+    /// Also emit [`StreamEvent::PartialToolArguments`] snapshots while tool
+    /// call arguments are streaming.
+    pub fn with_partial_tool_arguments(mut self) -> Self {
+        self.partial_tool_arguments = true;
+        self
+    }
+
     /// Process a single `CompletionsResponse` chunk, extracting events
     /// and accumulating tool call fragments.
     fn process_chunk(&mut self, response: CompletionsResponse) {
@@ -263,6 +299,20 @@ impl ToolAwareStream {
                     let idx = partial.index.unwrap_or(0);
                     let acc = self.tool_accumulators.entry(idx).or_default();
                     acc.merge(partial);
+
+                    if self.partial_tool_arguments {
+                        let parsed = parse_partial_json(&acc.arguments);
+                        if parsed.is_some() && parsed != acc.snapshot {
+                            acc.snapshot.clone_from(&parsed);
+                            self.pending_events
+                                .push_back(StreamEvent::PartialToolArguments {
+                                    index: idx,
+                                    id: acc.id.clone(),
+                                    name: acc.name.clone(),
+                                    arguments: parsed.unwrap_or_default(),
+                                });
+                        }
+                    }
                 }
             }
         }
@@ -336,6 +386,536 @@ impl Stream for ToolAwareStream {
     }
 }
 
+/// Rebuilds the final [`CompletionsResponse`] from a chat completions stream.
+///
+/// Each choice comes back as [`Choice::NonStreaming`] with its content,
+/// reasoning, reasoning details, tool calls, images, annotations, and
+/// logprobs merged across chunks, matching what `chat().create(...)` returns.
+///
+/// ```rust,no_run
+/// use futures_util::StreamExt;
+/// use openrouter_rs::types::stream::CompletionsStreamAccumulator;
+///
+/// # async fn example(client: openrouter_rs::OpenRouterClient, request: openrouter_rs::api::chat::ChatCompletionRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = client.chat().stream(&request).await?;
+/// let mut accumulator = CompletionsStreamAccumulator::new();
+/// while let Some(chunk) = stream.next().await {
+///     accumulator.push(&chunk?);
+/// }
+/// let completion = accumulator.finish();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CompletionsStreamAccumulator {
+    response: Option<CompletionsResponse>,
+    /// Choices accumulated by choice index.
+    choices: BTreeMap<u32, ChoiceAccumulator>,
+}
+
+/// Internal accumulator for a single streamed choice.
+#[derive(Debug, Clone, Default)]
+struct ChoiceAccumulator {
+    role: Option<String>,
+    content: String,
+    reasoning: Option<String>,
+    reasoning_details: Vec<ReasoningDetail>,
+    tool_calls: BTreeMap<u32, ToolCallAccumulator>,
+    images: Vec<Value>,
+    audio: Option<Value>,
+    refusal: Option<String>,
+    annotations: Vec<Value>,
+    logprobs: Option<Value>,
+    finish_reason: Option<FinishReason>,
+    native_finish_reason: Option<String>,
+    error: Option<ErrorResponse>,
+}
+
+impl CompletionsStreamAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply one chunk.
+    pub fn push(&mut self, chunk: &CompletionsResponse) {
+        match &mut self.response {
+            Some(response) => {
+                if !chunk.id.is_empty() {
+                    response.id.clone_from(&chunk.id);
+                }
+                if !chunk.model.is_empty() {
+                    response.model.clone_from(&chunk.model);
+                }
+                if chunk.provider.is_some() {
+                    response.provider.clone_from(&chunk.provider);
+                }
+                if chunk.system_fingerprint.is_some() {
+                    response
+                        .system_fingerprint
+                        .clone_from(&chunk.system_fingerprint);
+                }
+                if chunk.usage.is_some() {
+                    response.usage.clone_from(&chunk.usage);
+                }
+                if chunk.service_tier.is_some() {
+                    response.service_tier.clone_from(&chunk.service_tier);
+                }
+                if chunk.openrouter_metadata.is_some() {
+                    response
+                        .openrouter_metadata
+                        .clone_from(&chunk.openrouter_metadata);
+                }
+            }
+            None => {
+                let mut response = chunk.clone();
+                response.choices.clear();
+                self.response = Some(response);
+            }
+        }
+
+        for (position, choice) in chunk.choices.iter().enumerate() {
+            let index = choice.index().unwrap_or(position as u32);
+            self.choices.entry(index).or_default().merge(choice);
+        }
+    }
+
+    /// The completion assembled so far.
+    pub fn finish(self) -> CompletionsResponse {
+        let mut response = self.response.unwrap_or_else(|| CompletionsResponse {
+            id: String::new(),
+            choices: Vec::new(),
+            created: 0,
+            model: String::new(),
+            object_type: ObjectType::ChatCompletion,
+            provider: None,
+            system_fingerprint: None,
+            usage: None,
+            service_tier: None,
+            openrouter_metadata: None,
+        });
+        response.object_type = ObjectType::ChatCompletion;
+        response.choices = self
+            .choices
+            .into_iter()
+            .map(|(index, choice)| choice.into_choice(index))
+            .collect();
+        response
+    }
+}
+
+impl ChoiceAccumulator {
+    /// Merge one chunk's choice into this accumulator.
+    fn merge(&mut self, choice: &Choice) {
+        if let Some(role) = choice.role() {
+            self.role = Some(role.to_string());
+        }
+        if let Some(content) = choice.content() {
+            self.content.push_str(content);
+        }
+        if let Some(reasoning) = choice.reasoning() {
+            self.reasoning
+                .get_or_insert_with(String::new)
+                .push_str(reasoning);
+        }
+        for detail in choice.reasoning_details().unwrap_or_default() {
+            merge_reasoning_detail(&mut self.reasoning_details, detail);
+        }
+        for partial in choice.partial_tool_calls().unwrap_or_default() {
+            self.tool_calls
+                .entry(partial.index.unwrap_or(0))
+                .or_default()
+                .merge(partial);
+        }
+        if let Some(logprobs) = choice.logprobs() {
+            merge_logprobs(&mut self.logprobs, logprobs);
+        }
+        if let Some(reason) = choice.finish_reason() {
+            self.finish_reason = Some(reason.clone());
+        }
+        if let Some(reason) = choice.native_finish_reason() {
+            self.native_finish_reason = Some(reason.to_string());
+        }
+        if let Some(error) = choice.error() {
+            self.error = Some(error.clone());
+        }
+
+        if let Choice::Streaming(choice) = choice {
+            let delta = &choice.delta;
+            self.images.extend(delta.images.iter().flatten().cloned());
+            self.annotations
+                .extend(delta.annotations.iter().flatten().cloned());
+            if let Some(refusal) = &delta.refusal {
+                self.refusal
+                    .get_or_insert_with(String::new)
+                    .push_str(refusal);
+            }
+            if let Some(audio) = &delta.audio {
+                merge_audio(&mut self.audio, audio);
+            }
+        }
+    }
+
+    fn into_choice(self, index: u32) -> Choice {
+        let tool_calls: Vec<ToolCall> = self
+            .tool_calls
+            .into_iter()
+            .filter_map(|(index, acc)| acc.into_tool_call().map(|call| call.with_index(index)))
+            .collect();
+
+        Choice::NonStreaming(NonStreamingChoice {
+            finish_reason: self.finish_reason,
+            native_finish_reason: self.native_finish_reason,
+            message: Message {
+                content: (!self.content.is_empty()).then_some(self.content),
+                role: Some(self.role.unwrap_or_else(|| "assistant".to_string())),
+                name: None,
+                tool_calls: (!tool_calls.is_empty()).then_some(tool_calls),
+                reasoning: self.reasoning,
+                reasoning_details: (!self.reasoning_details.is_empty())
+                    .then_some(self.reasoning_details),
+                images: (!self.images.is_empty()).then_some(self.images),
+                audio: self.audio,
+                refusal: self.refusal,
+                annotations: (!self.annotations.is_empty()).then_some(self.annotations),
+            },
+            error: self.error,
+            index: Some(index),
+            logprobs: self.logprobs,
+        })
+    }
+}
+
+/// Append a streamed reasoning detail fragment to the block it continues.
+///
+/// Fragments continue a block with the same type and `index`; fragments
+/// without an index only continue the most recent block.
+fn merge_reasoning_detail(details: &mut Vec<ReasoningDetail>, fragment: &ReasoningDetail) {
+    let continues = |detail: &&mut ReasoningDetail| {
+        detail.block_type == fragment.block_type && detail.index == fragment.index
+    };
+    let existing = if fragment.index.is_some() {
+        details.iter_mut().find(continues)
+    } else {
+        details.last_mut().filter(continues)
+    };
+    let Some(existing) = existing else {
+        details.push(fragment.clone());
+        return;
+    };
+
+    for (current, update) in [
+        (&mut existing.text, &fragment.text),
+        (&mut existing.data, &fragment.data),
+    ] {
+        if let Some(update) = update {
+            current.get_or_insert_with(String::new).push_str(update);
+        }
+    }
+    for (current, update) in [
+        (&mut existing.signature, &fragment.signature),
+        (&mut existing.format, &fragment.format),
+        (&mut existing.id, &fragment.id),
+    ] {
+        if update.is_some() {
+            current.clone_from(update);
+        }
+    }
+}
+
+/// Concatenate per-chunk logprob arrays (`content`, `refusal`).
+fn merge_logprobs(current: &mut Option<Value>, update: &Value) {
+    match (current.as_mut(), update) {
+        (_, Value::Null) => {}
+        (Some(Value::Object(current)), Value::Object(update)) => {
+            for (key, value) in update {
+                match (current.get_mut(key), value) {
+                    (Some(Value::Array(items)), Value::Array(more)) => {
+                        items.extend(more.iter().cloned());
+                    }
+                    (_, Value::Null) => {}
+                    _ => {
+                        current.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        _ => *current = Some(update.clone()),
+    }
+}
+
+/// Concatenate streamed audio `data` and `transcript` fragments.
+fn merge_audio(current: &mut Option<Value>, update: &Value) {
+    match (current.as_mut(), update) {
+        (Some(Value::Object(current)), Value::Object(update)) => {
+            for (key, value) in update {
+                match (current.get_mut(key), value) {
+                    (Some(Value::String(text)), Value::String(more))
+                        if key == "data" || key == "transcript" =>
+                    {
+                        text.push_str(more);
+                    }
+                    _ => {
+                        current.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        _ => *current = Some(update.clone()),
+    }
+}
+
+/// Most placeholder entries an accumulator inserts to reach an event's wire
+/// index. Events pointing further past the end are ignored, so a malformed
+/// stream cannot force a huge allocation.
+const MAX_INDEX_GAP: usize = 64;
+
+/// The entry at wire `index`, padding `items` with `fill` up to it, or `None`
+/// when `index` is more than [`MAX_INDEX_GAP`] entries past the end.
+pub(crate) fn index_slot<T>(
+    items: &mut Vec<T>,
+    index: usize,
+    fill: impl FnMut() -> T,
+) -> Option<&mut T> {
+    if index >= items.len().saturating_add(MAX_INDEX_GAP) {
+        return None;
+    }
+    if items.len() <= index {
+        items.resize_with(index + 1, fill);
+    }
+    Some(&mut items[index])
+}
+
+/// Accumulates raw stream events into the matching non-streaming response.
+///
+/// Implemented by [`CompletionsStreamAccumulator`],
+/// [`ResponsesStreamAccumulator`], and [`AnthropicMessagesStreamAccumulator`].
+pub trait StreamAccumulator: Default {
+    /// One item of the raw stream.
+    type Event;
+    /// The non-streaming response the events add up to.
+    type Output;
+
+    /// Apply one event.
+    fn push(&mut self, event: &Self::Event);
+
+    /// The response assembled so far.
+    fn finish(self) -> Self::Output;
+}
+
+impl StreamAccumulator for CompletionsStreamAccumulator {
+    type Event = CompletionsResponse;
+    type Output = CompletionsResponse;
+
+    fn push(&mut self, event: &CompletionsResponse) {
+        CompletionsStreamAccumulator::push(self, event);
+    }
+
+    fn finish(self) -> CompletionsResponse {
+        CompletionsStreamAccumulator::finish(self)
+    }
+}
+
+impl StreamAccumulator for ResponsesStreamAccumulator {
+    type Event = ResponsesStreamEvent;
+    type Output = ResponsesResponse;
+
+    fn push(&mut self, event: &ResponsesStreamEvent) {
+        ResponsesStreamAccumulator::push(self, event);
+    }
+
+    fn finish(self) -> ResponsesResponse {
+        ResponsesStreamAccumulator::finish(self)
+    }
+}
+
+impl StreamAccumulator for AnthropicMessagesStreamAccumulator {
+    type Event = AnthropicMessagesSseEvent;
+    type Output = AnthropicMessagesResponse;
+
+    fn push(&mut self, event: &AnthropicMessagesSseEvent) {
+        AnthropicMessagesStreamAccumulator::push(self, event);
+    }
+
+    fn finish(self) -> AnthropicMessagesResponse {
+        AnthropicMessagesStreamAccumulator::finish(self)
+    }
+}
+
+/// Resolves to the response collected by a `tee_*` stream once it ends.
+///
+/// If the stream yields an error, this resolves to a copy of it, with the
+/// same variant and details (the original error is forwarded to the
+/// stream's consumer). If the stream is dropped before it ends, this
+/// resolves to [`OpenRouterError::Cancelled`].
+#[derive(Debug)]
+pub struct StreamCollector<T> {
+    receiver: oneshot::Receiver<Result<T, OpenRouterError>>,
+}
+
+impl<T> Future for StreamCollector<T> {
+    type Output = Result<T, OpenRouterError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(OpenRouterError::Cancelled)))
+    }
+}
+
+/// A forwarded event stream paired with the collector for its final response.
+pub type Tee<E, T> = (
+    BoxStream<'static, Result<E, OpenRouterError>>,
+    StreamCollector<T>,
+);
+
+/// Drain `stream` through accumulator `A`, stopping at the first error.
+async fn collect_stream<A>(
+    mut stream: BoxStream<'static, Result<A::Event, OpenRouterError>>,
+) -> Result<A::Output, OpenRouterError>
+where
+    A: StreamAccumulator,
+{
+    let mut accumulator = A::default();
+    while let Some(event) = stream.next().await {
+        accumulator.push(&event?);
+    }
+    Ok(accumulator.finish())
+}
+
+/// Forward `inner` unchanged while feeding each event to accumulator `A`.
+fn tee_stream<A>(
+    inner: BoxStream<'static, Result<A::Event, OpenRouterError>>,
+) -> Tee<A::Event, A::Output>
+where
+    A: StreamAccumulator + Send + 'static,
+    A::Event: Send + 'static,
+    A::Output: Send + 'static,
+{
+    struct State<A: StreamAccumulator> {
+        inner: BoxStream<'static, Result<A::Event, OpenRouterError>>,
+        accumulator: A,
+        sender: Option<oneshot::Sender<Result<A::Output, OpenRouterError>>>,
+    }
+
+    let (sender, receiver) = oneshot::channel();
+    let state = State {
+        inner,
+        accumulator: A::default(),
+        sender: Some(sender),
+    };
+
+    let stream = futures_util::stream::unfold(state, |mut state| async move {
+        let item = state.inner.next().await;
+        match &item {
+            Some(Ok(event)) => state.accumulator.push(event),
+            Some(Err(error)) => {
+                if let Some(sender) = state.sender.take() {
+                    let _ = sender.send(Err(error.duplicate()));
+                }
+            }
+            None => {
+                if let Some(sender) = state.sender.take() {
+                    let accumulator = std::mem::take(&mut state.accumulator);
+                    let _ = sender.send(Ok(accumulator.finish()));
+                }
+            }
+        }
+        item.map(|item| (item, state))
+    })
+    .boxed();
+
+    (stream, StreamCollector { receiver })
+}
+
+/// Collect a chat completions stream into a [`CompletionsResponse`].
+///
+/// ```rust,no_run
+/// use openrouter_rs::types::stream::ChatStreamExt;
+///
+/// # async fn example(client: openrouter_rs::OpenRouterClient, request: openrouter_rs::api::chat::ChatCompletionRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// let completion = client.chat().stream(&request).await?.collect_completion().await?;
+/// println!("{:?}", completion.usage);
+/// # Ok(())
+/// # }
+/// ```
+pub trait ChatStreamExt:
+    Stream<Item = Result<CompletionsResponse, OpenRouterError>> + Sized
+{
+    /// Drain the stream into the response `chat().create(...)` would return.
+    fn collect_completion(self)
+    -> BoxFuture<'static, Result<CompletionsResponse, OpenRouterError>>;
+
+    /// Forward every chunk unchanged while collecting the final response.
+    fn tee_completion(self) -> Tee<CompletionsResponse, CompletionsResponse>;
+}
+
+impl<S> ChatStreamExt for S
+where
+    S: Stream<Item = Result<CompletionsResponse, OpenRouterError>> + Send + 'static,
+{
+    fn collect_completion(
+        self,
+    ) -> BoxFuture<'static, Result<CompletionsResponse, OpenRouterError>> {
+        collect_stream::<CompletionsStreamAccumulator>(self.boxed()).boxed()
+    }
+
+    fn tee_completion(self) -> Tee<CompletionsResponse, CompletionsResponse> {
+        tee_stream::<CompletionsStreamAccumulator>(self.boxed())
+    }
+}
+
+/// Collect a Responses API event stream into a [`ResponsesResponse`].
+pub trait ResponsesStreamExt:
+    Stream<Item = Result<ResponsesStreamEvent, OpenRouterError>> + Sized
+{
+    /// Drain the stream into the response `responses().create(...)` would return.
+    fn collect_response(self) -> BoxFuture<'static, Result<ResponsesResponse, OpenRouterError>>;
+
+    /// Forward every event unchanged while collecting the final response.
+    fn tee_response(self) -> Tee<ResponsesStreamEvent, ResponsesResponse>;
+}
+
+impl<S> ResponsesStreamExt for S
+where
+    S: Stream<Item = Result<ResponsesStreamEvent, OpenRouterError>> + Send + 'static,
+{
+    fn collect_response(self) -> BoxFuture<'static, Result<ResponsesResponse, OpenRouterError>> {
+        collect_stream::<ResponsesStreamAccumulator>(self.boxed()).boxed()
+    }
+
+    fn tee_response(self) -> Tee<ResponsesStreamEvent, ResponsesResponse> {
+        tee_stream::<ResponsesStreamAccumulator>(self.boxed())
+    }
+}
+
+/// Collect a Messages API event stream into an [`AnthropicMessagesResponse`].
+pub trait MessagesStreamExt:
+    Stream<Item = Result<AnthropicMessagesSseEvent, OpenRouterError>> + Sized
+{
+    /// Drain the stream into the response `messages().create(...)` would return.
+    fn collect_message(
+        self,
+    ) -> BoxFuture<'static, Result<AnthropicMessagesResponse, OpenRouterError>>;
+
+    /// Forward every event unchanged while collecting the final response.
+    fn tee_message(self) -> Tee<AnthropicMessagesSseEvent, AnthropicMessagesResponse>;
+}
+
+impl<S> MessagesStreamExt for S
+where
+    S: Stream<Item = Result<AnthropicMessagesSseEvent, OpenRouterError>> + Send + 'static,
+{
+    fn collect_message(
+        self,
+    ) -> BoxFuture<'static, Result<AnthropicMessagesResponse, OpenRouterError>> {
+        collect_stream::<AnthropicMessagesStreamAccumulator>(self.boxed()).boxed()
+    }
+
+    fn tee_message(self) -> Tee<AnthropicMessagesSseEvent, AnthropicMessagesResponse> {
+        tee_stream::<AnthropicMessagesStreamAccumulator>(self.boxed())
+    }
+}
+
 /// Source stream family for a [`UnifiedStreamEvent`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
@@ -355,7 +935,21 @@ pub enum UnifiedStreamEvent {
     ReasoningDelta(String),
     /// Structured reasoning detail blocks (chat stream only).
     ReasoningDetailsDelta(Vec<ReasoningDetail>),
-    /// Tool-related delta payload (format depends on source API).
+    /// A function tool call began streaming.
+    ///
+    /// `index` is the chat tool call index, the Responses output index, or
+    /// the Messages content block index.
+    ToolCallStarted {
+        index: u32,
+        id: String,
+        name: String,
+    },
+    /// A fragment of the JSON-encoded arguments for the tool call at `index`.
+    ToolCallArgumentsDelta { index: u32, delta: String },
+    /// A function tool call finished streaming; arguments are complete.
+    ToolCallCompleted(ToolCall),
+    /// Other tool activity with no common projection, such as server-side
+    /// tool use (format depends on source API).
     ToolDelta(Value),
     /// Source-specific event payload when no common projection applies.
     Raw {
@@ -369,7 +963,7 @@ pub enum UnifiedStreamEvent {
         id: Option<String>,
         model: Option<String>,
         finish_reason: Option<String>,
-        usage: Option<Value>,
+        usage: Option<UnifiedUsage>,
     },
     /// Transport/parsing/runtime error from the underlying stream.
     Error(OpenRouterError),
@@ -378,6 +972,61 @@ pub enum UnifiedStreamEvent {
 /// A unified stream type across all streaming APIs.
 pub type UnifiedStream = BoxStream<'static, UnifiedStreamEvent>;
 
+/// Token usage reported by a unified stream, normalized across APIs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct UnifiedUsage {
+    /// Chat `prompt_tokens` or Responses/Messages `input_tokens`.
+    ///
+    /// Messages cache creation and cache read tokens are included, so this is
+    /// the full prompt size for every source.
+    pub prompt_tokens: u64,
+    /// Chat `completion_tokens` or Responses/Messages `output_tokens`.
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    /// Prompt tokens served from the provider's prompt cache, when reported.
+    pub cached_tokens: Option<u64>,
+    /// Completion tokens spent on reasoning, when reported.
+    pub reasoning_tokens: Option<u64>,
+    /// Cost in credits, when OpenRouter reported it.
+    pub cost: Option<f64>,
+}
+
+impl UnifiedUsage {
+    /// Normalize a chat, Responses, or Messages usage payload.
+    pub(crate) fn from_value(usage: &Value) -> Self {
+        let field = |key: &str| usage.get(key).and_then(Value::as_u64);
+        let detail = |outer: &str, inner: &str| {
+            usage
+                .get(outer)
+                .and_then(|details| details.get(inner))
+                .and_then(Value::as_u64)
+        };
+
+        let cache_read = field("cache_read_input_tokens");
+        let prompt_tokens = field("prompt_tokens")
+            .or_else(|| field("input_tokens"))
+            .unwrap_or_default()
+            + field("cache_creation_input_tokens").unwrap_or_default()
+            + cache_read.unwrap_or_default();
+        let completion_tokens = field("completion_tokens")
+            .or_else(|| field("output_tokens"))
+            .unwrap_or_default();
+
+        Self {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens: field("total_tokens").unwrap_or(prompt_tokens + completion_tokens),
+            cached_tokens: detail("prompt_tokens_details", "cached_tokens")
+                .or_else(|| detail("input_tokens_details", "cached_tokens"))
+                .or(cache_read),
+            reasoning_tokens: detail("completion_tokens_details", "reasoning_tokens")
+                .or_else(|| detail("output_tokens_details", "reasoning_tokens")),
+            cost: usage.get("cost").and_then(Value::as_f64),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 struct StreamMeta {
     id: Option<String>,
@@ -386,6 +1035,19 @@ struct StreamMeta {
     usage: Option<Value>,
 }
 
+impl StreamMeta {
+    /// Build the terminal event from the metadata seen so far.
+    fn done(&mut self, source: UnifiedStreamSource) -> UnifiedStreamEvent {
+        UnifiedStreamEvent::Done {
+            source,
+            id: self.id.take(),
+            model: self.model.take(),
+            finish_reason: self.finish_reason.take(),
+            usage: self.usage.take().as_ref().map(UnifiedUsage::from_value),
+        }
+    }
+}
+
 fn finish_reason_to_string(reason: &FinishReason) -> &'static str {
     match reason {
         FinishReason::ToolCalls => "tool_calls",
@@ -405,6 +1067,61 @@ pub fn adapt_chat_stream(
         pending: VecDeque<UnifiedStreamEvent>,
         done_emitted: bool,
         meta: StreamMeta,
+        /// Tool call fragments accumulated by tool call index.
+        tool_calls: BTreeMap<u32, ToolCallAccumulator>,
+    }
+
+    impl State {
+        fn merge_tool_fragment(&mut self, partial: &PartialToolCall) {
+            let index = partial.index.unwrap_or(0);
+            let acc = self.tool_calls.entry(index).or_default();
+            let started = acc.id.is_some() && acc.name.is_some();
+            acc.merge(partial);
+
+            if !started {
+                // Announce the call once its id and name are both known, then
+                // replay any arguments that arrived alongside them.
+                if let (Some(id), Some(name)) = (&acc.id, &acc.name) {
+                    self.pending.push_back(UnifiedStreamEvent::ToolCallStarted {
+                        index,
+                        id: id.clone(),
+                        name: name.clone(),
+                    });
+                    if !acc.arguments.is_empty() {
+                        self.pending
+                            .push_back(UnifiedStreamEvent::ToolCallArgumentsDelta {
+                                index,
+                                delta: acc.arguments.clone(),
+                            });
+                    }
+                }
+                return;
+            }
+
+            let delta = partial
+                .function
+                .as_ref()
+                .and_then(|function| function.arguments.as_deref())
+                .unwrap_or_default();
+            if !delta.is_empty() {
+                self.pending
+                    .push_back(UnifiedStreamEvent::ToolCallArgumentsDelta {
+                        index,
+                        delta: delta.to_string(),
+                    });
+            }
+        }
+
+        fn complete_tool_calls(&mut self) {
+            for (index, acc) in std::mem::take(&mut self.tool_calls) {
+                if let Some(tool_call) = acc.into_tool_call() {
+                    self.pending
+                        .push_back(UnifiedStreamEvent::ToolCallCompleted(
+                            tool_call.with_index(index),
+                        ));
+                }
+            }
+        }
     }
 
     let state = State {
@@ -412,6 +1129,7 @@ pub fn adapt_chat_stream(
         pending: VecDeque::new(),
         done_emitted: false,
         meta: StreamMeta::default(),
+        tool_calls: BTreeMap::new(),
     };
 
     futures_util::stream::unfold(state, |mut state| async move {
@@ -461,15 +1179,14 @@ pub fn adapt_chat_stream(
 
                         if let Some(partials) = choice.partial_tool_calls() {
                             for partial in partials {
-                                state.pending.push_back(UnifiedStreamEvent::ToolDelta(
-                                    serde_json::to_value(partial).unwrap_or(Value::Null),
-                                ));
+                                state.merge_tool_fragment(partial);
                             }
                         }
 
                         if let Some(reason) = choice.finish_reason() {
                             state.meta.finish_reason =
                                 Some(finish_reason_to_string(reason).to_string());
+                            state.complete_tool_calls();
                         }
                     }
                 }
@@ -478,13 +1195,10 @@ pub fn adapt_chat_stream(
                 }
                 None => {
                     state.done_emitted = true;
-                    state.pending.push_back(UnifiedStreamEvent::Done {
-                        source: UnifiedStreamSource::Chat,
-                        id: state.meta.id.take(),
-                        model: state.meta.model.take(),
-                        finish_reason: state.meta.finish_reason.take(),
-                        usage: state.meta.usage.take(),
-                    });
+                    state.complete_tool_calls();
+                    state
+                        .pending
+                        .push_back(state.meta.done(UnifiedStreamSource::Chat));
                 }
             }
         }
@@ -522,88 +1236,83 @@ pub fn adapt_responses_stream(
 
             match state.inner.next().await {
                 Some(Ok(event)) => {
-                    let event_type = event.event_type.clone();
-                    let data_value = serde_json::to_value(&event.data).unwrap_or(Value::Null);
-                    let mut emitted = false;
-
-                    if let Some(response) = event.data.get("response") {
-                        if let Some(id) = response.get("id").and_then(Value::as_str) {
-                            state.meta.id = Some(id.to_string());
+                    if let Some(response) = event.response() {
+                        if response.id.is_some() {
+                            state.meta.id.clone_from(&response.id);
                         }
-                        if let Some(model) = response.get("model").and_then(Value::as_str) {
-                            state.meta.model = Some(model.to_string());
+                        if response.model.is_some() {
+                            state.meta.model.clone_from(&response.model);
                         }
-                        if let Some(status) = response.get("status").and_then(Value::as_str) {
-                            state.meta.finish_reason = Some(status.to_string());
+                        if response.status.is_some() {
+                            state.meta.finish_reason.clone_from(&response.status);
                         }
-                        if let Some(usage) = response.get("usage") {
-                            state.meta.usage = Some(usage.clone());
-                        }
-                    }
-
-                    if event_type.contains("output_text.delta") {
-                        if let Some(delta) = event.data.get("delta").and_then(Value::as_str) {
-                            state
-                                .pending
-                                .push_back(UnifiedStreamEvent::ContentDelta(delta.to_string()));
-                            emitted = true;
-                        }
-                    }
-
-                    if !emitted && event_type.contains("reasoning") {
-                        let reasoning = event
-                            .data
-                            .get("delta")
-                            .and_then(Value::as_str)
-                            .or_else(|| event.data.get("text").and_then(Value::as_str))
-                            .or_else(|| event.data.get("reasoning").and_then(Value::as_str));
-                        if let Some(reasoning) = reasoning {
-                            state.pending.push_back(UnifiedStreamEvent::ReasoningDelta(
-                                reasoning.to_string(),
-                            ));
-                            emitted = true;
+                        if response.usage.is_some() {
+                            state.meta.usage.clone_from(&response.usage);
                         }
                     }
 
-                    if !emitted && event_type.contains("tool") {
+                    if event.is_terminal() {
+                        state.done_emitted = true;
                         state
                             .pending
-                            .push_back(UnifiedStreamEvent::ToolDelta(data_value.clone()));
-                        emitted = true;
-                    }
-
-                    if event_type == "response.completed" {
-                        state.done_emitted = true;
-                        state.pending.push_back(UnifiedStreamEvent::Done {
-                            source: UnifiedStreamSource::Responses,
-                            id: state.meta.id.take(),
-                            model: state.meta.model.take(),
-                            finish_reason: state.meta.finish_reason.take(),
-                            usage: state.meta.usage.take(),
-                        });
+                            .push_back(state.meta.done(UnifiedStreamSource::Responses));
                         continue;
                     }
 
-                    if !emitted {
-                        state.pending.push_back(UnifiedStreamEvent::Raw {
+                    let unified = match event {
+                        ResponsesStreamEvent::OutputTextDelta { delta, .. } => {
+                            UnifiedStreamEvent::ContentDelta(delta)
+                        }
+                        ResponsesStreamEvent::ReasoningSummaryTextDelta { delta, .. }
+                        | ResponsesStreamEvent::ReasoningTextDelta { delta, .. } => {
+                            UnifiedStreamEvent::ReasoningDelta(delta)
+                        }
+                        ResponsesStreamEvent::OutputItemAdded {
+                            output_index,
+                            item: ResponsesOutputItem::FunctionCall(call),
+                            ..
+                        } => UnifiedStreamEvent::ToolCallStarted {
+                            index: output_index as u32,
+                            id: call.call_id,
+                            name: call.name,
+                        },
+                        ResponsesStreamEvent::FunctionCallArgumentsDelta {
+                            output_index,
+                            delta,
+                            ..
+                        } => UnifiedStreamEvent::ToolCallArgumentsDelta {
+                            index: output_index as u32,
+                            delta,
+                        },
+                        // The finished item that follows carries the same arguments.
+                        ResponsesStreamEvent::FunctionCallArgumentsDone { .. } => continue,
+                        ResponsesStreamEvent::OutputItemDone {
+                            output_index,
+                            item: ResponsesOutputItem::FunctionCall(call),
+                            ..
+                        } => UnifiedStreamEvent::ToolCallCompleted(
+                            ToolCall::new(call.call_id, call.name, call.arguments)
+                                .with_index(output_index as u32),
+                        ),
+                        ResponsesStreamEvent::Unknown {
+                            event_type, data, ..
+                        } => unknown_responses_event(event_type, data),
+                        other => UnifiedStreamEvent::Raw {
                             source: UnifiedStreamSource::Responses,
-                            event_type,
-                            data: data_value,
-                        });
-                    }
+                            event_type: other.event_type().to_string(),
+                            data: serde_json::to_value(&other).unwrap_or(Value::Null),
+                        },
+                    };
+                    state.pending.push_back(unified);
                 }
                 Some(Err(error)) => {
                     state.pending.push_back(UnifiedStreamEvent::Error(error));
                 }
                 None => {
                     state.done_emitted = true;
-                    state.pending.push_back(UnifiedStreamEvent::Done {
-                        source: UnifiedStreamSource::Responses,
-                        id: state.meta.id.take(),
-                        model: state.meta.model.take(),
-                        finish_reason: state.meta.finish_reason.take(),
-                        usage: state.meta.usage.take(),
-                    });
+                    state
+                        .pending
+                        .push_back(state.meta.done(UnifiedStreamSource::Responses));
                 }
             }
         }
@@ -611,6 +1320,30 @@ pub fn adapt_responses_stream(
     .boxed()
 }
 
+/// Project an event type this SDK doesn't model by its name.
+fn unknown_responses_event(
+    event_type: String,
+    data: std::collections::HashMap<String, Value>,
+) -> UnifiedStreamEvent {
+    if event_type.contains("reasoning") {
+        let reasoning = ["delta", "text", "reasoning"]
+            .iter()
+            .find_map(|key| data.get(*key).and_then(Value::as_str));
+        if let Some(reasoning) = reasoning {
+            return UnifiedStreamEvent::ReasoningDelta(reasoning.to_string());
+        }
+    }
+    let data = serde_json::to_value(data).unwrap_or(Value::Null);
+    if event_type.contains("tool") {
+        return UnifiedStreamEvent::ToolDelta(data);
+    }
+    UnifiedStreamEvent::Raw {
+        source: UnifiedStreamSource::Responses,
+        event_type,
+        data,
+    }
+}
+
 /// Adapt a Messages API SSE stream to [`UnifiedStreamEvent`].
 pub fn adapt_messages_stream(
     inner: BoxStream<'static, Result<AnthropicMessagesSseEvent, OpenRouterError>>,
@@ -620,6 +1353,9 @@ pub fn adapt_messages_stream(
         pending: VecDeque<UnifiedStreamEvent>,
         done_emitted: bool,
         meta: StreamMeta,
+        /// Open `tool_use` blocks accumulated by content block index, with
+        /// the `input` the block started with.
+        tool_blocks: BTreeMap<u32, (ToolCallAccumulator, Option<Value>)>,
     }
 
     let state = State {
@@ -627,6 +1363,7 @@ pub fn adapt_messages_stream(
         pending: VecDeque::new(),
         done_emitted: false,
         meta: StreamMeta::default(),
+        tool_blocks: BTreeMap::new(),
     };
 
     futures_util::stream::unfold(state, |mut state| async move {
@@ -651,7 +1388,14 @@ pub fn adapt_messages_stream(
                             }
                         }
                         AnthropicMessagesStreamEvent::MessageDelta { delta, usage } => {
-                            state.meta.usage = Some(usage);
+                            // Deltas report output counts; keep the input counts
+                            // from `message_start`.
+                            match (&mut state.meta.usage, usage) {
+                                (Some(Value::Object(current)), Value::Object(update)) => {
+                                    current.extend(update);
+                                }
+                                (current, usage) => *current = Some(usage),
+                            }
                             if let Some(reason) = delta.get("stop_reason").and_then(Value::as_str) {
                                 state.meta.finish_reason = Some(reason.to_string());
                             }
@@ -674,8 +1418,24 @@ pub fn adapt_messages_stream(
                                     .pending
                                     .push_back(UnifiedStreamEvent::ReasoningDelta(thinking));
                             }
-                            AnthropicContentPart::ToolUse { .. }
-                            | AnthropicContentPart::ServerToolUse { .. } => {
+                            AnthropicContentPart::ToolUse {
+                                id, name, input, ..
+                            } => {
+                                let acc = ToolCallAccumulator {
+                                    id: Some(id.clone()),
+                                    name: Some(name.clone()),
+                                    ..Default::default()
+                                };
+                                state.tool_blocks.insert(index, (acc, input));
+                                state
+                                    .pending
+                                    .push_back(UnifiedStreamEvent::ToolCallStarted {
+                                        index,
+                                        id,
+                                        name,
+                                    });
+                            }
+                            AnthropicContentPart::ServerToolUse { .. } => {
                                 let content_block_value =
                                     serde_json::to_value(content_block).unwrap_or(Value::Null);
                                 state.pending.push_back(UnifiedStreamEvent::ToolDelta(
@@ -708,6 +1468,17 @@ pub fn adapt_messages_stream(
                                         reasoning.to_string(),
                                     ));
                                 }
+                            } else if let (Some((acc, _)), Some(partial_json)) = (
+                                state.tool_blocks.get_mut(&index),
+                                delta.get("partial_json").and_then(Value::as_str),
+                            ) {
+                                acc.arguments.push_str(partial_json);
+                                state.pending.push_back(
+                                    UnifiedStreamEvent::ToolCallArgumentsDelta {
+                                        index,
+                                        delta: partial_json.to_string(),
+                                    },
+                                );
                             } else if delta_type.contains("tool")
                                 || delta_type.contains("json")
                                 || delta.get("partial_json").is_some()
@@ -728,13 +1499,9 @@ pub fn adapt_messages_stream(
                         }
                         AnthropicMessagesStreamEvent::MessageStop { .. } => {
                             state.done_emitted = true;
-                            state.pending.push_back(UnifiedStreamEvent::Done {
-                                source: UnifiedStreamSource::Messages,
-                                id: state.meta.id.take(),
-                                model: state.meta.model.take(),
-                                finish_reason: state.meta.finish_reason.take(),
-                                usage: state.meta.usage.take(),
-                            });
+                            state
+                                .pending
+                                .push_back(state.meta.done(UnifiedStreamSource::Messages));
                         }
                         AnthropicMessagesStreamEvent::Error { error } => {
                             let message = error
@@ -748,8 +1515,25 @@ pub fn adapt_messages_stream(
                                 )),
                             ));
                         }
-                        AnthropicMessagesStreamEvent::ContentBlockStop { .. }
-                        | AnthropicMessagesStreamEvent::Ping => {}
+                        AnthropicMessagesStreamEvent::ContentBlockStop { index } => {
+                            if let Some((mut acc, input)) = state.tool_blocks.remove(&index) {
+                                // Without `input_json_delta` fragments the
+                                // starting `input` is the whole argument object.
+                                if acc.arguments.is_empty() {
+                                    acc.arguments = input
+                                        .map(|input| input.to_string())
+                                        .unwrap_or_else(|| "{}".to_string());
+                                }
+                                if let Some(tool_call) = acc.into_tool_call() {
+                                    state
+                                        .pending
+                                        .push_back(UnifiedStreamEvent::ToolCallCompleted(
+                                            tool_call.with_index(index),
+                                        ));
+                                }
+                            }
+                        }
+                        AnthropicMessagesStreamEvent::Ping => {}
                     }
                 }
                 Some(Err(error)) => {
@@ -757,13 +1541,9 @@ pub fn adapt_messages_stream(
                 }
                 None => {
                     state.done_emitted = true;
-                    state.pending.push_back(UnifiedStreamEvent::Done {
-                        source: UnifiedStreamSource::Messages,
-                        id: state.meta.id.take(),
-                        model: state.meta.model.take(),
-                        finish_reason: state.meta.finish_reason.take(),
-                        usage: state.meta.usage.take(),
-                    });
+                    state
+                        .pending
+                        .push_back(state.meta.done(UnifiedStreamSource::Messages));
                 }
             }
         }
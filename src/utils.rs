@@ -85,7 +85,7 @@ where
                     }
                     Some(Err(error)) => {
                         return Some((
-                            Err(OpenRouterError::Io(error)),
+                            Err(crate::transport::timeout::stream_read_error(error)),
                             (lines, None, String::new()),
                         ));
                     }
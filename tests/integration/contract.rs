@@ -86,7 +86,11 @@ async fn assert_stream_completes(
                 UnifiedStreamEvent::ReasoningDetailsDelta(details) if !details.is_empty() => {
                     saw_payload = true;
                 }
-                UnifiedStreamEvent::ToolDelta(_) | UnifiedStreamEvent::Raw { .. } => {
+                UnifiedStreamEvent::ToolCallStarted { .. }
+                | UnifiedStreamEvent::ToolCallArgumentsDelta { .. }
+                | UnifiedStreamEvent::ToolCallCompleted(_)
+                | UnifiedStreamEvent::ToolDelta(_)
+                | UnifiedStreamEvent::Raw { .. } => {
                     saw_payload = true;
                 }
                 _ => {}
@@ -225,10 +229,13 @@ async fn test_read_only_contract_responses_and_messages_surface() -> Result<(),
     let responses_model = test_responses_model();
     let responses_request = ResponsesRequest::builder()
         .model(responses_model.clone())
-        .input(json!([{
-            "role": "user",
-            "content": "Reply with a short sentence that includes the word Rust."
-        }]))
+        .input(
+            json!([{
+                "role": "user",
+                "content": "Reply with a short sentence that includes the word Rust."
+            }])
+            .into(),
+        )
         .max_output_tokens(80)
         .temperature(0.0)
         .build()?;
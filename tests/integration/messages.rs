@@ -112,7 +112,10 @@ async fn test_stream_messages_unified_done_semantics() -> Result<(), OpenRouterE
                 UnifiedStreamEvent::ReasoningDetailsDelta(details) if !details.is_empty() => {
                     saw_payload_event = true;
                 }
-                UnifiedStreamEvent::ToolDelta(_) => {
+                UnifiedStreamEvent::ToolCallStarted { .. }
+                | UnifiedStreamEvent::ToolCallArgumentsDelta { .. }
+                | UnifiedStreamEvent::ToolCallCompleted(_)
+                | UnifiedStreamEvent::ToolDelta(_) => {
                     saw_payload_event = true;
                 }
                 UnifiedStreamEvent::Raw { source, .. } => {
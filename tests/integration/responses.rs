@@ -30,10 +30,13 @@ fn hot_responses_request(model: &str) -> Result<ResponsesRequest, OpenRouterErro
     builder.instructions(
         "Return a plain-text final answer only. Do not call tools or use external actions.",
     );
-    builder.input(json!([{
-        "role": "user",
-        "content": "Reply with exactly: hot-model-check"
-    }]));
+    builder.input(
+        json!([{
+            "role": "user",
+            "content": "Reply with exactly: hot-model-check"
+        }])
+        .into(),
+    );
     builder.max_output_tokens(64);
     builder.temperature(0.0);
     builder.parallel_tool_calls(false);
@@ -96,7 +99,8 @@ fn collect_response_text(response: &ResponsesResponse) -> String {
 
     if let Some(output) = response.output.as_ref() {
         for item in output {
-            collect_responses_output_text(item, &mut text);
+            let item = serde_json::to_value(item).unwrap_or(Value::Null);
+            collect_responses_output_text(&item, &mut text);
         }
     }
 
@@ -169,10 +173,13 @@ async fn test_create_response_non_streaming() -> Result<(), OpenRouterError> {
     let model = test_responses_model();
     let request = openrouter_rs::api::responses::ResponsesRequest::builder()
         .model(model.clone())
-        .input(json!([{
-            "role": "user",
-            "content": "Reply with a short sentence that includes the word Rust."
-        }]))
+        .input(
+            json!([{
+                "role": "user",
+                "content": "Reply with a short sentence that includes the word Rust."
+            }])
+            .into(),
+        )
         .max_output_tokens(80)
         .temperature(0.0)
         .build()?;
@@ -318,10 +325,13 @@ async fn test_stream_response_unified_done_semantics() -> Result<(), OpenRouterE
     let model = test_responses_model();
     let request = openrouter_rs::api::responses::ResponsesRequest::builder()
         .model(model.clone())
-        .input(json!([{
-            "role": "user",
-            "content": "Give a brief greeting."
-        }]))
+        .input(
+            json!([{
+                "role": "user",
+                "content": "Give a brief greeting."
+            }])
+            .into(),
+        )
         .max_output_tokens(60)
         .temperature(0.0)
         .build()?;
@@ -353,7 +363,10 @@ async fn test_stream_response_unified_done_semantics() -> Result<(), OpenRouterE
                 UnifiedStreamEvent::ReasoningDetailsDelta(details) if !details.is_empty() => {
                     saw_payload_event = true;
                 }
-                UnifiedStreamEvent::ToolDelta(_) => {
+                UnifiedStreamEvent::ToolCallStarted { .. }
+                | UnifiedStreamEvent::ToolCallArgumentsDelta { .. }
+                | UnifiedStreamEvent::ToolCallCompleted(_)
+                | UnifiedStreamEvent::ToolDelta(_) => {
                     saw_payload_event = true;
                 }
                 UnifiedStreamEvent::Raw { .. } => {
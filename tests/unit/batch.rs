@@ -0,0 +1,312 @@
+use std::{path::PathBuf, time::Duration};
+
+use openrouter_rs::{
+    OpenRouterClient,
+    api::{chat::ChatCompletionRequest, embeddings::EmbeddingRequest},
+    batch::BatchRunner,
+    error::OpenRouterError,
+};
+use serde_json::{Value, json};
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+fn completion(content: &str, cost: f64) -> Scripted {
+    Scripted::json(
+        200,
+        &json!({
+            "id": "gen-1",
+            "choices": [{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": content}}],
+            "created": 1700000000,
+            "model": "openai/gpt-4.1-mini",
+            "object": "chat.completion",
+            "usage": {"prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15, "cost": cost}
+        }),
+    )
+}
+
+fn api_error(status: u16, message: &str) -> Scripted {
+    Scripted::json(
+        status,
+        &json!({"error": {"code": status, "message": message}}),
+    )
+}
+
+fn chat_item(custom_id: &str) -> String {
+    json!({
+        "custom_id": custom_id,
+        "body": {
+            "model": "openai/gpt-4.1-mini",
+            "messages": [{"role": "user", "content": format!("question {custom_id}")}]
+        }
+    })
+    .to_string()
+}
+
+fn client(base_url: String) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("test-api-key")
+        .build()
+        .expect("client should build")
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("openrouter-rs-batch-{name}-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    std::fs::create_dir_all(&dir).expect("temp dir should be created");
+    dir
+}
+
+fn read_jsonl(path: &PathBuf) -> Vec<Value> {
+    std::fs::read_to_string(path)
+        .expect("file should exist")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("line should be JSON"))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_batch_runner_retries_rate_limits_and_resumes_from_checkpoint() {
+    let dir = temp_dir("resume");
+    let input = dir.join("input.jsonl");
+    let output = dir.join("output.jsonl");
+    let checkpoint = dir.join("checkpoint.jsonl");
+    std::fs::write(
+        &input,
+        format!(
+            "{}\n\n{}\n{}\n",
+            chat_item("a"),
+            chat_item("b"),
+            chat_item("c")
+        ),
+    )
+    .expect("input should be written");
+
+    let runner = BatchRunner::new()
+        .max_concurrency(1)
+        .rate_limit_cooldown(Duration::from_millis(1), Duration::from_millis(5))
+        .checkpoint(&checkpoint);
+
+    let (base_url, _rx, server) = spawn_scripted_server(vec![
+        completion("answer a", 0.01),
+        api_error(429, "slow down"),
+        completion("answer b", 0.02),
+        api_error(400, "bad request"),
+    ]);
+    let summary = runner
+        .run::<ChatCompletionRequest>(&client(base_url), &input, &output)
+        .await
+        .expect("first run should finish");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(summary.succeeded, 2);
+    assert_eq!(summary.failed, 1);
+    assert_eq!(summary.retries, 1);
+    assert_eq!(summary.rate_limited, 1);
+    assert_eq!(summary.usage.requests, 2);
+    assert_eq!(summary.usage.total_tokens, 30);
+    assert!((summary.usage.cost - 0.03).abs() < 1e-9);
+
+    let results = read_jsonl(&output);
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["custom_id"], "a");
+    assert_eq!(
+        results[0]["response"]["choices"][0]["message"]["content"],
+        "answer a"
+    );
+    assert_eq!(results[1]["custom_id"], "b");
+    assert_eq!(results[1]["attempts"], 2);
+    assert_eq!(results[2]["custom_id"], "c");
+    assert_eq!(results[2]["error"]["status"], 400);
+    assert_eq!(results[2]["error"]["message"], "bad request");
+    assert!(results[2].get("response").is_none());
+    assert_eq!(read_jsonl(&checkpoint).len(), 2);
+
+    let (base_url, rx, server) = spawn_scripted_server(vec![completion("answer c", 0.04)]);
+    let summary = runner
+        .run::<ChatCompletionRequest>(&client(base_url), &input, &output)
+        .await
+        .expect("resumed run should finish");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(summary.skipped, 2);
+    assert_eq!(summary.succeeded, 1);
+    assert_eq!(summary.failed, 0);
+    let total = summary.total_usage();
+    assert_eq!(total.requests, 3);
+    assert!((total.cost - 0.07).abs() < 1e-9);
+
+    let bodies: Vec<String> = rx.try_iter().map(|request| request.body_text()).collect();
+    assert_eq!(bodies.len(), 1);
+    assert!(bodies[0].contains("question c"));
+    // The failed line for `c` is replaced by its successful retry.
+    let results = read_jsonl(&output);
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["custom_id"], "a");
+    assert_eq!(results[1]["custom_id"], "b");
+    assert_eq!(results[2]["custom_id"], "c");
+    assert!(results[2].get("error").is_none());
+    assert_eq!(read_jsonl(&checkpoint).len(), 3);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_batch_runner_backs_off_before_retrying_server_errors() {
+    let dir = temp_dir("backoff");
+    let input = dir.join("input.jsonl");
+    let output = dir.join("output.jsonl");
+    std::fs::write(&input, chat_item("a")).expect("input should be written");
+
+    let (base_url, _rx, server) = spawn_scripted_server(vec![
+        api_error(503, "overloaded"),
+        completion("answer a", 0.01),
+    ]);
+    let started = std::time::Instant::now();
+    let summary = BatchRunner::new()
+        .retry_backoff(Duration::from_millis(80), Duration::from_millis(80))
+        .run::<ChatCompletionRequest>(&client(base_url), &input, &output)
+        .await
+        .expect("run should finish");
+    server.join().expect("server thread should finish");
+
+    // Equal jitter keeps at least half of the configured backoff.
+    assert!(started.elapsed() >= Duration::from_millis(40));
+    assert_eq!(summary.succeeded, 1);
+    assert_eq!(summary.retries, 1);
+    assert_eq!(summary.rate_limited, 0);
+    let results = read_jsonl(&output);
+    assert_eq!(results[0]["attempts"], 2);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_batch_runner_drops_output_lines_missing_from_checkpoint() {
+    let dir = temp_dir("prune");
+    let input = dir.join("input.jsonl");
+    let output = dir.join("output.jsonl");
+    let checkpoint = dir.join("checkpoint.jsonl");
+    std::fs::write(&input, format!("{}\n{}\n", chat_item("a"), chat_item("b")))
+        .expect("input should be written");
+    // A crash after `b`'s output line but before its checkpoint entry, with
+    // a torn line after it.
+    std::fs::write(
+        &checkpoint,
+        format!(
+            "{}\n",
+            json!({"custom_id": "a", "usage": {"requests": 1, "prompt_tokens": 0, "completion_tokens": 0, "total_tokens": 0, "cost": 0.01}})
+        ),
+    )
+    .expect("checkpoint should be written");
+    std::fs::write(
+        &output,
+        format!(
+            "{}\n{}\n{{\"custom_id\":\"b\",\"att",
+            json!({"custom_id": "a", "attempts": 1, "response": {}}),
+            json!({"custom_id": "b", "attempts": 1, "response": {}})
+        ),
+    )
+    .expect("output should be written");
+
+    let (base_url, _rx, server) = spawn_scripted_server(vec![completion("answer b", 0.02)]);
+    let summary = BatchRunner::new()
+        .checkpoint(&checkpoint)
+        .run::<ChatCompletionRequest>(&client(base_url), &input, &output)
+        .await
+        .expect("run should finish");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.succeeded, 1);
+    let results = read_jsonl(&output);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["custom_id"], "a");
+    assert_eq!(results[1]["custom_id"], "b");
+    assert_eq!(
+        results[1]["response"]["choices"][0]["message"]["content"],
+        "answer b"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_batch_runner_runs_embeddings_and_tolerates_torn_checkpoint() {
+    let dir = temp_dir("embeddings");
+    let input = dir.join("input.jsonl");
+    let output = dir.join("output.jsonl");
+    let checkpoint = dir.join("checkpoint.jsonl");
+    std::fs::write(
+        &input,
+        json!({"custom_id": "doc-1", "body": {"model": "openai/text-embedding-3-small", "input": "hello"}})
+            .to_string(),
+    )
+    .expect("input should be written");
+    std::fs::write(&checkpoint, "{\"custom_id\":\"doc-0\",\"us")
+        .expect("checkpoint should be written");
+
+    let (base_url, _rx, server) = spawn_scripted_server(vec![Scripted::json(
+        200,
+        &json!({
+            "object": "list",
+            "data": [{"object": "embedding", "embedding": [0.1, 0.2], "index": 0}],
+            "model": "openai/text-embedding-3-small",
+            "usage": {"prompt_tokens": 2, "total_tokens": 2, "cost": 0.0001}
+        }),
+    )]);
+    let summary = BatchRunner::new()
+        .checkpoint(&checkpoint)
+        .run::<EmbeddingRequest>(&client(base_url), &input, &output)
+        .await
+        .expect("run should finish");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(summary.succeeded, 1);
+    assert_eq!(summary.usage.prompt_tokens, 2);
+    assert_eq!(summary.checkpoint_usage.requests, 0);
+    let results = read_jsonl(&output);
+    assert_eq!(
+        results[0]["response"]["data"][0]["embedding"],
+        json!([0.1, 0.2])
+    );
+
+    let checkpoint_lines: Vec<String> = std::fs::read_to_string(&checkpoint)
+        .expect("checkpoint should exist")
+        .lines()
+        .map(str::to_string)
+        .collect();
+    assert_eq!(checkpoint_lines.len(), 2);
+    let entry: Value = serde_json::from_str(&checkpoint_lines[1]).expect("entry should be JSON");
+    assert_eq!(entry["custom_id"], "doc-1");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_batch_runner_rejects_invalid_input() {
+    let dir = temp_dir("invalid");
+    let input = dir.join("input.jsonl");
+    let output = dir.join("output.jsonl");
+    let client = client("http://127.0.0.1:9/api/v1".to_string());
+
+    std::fs::write(&input, format!("{}\n{}\n", chat_item("a"), chat_item("a")))
+        .expect("input should be written");
+    let error = BatchRunner::new()
+        .run::<ChatCompletionRequest>(&client, &input, &output)
+        .await
+        .expect_err("duplicate ids should be rejected");
+    assert!(
+        matches!(&error, OpenRouterError::ConfigError(message) if message.contains("duplicate custom_id `a` on line 2"))
+    );
+
+    std::fs::write(&input, "{\"custom_id\": \"a\"}\n").expect("input should be written");
+    let error = BatchRunner::new()
+        .run::<ChatCompletionRequest>(&client, &input, &output)
+        .await
+        .expect_err("missing body should be rejected");
+    assert!(matches!(&error, OpenRouterError::ConfigError(message) if message.contains("line 1")));
+
+    std::fs::remove_dir_all(&dir).ok();
+}
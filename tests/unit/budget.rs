@@ -0,0 +1,232 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::chat::{ChatCompletionRequest, Message},
+    budget::{BudgetAction, BudgetGuard},
+    error::{BudgetKind, OpenRouterError},
+    types::Role,
+};
+use serde_json::json;
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+fn completion(model: &str, cost: f64) -> Scripted {
+    Scripted::json(
+        200,
+        &json!({
+            "id": "gen-1",
+            "choices": [{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": "ok"}}],
+            "created": 1700000000,
+            "model": model,
+            "object": "chat.completion",
+            "usage": {"prompt_tokens": 10, "completion_tokens": 4, "total_tokens": 14, "cost": cost}
+        }),
+    )
+}
+
+fn credits(total_credits: f64, total_usage: f64) -> Scripted {
+    Scripted::json(
+        200,
+        &json!({"data": {"total_credits": total_credits, "total_usage": total_usage}}),
+    )
+}
+
+fn client(base_url: String, budget: BudgetGuard) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("test-api-key")
+        .budget_guard(budget)
+        .build()
+        .expect("client should build")
+}
+
+fn request(model: &str, team: &str) -> ChatCompletionRequest {
+    ChatCompletionRequest::builder()
+        .model(model)
+        .messages(vec![Message::new(Role::User, "Say ok.")])
+        .metadata([("team", team)])
+        .build()
+        .expect("request should build")
+}
+
+fn assert_budget_exceeded(result: Result<impl std::fmt::Debug, OpenRouterError>, kind: BudgetKind) {
+    match result {
+        Err(OpenRouterError::BudgetExceeded(budget)) => assert_eq!(budget.kind(), kind),
+        other => panic!("expected BudgetExceeded, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_session_budget_rejects_after_limit_and_reports_breakdown() {
+    let (base_url, requests, server) = spawn_scripted_server(vec![
+        completion("openai/gpt-4.1-mini", 0.25),
+        completion("anthropic/claude-sonnet-4", 0.75),
+    ]);
+    let budget = BudgetGuard::new().session_budget(1.0);
+    let client = client(base_url, budget.clone());
+
+    client
+        .chat()
+        .create(&request("openai/gpt-4.1-mini", "search"))
+        .await
+        .expect("first request should succeed");
+    client
+        .chat()
+        .create(&request("anthropic/claude-sonnet-4", "billing"))
+        .await
+        .expect("second request should succeed");
+    assert_budget_exceeded(
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1-mini", "search"))
+            .await,
+        BudgetKind::Session,
+    );
+    server.join().expect("server should finish");
+    assert_eq!(requests.try_iter().count(), 2);
+
+    let report = budget.report();
+    assert_eq!(report.session.requests, 2);
+    assert_eq!(report.session.prompt_tokens, 20);
+    assert_eq!(report.session.completion_tokens, 8);
+    assert!((report.session.cost - 1.0).abs() < 1e-9);
+    assert!((report.today.cost - 1.0).abs() < 1e-9);
+    assert!((report.by_model["anthropic/claude-sonnet-4"].cost - 0.75).abs() < 1e-9);
+    assert!((report.by_tag["team"]["search"].cost - 0.25).abs() < 1e-9);
+    assert_eq!(report.by_tag["team"]["billing"].requests, 1);
+
+    budget.reset_session();
+    assert!(budget.check().is_ok());
+    assert_eq!(budget.report().session.requests, 0);
+}
+
+#[tokio::test]
+async fn test_failed_request_is_not_recorded() {
+    let error = json!({"error": {"code": 500, "message": "upstream failed"}});
+    let (base_url, _requests, server) = spawn_scripted_server(vec![Scripted::json(500, &error)]);
+    let budget = BudgetGuard::new().session_budget(1.0);
+    let client = client(base_url, budget.clone());
+
+    let result = client
+        .chat()
+        .create(&request("openai/gpt-4.1-mini", "search"))
+        .await;
+    assert!(matches!(result, Err(OpenRouterError::Api(_))), "{result:?}");
+    server.join().expect("server should finish");
+
+    let report = budget.report();
+    assert_eq!(report.session.requests, 0);
+    assert!(report.by_model.is_empty());
+}
+
+#[tokio::test]
+async fn test_stream_cost_is_recorded_from_final_usage_chunk() {
+    let chunk = json!({
+        "id": "gen-2",
+        "choices": [{"index": 0, "finish_reason": null, "delta": {"role": "assistant", "content": "ok"}}],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion.chunk"
+    });
+    let usage = json!({
+        "id": "gen-2",
+        "choices": [],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion.chunk",
+        "usage": {"prompt_tokens": 7, "completion_tokens": 3, "total_tokens": 10, "cost": 0.125}
+    });
+    let body = format!("data: {chunk}\n\ndata: {usage}\n\ndata: [DONE]\n\n");
+    let (base_url, _requests, server) =
+        spawn_scripted_server(vec![Scripted::new(200, "text/event-stream", body)]);
+    let budget = BudgetGuard::new();
+    let client = client(base_url, budget.clone());
+
+    let stream = client
+        .chat()
+        .stream(&request("openai/gpt-4.1-mini", "search"))
+        .await
+        .expect("stream should start");
+    let chunks = stream.collect::<Vec<_>>().await;
+    assert!(chunks.iter().all(Result::is_ok));
+    server.join().expect("server should finish");
+
+    let report = budget.report();
+    assert_eq!(report.session.requests, 1);
+    assert_eq!(report.session.prompt_tokens, 7);
+    assert!((report.session.cost - 0.125).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_credit_floor_uses_refreshed_credits_minus_spend() {
+    let (base_url, requests, server) = spawn_scripted_server(vec![
+        credits(10.0, 8.8),
+        completion("openai/gpt-4.1-mini", 0.5),
+    ]);
+    let budget = BudgetGuard::new()
+        .min_remaining_credits(1.0)
+        .refresh_credits_every(std::time::Duration::from_secs(3600));
+    let client = client(base_url, budget.clone());
+
+    client
+        .chat()
+        .create(&request("openai/gpt-4.1-mini", "search"))
+        .await
+        .expect("request above the floor should succeed");
+    assert_budget_exceeded(
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1-mini", "search"))
+            .await,
+        BudgetKind::CreditFloor,
+    );
+    server.join().expect("server should finish");
+
+    let lines: Vec<String> = requests
+        .try_iter()
+        .map(|request| request.request_line)
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("GET /api/v1/credits"));
+    let remaining = budget
+        .report()
+        .remaining_credits
+        .expect("credits should be known");
+    assert!((remaining - 0.7).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_warn_action_sends_request_and_calls_handler() {
+    let (base_url, requests, server) = spawn_scripted_server(vec![
+        completion("openai/gpt-4.1-mini", 2.0),
+        completion("openai/gpt-4.1-mini", 2.0),
+    ]);
+    let warnings = Arc::new(AtomicUsize::new(0));
+    let counted = warnings.clone();
+    let budget = BudgetGuard::new()
+        .daily_budget(1.0)
+        .action(BudgetAction::Warn)
+        .on_warning(move |error| {
+            assert_eq!(error.kind(), BudgetKind::Daily);
+            counted.fetch_add(1, Ordering::SeqCst);
+        });
+    let client = client(base_url, budget.clone());
+
+    for _ in 0..2 {
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1-mini", "search"))
+            .await
+            .expect("request should be sent despite the budget");
+    }
+    server.join().expect("server should finish");
+
+    assert_eq!(requests.try_iter().count(), 2);
+    assert_eq!(warnings.load(Ordering::SeqCst), 1);
+    assert!((budget.report().today.cost - 4.0).abs() < 1e-9);
+}
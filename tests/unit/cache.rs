@@ -0,0 +1,357 @@
+use std::time::Duration;
+
+use futures_util::{StreamExt, stream::BoxStream};
+use openrouter_rs::{
+    OpenRouterClient,
+    api::chat::{ChatCompletionRequest, Message},
+    cache::{
+        CacheEntry, CacheMode, CacheStatus, CacheStore, DiskCache, MemoryCache, ResponseCache,
+        cache_key,
+    },
+    error::OpenRouterError,
+    types::{Role, completion::CompletionsResponse, stream::StreamEvent},
+};
+use serde_json::{Value, json};
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+fn completion(content: &str) -> Scripted {
+    Scripted::json(
+        200,
+        &json!({
+            "id": "gen-1",
+            "choices": [{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": content}}],
+            "created": 1700000000,
+            "model": "openai/gpt-4.1-mini",
+            "object": "chat.completion",
+            "usage": {"prompt_tokens": 5, "completion_tokens": 2, "total_tokens": 7}
+        }),
+    )
+}
+
+fn chunk(content: &str, finish_reason: Option<&str>) -> Value {
+    json!({
+        "id": "gen-2",
+        "choices": [{"index": 0, "finish_reason": finish_reason, "delta": {"role": "assistant", "content": content}}],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion.chunk"
+    })
+}
+
+fn event_stream_of(chunks: impl IntoIterator<Item = Value>) -> Scripted {
+    let mut body = String::new();
+    for chunk in chunks {
+        body.push_str(&format!("data: {chunk}\n\n"));
+    }
+    body.push_str("data: [DONE]\n\n");
+    Scripted::new(200, "text/event-stream", body)
+}
+
+fn event_stream(parts: &[&str]) -> Scripted {
+    event_stream_of(parts.iter().map(|part| chunk(part, None)))
+}
+
+fn client(base_url: String, cache: ResponseCache) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("test-api-key")
+        .cache(cache)
+        .build()
+        .expect("client should build")
+}
+
+fn request() -> ChatCompletionRequest {
+    ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![Message::new(Role::User, "What is 2 + 2?")])
+        .temperature(0.0)
+        .seed(7)
+        .build()
+        .expect("request should build")
+}
+
+async fn stream_text(
+    stream: BoxStream<'static, Result<CompletionsResponse, OpenRouterError>>,
+) -> String {
+    stream
+        .map(|chunk| {
+            chunk
+                .expect("chunk should parse")
+                .choices
+                .first()
+                .and_then(|choice| choice.content())
+                .unwrap_or_default()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .await
+        .concat()
+}
+
+#[test]
+fn test_cache_key_is_canonical() {
+    let a = json!({"model": "m", "messages": [{"role": "user", "content": "hi"}], "seed": 7});
+    let b = json!({"seed": 7, "messages": [{"content": "hi", "role": "user"}], "model": "m"});
+    let key = cache_key("chat.completions", &a).expect("key should compute");
+
+    assert_eq!(
+        key,
+        cache_key("chat.completions", &b).expect("key should compute")
+    );
+    assert_eq!(key.len(), 64);
+    assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_ne!(key, cache_key("other", &a).expect("key should compute"));
+}
+
+#[tokio::test]
+async fn test_chat_create_hits_cache_and_honors_modes() {
+    let (base_url, rx, server) =
+        spawn_scripted_server(vec![completion("4"), completion("four"), completion("4!")]);
+    let client = client(base_url, ResponseCache::memory(16));
+    let chat = client.chat();
+
+    let first = chat
+        .create_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("first call should succeed");
+    assert_eq!(first.cache.status, CacheStatus::Miss);
+    assert!(first.cache.key.is_some());
+
+    let second = chat
+        .create_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("second call should succeed");
+    assert_eq!(second.cache.status, CacheStatus::Hit);
+    assert_eq!(second.cache.key, first.cache.key);
+    assert!(second.cache.age.is_some());
+    assert_eq!(second.response.choices[0].content(), Some("4"));
+
+    let bypassed = chat
+        .create_with_cache(&request(), CacheMode::Bypass)
+        .await
+        .expect("bypass should succeed");
+    assert_eq!(bypassed.cache.status, CacheStatus::Bypassed);
+    assert_eq!(bypassed.response.choices[0].content(), Some("four"));
+
+    let refreshed = chat
+        .create_with_cache(&request(), CacheMode::Refresh)
+        .await
+        .expect("refresh should succeed");
+    assert_eq!(refreshed.cache.status, CacheStatus::Refreshed);
+
+    let response = chat
+        .create(&request())
+        .await
+        .expect("cached call should succeed");
+    server.join().expect("server thread should finish");
+    assert_eq!(response.choices[0].content(), Some("4!"));
+    assert_eq!(rx.try_iter().count(), 3);
+}
+
+#[tokio::test]
+async fn test_cached_completion_replays_as_stream() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![completion("4")]);
+    let client = client(base_url, ResponseCache::memory(16));
+
+    client
+        .chat()
+        .create(&request())
+        .await
+        .expect("call should succeed");
+    server.join().expect("server thread should finish");
+
+    let cached = client
+        .chat()
+        .stream_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("stream should replay");
+    assert_eq!(cached.cache.status, CacheStatus::Hit);
+    assert_eq!(stream_text(cached.response).await, "4");
+
+    let mut events = client
+        .chat()
+        .stream_tool_aware(&request())
+        .await
+        .expect("tool-aware stream should replay");
+    let mut saw_done = false;
+    while let Some(event) = events.next().await {
+        if let StreamEvent::Done { finish_reason, .. } = event {
+            saw_done = finish_reason.is_some();
+        }
+    }
+    assert!(saw_done);
+    assert_eq!(rx.try_iter().count(), 1);
+}
+
+#[tokio::test]
+async fn test_completed_stream_is_recorded_and_replayed() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![event_stream(&["2 + 2", " is 4"])]);
+    let client = client(base_url, ResponseCache::memory(16));
+
+    let live = client
+        .chat()
+        .stream_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("stream should start");
+    assert_eq!(live.cache.status, CacheStatus::Miss);
+    assert_eq!(stream_text(live.response).await, "2 + 2 is 4");
+    server.join().expect("server thread should finish");
+
+    let replayed = client
+        .chat()
+        .stream_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("stream should replay");
+    assert_eq!(replayed.cache.status, CacheStatus::Hit);
+    assert_eq!(stream_text(replayed.response).await, "2 + 2 is 4");
+    assert_eq!(rx.try_iter().count(), 1);
+}
+
+#[tokio::test]
+async fn test_error_responses_and_streams_are_not_cached() {
+    let failed = json!({
+        "id": "gen-3",
+        "choices": [{
+            "index": 0,
+            "finish_reason": "error",
+            "message": {"role": "assistant", "content": ""},
+            "error": {"code": 502, "message": "provider failed"}
+        }],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion"
+    });
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::json(200, &failed),
+        completion("4"),
+        event_stream_of([chunk("2 + 2", None), chunk("", Some("error"))]),
+        event_stream(&["4"]),
+    ]);
+    let completions = client(base_url.clone(), ResponseCache::memory(16));
+    let streams = client(base_url, ResponseCache::memory(16));
+
+    completions
+        .chat()
+        .create(&request())
+        .await
+        .expect("call should succeed");
+    let retried = completions
+        .chat()
+        .create_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("call should succeed");
+    assert_eq!(retried.cache.status, CacheStatus::Miss);
+
+    let failed = streams
+        .chat()
+        .stream_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("stream should start");
+    assert_eq!(stream_text(failed.response).await, "2 + 2");
+    let retried = streams
+        .chat()
+        .stream_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("stream should start");
+    assert_eq!(retried.cache.status, CacheStatus::Miss);
+    assert_eq!(stream_text(retried.response).await, "4");
+    server.join().expect("server thread should finish");
+    assert_eq!(rx.try_iter().count(), 4);
+}
+
+#[tokio::test]
+async fn test_expired_entries_are_misses() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![completion("4"), completion("4")]);
+    let client = client(
+        base_url,
+        ResponseCache::memory(16).ttl(Duration::from_millis(20)),
+    );
+
+    client
+        .chat()
+        .create(&request())
+        .await
+        .expect("call should succeed");
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    let cached = client
+        .chat()
+        .create_with_cache(&request(), CacheMode::Use)
+        .await
+        .expect("call should succeed");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(cached.cache.status, CacheStatus::Miss);
+    assert_eq!(rx.try_iter().count(), 2);
+}
+
+#[tokio::test]
+async fn test_memory_cache_evicts_least_recently_used() {
+    let cache = MemoryCache::new(2);
+    cache
+        .put("a", CacheEntry::new(json!(1), None))
+        .await
+        .expect("put should succeed");
+    cache
+        .put("b", CacheEntry::new(json!(2), None))
+        .await
+        .expect("put should succeed");
+    cache.get("a").await.expect("get should succeed");
+    cache
+        .put("c", CacheEntry::new(json!(3), None))
+        .await
+        .expect("put should succeed");
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("a").await.expect("get should succeed").is_some());
+    assert!(cache.get("b").await.expect("get should succeed").is_none());
+    assert!(cache.get("c").await.expect("get should succeed").is_some());
+}
+
+#[tokio::test]
+async fn test_disk_cache_persists_entries() {
+    let dir = std::env::temp_dir().join(format!("openrouter-rs-cache-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+
+    DiskCache::new(&dir)
+        .put("abc123", CacheEntry::new(json!({"answer": 4}), None))
+        .await
+        .expect("put should succeed");
+
+    let store = DiskCache::new(&dir);
+    let entry = store
+        .get("abc123")
+        .await
+        .expect("get should succeed")
+        .expect("entry should persist");
+    assert_eq!(entry.value, json!({"answer": 4}));
+    assert!(!entry.is_expired());
+
+    store.remove("abc123").await.expect("remove should succeed");
+    assert!(
+        store
+            .get("abc123")
+            .await
+            .expect("get should succeed")
+            .is_none()
+    );
+    store
+        .remove("abc123")
+        .await
+        .expect("removing twice is fine");
+    let leftovers: Vec<Value> = std::fs::read_dir(&dir)
+        .expect("dir should exist")
+        .map(|entry| {
+            Value::from(
+                entry
+                    .expect("entry")
+                    .file_name()
+                    .to_string_lossy()
+                    .to_string(),
+            )
+        })
+        .collect();
+    assert!(leftovers.is_empty(), "unexpected files: {leftovers:?}");
+
+    std::fs::remove_dir_all(&dir).ok();
+}
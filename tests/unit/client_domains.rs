@@ -624,7 +624,7 @@ async fn test_management_domain_remaining_methods_require_configured_key() {
         .expect("chat preset request should build");
     let response_preset_request = responses::ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(serde_json::json!("hello"))
+        .input(serde_json::json!("hello").into())
         .build()
         .expect("response preset request should build");
     let message_preset_request = messages::AnthropicMessagesRequest::builder()
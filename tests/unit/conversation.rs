@@ -0,0 +1,222 @@
+use openrouter_rs::{
+    OpenRouterClient,
+    api::{chat::Message, models::Model},
+    conversation::{Conversation, TrimStrategy},
+    error::OpenRouterError,
+    estimate::EstimateTokens,
+    types::{CompletionsResponse, Role, Tool},
+};
+use serde_json::{Value, json};
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+/// A message of roughly 100 estimated tokens.
+fn long_text(tag: &str) -> String {
+    format!("{tag} {}", "x".repeat(400 - tag.len() - 1))
+}
+
+fn completion(content: &str, tool_calls: Option<Value>) -> Value {
+    let mut message = json!({"role": "assistant", "content": content});
+    if let Some(tool_calls) = tool_calls {
+        message["tool_calls"] = tool_calls;
+    }
+    json!({
+        "id": "gen-1",
+        "choices": [{"index": 0, "finish_reason": "stop", "message": message}],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion",
+        "usage": {"prompt_tokens": 120, "completion_tokens": 30, "total_tokens": 150, "cost": 0.002}
+    })
+}
+
+fn record(conversation: &mut Conversation, response: Value) {
+    let response: CompletionsResponse =
+        serde_json::from_value(response).expect("completion should deserialize");
+    conversation.record_response(&response);
+}
+
+fn contents(conversation: &Conversation) -> Vec<String> {
+    conversation
+        .messages()
+        .iter()
+        .map(|message| {
+            let text = serde_json::to_value(&message.content).expect("content should serialize");
+            text.as_str().unwrap_or_default().chars().take(3).collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_conversation_records_responses_and_round_trips_json() {
+    let mut conversation = Conversation::new("openai/gpt-4.1-mini").with_system_prompt("Be brief.");
+    conversation.push_user("Weather in Paris?");
+    record(
+        &mut conversation,
+        completion(
+            "",
+            Some(json!([{
+                "id": "call_1",
+                "type": "function",
+                "function": {"name": "get_weather", "arguments": "{\"location\":\"Paris\"}"}
+            }])),
+        ),
+    );
+    conversation.push(Message::tool_response_named(
+        "call_1",
+        "get_weather",
+        "Sunny",
+    ));
+    record(&mut conversation, completion("It is sunny.", None));
+
+    assert_eq!(conversation.len(), 4);
+    assert_eq!(conversation.usage().requests, 2);
+    assert_eq!(conversation.usage().total_tokens, 300);
+    assert!((conversation.usage().cost - 0.004).abs() < 1e-9);
+
+    let request = serde_json::to_value(conversation.to_request().expect("request should build"))
+        .expect("request should serialize");
+    assert_eq!(request["model"], "openai/gpt-4.1-mini");
+    assert_eq!(request["messages"][0]["role"], "system");
+    assert_eq!(request["messages"][2]["tool_calls"][0]["id"], "call_1");
+    assert_eq!(request["messages"][3]["tool_call_id"], "call_1");
+
+    let saved = serde_json::to_string(&conversation).expect("conversation should serialize");
+    let restored: Conversation = serde_json::from_str(&saved).expect("conversation should load");
+    assert_eq!(restored.len(), 4);
+    assert_eq!(restored.system_prompt(), Some("Be brief."));
+    assert_eq!(restored.usage(), conversation.usage());
+}
+
+#[test]
+fn test_conversation_trims_oldest_turns_and_respects_pins() {
+    let build = |strategy: TrimStrategy| {
+        let mut conversation = Conversation::new("openai/gpt-4.1-mini")
+            .with_context_length(400)
+            .with_reserved_completion_tokens(50)
+            .with_strategy(strategy);
+        conversation.push_pinned(Message::new(Role::User, long_text("u1")));
+        conversation.push(Message::new(Role::Assistant, long_text("a1")));
+        conversation.push_user(long_text("u2"));
+        conversation.push(Message::new(Role::Assistant, long_text("a2")));
+        conversation.push_user(long_text("u3"));
+        conversation
+    };
+
+    let mut keep_pinned = build(TrimStrategy::KeepPinned);
+    assert!(keep_pinned.estimated_tokens() > 350);
+    keep_pinned.to_request().expect("history should be trimmed");
+    assert_eq!(contents(&keep_pinned), vec!["u1 ", "a1 ", "u3 "]);
+    assert!(keep_pinned.estimated_tokens() <= 350);
+
+    let mut drop_oldest = build(TrimStrategy::DropOldest);
+    drop_oldest.to_request().expect("history should be trimmed");
+    assert_eq!(contents(&drop_oldest), vec!["u2 ", "a2 ", "u3 "]);
+
+    let mut too_small = build(TrimStrategy::KeepPinned).with_context_length(150);
+    match too_small.to_request() {
+        Err(OpenRouterError::ConfigError(message)) => assert!(message.contains("budget")),
+        other => panic!("expected budget error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_conversation_estimate_matches_request_estimate() {
+    let mut conversation = Conversation::new("openai/gpt-4.1-mini")
+        .with_system_prompt("You are terse.")
+        .with_tools(vec![Tool::new(
+            "lookup",
+            "Look up an object",
+            json!({"type": "object", "properties": {"name": {"type": "string"}}}),
+        )]);
+    conversation.push_user("What is the capital of France?");
+
+    let estimated = conversation.estimated_tokens();
+    let request = conversation.to_request().expect("request should build");
+    let tokens = request.estimate_tokens();
+    assert!(tokens.tool_tokens > 0);
+    assert_eq!(estimated, tokens.prompt_tokens());
+}
+
+#[test]
+fn test_conversation_takes_context_length_from_model() {
+    let model: Model = serde_json::from_value(json!({
+        "id": "openai/gpt-4.1-mini",
+        "name": "GPT-4.1 Mini",
+        "created": 1735689600,
+        "context_length": 1047576,
+        "architecture": {"modality": "text->text", "tokenizer": "GPT", "instruct_type": null},
+        "top_provider": {"context_length": 1047576, "max_completion_tokens": 32768, "is_moderated": true},
+        "pricing": {"prompt": "0.0000004", "completion": "0.0000016"},
+        "per_request_limits": null
+    }))
+    .expect("model should deserialize");
+
+    let conversation = Conversation::new(model.id.clone())
+        .with_model_limits(&model)
+        .with_reserved_completion_tokens(32768);
+    assert_eq!(conversation.prompt_budget(), Some(1047576 - 32768));
+}
+
+#[tokio::test]
+async fn test_conversation_summarizes_older_turns_through_client() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![Scripted::json(
+        200,
+        &completion("User asked about u1 and u2.", None),
+    )]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .build()
+        .expect("client should build");
+
+    let mut conversation = Conversation::new("openai/gpt-4.1-mini")
+        .with_system_prompt("Be brief.")
+        .with_context_length(350)
+        .with_strategy(TrimStrategy::Summarize {
+            model: "openai/gpt-4.1-nano".to_string(),
+            keep_recent: 1,
+        });
+    conversation.push_user(long_text("u1"));
+    conversation.push(Message::new(Role::Assistant, long_text("a1")));
+    conversation.push_user(long_text("u2"));
+    conversation.push_user(long_text("u3"));
+
+    match conversation.clone().to_request() {
+        Err(OpenRouterError::ConfigError(message)) => {
+            assert!(message.contains("to_request_with_client"))
+        }
+        other => panic!("expected client requirement, got {other:?}"),
+    }
+
+    let request = conversation
+        .to_request_with_client(&client)
+        .await
+        .expect("summarized request should build");
+    server.join().expect("server thread should finish");
+
+    let summary_request = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("summary request should be captured");
+    assert_eq!(summary_request["model"], "openai/gpt-4.1-nano");
+    let transcript = summary_request["messages"][1]["content"]
+        .as_str()
+        .expect("transcript should be text");
+    assert!(transcript.contains("user: u1"));
+    assert!(transcript.contains("assistant: a1"));
+    assert!(!transcript.contains("u3"));
+
+    assert_eq!(conversation.summary(), Some("User asked about u1 and u2."));
+    assert_eq!(conversation.len(), 1);
+    assert_eq!(conversation.usage().requests, 1);
+    let request = serde_json::to_value(request).expect("request should serialize");
+    assert_eq!(request["model"], "openai/gpt-4.1-mini");
+    assert_eq!(request["messages"].as_array().map(Vec::len), Some(3));
+    assert!(
+        request["messages"][1]["content"]
+            .as_str()
+            .expect("summary should be text")
+            .ends_with("User asked about u1 and u2.")
+    );
+}
@@ -0,0 +1,398 @@
+use openrouter_rs::{
+    api::{
+        chat::{ChatCompletionRequest, ContentPart, Message},
+        messages::{AnthropicMessagesRequest, AnthropicMessagesResponse},
+        responses::{ResponsesRequest, ResponsesResponse},
+    },
+    convert::Converted,
+    error::OpenRouterError,
+    types::{
+        Effort, ReasoningConfig, ResponseFormat, Role, Tool, ToolCall,
+        completion::CompletionsResponse,
+    },
+};
+use serde_json::{Value, json};
+
+fn weather_tool() -> Tool {
+    Tool::new(
+        "get_weather",
+        "Look up the weather",
+        json!({"type": "object", "properties": {"city": {"type": "string"}}}),
+    )
+}
+
+fn conversation() -> Vec<Message> {
+    vec![
+        Message::new(Role::System, "You are terse."),
+        Message::with_parts(
+            Role::User,
+            vec![
+                ContentPart::text("What is in this picture, and what's the weather?"),
+                ContentPart::image_url("data:image/png;base64,iVBORw0KGgo="),
+                ContentPart::file_id_with_filename("file-123", "notes.pdf"),
+            ],
+        ),
+        Message::assistant_with_tool_calls(
+            "",
+            vec![ToolCall::new(
+                "call_1",
+                "get_weather",
+                r#"{"city":"Paris"}"#,
+            )],
+        ),
+        Message::tool_response("call_1", "Sunny, 21C"),
+        Message::new(Role::Assistant, "A cat. Paris is sunny."),
+    ]
+}
+
+fn to_json(value: &impl serde::Serialize) -> Value {
+    serde_json::to_value(value).expect("value should serialize")
+}
+
+#[test]
+fn test_chat_request_round_trips_through_responses() {
+    let request = ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1")
+        .messages(conversation())
+        .max_completion_tokens(256)
+        .temperature(0.2)
+        .tool(weather_tool())
+        .tool_choice_required()
+        .parallel_tool_calls(false)
+        .response_format(ResponseFormat::json_schema(
+            "answer",
+            true,
+            json!({"type": "object"}),
+        ))
+        .reasoning(ReasoningConfig::with_effort(Effort::High).max_tokens(512))
+        .build()
+        .expect("request should build");
+
+    let converted = Converted::<ResponsesRequest>::try_from(&request).expect("should convert");
+    assert!(converted.report.is_lossless(), "{}", converted.report);
+    let body = to_json(&converted.value);
+    assert_eq!(body["max_output_tokens"], 256);
+    assert_eq!(body["parallel_tool_calls"], false);
+    assert_eq!(body["tool_choice"], "required");
+    assert_eq!(body["tools"][0]["type"], "function");
+    assert_eq!(body["tools"][0]["name"], "get_weather");
+    assert_eq!(
+        body["text"]["format"],
+        json!({"type": "json_schema", "name": "answer", "schema": {"type": "object"}, "strict": true})
+    );
+    assert_eq!(
+        body["reasoning"],
+        json!({"effort": "high", "max_tokens": 512})
+    );
+    assert_eq!(
+        body["input"],
+        json!([
+            {"type": "message", "role": "system", "content": "You are terse."},
+            {"type": "message", "role": "user", "content": [
+                {"type": "input_text", "text": "What is in this picture, and what's the weather?"},
+                {"type": "input_image", "image_url": "data:image/png;base64,iVBORw0KGgo="},
+                {"type": "input_file", "file_id": "file-123", "filename": "notes.pdf"}
+            ]},
+            {"type": "function_call", "call_id": "call_1", "name": "get_weather", "arguments": "{\"city\":\"Paris\"}"},
+            {"type": "function_call_output", "call_id": "call_1", "output": "Sunny, 21C"},
+            {"type": "message", "role": "assistant", "content": "A cat. Paris is sunny."}
+        ])
+    );
+
+    let back = Converted::<ChatCompletionRequest>::try_from(&converted.value)
+        .expect("should convert back");
+    assert!(back.report.is_lossless(), "{}", back.report);
+    let mut expected = to_json(&request);
+    expected["max_tokens"] = expected["max_completion_tokens"].take();
+    expected
+        .as_object_mut()
+        .unwrap()
+        .remove("max_completion_tokens");
+    assert_eq!(to_json(&back.value), expected);
+}
+
+#[test]
+fn test_chat_request_round_trips_through_messages() {
+    let mut messages = conversation();
+    messages[0] = Message::with_parts(
+        Role::System,
+        vec![ContentPart::cacheable_text("You are terse.")],
+    );
+    let request = ChatCompletionRequest::builder()
+        .model("anthropic/claude-sonnet-4")
+        .messages(messages)
+        .max_tokens(1024)
+        .stop(vec!["END".to_string()])
+        .tool(weather_tool())
+        .force_tool("get_weather")
+        .parallel_tool_calls(false)
+        .reasoning_max_tokens(2048)
+        .metadata([("user_id", "u-1"), ("team", "search")])
+        .build()
+        .expect("request should build");
+
+    let converted =
+        Converted::<AnthropicMessagesRequest>::try_from(&request).expect("should convert");
+    assert!(converted.report.is_lossless(), "{}", converted.report);
+    let body = to_json(&converted.value);
+    assert_eq!(
+        body["system"],
+        json!([{"type": "text", "text": "You are terse.", "cache_control": {"type": "ephemeral"}}])
+    );
+    assert_eq!(body["stop_sequences"], json!(["END"]));
+    assert_eq!(
+        body["metadata"],
+        json!({"user_id": "u-1", "team": "search"})
+    );
+    assert_eq!(
+        body["tool_choice"],
+        json!({"type": "tool", "name": "get_weather", "disable_parallel_tool_use": true})
+    );
+    assert_eq!(
+        body["thinking"],
+        json!({"type": "enabled", "budget_tokens": 2048})
+    );
+    assert_eq!(
+        body["messages"][0]["content"][1]["source"],
+        json!({"type": "base64", "media_type": "image/png", "data": "iVBORw0KGgo="})
+    );
+    assert_eq!(
+        body["messages"][0]["content"][2],
+        json!({"type": "document", "source": {"type": "file", "file_id": "file-123"}, "title": "notes.pdf"})
+    );
+    assert_eq!(
+        body["messages"][1]["content"],
+        json!([{"type": "tool_use", "id": "call_1", "name": "get_weather", "input": {"city": "Paris"}}])
+    );
+    assert_eq!(
+        body["messages"][2],
+        json!({"role": "user", "content": [{"type": "tool_result", "tool_use_id": "call_1", "content": "Sunny, 21C"}]})
+    );
+
+    let back = Converted::<ChatCompletionRequest>::try_from(&converted.value)
+        .expect("should convert back");
+    assert!(back.report.is_lossless(), "{}", back.report);
+    assert_eq!(to_json(&back.value), to_json(&request));
+}
+
+#[test]
+fn test_conversion_reports_unmapped_fields() {
+    let request = ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1")
+        .messages(vec![
+            Message::new(Role::System, "Rules"),
+            Message::with_parts(
+                Role::User,
+                vec![
+                    ContentPart::cacheable_text("Long context"),
+                    ContentPart::input_audio("UklGRg==", "wav"),
+                ],
+            ),
+        ])
+        .max_tokens(100)
+        .seed(7)
+        .exclude_reasoning()
+        .build()
+        .expect("request should build");
+
+    let converted = Converted::<ResponsesRequest>::try_from(&request).expect("should convert");
+    let report = &converted.report;
+    assert!(report.contains("messages[1].content[0].cache_control"));
+    assert!(report.contains("messages[1].content[1]"));
+    assert!(report.contains("reasoning.exclude"));
+    assert!(report.contains("seed"));
+    assert_eq!(report.unmapped().len(), 4);
+    assert!(converted.clone().into_lossless().is_err());
+
+    let messages =
+        Converted::<AnthropicMessagesRequest>::try_from(&request).expect("should convert");
+    assert!(
+        !messages
+            .report
+            .contains("messages[1].content[0].cache_control")
+    );
+    assert!(messages.report.contains("messages[1].content[1]"));
+
+    let no_limit = ChatCompletionRequest::new("anthropic/claude-sonnet-4", conversation());
+    assert!(matches!(
+        AnthropicMessagesRequest::try_from(&no_limit),
+        Err(OpenRouterError::ConfigError(_))
+    ));
+    let no_model = ResponsesRequest::builder()
+        .input("hello".into())
+        .build()
+        .expect("request should build");
+    assert!(matches!(
+        ChatCompletionRequest::try_from(&no_model),
+        Err(OpenRouterError::ConfigError(_))
+    ));
+}
+
+#[test]
+fn test_responses_request_converts_to_messages() {
+    let request: ResponsesRequest = serde_json::from_value(json!({
+        "model": "anthropic/claude-sonnet-4",
+        "instructions": "Be brief.",
+        "input": [
+            {"type": "message", "role": "user", "content": "Weather in Paris?"},
+            {"type": "function_call", "call_id": "call_1", "name": "get_weather", "arguments": "{\"city\":\"Paris\"}"},
+            {"type": "function_call_output", "call_id": "call_1", "output": "Sunny"}
+        ],
+        "tools": [
+            {"type": "function", "name": "get_weather", "parameters": {"type": "object"}},
+            {"type": "web_search"}
+        ],
+        "max_output_tokens": 300,
+        "reasoning": {"effort": "low", "summary": "auto"},
+        "store": false
+    }))
+    .expect("request should deserialize");
+
+    let converted =
+        Converted::<AnthropicMessagesRequest>::try_from(&request).expect("should convert");
+    let body = to_json(&converted.value);
+    assert_eq!(body["system"], "Be brief.");
+    assert_eq!(body["max_tokens"], 300);
+    assert_eq!(body["messages"][1]["content"][0]["type"], "tool_use");
+    assert_eq!(body["messages"][2]["content"][0]["type"], "tool_result");
+    assert_eq!(body["tools"].as_array().map(Vec::len), Some(1));
+    assert_eq!(body["output_config"], json!({"effort": "low"}));
+    assert_eq!(body["thinking"], json!({"type": "adaptive"}));
+    for path in ["tools[1]", "reasoning.summary", "store"] {
+        assert!(
+            converted.report.contains(path),
+            "missing {path}: {}",
+            converted.report
+        );
+    }
+
+    let back = ResponsesRequest::try_from(&converted.value).expect("should convert back");
+    let back = to_json(&back);
+    assert_eq!(back["reasoning"], json!({"effort": "low"}));
+    assert_eq!(
+        back["input"][0],
+        json!({"type": "message", "role": "system", "content": "Be brief."})
+    );
+    assert_eq!(back["input"][2]["type"], "function_call");
+}
+
+fn chat_response() -> CompletionsResponse {
+    serde_json::from_value(json!({
+        "id": "gen-1",
+        "created": 1700000000,
+        "model": "anthropic/claude-sonnet-4",
+        "object": "chat.completion",
+        "provider": "Anthropic",
+        "choices": [{
+            "index": 0,
+            "finish_reason": "tool_calls",
+            "native_finish_reason": "tool_use",
+            "message": {
+                "role": "assistant",
+                "content": "Checking.",
+                "reasoning": "Need the weather.",
+                "reasoning_details": [{"type": "reasoning.text", "text": "Need the weather.", "signature": "sig"}],
+                "tool_calls": [{"id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": "{\"city\":\"Paris\"}"}}]
+            }
+        }],
+        "usage": {"prompt_tokens": 12, "completion_tokens": 8, "total_tokens": 20, "cost": 0.001}
+    }))
+    .expect("response should deserialize")
+}
+
+#[test]
+fn test_chat_response_converts_to_responses_and_back() {
+    let converted =
+        Converted::<ResponsesResponse>::try_from(&chat_response()).expect("should convert");
+    assert!(converted.report.contains("provider"));
+    let response = &converted.value;
+    assert_eq!(response.status.as_deref(), Some("completed"));
+    assert_eq!(response.output_text().as_deref(), Some("Checking."));
+    assert_eq!(
+        response
+            .function_calls()
+            .next()
+            .map(|call| call.call_id.as_str()),
+        Some("call_1")
+    );
+    let body = to_json(response);
+    assert_eq!(
+        body["output"][0],
+        json!({"type": "reasoning", "summary": [], "content": [{"type": "reasoning_text", "text": "Need the weather."}], "signature": "sig"})
+    );
+    assert_eq!(
+        body["usage"],
+        json!({"input_tokens": 12, "output_tokens": 8, "total_tokens": 20, "cost": 0.001})
+    );
+
+    let back = Converted::<CompletionsResponse>::try_from(response).expect("should convert back");
+    assert!(back.report.is_lossless(), "{}", back.report);
+    let choice = &back.value.choices[0];
+    assert_eq!(choice.content(), Some("Checking."));
+    assert_eq!(choice.reasoning(), Some("Need the weather."));
+    assert_eq!(
+        choice.reasoning_details().unwrap()[0].signature.as_deref(),
+        Some("sig")
+    );
+    assert_eq!(choice.tool_calls().unwrap()[0].function.name, "get_weather");
+    assert_eq!(format!("{:?}", choice.finish_reason()), "Some(ToolCalls)");
+    let usage = back.value.usage.expect("usage should convert");
+    assert_eq!(
+        (usage.prompt_tokens, usage.total_tokens, usage.cost),
+        (12, 20, Some(0.001))
+    );
+}
+
+#[test]
+fn test_messages_response_converts_to_chat_and_responses() {
+    let converted = AnthropicMessagesResponse::try_from(&chat_response()).expect("should convert");
+    let body = to_json(&converted);
+    assert_eq!(body["stop_reason"], "tool_use");
+    assert_eq!(
+        body["content"],
+        json!([
+            {"type": "thinking", "thinking": "Need the weather.", "signature": "sig"},
+            {"type": "text", "text": "Checking."},
+            {"type": "tool_use", "id": "call_1", "name": "get_weather", "input": {"city": "Paris"}}
+        ])
+    );
+    assert_eq!(
+        body["usage"],
+        json!({"input_tokens": 12, "output_tokens": 8, "cost": 0.001})
+    );
+
+    let message: AnthropicMessagesResponse = serde_json::from_value(json!({
+        "id": "msg_1",
+        "type": "message",
+        "role": "assistant",
+        "model": "anthropic/claude-sonnet-4",
+        "content": [
+            {"type": "redacted_thinking", "data": "opaque"},
+            {"type": "text", "text": "Done."}
+        ],
+        "stop_reason": "max_tokens",
+        "usage": {"input_tokens": 5, "output_tokens": 3, "cache_read_input_tokens": 100}
+    }))
+    .expect("message should deserialize");
+    let chat = Converted::<CompletionsResponse>::try_from(&message).expect("should convert");
+    assert!(chat.report.contains("usage.cache_read_input_tokens"));
+    let choice = &chat.value.choices[0];
+    assert_eq!(choice.content(), Some("Done."));
+    assert_eq!(choice.native_finish_reason(), Some("max_tokens"));
+    assert_eq!(
+        choice.reasoning_details().unwrap()[0].data.as_deref(),
+        Some("opaque")
+    );
+    assert_eq!(
+        chat.value.usage.as_ref().map(|u| u.prompt_tokens),
+        Some(105)
+    );
+
+    let responses = ResponsesResponse::try_from(&message).expect("should convert");
+    assert_eq!(responses.status.as_deref(), Some("incomplete"));
+    assert_eq!(responses.output_text().as_deref(), Some("Done."));
+    assert_eq!(
+        to_json(&responses)["output"][0],
+        json!({"type": "reasoning", "summary": [], "encrypted_content": "opaque"})
+    );
+}
@@ -1,5 +1,7 @@
-use openrouter_rs::OpenRouterClient;
-use std::time::Duration;
+use openrouter_rs::{OpenRouterClient, api::credits};
+use std::{sync::atomic::Ordering, time::Duration};
+
+use crate::support::spawn_keep_alive_server;
 
 #[test]
 fn test_builder_accepts_custom_http_client() {
@@ -25,3 +27,26 @@ fn test_builder_falls_back_to_default_http_client_when_omitted() {
         "builder should use default http_client when none provided"
     );
 }
+
+#[tokio::test]
+async fn test_free_functions_and_default_clients_share_connection_pool() {
+    let (base_url, connections) = spawn_keep_alive_server().await;
+
+    for _ in 0..3 {
+        let credits = credits::get_credits(&base_url, "test-api-key")
+            .await
+            .expect("credits request should succeed");
+        assert_eq!(credits.total_credits, 10.0);
+    }
+    let client = OpenRouterClient::builder()
+        .base_url(base_url.clone())
+        .api_key("test-api-key")
+        .build()
+        .expect("client should build");
+    client
+        .get_credits()
+        .await
+        .expect("credits request should succeed");
+
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
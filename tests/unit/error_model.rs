@@ -7,7 +7,7 @@ use std::{
 
 use openrouter_rs::{
     api::models,
-    error::{ApiErrorKind, HttpRequestError, OpenRouterError},
+    error::{ApiErrorKind, HttpRequestError, OpenRouterError, TimeoutError, TimeoutKind},
 };
 
 fn spawn_error_server(
@@ -384,4 +384,22 @@ fn test_http_request_error_is_backend_neutral() {
 
     assert_eq!(error.message(), "connection refused");
     assert_eq!(error.to_string(), "connection refused");
+    assert!(!error.is_connect());
+
+    let error = HttpRequestError::connect("connection refused");
+    assert!(error.is_connect());
+    assert_eq!(error.to_string(), "connection refused");
+}
+
+#[test]
+fn test_timeout_error_reports_kind_and_duration() {
+    let error = OpenRouterError::Timeout(TimeoutError::new(
+        TimeoutKind::StreamIdle,
+        Some(std::time::Duration::from_secs(30)),
+    ));
+
+    assert_eq!(
+        error.to_string(),
+        "Timeout: stream idle timeout elapsed after 30s"
+    );
 }
@@ -0,0 +1,177 @@
+use openrouter_rs::{
+    api::{
+        chat::{ChatCompletionRequest, ContentPart, Message},
+        messages::{
+            AnthropicContentPart, AnthropicMessage, AnthropicMessagesRequest, AnthropicRole,
+        },
+        models::{Endpoint, Model},
+        responses::ResponsesRequest,
+    },
+    error::OpenRouterError,
+    estimate::{
+        EstimateTokens, HIGH_DETAIL_IMAGE_TOKENS, LOW_DETAIL_IMAGE_TOKENS, estimate_cost,
+        estimate_endpoint_cost,
+    },
+    types::{Role, Tool},
+};
+use serde_json::{Value, json};
+
+fn model(pricing: Value, max_completion_tokens: Value) -> Model {
+    serde_json::from_value(json!({
+        "id": "openai/gpt-4.1-mini",
+        "name": "GPT-4.1 Mini",
+        "created": 1735689600,
+        "context_length": 8000,
+        "architecture": {"modality": "text+image->text", "tokenizer": "GPT", "instruct_type": null},
+        "top_provider": {"context_length": 8000, "max_completion_tokens": max_completion_tokens, "is_moderated": true},
+        "pricing": pricing,
+        "per_request_limits": null
+    }))
+    .expect("model should deserialize")
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-12,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_estimate_chat_request_counts_text_images_and_tools() {
+    let request = ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![
+            Message::new(Role::System, "You are terse."),
+            Message::with_parts(
+                Role::User,
+                vec![
+                    ContentPart::text("What is in this image?"),
+                    ContentPart::image_url_with_detail("https://example.com/a.png", "low"),
+                    ContentPart::image_url("https://example.com/b.png"),
+                ],
+            ),
+        ])
+        .tool(Tool::new(
+            "lookup",
+            "Look up an object",
+            json!({"type": "object", "properties": {"name": {"type": "string"}}}),
+        ))
+        .max_tokens(100)
+        .build()
+        .expect("request should build");
+
+    let tokens = request.estimate_tokens();
+    // (4 + ceil(14 / 4)) + (4 + ceil(22 / 4))
+    assert_eq!(tokens.text_tokens, 8 + 10);
+    assert_eq!(tokens.images, 2);
+    assert_eq!(
+        tokens.image_tokens,
+        LOW_DETAIL_IMAGE_TOKENS + HIGH_DETAIL_IMAGE_TOKENS
+    );
+    assert!(tokens.tool_tokens > 10);
+    assert_eq!(tokens.unestimated_parts, 0);
+    assert_eq!(tokens.max_output_tokens, Some(100));
+
+    let model = model(
+        json!({"prompt": "0.000001", "completion": "0.000002", "image": "0.001", "request": "0"}),
+        json!(4096),
+    );
+    let estimate = estimate_cost(&request, &model).expect("pricing is fixed");
+    let min = f64::from(tokens.prompt_tokens()) * 0.000001 + 2.0 * 0.001;
+    assert_eq!(estimate.max_completion_tokens, Some(100));
+    assert_close(estimate.min_cost, min);
+    assert_close(
+        estimate.max_cost.expect("limit is known"),
+        min + 100.0 * 0.000002,
+    );
+}
+
+#[test]
+fn test_estimate_responses_and_messages_requests() {
+    let responses = ResponsesRequest::new(
+        "openai/gpt-4.1-mini",
+        json!([{
+            "type": "message",
+            "role": "user",
+            "content": [
+                {"type": "input_text", "text": "Compare these"},
+                {"type": "input_image", "image_url": "https://example.com/a.png", "detail": "high"},
+                {"type": "input_file", "file_id": "file_1"}
+            ]
+        }]),
+    );
+    let tokens = responses.estimate_tokens();
+    assert_eq!(tokens.text_tokens, 4 + 4);
+    assert_eq!(tokens.image_tokens, HIGH_DETAIL_IMAGE_TOKENS);
+    assert_eq!(tokens.unestimated_parts, 1);
+    assert_eq!(tokens.max_output_tokens, None);
+
+    // Without a request limit the model's completion limit bounds the cost.
+    let model = model(
+        json!({"prompt": "0.000001", "completion": "0.000002"}),
+        json!(1000),
+    );
+    let estimate = estimate_cost(&responses, &model).expect("pricing is fixed");
+    assert_eq!(estimate.max_completion_tokens, Some(1000));
+
+    let messages = AnthropicMessagesRequest::new(
+        "anthropic/claude-sonnet-4",
+        256,
+        vec![
+            AnthropicMessage::user("Weather?"),
+            AnthropicMessage::with_parts(
+                AnthropicRole::Assistant,
+                vec![AnthropicContentPart::tool_use(
+                    "toolu_1",
+                    "get_weather",
+                    json!({"city": "Oslo"}),
+                )],
+            ),
+            AnthropicMessage::user(vec![AnthropicContentPart::tool_result("toolu_1", "Sunny")]),
+        ],
+    );
+    let tokens = messages.estimate_tokens();
+    // user: 4 + 2; tool_use: 4 + ceil(11 / 4) + ceil(15 / 4); tool_result: 4 + 2
+    assert_eq!(tokens.text_tokens, 6 + 11 + 6);
+    assert_eq!(tokens.max_output_tokens, Some(256));
+}
+
+#[test]
+fn test_estimate_endpoint_cost_falls_back_to_remaining_context() {
+    let endpoint: Endpoint = serde_json::from_value(json!({
+        "name": "OpenAI | gpt-4.1-mini",
+        "context_length": 1000,
+        "pricing": {"prompt": "0.000001", "completion": "0.000002", "request": "0.0005"},
+        "provider_name": "OpenAI",
+        "supported_parameters": [],
+        "quantization": null,
+        "max_completion_tokens": null,
+        "max_prompt_tokens": null,
+        "status": 0
+    }))
+    .expect("endpoint should deserialize");
+    let request = ResponsesRequest::new("openai/gpt-4.1-mini", json!("Hello there"));
+
+    let estimate = estimate_endpoint_cost(&request, &endpoint).expect("pricing is fixed");
+    let prompt = estimate.tokens.prompt_tokens();
+    assert_eq!(prompt, 4 + 3);
+    assert_eq!(estimate.max_completion_tokens, Some(1000 - prompt));
+    assert_close(estimate.min_cost, f64::from(prompt) * 0.000001 + 0.0005);
+    assert!(estimate.ensure_within(1.0).is_ok());
+    match estimate.ensure_within(0.001) {
+        Err(OpenRouterError::ConfigError(message)) => assert!(message.contains("exceeds")),
+        other => panic!("expected cap error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_estimate_cost_rejects_variable_pricing() {
+    let router = model(json!({"prompt": "-1", "completion": "-1"}), Value::Null);
+    let request = ResponsesRequest::new("openrouter/auto", json!("Hi"));
+
+    match estimate_cost(&request, &router) {
+        Err(OpenRouterError::ConfigError(message)) => assert!(message.contains("prompt price")),
+        other => panic!("expected pricing error, got {other:?}"),
+    }
+}
@@ -1,12 +1,18 @@
 use std::{
     io::{Read, Write},
     net::TcpListener,
-    sync::mpsc,
+    sync::{Arc, Mutex, mpsc},
     thread,
     time::Duration,
 };
 
-use openrouter_rs::api::files::{self, FileDeleteResponse, FileListResponse, FileMetadata};
+use bytes::Bytes;
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::files::{self, FileDeleteResponse, FileListResponse, FileMetadata},
+    client::Timeouts,
+};
 
 struct CapturedRequest {
     request_line: String,
@@ -65,8 +71,16 @@ fn spawn_server(
             })
             .unwrap_or(0);
 
+        let chunked = header_text
+            .to_ascii_lowercase()
+            .contains("transfer-encoding: chunked");
+
         let mut body_bytes = request_bytes[header_end..].to_vec();
-        while body_bytes.len() < content_length {
+        while if chunked {
+            !body_bytes.ends_with(b"0\r\n\r\n")
+        } else {
+            body_bytes.len() < content_length
+        } {
             let read = stream
                 .read(&mut chunk)
                 .expect("server should read request body");
@@ -75,8 +89,12 @@ fn spawn_server(
             }
             body_bytes.extend_from_slice(&chunk[..read]);
         }
+        if !chunked {
+            body_bytes.truncate(content_length);
+        }
 
-        let body_text = String::from_utf8_lossy(&body_bytes[..content_length]).to_string();
+        // Chunked bodies keep their chunk framing; tests only look for substrings.
+        let body_text = String::from_utf8_lossy(&body_bytes).to_string();
         let request_text = format!("{header_text}{body_text}");
         tx.send(CapturedRequest {
             request_line,
@@ -263,3 +281,140 @@ async fn test_upload_file_uses_multipart_file_part() {
 
     server.join().expect("server thread should finish");
 }
+
+#[tokio::test]
+async fn test_upload_from_path_streams_file_with_length_and_progress() {
+    let path =
+        std::env::temp_dir().join(format!("openrouter-rs-upload-{}.txt", std::process::id()));
+    std::fs::write(&path, b"streamed file body").expect("temp file should be written");
+
+    let (base_url, rx, server) = spawn_server(file_metadata_json().as_bytes(), "application/json");
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let seen = progress.clone();
+    let upload = files::StreamingUpload::from_path(&path)
+        .await
+        .expect("upload should open the file")
+        .mime_type("text/plain")
+        .on_progress(move |progress| seen.lock().unwrap().push(progress));
+
+    let uploaded = files::upload_file_stream(&base_url, "api-key", upload, None)
+        .await
+        .expect("streaming upload should succeed");
+    assert_eq!(uploaded.id, "file_123");
+    std::fs::remove_file(&path).ok();
+
+    let captured = rx
+        .recv_timeout(Duration::from_secs(2))
+        .expect("should capture upload request");
+    assert_eq!(captured.request_line, "POST /api/v1/files HTTP/1.1");
+    let filename = path.file_name().unwrap().to_string_lossy();
+    assert!(
+        captured
+            .body_text
+            .contains(&format!(r#"filename="{filename}""#))
+    );
+    assert!(captured.body_text.contains("Content-Type: text/plain"));
+    assert!(captured.body_text.contains("streamed file body"));
+
+    let progress = progress.lock().unwrap();
+    let last = progress.last().expect("progress should be reported");
+    assert_eq!(last.transferred, 18);
+    assert_eq!(last.total, Some(18));
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_upload_stream_is_not_bounded_by_request_timeout() {
+    let (base_url, rx, server) = spawn_server(file_metadata_json().as_bytes(), "application/json");
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .timeouts(Timeouts::new().request(Duration::from_millis(100)))
+        .build()
+        .expect("client should build");
+    let chunks = futures_util::stream::iter(0..5).then(|index| async move {
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        Ok(Bytes::from(format!("part-{index};")))
+    });
+    let upload = files::StreamingUpload::from_stream("slow.jsonl", chunks).length(35);
+
+    let uploaded = client
+        .files()
+        .upload_stream(upload, None)
+        .await
+        .expect("slow upload should outlast the request timeout");
+    assert_eq!(uploaded.id, "file_123");
+
+    let captured = rx
+        .recv_timeout(Duration::from_secs(2))
+        .expect("should capture upload request");
+    assert!(
+        captured
+            .body_text
+            .contains("part-0;part-1;part-2;part-3;part-4;")
+    );
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_upload_stream_with_unknown_length_is_chunked() {
+    let (base_url, rx, server) = spawn_server(file_metadata_json().as_bytes(), "application/json");
+    let chunks = futures_util::stream::iter(vec![
+        Ok(Bytes::from_static(b"first-part;")),
+        Ok(Bytes::from_static(b"second-part")),
+    ]);
+    let upload = files::StreamingUpload::from_stream("data.jsonl", chunks);
+
+    files::upload_file_stream(&base_url, "api-key", upload, Some("ws_123"))
+        .await
+        .expect("streaming upload should succeed");
+
+    let captured = rx
+        .recv_timeout(Duration::from_secs(2))
+        .expect("should capture upload request");
+    assert!(
+        captured
+            .request_text
+            .to_ascii_lowercase()
+            .contains("transfer-encoding: chunked")
+    );
+    assert!(captured.body_text.contains(r#"filename="data.jsonl""#));
+    assert!(captured.body_text.contains("first-part;"));
+    assert!(captured.body_text.contains("second-part"));
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_download_stream_writes_content_and_reports_progress() {
+    let (base_url, rx, server) = spawn_server(b"0123456789", "application/octet-stream");
+    let download = files::download_file_stream(&base_url, "api-key", "file_123", None)
+        .await
+        .expect("download should start");
+    assert_eq!(download.content_length(), Some(10));
+
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let seen = progress.clone();
+    let mut output = Vec::new();
+    let written = download
+        .on_progress(move |progress| seen.lock().unwrap().push(progress))
+        .write_to(&mut output)
+        .await
+        .expect("download should complete");
+
+    assert_eq!(written, 10);
+    assert_eq!(output, b"0123456789");
+    let progress = progress.lock().unwrap();
+    assert_eq!(
+        progress.last().map(|progress| progress.transferred),
+        Some(10)
+    );
+    assert!(progress.iter().all(|progress| progress.total == Some(10)));
+    let captured = rx
+        .recv_timeout(Duration::from_secs(2))
+        .expect("should capture download request");
+    assert_eq!(
+        captured.request_line,
+        "GET /api/v1/files/file_123/content HTTP/1.1"
+    );
+    server.join().expect("server thread should finish");
+}
@@ -0,0 +1,295 @@
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use bytes::Bytes;
+use futures_util::{StreamExt, stream};
+use http::{HeaderMap, HeaderValue, Method, StatusCode, header::CONTENT_TYPE};
+use openrouter_rs::{
+    OpenRouterClient,
+    api::chat,
+    client::{Middleware, MiddlewareFuture, MiddlewareRequest, MiddlewareResponse, Next},
+    error::OpenRouterError,
+    types::Role,
+};
+
+fn read_request(stream: &mut TcpStream) -> String {
+    let mut request_bytes = Vec::new();
+    let mut chunk = [0_u8; 1024];
+    let header_end = loop {
+        let read = stream.read(&mut chunk).expect("server should read request");
+        if read == 0 {
+            break None;
+        }
+        request_bytes.extend_from_slice(&chunk[..read]);
+        if let Some(pos) = request_bytes
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+        {
+            break Some(pos + 4);
+        }
+    }
+    .expect("request should contain header terminator");
+
+    let header_text = String::from_utf8_lossy(&request_bytes[..header_end]).to_string();
+    let content_length = header_text
+        .lines()
+        .find_map(|line| {
+            let lower = line.to_ascii_lowercase();
+            if lower.starts_with("content-length:") {
+                line.split(':').nth(1)?.trim().parse::<usize>().ok()
+            } else {
+                None
+            }
+        })
+        .unwrap_or(0);
+
+    let mut body_bytes = request_bytes[header_end..].to_vec();
+    while body_bytes.len() < content_length {
+        let read = stream
+            .read(&mut chunk)
+            .expect("server should read request body");
+        if read == 0 {
+            break;
+        }
+        body_bytes.extend_from_slice(&chunk[..read]);
+    }
+
+    header_text
+}
+
+fn spawn_json_server(body: &'static str) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener
+            .accept()
+            .expect("server should accept one connection");
+        let headers = read_request(&mut stream);
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream
+            .write_all(response.as_bytes())
+            .expect("server should write response");
+        headers
+    });
+
+    (format!("http://{addr}/api/v1"), server)
+}
+
+fn unreachable_base_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    drop(listener);
+    format!("http://{addr}/api/v1")
+}
+
+fn build_chat_request() -> chat::ChatCompletionRequest {
+    chat::ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![chat::Message::new(Role::User, "hello")])
+        .build()
+        .expect("chat request should build")
+}
+
+const CHAT_RESPONSE: &str = r#"{"id":"gen-1","choices":[{"index":0,"message":{"role":"assistant","content":"hi"},"finish_reason":"stop"}],"created":1700000000,"model":"openai/gpt-4.1-mini","object":"chat.completion"}"#;
+
+struct Recorder {
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn handle<'a>(
+        &'a self,
+        mut request: MiddlewareRequest,
+        next: Next<'a>,
+    ) -> MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let model = request
+                .json_body()
+                .and_then(|body| body["model"].as_str().map(str::to_string));
+            self.log.lock().unwrap().push(format!(
+                "{} {} {}",
+                request.method(),
+                request.url(),
+                model.unwrap_or_default()
+            ));
+            request
+                .headers_mut()
+                .insert("x-tenant-id", HeaderValue::from_static("acme"));
+
+            let mut response = next.run(request).await?;
+            let body = response.body().await?;
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", response.status(), body.len()));
+            Ok(response)
+        })
+    }
+}
+
+struct Named(&'static str, Arc<Mutex<Vec<String>>>);
+
+impl Middleware for Named {
+    fn handle<'a>(&'a self, request: MiddlewareRequest, next: Next<'a>) -> MiddlewareFuture<'a> {
+        Box::pin(async move {
+            self.1.lock().unwrap().push(format!("enter {}", self.0));
+            let response = next.run(request).await;
+            self.1.lock().unwrap().push(format!("exit {}", self.0));
+            response
+        })
+    }
+}
+
+struct CannedCredits;
+
+impl Middleware for CannedCredits {
+    fn handle<'a>(&'a self, request: MiddlewareRequest, next: Next<'a>) -> MiddlewareFuture<'a> {
+        if request.method() == Method::GET && request.url().ends_with("/credits") {
+            let response = MiddlewareResponse::json(
+                StatusCode::OK,
+                &serde_json::json!({"data": {"total_credits": 42.0, "total_usage": 1.5}}),
+            );
+            return Box::pin(async move { response });
+        }
+        next.run(request)
+    }
+}
+
+struct CannedStream(Arc<Mutex<usize>>);
+
+impl Middleware for CannedStream {
+    fn handle<'a>(&'a self, request: MiddlewareRequest, _next: Next<'a>) -> MiddlewareFuture<'a> {
+        assert!(request.is_streaming());
+        let seen = self.0.clone();
+        Box::pin(async move {
+            let mut headers = HeaderMap::new();
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+            let chunks = vec![
+                Ok(Bytes::from_static(
+                    b"data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"hi\"}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+                )),
+                Ok(Bytes::from_static(b"data: [DONE]\n\n")),
+            ];
+            let response =
+                MiddlewareResponse::stream(StatusCode::OK, headers, stream::iter(chunks).boxed());
+            assert!(response.is_event_stream());
+            Ok(response.inspect_stream(move |chunk| *seen.lock().unwrap() += chunk.len()))
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_middleware_observes_and_mutates_request_and_response() {
+    let (base_url, server) = spawn_json_server(CHAT_RESPONSE);
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let client = OpenRouterClient::builder()
+        .base_url(base_url.clone())
+        .api_key("api-key")
+        .middleware(Recorder { log: log.clone() })
+        .build()
+        .expect("client should build");
+
+    let response = client
+        .chat()
+        .create(&build_chat_request())
+        .await
+        .expect("chat request should succeed");
+    assert_eq!(response.choices[0].content(), Some("hi"));
+
+    let headers = server.join().expect("server thread should finish");
+    assert!(headers.to_ascii_lowercase().contains("x-tenant-id: acme"));
+
+    let log = log.lock().unwrap();
+    assert_eq!(
+        log[0],
+        format!("POST {base_url}/chat/completions openai/gpt-4.1-mini")
+    );
+    assert_eq!(log[1], format!("200 OK {}", CHAT_RESPONSE.len()));
+}
+
+#[tokio::test]
+async fn test_middleware_runs_in_registration_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let client = OpenRouterClient::builder()
+        .base_url(unreachable_base_url())
+        .api_key("api-key")
+        .middleware(Named("outer", log.clone()))
+        .middleware(Named("inner", log.clone()))
+        .middleware(CannedCredits)
+        .build()
+        .expect("client should build");
+
+    client
+        .management()
+        .get_credits()
+        .await
+        .expect("short-circuited request should succeed");
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["enter outer", "enter inner", "exit inner", "exit outer"]
+    );
+}
+
+#[tokio::test]
+async fn test_middleware_can_short_circuit_without_network() {
+    let client = OpenRouterClient::builder()
+        .base_url(unreachable_base_url())
+        .api_key("api-key")
+        .middleware(CannedCredits)
+        .build()
+        .expect("client should build");
+
+    let credits = client
+        .management()
+        .get_credits()
+        .await
+        .expect("canned response should be returned");
+
+    assert_eq!(credits.total_credits, 42.0);
+    assert_eq!(credits.total_usage, 1.5);
+
+    let error = client
+        .models()
+        .list()
+        .await
+        .expect_err("other requests should still reach the network");
+    assert!(matches!(error, OpenRouterError::HttpRequest(_)));
+}
+
+#[tokio::test]
+async fn test_middleware_synthetic_stream_is_parsed_and_inspected() {
+    let seen = Arc::new(Mutex::new(0));
+    let client = OpenRouterClient::builder()
+        .base_url(unreachable_base_url())
+        .api_key("api-key")
+        .middleware(CannedStream(seen.clone()))
+        .build()
+        .expect("client should build");
+
+    let chunks: Vec<_> = client
+        .chat()
+        .stream(&build_chat_request())
+        .await
+        .expect("synthetic stream should open")
+        .collect()
+        .await;
+
+    assert_eq!(chunks.len(), 1);
+    let chunk = chunks[0].as_ref().expect("chunk should parse");
+    assert_eq!(chunk.choices[0].content(), Some("hi"));
+    assert!(*seen.lock().unwrap() > 0);
+}
@@ -7,7 +7,10 @@ pub mod analytics;
 pub mod api_keys;
 pub mod audio;
 pub mod auth;
+pub mod batch;
+pub mod budget;
 pub mod byok;
+pub mod cache;
 pub mod chat_api;
 pub mod chat_request;
 pub mod client_domains;
@@ -15,28 +18,46 @@ pub mod client_domains;
 pub mod client_legacy;
 pub mod client_management_key;
 pub mod completion;
+pub mod conversation;
+pub mod convert;
 pub mod credits;
 pub mod custom_http_client;
 pub mod default_headers;
 pub mod discovery;
 pub mod embeddings;
 pub mod error_model;
+pub mod estimate;
 pub mod files;
 pub mod generation;
 pub mod guardrails;
 pub mod images;
 pub mod messages;
+pub mod middleware;
 pub mod models;
 pub mod observability;
 pub mod organization;
 pub mod pagination;
+pub mod partial_json;
 pub mod presets;
 pub mod provider;
+pub mod rate_limit;
 pub mod rerank;
 pub mod response_format;
 pub mod responses;
+pub mod retry;
+pub mod router;
 pub mod stream;
+pub mod stream_collect;
+pub mod structured_output;
+pub mod support;
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+pub mod telemetry;
+#[cfg(feature = "test-support")]
+pub mod testing;
+pub mod timeouts;
 pub mod tool_builder;
+pub mod tool_runner;
+pub mod transport;
 pub mod unified_stream;
 pub mod videos;
 pub mod workspaces;
@@ -1,4 +1,24 @@
-use openrouter_rs::types::PaginationOptions;
+use futures_util::StreamExt;
+use openrouter_rs::{OpenRouterClient, error::OpenRouterError, types::PaginationOptions};
+use serde_json::{Value, json};
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+fn member(id: &str) -> Value {
+    json!({"id": id, "email": format!("{id}@example.com"), "role": "member"})
+}
+
+fn file(id: &str) -> Value {
+    json!({
+        "id": id,
+        "type": "file",
+        "filename": "document.pdf",
+        "mime_type": "application/pdf",
+        "size_bytes": 1024,
+        "created_at": "2026-06-15T00:00:00Z",
+        "downloadable": true
+    })
+}
 
 #[test]
 fn test_pagination_to_query_pairs_orders_offset_then_limit() {
@@ -21,3 +41,97 @@ fn test_pagination_to_query_pairs_omits_unset_values() {
 
     assert_eq!(pairs, vec![("limit", String::from("30"))]);
 }
+
+#[tokio::test]
+async fn test_offset_paginator_stops_at_total_count() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::json(
+            200,
+            &json!({"data": [member("m1"), member("m2")], "total_count": 3}),
+        ),
+        Scripted::json(200, &json!({"data": [member("m3")], "total_count": 3})),
+    ]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .management_key("mgmt-key")
+        .build()
+        .expect("client should build");
+
+    let members = client
+        .management()
+        .paginate_organization_members(Some(2))
+        .collect_all()
+        .await
+        .expect("pagination should succeed");
+    server.join().expect("server should finish");
+
+    let ids: Vec<&str> = members.iter().map(|member| member.id.as_str()).collect();
+    assert_eq!(ids, vec!["m1", "m2", "m3"]);
+    let request_lines: Vec<String> = rx.try_iter().map(|request| request.request_line).collect();
+    assert_eq!(
+        request_lines,
+        vec![
+            "GET /api/v1/organization/members?offset=0&limit=2 HTTP/1.1",
+            "GET /api/v1/organization/members?offset=2&limit=2 HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_cursor_paginator_follows_cursor_and_stops_early() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::json(
+            200,
+            &json!({"data": [file("file_1")], "has_more": true, "first_id": "file_1", "last_id": "file_1", "cursor": "cur_2"}),
+        ),
+        Scripted::json(
+            200,
+            &json!({"data": [file("file_2"), file("file_3")], "has_more": true, "first_id": "file_2", "last_id": "file_3", "cursor": "cur_3"}),
+        ),
+    ]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .build()
+        .expect("client should build");
+
+    let files: Vec<String> = client
+        .files()
+        .paginate(Some(1), None)
+        .take(2)
+        .map(|file| file.expect("page should load").id)
+        .collect()
+        .await;
+    server.join().expect("server should finish");
+
+    // The third page is never requested once two items were taken.
+    assert_eq!(files, vec!["file_1", "file_2"]);
+    let request_lines: Vec<String> = rx.try_iter().map(|request| request.request_line).collect();
+    assert_eq!(request_lines.len(), 2);
+    assert!(!request_lines[0].contains("cursor="));
+    assert!(request_lines[1].contains("cursor=cur_2"));
+}
+
+#[tokio::test]
+async fn test_paginator_yields_error_and_ends() {
+    let (base_url, _rx, server) = spawn_scripted_server(vec![
+        Scripted::json(200, &json!({"data": [member("m1")], "total_count": 5})),
+        Scripted::json(500, &json!({"error": {"code": 500, "message": "boom"}})),
+    ]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .management_key("mgmt-key")
+        .build()
+        .expect("client should build");
+
+    let results: Vec<_> = client
+        .management()
+        .paginate_organization_members(Some(1))
+        .collect()
+        .await;
+    server.join().expect("server should finish");
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().expect("first page loads").id, "m1");
+    assert!(matches!(results[1], Err(OpenRouterError::Api(_))));
+}
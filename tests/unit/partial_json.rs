@@ -0,0 +1,246 @@
+use futures_util::{StreamExt, stream};
+use openrouter_rs::{
+    error::OpenRouterError,
+    types::{
+        completion::CompletionsResponse,
+        partial_json::{
+            PartialJson, PartialJsonEvent, parse_partial_json, partial_json_stream,
+            partial_json_unified_stream,
+        },
+        stream::{StreamEvent, ToolAwareStream, UnifiedStreamEvent, UnifiedStreamSource},
+    },
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct CityAnswer {
+    city: String,
+    tags: Vec<String>,
+}
+
+fn chunk(delta: Value) -> Result<CompletionsResponse, OpenRouterError> {
+    Ok(serde_json::from_value(json!({
+        "id": "gen-1",
+        "choices": [{"index": 0, "delta": delta}],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion.chunk"
+    }))
+    .expect("chunk should deserialize"))
+}
+
+fn content_chunk(content: &str) -> Result<CompletionsResponse, OpenRouterError> {
+    chunk(json!({"role": "assistant", "content": content}))
+}
+
+fn arguments_chunk(
+    id: Option<&str>,
+    name: Option<&str>,
+    arguments: &str,
+) -> Result<CompletionsResponse, OpenRouterError> {
+    chunk(json!({
+        "tool_calls": [{
+            "index": 0,
+            "id": id,
+            "type": "function",
+            "function": {"name": name, "arguments": arguments}
+        }]
+    }))
+}
+
+#[test]
+fn test_parse_partial_json_closes_open_values() {
+    let cases = [
+        ("", None),
+        ("{", Some(json!({}))),
+        (r#"{"ci"#, Some(json!({}))),
+        (r#"{"city""#, Some(json!({}))),
+        (r#"{"city": "Pa"#, Some(json!({"city": "Pa"}))),
+        (
+            r#"{"city": "Paris", "tags": ["a", "b"#,
+            Some(json!({"city": "Paris", "tags": ["a", "b"]})),
+        ),
+        (r#"{"ok": tr"#, Some(json!({}))),
+        (
+            r#"{"ok": true, "n": 12."#,
+            Some(json!({"ok": true, "n": 12})),
+        ),
+        (r#"{"n": -"#, Some(json!({}))),
+        (r#"{"text": "line\"#, Some(json!({"text": "line"}))),
+        (r#"{"text": "caf\u00e"#, Some(json!({"text": "caf"}))),
+        (r#"{"text": "café"}"#, Some(json!({"text": "café"}))),
+        (r#"[1, {"a": null}, "#, Some(json!([1, {"a": null}]))),
+        ("```json\n{\"a\": 1", Some(json!({"a": 1}))),
+        ("The answer is", None),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(parse_partial_json(input), expected, "input: {input:?}");
+    }
+}
+
+#[test]
+fn test_partial_json_reports_only_changed_snapshots() {
+    let mut partial = PartialJson::new();
+    assert_eq!(partial.push(r#"{"city""#), Some(&json!({})));
+    assert_eq!(partial.push(": "), None);
+    assert_eq!(partial.push(r#""Ro"#), Some(&json!({"city": "Ro"})));
+    assert_eq!(
+        partial.push(r#"me", "tags": []}"#).cloned(),
+        Some(json!({"city": "Rome", "tags": []}))
+    );
+    assert_eq!(
+        partial
+            .deserialize::<CityAnswer>()
+            .expect("complete text should parse"),
+        CityAnswer {
+            city: "Rome".to_string(),
+            tags: Vec::new()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_partial_json_stream_emits_snapshots_then_typed_value() {
+    let chunks = vec![
+        content_chunk(r#"{"city": "Pa"#),
+        content_chunk(r#"ris", "tags": ["capital"#),
+        content_chunk(r#""]}"#),
+    ];
+    let events: Vec<_> =
+        partial_json_stream::<CityAnswer>(ToolAwareStream::new(stream::iter(chunks).boxed()))
+            .collect()
+            .await;
+
+    let snapshots: Vec<&Value> = events
+        .iter()
+        .filter_map(|event| match event {
+            PartialJsonEvent::PartialObject(snapshot) => Some(snapshot),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        snapshots,
+        vec![
+            &json!({"city": "Pa"}),
+            &json!({"city": "Paris", "tags": ["capital"]}),
+        ]
+    );
+
+    let deltas = events
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                PartialJsonEvent::Source(StreamEvent::ContentDelta(_))
+            )
+        })
+        .count();
+    assert_eq!(deltas, 3);
+
+    match &events[events.len() - 2] {
+        PartialJsonEvent::Complete(answer) => {
+            assert_eq!(answer.city, "Paris");
+            assert_eq!(answer.tags, vec!["capital".to_string()]);
+        }
+        other => panic!("expected complete value, got {other:?}"),
+    }
+    assert!(matches!(
+        events.last(),
+        Some(PartialJsonEvent::Source(StreamEvent::Done { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_partial_json_stream_snapshots_tool_arguments() {
+    let chunks = vec![
+        arguments_chunk(Some("call_1"), Some("get_weather"), ""),
+        arguments_chunk(None, None, r#"{"loc"#),
+        arguments_chunk(None, None, r#"ation": "NY"#),
+        arguments_chunk(None, None, r#"C"}"#),
+    ];
+    let events: Vec<_> =
+        partial_json_stream::<Value>(ToolAwareStream::new(stream::iter(chunks).boxed()))
+            .collect()
+            .await;
+
+    let snapshots: Vec<(u32, Option<&str>, Option<&str>, &Value)> = events
+        .iter()
+        .filter_map(|event| match event {
+            PartialJsonEvent::PartialToolArguments {
+                index,
+                id,
+                name,
+                arguments,
+            } => Some((*index, id.as_deref(), name.as_deref(), arguments)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        snapshots,
+        vec![
+            (0, Some("call_1"), Some("get_weather"), &json!({})),
+            (
+                0,
+                Some("call_1"),
+                Some("get_weather"),
+                &json!({"location": "NY"})
+            ),
+            (
+                0,
+                Some("call_1"),
+                Some("get_weather"),
+                &json!({"location": "NYC"})
+            ),
+        ]
+    );
+
+    // No content was streamed, so there is no typed value; Done still carries the call.
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, PartialJsonEvent::Complete(_)))
+    );
+    match events.last() {
+        Some(PartialJsonEvent::Source(StreamEvent::Done { tool_calls, .. })) => {
+            assert_eq!(tool_calls[0].function.arguments, r#"{"location": "NYC"}"#);
+        }
+        other => panic!("expected done event, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_partial_json_unified_stream_reports_final_parse_error() {
+    let source = stream::iter(vec![
+        UnifiedStreamEvent::ContentDelta(r#"{"city": "Oslo""#.to_string()),
+        UnifiedStreamEvent::ContentDelta("}".to_string()),
+        UnifiedStreamEvent::Done {
+            source: UnifiedStreamSource::Responses,
+            id: None,
+            model: None,
+            finish_reason: None,
+            usage: None,
+        },
+    ])
+    .boxed();
+    let events: Vec<_> = partial_json_unified_stream::<CityAnswer>(source)
+        .collect()
+        .await;
+
+    assert!(matches!(
+        &events[0],
+        PartialJsonEvent::PartialObject(snapshot) if snapshot == &json!({"city": "Oslo"})
+    ));
+    match &events[events.len() - 2] {
+        PartialJsonEvent::Error(OpenRouterError::StructuredOutput(error)) => {
+            assert_eq!(error.raw(), r#"{"city": "Oslo"}"#);
+            assert!(error.message().contains("tags"));
+        }
+        other => panic!("expected structured output error, got {other:?}"),
+    }
+    assert!(matches!(
+        events.last(),
+        Some(PartialJsonEvent::Source(UnifiedStreamEvent::Done { .. }))
+    ));
+}
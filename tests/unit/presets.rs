@@ -244,7 +244,7 @@ async fn test_create_response_preset_path_body_and_auth_header() {
     let (base_url, rx, server) = spawn_json_server(preset_response_body());
     let request = responses::ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(json!("hello"))
+        .input(json!("hello").into())
         .instructions("You are concise.")
         .build()
         .expect("responses request should build");
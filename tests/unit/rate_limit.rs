@@ -0,0 +1,294 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use futures_util::future::join_all;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::{
+        api_keys::RateLimit,
+        chat::{ChatCompletionRequest, Message},
+    },
+    client::RateLimiter,
+    error::OpenRouterError,
+    types::Role,
+};
+use serde_json::json;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+/// Server that answers every request with a chat completion after `delay`,
+/// recording the most requests it had in progress at once.
+async fn spawn_server(delay: Duration) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    let active = Arc::new(AtomicUsize::new(0));
+    let max_active = Arc::new(AtomicUsize::new(0));
+    let observed = max_active.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                return;
+            };
+            let active = active.clone();
+            let max_active = max_active.clone();
+            tokio::spawn(async move {
+                if !read_request(&mut stream).await {
+                    return;
+                }
+                let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                max_active.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(delay).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+
+                let body = json!({
+                    "id": "gen-rate-limit",
+                    "choices": [{"message": {"role": "assistant", "content": "ok"}}],
+                    "created": 1700000000,
+                    "model": "openai/gpt-4.1-mini",
+                    "object": "chat.completion"
+                })
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+
+    (format!("http://{addr}/api/v1"), observed)
+}
+
+async fn read_request(stream: &mut tokio::net::TcpStream) -> bool {
+    let mut request = Vec::new();
+    let mut chunk = [0_u8; 4096];
+    loop {
+        if let Some(end) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+            let headers = String::from_utf8_lossy(&request[..end]).to_ascii_lowercase();
+            let length = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            if request.len() >= end + 4 + length {
+                return true;
+            }
+        }
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return false,
+            Ok(read) => request.extend_from_slice(&chunk[..read]),
+        }
+    }
+}
+
+fn client(base_url: String, limiter: RateLimiter) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("test-api-key")
+        .rate_limiter(limiter)
+        .build()
+        .expect("client should build")
+}
+
+fn request(model: &str) -> ChatCompletionRequest {
+    ChatCompletionRequest::builder()
+        .model(model)
+        .messages(vec![Message::new(Role::User, "Say ok.")])
+        .max_tokens(100)
+        .build()
+        .expect("request should build")
+}
+
+#[tokio::test]
+async fn test_request_limit_spaces_out_requests_after_burst() {
+    let (base_url, _) = spawn_server(Duration::ZERO).await;
+    let client = self::client(
+        base_url,
+        RateLimiter::new().requests(2, Duration::from_millis(200)),
+    );
+
+    let started = Instant::now();
+    for _ in 0..4 {
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1-mini"))
+            .await
+            .expect("request should succeed");
+    }
+
+    // Two requests fit the burst; the other two wait 100ms each.
+    assert!(
+        started.elapsed() >= Duration::from_millis(180),
+        "requests finished after {:?}",
+        started.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn test_max_in_flight_caps_concurrent_requests() {
+    let (base_url, max_active) = spawn_server(Duration::from_millis(50)).await;
+    let client = self::client(base_url, RateLimiter::new().max_in_flight(1));
+
+    let chat = client.chat();
+    let request = request("openai/gpt-4.1-mini");
+    let results = join_all((0..3).map(|_| chat.create(&request))).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(max_active.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_max_in_flight_without_cap_allows_concurrency() {
+    let (base_url, max_active) = spawn_server(Duration::from_millis(100)).await;
+    let client = self::client(base_url, RateLimiter::new().max_in_flight(3));
+
+    let chat = client.chat();
+    let request = request("openai/gpt-4.1-mini");
+    let results = join_all((0..3).map(|_| chat.create(&request))).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert!(max_active.load(Ordering::SeqCst) > 1);
+}
+
+#[tokio::test]
+async fn test_model_token_limit_only_throttles_that_model() {
+    let (base_url, _) = spawn_server(Duration::ZERO).await;
+    // Each request is charged its prompt estimate plus 100 output tokens,
+    // which fills the whole 100-token bucket, so the second request waits
+    // for it to refill.
+    let client = self::client(
+        base_url,
+        RateLimiter::new().model_tokens("openai/gpt-4.1", 100, Duration::from_millis(200)),
+    );
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1-mini"))
+            .await
+            .expect("request should succeed");
+    }
+    assert!(
+        started.elapsed() < Duration::from_millis(150),
+        "unlimited model was throttled: {:?}",
+        started.elapsed()
+    );
+
+    let started = Instant::now();
+    for _ in 0..2 {
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1"))
+            .await
+            .expect("request should succeed");
+    }
+    assert!(
+        started.elapsed() >= Duration::from_millis(150),
+        "limited model was not throttled: {:?}",
+        started.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn test_key_rate_limit_sets_request_limit() {
+    let (base_url, _) = spawn_server(Duration::ZERO).await;
+    let rate_limit: RateLimit =
+        serde_json::from_value(json!({"requests": 1, "interval": "200ms"})).unwrap();
+    let limiter = RateLimiter::new()
+        .key_rate_limit(&rate_limit)
+        .expect("interval should parse");
+    let client = self::client(base_url, limiter.clone());
+
+    let started = Instant::now();
+    for _ in 0..2 {
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1-mini"))
+            .await
+            .expect("request should succeed");
+    }
+    assert!(started.elapsed() >= Duration::from_millis(180));
+
+    // An unlimited key clears the limit.
+    let unlimited: RateLimit =
+        serde_json::from_value(json!({"requests": -1, "interval": "10s"})).unwrap();
+    limiter
+        .apply_key_rate_limit(&unlimited)
+        .expect("interval should parse");
+    let started = Instant::now();
+    for _ in 0..3 {
+        client
+            .chat()
+            .create(&request("openai/gpt-4.1-mini"))
+            .await
+            .expect("request should succeed");
+    }
+    assert!(started.elapsed() < Duration::from_millis(150));
+}
+
+#[test]
+fn test_key_rate_limit_rejects_invalid_interval() {
+    let rate_limit: RateLimit =
+        serde_json::from_value(json!({"requests": 10, "interval": "soon"})).unwrap();
+
+    match RateLimiter::new().key_rate_limit(&rate_limit) {
+        Err(OpenRouterError::ConfigError(message)) => {
+            assert!(message.contains("soon"), "unexpected message: {message}");
+        }
+        other => panic!("expected ConfigError, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_key_rate_limit_rejects_out_of_range_interval() {
+    let rate_limit: RateLimit =
+        serde_json::from_value(json!({"requests": 10, "interval": "99999999999999999999999999d"}))
+            .unwrap();
+
+    assert!(matches!(
+        RateLimiter::new().key_rate_limit(&rate_limit),
+        Err(OpenRouterError::ConfigError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_out_of_range_wait_fails_instead_of_panicking() {
+    let (base_url, _) = spawn_server(Duration::ZERO).await;
+    let client = self::client(base_url, RateLimiter::new().requests(1, Duration::MAX));
+
+    client
+        .chat()
+        .create(&request("openai/gpt-4.1-mini"))
+        .await
+        .expect("first request fits the burst");
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.chat().create(&request("openai/gpt-4.1-mini")),
+    )
+    .await
+    .expect("an out-of-range wait should fail, not sleep");
+
+    match result {
+        Err(OpenRouterError::ConfigError(message)) => {
+            assert!(
+                message.contains("out of range"),
+                "unexpected message: {message}"
+            );
+        }
+        other => panic!("expected ConfigError, got {other:?}"),
+    }
+}
@@ -60,3 +60,20 @@ fn test_response_format_legacy_type_only_deserialize() {
         _ => panic!("expected legacy TypeOnly(JsonObject)"),
     }
 }
+
+#[test]
+fn test_response_format_json_schema_deserialize_keeps_schema() {
+    let format: ResponseFormat = serde_json::from_value(json!({
+        "type": "json_schema",
+        "json_schema": {"name": "answer", "strict": true, "schema": {"type": "object"}}
+    }))
+    .expect("json_schema format should deserialize");
+
+    match format {
+        ResponseFormat::JsonSchema { json_schema, .. } => {
+            assert_eq!(json_schema.name, "answer");
+            assert!(json_schema.strict);
+        }
+        _ => panic!("expected JsonSchema"),
+    }
+}
@@ -10,10 +10,13 @@ use futures_util::StreamExt;
 use openrouter_rs::api::{
     chat::{CacheControl, DebugOptions, Plugin, TraceOptions},
     responses::{
-        ResponsesRequest, ResponsesResponse, ResponsesStreamEvent, create_response, stream_response,
+        ResponsesContentPart, ResponsesInput, ResponsesInputItem, ResponsesOutputItem,
+        ResponsesReasoningConfig, ResponsesRequest, ResponsesResponse, ResponsesStreamAccumulator,
+        ResponsesStreamEvent, ResponsesTextConfig, ResponsesTextFormat, ResponsesTool,
+        ResponsesToolChoice, create_response, stream_response,
     },
 };
-use openrouter_rs::types::OpenRouterExperimentalMetadata;
+use openrouter_rs::types::{Effort, OpenRouterExperimentalMetadata, Role, tool::create_tool};
 use serde_json::json;
 
 struct CapturedRequest {
@@ -109,10 +112,13 @@ fn spawn_server(
 fn test_responses_request_serialization() {
     let request = ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(json!([{
-            "role": "user",
-            "content": "Hello from responses API"
-        }]))
+        .input(
+            json!([{
+                "role": "user",
+                "content": "Hello from responses API"
+            }])
+            .into(),
+        )
         .instructions("Be concise")
         .metadata([("env", "test"), ("feature", "responses")])
         .tools(vec![json!({
@@ -178,6 +184,191 @@ fn test_responses_request_serialization() {
     assert_eq!(value["debug"]["echo_upstream_body"], true);
 }
 
+#[test]
+fn test_responses_request_typed_items_and_config() {
+    let request = ResponsesRequest::builder()
+        .model("openai/gpt-5")
+        .input(
+            vec![
+                ResponsesInputItem::developer("Answer briefly."),
+                ResponsesInputItem::message(
+                    Role::User,
+                    vec![
+                        ResponsesContentPart::input_text("What is in this image?"),
+                        ResponsesContentPart::image_url_with_detail(
+                            "https://example.com/a.png",
+                            "low",
+                        ),
+                        ResponsesContentPart::file_id("file-1"),
+                    ],
+                ),
+                ResponsesInputItem::function_call("call_1", "get_weather", r#"{"city":"Paris"}"#),
+                ResponsesInputItem::function_call_output("call_1", "sunny"),
+            ]
+            .into(),
+        )
+        .tools(vec![
+            ResponsesTool::from(create_tool(
+                "get_weather",
+                "Look up the weather",
+                json!({"city": {"type": "string"}}),
+                &["city"],
+            )),
+            ResponsesTool::web_search(),
+        ])
+        .tool_choice(ResponsesToolChoice::force_tool("get_weather"))
+        .text(
+            ResponsesTextConfig::with_format(ResponsesTextFormat::json_schema(
+                "answer",
+                json!({"type": "object"}),
+            ))
+            .verbosity("low"),
+        )
+        .reasoning(ResponsesReasoningConfig::with_effort(Effort::High).summary("auto"))
+        .build()
+        .expect("responses request should build");
+
+    let value = serde_json::to_value(&request).expect("responses request should serialize");
+    let input = &value["input"];
+    assert_eq!(input[0]["type"], "message");
+    assert_eq!(input[0]["role"], "developer");
+    assert_eq!(input[0]["content"], "Answer briefly.");
+    assert_eq!(input[1]["content"][0]["type"], "input_text");
+    assert_eq!(input[1]["content"][1]["type"], "input_image");
+    assert_eq!(input[1]["content"][1]["detail"], "low");
+    assert_eq!(input[1]["content"][2]["type"], "input_file");
+    assert_eq!(input[2]["type"], "function_call");
+    assert_eq!(input[2]["call_id"], "call_1");
+    assert_eq!(input[2]["arguments"], r#"{"city":"Paris"}"#);
+    assert_eq!(input[3]["type"], "function_call_output");
+    assert_eq!(input[3]["output"], "sunny");
+
+    assert_eq!(value["tools"][0]["type"], "function");
+    assert_eq!(value["tools"][0]["name"], "get_weather");
+    assert_eq!(value["tools"][0]["parameters"]["required"][0], "city");
+    assert!(value["tools"][0].get("function").is_none());
+    assert_eq!(value["tools"][1], json!({"type": "web_search"}));
+    assert_eq!(
+        value["tool_choice"],
+        json!({"type": "function", "name": "get_weather"})
+    );
+    assert_eq!(
+        value["text"],
+        json!({
+            "format": {"type": "json_schema", "name": "answer", "schema": {"type": "object"}},
+            "verbosity": "low"
+        })
+    );
+    assert_eq!(
+        value["reasoning"],
+        json!({"effort": "high", "summary": "auto"})
+    );
+}
+
+#[test]
+fn test_responses_request_value_escape_hatch_round_trips() {
+    let raw_input = json!([
+        {"role": "user", "content": "hello"},
+        {"type": "item_reference", "id": "msg_1"},
+        {"type": "function_call_output", "call_id": "call_1", "output": [{"type": "input_text", "text": "ok"}]}
+    ]);
+    let raw_tool = json!({"type": "web_search_preview", "search_context_size": "high"});
+    let raw_text =
+        json!({"format": {"type": "json_schema", "name": "x", "schema": {}}, "future": 1});
+    let request = ResponsesRequest::builder()
+        .model("openai/gpt-5")
+        .input(raw_input.clone().into())
+        .tools(vec![raw_tool.clone()])
+        .tool_choice(json!("auto"))
+        .text(raw_text.clone())
+        .reasoning(json!({"enabled": false}))
+        .build()
+        .expect("responses request should build");
+
+    let Some(ResponsesInput::Items(items)) = request.input() else {
+        panic!("input should be a list of items");
+    };
+    assert!(
+        matches!(&items[0], ResponsesInputItem::Message(message) if message.role == Role::User)
+    );
+    assert!(matches!(&items[1], ResponsesInputItem::Other(_)));
+    assert!(matches!(&items[2], ResponsesInputItem::Other(_)));
+    assert!(matches!(
+        request.tools().expect("tools should be set")[0],
+        ResponsesTool::Other(_)
+    ));
+
+    let value = serde_json::to_value(&request).expect("responses request should serialize");
+    assert_eq!(value["input"][0]["type"], "message");
+    assert_eq!(value["input"][1], raw_input[1]);
+    assert_eq!(value["input"][2], raw_input[2]);
+    assert_eq!(value["tools"][0], raw_tool);
+    assert_eq!(value["tool_choice"], "auto");
+    assert_eq!(value["text"], raw_text);
+    assert_eq!(value["reasoning"], json!({"enabled": false}));
+}
+
+#[test]
+fn test_responses_response_typed_output_items() {
+    let response: ResponsesResponse = serde_json::from_value(json!({
+        "id": "resp-1",
+        "object": "response",
+        "status": "completed",
+        "output": [
+            {
+                "type": "reasoning",
+                "id": "rs_1",
+                "summary": [{"type": "summary_text", "text": "Checked the forecast."}],
+                "encrypted_content": "opaque"
+            },
+            {
+                "type": "function_call",
+                "id": "fc_1",
+                "call_id": "call_1",
+                "name": "get_weather",
+                "arguments": "{\"city\":\"Paris\"}",
+                "status": "completed"
+            },
+            {
+                "type": "message",
+                "id": "msg_1",
+                "role": "assistant",
+                "status": "completed",
+                "content": [
+                    {"type": "output_text", "text": "It is ", "annotations": []},
+                    {"type": "output_text", "text": "sunny.", "annotations": []}
+                ]
+            },
+            {"type": "image_generation_call", "id": "ig_1", "result": "aGk="}
+        ]
+    }))
+    .expect("responses payload should deserialize");
+
+    let output = response.output.as_ref().expect("output should be present");
+    let ResponsesOutputItem::Reasoning(reasoning) = &output[0] else {
+        panic!("first item should be reasoning");
+    };
+    assert_eq!(reasoning.text(), "Checked the forecast.");
+    assert_eq!(reasoning.encrypted_content.as_deref(), Some("opaque"));
+    assert!(
+        matches!(&output[3], ResponsesOutputItem::Other(item) if item["type"] == "image_generation_call")
+    );
+    assert_eq!(response.output_text().as_deref(), Some("It is sunny."));
+
+    let calls: Vec<_> = response.function_calls().collect();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].name, "get_weather");
+    assert_eq!(calls[0].call_id, "call_1");
+
+    let next_input: Vec<ResponsesInputItem> = output.iter().cloned().map(Into::into).collect();
+    let value = serde_json::to_value(&next_input).expect("input items should serialize");
+    assert_eq!(value[0]["type"], "reasoning");
+    assert_eq!(value[0]["encrypted_content"], "opaque");
+    assert_eq!(value[1]["type"], "function_call");
+    assert_eq!(value[2]["content"][1]["text"], "sunny.");
+    assert_eq!(value[3]["type"], "image_generation_call");
+}
+
 #[test]
 fn test_responses_response_deserialization() {
     let raw = r#"{
@@ -223,12 +414,12 @@ fn test_responses_stream_event_deserialization() {
 
     let event: ResponsesStreamEvent =
         serde_json::from_str(raw).expect("stream event should deserialize");
-    assert_eq!(event.event_type, "response.output_text.delta");
-    assert_eq!(event.sequence_number, Some(4));
-    assert_eq!(
-        event.data.get("delta").and_then(|value| value.as_str()),
-        Some("Hello")
-    );
+    assert_eq!(event.event_type(), "response.output_text.delta");
+    assert_eq!(event.sequence_number(), Some(4));
+    assert!(matches!(
+        event,
+        ResponsesStreamEvent::OutputTextDelta { ref delta, .. } if delta == "Hello"
+    ));
 }
 
 #[test]
@@ -244,18 +435,137 @@ fn test_responses_stream_event_with_response_payload() {
 
     let event: ResponsesStreamEvent =
         serde_json::from_str(raw).expect("stream event with response should deserialize");
-    assert_eq!(event.event_type, "response.completed");
-    assert_eq!(event.sequence_number, Some(10));
+    assert_eq!(event.event_type(), "response.completed");
+    assert_eq!(event.sequence_number(), Some(10));
+    assert!(event.is_terminal());
     assert_eq!(
-        event
-            .data
-            .get("response")
-            .and_then(|value| value.get("id"))
-            .and_then(|value| value.as_str()),
+        event.response().and_then(|response| response.id.as_deref()),
         Some("resp-abc123")
     );
 }
 
+#[test]
+fn test_responses_stream_event_unknown_type_keeps_data() {
+    let event: ResponsesStreamEvent = serde_json::from_value(json!({
+        "type": "response.audio.delta",
+        "sequence_number": 3,
+        "delta": "AAAA"
+    }))
+    .expect("unknown stream event should deserialize");
+
+    assert_eq!(event.event_type(), "response.audio.delta");
+    assert_eq!(event.sequence_number(), Some(3));
+    match &event {
+        ResponsesStreamEvent::Unknown { data, .. } => assert_eq!(data["delta"], "AAAA"),
+        other => panic!("expected Unknown event, got {other:?}"),
+    }
+    let value = serde_json::to_value(&event).expect("unknown event should serialize");
+    assert_eq!(value["type"], "response.audio.delta");
+    assert_eq!(value["delta"], "AAAA");
+
+    let malformed: ResponsesStreamEvent =
+        serde_json::from_value(json!({"type": "response.output_text.delta", "delta": 7}))
+            .expect("malformed known event should fall back to Unknown");
+    assert!(matches!(malformed, ResponsesStreamEvent::Unknown { .. }));
+    assert_eq!(malformed.event_type(), "response.output_text.delta");
+}
+
+#[test]
+fn test_responses_stream_accumulator_rebuilds_response() {
+    let events = [
+        json!({"type": "response.created", "sequence_number": 0, "response": {
+            "id": "resp_1", "object": "response", "model": "openai/gpt-5",
+            "status": "in_progress", "output": []
+        }}),
+        json!({"type": "response.output_item.added", "output_index": 0, "item": {
+            "type": "reasoning", "id": "rs_1", "summary": []
+        }}),
+        json!({"type": "response.reasoning_summary_part.added", "item_id": "rs_1",
+            "output_index": 0, "summary_index": 0, "part": {"type": "summary_text", "text": ""}}),
+        json!({"type": "response.reasoning_summary_text.delta", "item_id": "rs_1",
+            "output_index": 0, "summary_index": 0, "delta": "Thinking"}),
+        json!({"type": "response.output_item.added", "output_index": 1, "item": {
+            "type": "message", "id": "msg_1", "role": "assistant", "status": "in_progress", "content": []
+        }}),
+        json!({"type": "response.content_part.added", "item_id": "msg_1", "output_index": 1,
+            "content_index": 0, "part": {"type": "output_text", "text": "", "annotations": []}}),
+        json!({"type": "response.output_text.delta", "item_id": "msg_1", "output_index": 1,
+            "content_index": 0, "delta": "Let me "}),
+        json!({"type": "response.output_text.delta", "item_id": "msg_1", "output_index": 1,
+            "content_index": 0, "delta": "check."}),
+        json!({"type": "response.output_item.added", "output_index": 2, "item": {
+            "type": "function_call", "id": "fc_1", "call_id": "call_1",
+            "name": "get_weather", "arguments": ""
+        }}),
+        json!({"type": "response.function_call_arguments.delta", "item_id": "fc_1",
+            "output_index": 2, "delta": "{\"city\":"}),
+        json!({"type": "response.function_call_arguments.delta", "item_id": "fc_1",
+            "output_index": 2, "delta": "\"Paris\"}"}),
+        json!({"type": "response.completed", "sequence_number": 12, "response": {
+            "id": "resp_1", "object": "response", "model": "openai/gpt-5",
+            "status": "completed", "usage": {"input_tokens": 5, "output_tokens": 7}
+        }}),
+    ];
+
+    let mut accumulator = ResponsesStreamAccumulator::new();
+    for event in events {
+        let event: ResponsesStreamEvent =
+            serde_json::from_value(event).expect("stream event should deserialize");
+        assert!(!matches!(event, ResponsesStreamEvent::Unknown { .. }));
+        accumulator.push(&event);
+    }
+    let response = accumulator.finish();
+
+    assert_eq!(response.id.as_deref(), Some("resp_1"));
+    assert_eq!(response.status.as_deref(), Some("completed"));
+    assert_eq!(response.usage.as_ref().expect("usage")["output_tokens"], 7);
+    assert_eq!(response.output_text().as_deref(), Some("Let me check."));
+    let output = response.output.as_ref().expect("output should be rebuilt");
+    assert_eq!(output.len(), 3);
+    match &output[0] {
+        ResponsesOutputItem::Reasoning(reasoning) => assert_eq!(reasoning.text(), "Thinking"),
+        other => panic!("expected reasoning item, got {other:?}"),
+    }
+    let call = response.function_calls().next().expect("function call");
+    assert_eq!(call.call_id, "call_1");
+    assert_eq!(call.arguments, r#"{"city":"Paris"}"#);
+}
+
+#[test]
+fn test_responses_stream_accumulator_ignores_indices_far_past_the_end() {
+    let events = [
+        json!({
+            "type": "response.output_text.delta",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "kept"
+        }),
+        json!({
+            "type": "response.output_text.delta",
+            "output_index": 0,
+            "content_index": 4_000_000_000_u64,
+            "delta": "dropped"
+        }),
+        json!({
+            "type": "response.output_text.delta",
+            "output_index": 4_000_000_000_u64,
+            "content_index": 0,
+            "delta": "dropped"
+        }),
+    ];
+
+    let mut accumulator = ResponsesStreamAccumulator::new();
+    for event in events {
+        let event: ResponsesStreamEvent =
+            serde_json::from_value(event).expect("stream event should deserialize");
+        accumulator.push(&event);
+    }
+    let response = accumulator.finish();
+
+    assert_eq!(response.output.as_ref().map(Vec::len), Some(1));
+    assert_eq!(response.output_text().as_deref(), Some("kept"));
+}
+
 #[tokio::test]
 async fn test_create_response_sets_stream_false_and_headers() {
     let (base_url, rx, server) = spawn_server(
@@ -265,7 +575,7 @@ async fn test_create_response_sets_stream_false_and_headers() {
 
     let request = ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(json!([{"role":"user","content":"hello"}]))
+        .input(json!([{"role":"user","content":"hello"}]).into())
         .experimental_metadata(OpenRouterExperimentalMetadata::Enabled)
         .build()
         .expect("responses request should build");
@@ -353,7 +663,7 @@ async fn test_stream_response_sets_stream_true_and_parses_sse() {
 
     let request = ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(json!([{"role":"user","content":"hello"}]))
+        .input(json!([{"role":"user","content":"hello"}]).into())
         .build()
         .expect("responses request should build");
     let x_title = Some("openrouter-rs-tests".to_string());
@@ -375,12 +685,12 @@ async fn test_stream_response_sets_stream_true_and_parses_sse() {
         events.push(item.expect("stream event should parse"));
     }
     assert_eq!(events.len(), 1);
-    assert_eq!(events[0].event_type, "response.output_text.delta");
-    assert_eq!(events[0].sequence_number, Some(1));
-    assert_eq!(
-        events[0].data.get("delta").and_then(|value| value.as_str()),
-        Some("Hi")
-    );
+    assert_eq!(events[0].event_type(), "response.output_text.delta");
+    assert_eq!(events[0].sequence_number(), Some(1));
+    assert!(matches!(
+        &events[0],
+        ResponsesStreamEvent::OutputTextDelta { delta, .. } if delta == "Hi"
+    ));
 
     let captured = rx
         .recv_timeout(Duration::from_secs(2))
@@ -413,7 +723,7 @@ async fn test_stream_response_parses_multiline_sse_data_frames() {
 
     let request = ResponsesRequest::builder()
         .model("openai/gpt-5")
-        .input(json!([{"role":"user","content":"hello"}]))
+        .input(json!([{"role":"user","content":"hello"}]).into())
         .build()
         .expect("responses request should build");
 
@@ -426,12 +736,12 @@ async fn test_stream_response_parses_multiline_sse_data_frames() {
     }
 
     assert_eq!(events.len(), 1);
-    assert_eq!(events[0].event_type, "response.output_text.delta");
-    assert_eq!(events[0].sequence_number, Some(2));
-    assert_eq!(
-        events[0].data.get("delta").and_then(|value| value.as_str()),
-        Some("Hello from multiline SSE")
-    );
+    assert_eq!(events[0].event_type(), "response.output_text.delta");
+    assert_eq!(events[0].sequence_number(), Some(2));
+    assert!(matches!(
+        &events[0],
+        ResponsesStreamEvent::OutputTextDelta { delta, .. } if delta == "Hello from multiline SSE"
+    ));
 
     server.join().expect("server thread should finish");
 }
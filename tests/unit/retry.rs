@@ -0,0 +1,196 @@
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient, api::chat, client::RetryPolicy, error::OpenRouterError, types::Role,
+};
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+fn chat_response_json() -> &'static str {
+    r#"{
+        "id": "gen-123",
+        "choices": [{
+            "finish_reason": "stop",
+            "index": 0,
+            "message": {
+                "role": "assistant",
+                "content": "ok"
+            }
+        }],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion"
+    }"#
+}
+
+fn error_json(code: u16) -> String {
+    format!(r#"{{"error":{{"code":{code},"message":"try again"}}}}"#)
+}
+
+fn build_chat_request() -> chat::ChatCompletionRequest {
+    chat::ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![chat::Message::new(Role::User, "hello")])
+        .build()
+        .expect("chat request should build")
+}
+
+fn fast_retry_policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::new(max_attempts)
+        .initial_backoff(Duration::from_millis(1))
+        .max_backoff(Duration::from_millis(5))
+}
+
+#[tokio::test]
+async fn test_retry_policy_retries_retryable_status_until_success() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::new(503, "application/json", error_json(503)),
+        Scripted::new(429, "application/json", error_json(429)).header("Retry-After", "0"),
+        Scripted::new(200, "application/json", chat_response_json()),
+    ]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .retry_policy(fast_retry_policy(3))
+        .build()
+        .expect("client should build");
+
+    let response = client
+        .chat()
+        .create(&build_chat_request())
+        .await
+        .expect("chat request should eventually succeed");
+    assert_eq!(response.choices[0].content(), Some("ok"));
+
+    let attempts: Vec<String> = rx.try_iter().map(|request| request.request_line).collect();
+    assert_eq!(attempts.len(), 3);
+    assert!(
+        attempts
+            .iter()
+            .all(|line| line == "POST /api/v1/chat/completions HTTP/1.1")
+    );
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_retry_policy_returns_last_error_after_max_attempts() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::new(502, "application/json", error_json(502)),
+        Scripted::new(502, "application/json", error_json(502)),
+    ]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .retry_policy(fast_retry_policy(2))
+        .build()
+        .expect("client should build");
+
+    let error = client
+        .chat()
+        .create(&build_chat_request())
+        .await
+        .expect_err("chat request should fail after retries");
+
+    match error {
+        OpenRouterError::Api(api_error) => {
+            assert_eq!(api_error.status, http::StatusCode::BAD_GATEWAY);
+            assert!(api_error.is_retryable());
+        }
+        other => panic!("expected api error, got {other:?}"),
+    }
+    assert_eq!(rx.try_iter().count(), 2);
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_retry_policy_skips_non_retryable_status() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![Scripted::new(
+        400,
+        "application/json",
+        error_json(400),
+    )]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .retry_policy(fast_retry_policy(3))
+        .build()
+        .expect("client should build");
+
+    let error = client
+        .chat()
+        .create(&build_chat_request())
+        .await
+        .expect_err("bad request should not be retried");
+
+    assert!(matches!(error, OpenRouterError::Api(ref api) if api.status == 400));
+    assert_eq!(rx.try_iter().count(), 1);
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_retry_policy_custom_status_set_applies_to_management_calls() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::new(409, "application/json", error_json(409)),
+        Scripted::new(
+            200,
+            "application/json",
+            r#"{"data":{"total_credits":10.0,"total_usage":2.5}}"#,
+        ),
+    ]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .retry_policy(fast_retry_policy(2).retry_on_status([http::StatusCode::CONFLICT]))
+        .build()
+        .expect("client should build");
+
+    let credits = client
+        .management()
+        .get_credits()
+        .await
+        .expect("credits request should succeed after retry");
+    assert_eq!(credits.total_credits, 10.0);
+    assert_eq!(rx.try_iter().count(), 2);
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_retry_policy_retries_stream_before_first_sse_byte() {
+    let sse_body = concat!(
+        "data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"hi\"}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+        "data: [DONE]\n\n"
+    );
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::new(503, "application/json", error_json(503)),
+        Scripted::new(200, "text/event-stream", sse_body),
+    ]);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .retry_policy(fast_retry_policy(3))
+        .build()
+        .expect("client should build");
+
+    let mut stream = client
+        .chat()
+        .stream(&build_chat_request())
+        .await
+        .expect("stream should open after retry");
+    let mut content = String::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.expect("stream chunk should parse");
+        if let Some(delta) = chunk.choices[0].content() {
+            content.push_str(delta);
+        }
+    }
+
+    assert_eq!(content, "hi");
+    assert_eq!(rx.try_iter().count(), 2);
+
+    server.join().expect("server thread should finish");
+}
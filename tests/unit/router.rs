@@ -0,0 +1,344 @@
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
+    time::Duration,
+};
+
+use http::StatusCode;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::{
+        chat::{ChatCompletionRequest, Message},
+        messages::{AnthropicMessage, AnthropicMessagesRequest},
+        responses::ResponsesRequest,
+    },
+    client::{MiddlewareFuture, MiddlewareRequest, MiddlewareResponse, Network, Transport},
+    error::OpenRouterError,
+    router::{CircuitState, ErrorClass, Router, RoutingStrategy},
+    types::Role,
+};
+use serde_json::json;
+
+use crate::support::{Captured, Scripted, spawn_scripted_server};
+
+fn completion(model: &str) -> Scripted {
+    Scripted::json(
+        200,
+        &json!({
+            "id": "gen-1",
+            "choices": [{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": "hi"}}],
+            "created": 1700000000,
+            "model": model,
+            "object": "chat.completion"
+        }),
+    )
+}
+
+fn api_error(status: u16, message: &str) -> Scripted {
+    Scripted::json(
+        status,
+        &json!({"error": {"code": status, "message": message}}),
+    )
+}
+
+fn moderation_error() -> Scripted {
+    Scripted::json(
+        403,
+        &json!({"error": {
+            "code": 403,
+            "message": "Input was flagged",
+            "metadata": {
+                "reasons": ["violence"],
+                "flagged_input": "...",
+                "provider_name": "OpenAI",
+                "model_slug": "openai/gpt-4.1-mini"
+            }
+        }}),
+    )
+}
+
+/// Models requested from the server, in order.
+fn requested_models(requests: &mpsc::Receiver<Captured>) -> Vec<String> {
+    requests
+        .try_iter()
+        .map(|request| {
+            request.json()["model"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string()
+        })
+        .collect()
+}
+
+fn client(base_url: String) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("test-api-key")
+        .build()
+        .expect("client should build")
+}
+
+fn chat_request() -> ChatCompletionRequest {
+    ChatCompletionRequest::new("ignored/model", vec![Message::new(Role::User, "Hello")])
+}
+
+#[tokio::test]
+async fn test_router_falls_back_on_server_errors_and_reports_attempts() {
+    let (base_url, rx, server) =
+        spawn_scripted_server(vec![api_error(503, "overloaded"), completion("model-b")]);
+    let router = Router::new(["model-a", "model-b"]);
+
+    let routed = router
+        .chat(&client(base_url), &chat_request())
+        .await
+        .expect("fallback should succeed");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(routed.model, "model-b");
+    assert_eq!(routed.response.model, "model-b");
+    assert_eq!(routed.attempts.len(), 2);
+    assert_eq!(routed.attempts[0].model, "model-a");
+    assert_eq!(
+        routed.attempts[0].error_class,
+        Some(ErrorClass::ServerError)
+    );
+    assert!(
+        routed.attempts[0]
+            .error
+            .as_deref()
+            .is_some_and(|error| error.contains("overloaded"))
+    );
+    assert_eq!(routed.attempts[1].error_class, None);
+    assert_eq!(requested_models(&rx), vec!["model-a", "model-b"]);
+}
+
+#[tokio::test]
+async fn test_router_returns_client_errors_without_fallback() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![api_error(400, "bad request")]);
+    let router = Router::new(["model-a", "model-b"]);
+
+    let error = router
+        .chat(&client(base_url), &chat_request())
+        .await
+        .expect_err("client errors should not fall back");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(ErrorClass::of(error.error()), ErrorClass::ClientError);
+    assert_eq!(error.attempts().len(), 1);
+    assert_eq!(
+        error.attempts()[0].error_class,
+        Some(ErrorClass::ClientError)
+    );
+    assert!(matches!(error.into_error(), OpenRouterError::Api(context) if context.status == 400));
+    assert_eq!(rx.try_iter().count(), 1);
+}
+
+#[tokio::test]
+async fn test_router_falls_back_on_moderation_for_messages_and_responses() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        moderation_error(),
+        Scripted::json(
+            200,
+            &json!({
+                "id": "msg_1",
+                "type": "message",
+                "role": "assistant",
+                "model": "model-b",
+                "content": [{"type": "text", "text": "hi"}],
+                "stop_reason": "end_turn",
+                "usage": {"input_tokens": 1, "output_tokens": 1}
+            }),
+        ),
+        moderation_error(),
+        Scripted::json(
+            200,
+            &json!({
+                "id": "resp_1",
+                "object": "response",
+                "created_at": 1700000000,
+                "model": "model-b",
+                "status": "completed",
+                "output": []
+            }),
+        ),
+    ]);
+    let client = client(base_url);
+    let router = Router::new(["model-a", "model-b"]);
+
+    let routed = router
+        .messages(
+            &client,
+            &AnthropicMessagesRequest::new("ignored", 16, vec![AnthropicMessage::user("Hello")]),
+        )
+        .await
+        .expect("messages fallback should succeed");
+    assert_eq!(routed.model, "model-b");
+    assert_eq!(routed.attempts[0].error_class, Some(ErrorClass::Moderation));
+
+    let routed = router
+        .responses(&client, &ResponsesRequest::new("ignored", json!("Hello")))
+        .await
+        .expect("responses fallback should succeed");
+    server.join().expect("server thread should finish");
+    assert_eq!(routed.model, "model-b");
+    assert_eq!(
+        requested_models(&rx),
+        vec!["model-a", "model-b", "model-a", "model-b"]
+    );
+
+    // Moderation is not a health failure, so no circuit opened.
+    assert_eq!(router.circuit_state("model-a"), CircuitState::Closed);
+}
+
+#[tokio::test]
+async fn test_router_circuit_breaker_skips_failing_model() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        api_error(500, "boom"),
+        completion("model-b"),
+        completion("model-b"),
+    ]);
+    let client = client(base_url);
+    let router = Router::new(["model-a", "model-b"]).circuit_breaker(1, Duration::from_secs(60));
+
+    router
+        .chat(&client, &chat_request())
+        .await
+        .expect("fallback should succeed");
+    assert_eq!(router.circuit_state("model-a"), CircuitState::Open);
+    assert_eq!(router.circuit_state("model-b"), CircuitState::Closed);
+
+    let routed = router
+        .clone()
+        .chat(&client, &chat_request())
+        .await
+        .expect("open circuit should be skipped");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(routed.attempts.len(), 1);
+    assert_eq!(routed.model, "model-b");
+    assert_eq!(requested_models(&rx), vec!["model-a", "model-b", "model-b"]);
+
+    let (base_url, rx, server) = spawn_scripted_server(vec![api_error(500, "boom")]);
+    let client = self::client(base_url);
+    let router = Router::new(["model-a"]).circuit_breaker(1, Duration::from_secs(60));
+    let error = router
+        .chat(&client, &chat_request())
+        .await
+        .expect_err("last model error should be returned");
+    server.join().expect("server thread should finish");
+    assert_eq!(ErrorClass::of(error.error()), ErrorClass::ServerError);
+    assert_eq!(error.attempts().len(), 1);
+    assert_eq!(error.attempts()[0].model, "model-a");
+
+    let error = router
+        .chat(&client, &chat_request())
+        .await
+        .expect_err("no route should be available");
+    assert!(error.attempts().is_empty());
+    assert!(
+        matches!(error.into_error(), OpenRouterError::ConfigError(message) if message.contains("no route available"))
+    );
+    assert_eq!(rx.try_iter().count(), 1);
+}
+
+#[tokio::test]
+async fn test_router_weighted_strategy_prefers_weighted_models() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![completion("model-b")]);
+    let router = Router::new(["model-a"])
+        .weight("model-a", 0)
+        .weight("model-b", 5)
+        .strategy(RoutingStrategy::Weighted);
+
+    let routed = router
+        .chat(&client(base_url), &chat_request())
+        .await
+        .expect("weighted route should succeed");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(routed.model, "model-b");
+    assert_eq!(requested_models(&rx), vec!["model-b"]);
+}
+
+/// Fails `model-a` once, then answers it slowly; answers other models at once.
+#[derive(Clone, Default)]
+struct Recovering {
+    failed: Arc<AtomicBool>,
+    requested: Arc<Mutex<Vec<String>>>,
+}
+
+impl Transport for Recovering {
+    fn send<'a>(
+        &'a self,
+        request: MiddlewareRequest,
+        _network: Network<'a>,
+    ) -> MiddlewareFuture<'a> {
+        Box::pin(async move {
+            let model = request.json_body().expect("request should be JSON")["model"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            self.requested.lock().unwrap().push(model.clone());
+            if model == "model-a" {
+                if !self.failed.swap(true, Ordering::SeqCst) {
+                    return MiddlewareResponse::json(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        &json!({"error": {"code": 500, "message": "boom"}}),
+                    );
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            MiddlewareResponse::json(
+                StatusCode::OK,
+                &json!({
+                    "id": "gen-1",
+                    "choices": [{"index": 0, "finish_reason": "stop", "message": {"role": "assistant", "content": "hi"}}],
+                    "created": 1700000000,
+                    "model": model,
+                    "object": "chat.completion"
+                }),
+            )
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_router_half_open_circuit_admits_a_single_trial() {
+    let transport = Recovering::default();
+    let client = OpenRouterClient::builder()
+        .base_url("http://127.0.0.1:9/api/v1")
+        .api_key("test-api-key")
+        .transport(transport.clone())
+        .build()
+        .expect("client should build");
+    let router = Router::new(["model-a", "model-b"]).circuit_breaker(1, Duration::from_millis(20));
+
+    router
+        .chat(&client, &chat_request())
+        .await
+        .expect("fallback should succeed");
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(router.circuit_state("model-a"), CircuitState::HalfOpen);
+
+    let request = chat_request();
+    let (first, second) = tokio::join!(
+        router.chat(&client, &request),
+        router.chat(&client, &request)
+    );
+    let mut models = vec![
+        first.expect("trial should succeed").model,
+        second
+            .expect("concurrent request should skip the trial")
+            .model,
+    ];
+    models.sort();
+
+    assert_eq!(models, vec!["model-a", "model-b"]);
+    assert_eq!(
+        *transport.requested.lock().unwrap(),
+        vec!["model-a", "model-b", "model-a", "model-b"]
+    );
+    assert_eq!(router.circuit_state("model-a"), CircuitState::Closed);
+}
@@ -0,0 +1,390 @@
+use std::time::Duration;
+
+use futures_util::{StreamExt, stream};
+use openrouter_rs::{
+    api::{
+        messages::{AnthropicContentPart, AnthropicMessagesSseEvent},
+        responses::ResponsesStreamEvent,
+    },
+    error::{OpenRouterError, TimeoutError, TimeoutKind},
+    types::{
+        completion::{Choice, CompletionsResponse, FinishReason},
+        stream::{ChatStreamExt, MessagesStreamExt, ResponsesStreamExt},
+    },
+};
+use serde_json::{Value, json};
+
+fn chat_chunk(delta: Value, extra: Value) -> CompletionsResponse {
+    let mut chunk = json!({
+        "id": "gen_1",
+        "choices": [{
+            "finish_reason": null,
+            "native_finish_reason": null,
+            "delta": delta,
+            "error": null,
+            "index": 0,
+            "logprobs": null
+        }],
+        "created": 1_700_000_000_u64,
+        "model": "openai/gpt-4o",
+        "object": "chat.completion.chunk",
+        "provider": "OpenAI",
+        "system_fingerprint": null,
+        "usage": null
+    });
+    for (key, value) in extra.as_object().into_iter().flatten() {
+        if key == "finish_reason" || key == "logprobs" {
+            chunk["choices"][0][key] = value.clone();
+        } else {
+            chunk[key] = value.clone();
+        }
+    }
+    serde_json::from_value(chunk).expect("chat chunk should deserialize")
+}
+
+fn chat_chunks() -> Vec<Result<CompletionsResponse, OpenRouterError>> {
+    vec![
+        Ok(chat_chunk(
+            json!({
+                "role": "assistant",
+                "content": "It is ",
+                "reasoning": "Look up ",
+                "reasoning_details": [
+                    { "type": "reasoning.text", "text": "Look up ", "index": 0 }
+                ]
+            }),
+            json!({ "logprobs": { "content": [{ "token": "It", "logprob": -0.1 }] } }),
+        )),
+        Ok(chat_chunk(
+            json!({
+                "content": "sunny.",
+                "reasoning": "weather.",
+                "reasoning_details": [
+                    { "type": "reasoning.text", "text": "weather.", "signature": "sig", "index": 0 }
+                ],
+                "annotations": [
+                    { "type": "url_citation", "url_citation": { "url": "https://example.com" } }
+                ],
+                "tool_calls": [{
+                    "index": 0,
+                    "id": "call_1",
+                    "type": "function",
+                    "function": { "name": "get_weather", "arguments": "{\"city\":" }
+                }]
+            }),
+            json!({ "logprobs": { "content": [{ "token": " is", "logprob": -0.2 }] } }),
+        )),
+        Ok(chat_chunk(
+            json!({
+                "tool_calls": [{ "index": 0, "function": { "arguments": "\"SF\"}" } }]
+            }),
+            json!({ "finish_reason": "tool_calls" }),
+        )),
+        Ok(chat_chunk(
+            json!({}),
+            json!({
+                "choices": [],
+                "usage": { "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15 }
+            }),
+        )),
+    ]
+}
+
+#[tokio::test]
+async fn test_collect_completion_matches_non_streaming_shape() {
+    let completion = stream::iter(chat_chunks())
+        .collect_completion()
+        .await
+        .expect("stream should collect");
+
+    assert_eq!(completion.id, "gen_1");
+    assert_eq!(completion.provider.as_deref(), Some("OpenAI"));
+    assert_eq!(completion.usage.as_ref().map(|u| u.total_tokens), Some(15));
+    assert_eq!(completion.choices.len(), 1);
+
+    let choice = &completion.choices[0];
+    assert!(matches!(choice, Choice::NonStreaming(_)));
+    assert_eq!(choice.content(), Some("It is sunny."));
+    assert_eq!(choice.role(), Some("assistant"));
+    assert_eq!(choice.reasoning(), Some("Look up weather."));
+    assert!(matches!(
+        choice.finish_reason(),
+        Some(FinishReason::ToolCalls)
+    ));
+
+    let details = choice.reasoning_details().expect("reasoning details");
+    assert_eq!(details.len(), 1);
+    assert_eq!(details[0].text.as_deref(), Some("Look up weather."));
+    assert_eq!(details[0].signature.as_deref(), Some("sig"));
+
+    let tool_calls = choice.tool_calls().expect("tool calls");
+    assert_eq!(tool_calls.len(), 1);
+    assert_eq!(tool_calls[0].id(), "call_1");
+    assert_eq!(tool_calls[0].arguments_json(), "{\"city\":\"SF\"}");
+
+    let logprobs = choice.logprobs().expect("logprobs");
+    assert_eq!(logprobs["content"].as_array().map(Vec::len), Some(2));
+
+    match choice {
+        Choice::NonStreaming(choice) => {
+            assert_eq!(choice.message.annotations.as_ref().map(Vec::len), Some(1));
+        }
+        other => panic!("expected non-streaming choice, got {other:?}"),
+    }
+
+    // The collected value serializes as a regular chat.completion body.
+    let body = serde_json::to_value(&completion).expect("completion should serialize");
+    assert_eq!(body["object"], "chat.completion");
+    assert!(body["choices"][0].get("message").is_some());
+}
+
+#[tokio::test]
+async fn test_collect_completion_stops_at_first_error() {
+    let chunks = vec![
+        Ok(chat_chunk(json!({ "content": "partial" }), json!({}))),
+        Err(OpenRouterError::Unknown("stream-failed".to_string())),
+    ];
+
+    let result = stream::iter(chunks).collect_completion().await;
+    assert!(matches!(result, Err(OpenRouterError::Unknown(message)) if message == "stream-failed"));
+}
+
+#[tokio::test]
+async fn test_tee_completion_forwards_chunks_while_collecting() {
+    let (forwarded, collector) = stream::iter(chat_chunks()).tee_completion();
+
+    let forwarded: Vec<_> = forwarded.collect().await;
+    assert_eq!(forwarded.len(), 4);
+    assert!(forwarded.iter().all(Result::is_ok));
+
+    let completion = collector.await.expect("collector should resolve");
+    assert_eq!(completion.choices[0].content(), Some("It is sunny."));
+    assert_eq!(completion.usage.as_ref().map(|u| u.total_tokens), Some(15));
+}
+
+#[tokio::test]
+async fn test_tee_completion_reports_dropped_and_failed_streams() {
+    let (mut forwarded, collector) = stream::iter(chat_chunks()).tee_completion();
+    forwarded.next().await;
+    drop(forwarded);
+    assert!(matches!(collector.await, Err(OpenRouterError::Cancelled)));
+
+    let chunks = vec![Err(OpenRouterError::Timeout(TimeoutError::new(
+        TimeoutKind::StreamIdle,
+        Some(Duration::from_secs(5)),
+    )))];
+    let (forwarded, collector) = stream::iter(chunks).tee_completion();
+    let forwarded: Vec<_> = forwarded.collect().await;
+    assert!(matches!(
+        forwarded.as_slice(),
+        [Err(OpenRouterError::Timeout(error))] if error.kind() == TimeoutKind::StreamIdle
+    ));
+    match collector.await {
+        Err(OpenRouterError::Timeout(error)) => {
+            assert_eq!(error.kind(), TimeoutKind::StreamIdle);
+            assert_eq!(error.duration(), Some(Duration::from_secs(5)));
+        }
+        other => panic!("expected the stream's timeout error, got {other:?}"),
+    }
+}
+
+fn responses_event(value: Value) -> Result<ResponsesStreamEvent, OpenRouterError> {
+    Ok(serde_json::from_value(value).expect("responses event should deserialize"))
+}
+
+#[tokio::test]
+async fn test_collect_response_rebuilds_output() {
+    let events = vec![
+        responses_event(json!({
+            "type": "response.created",
+            "response": { "id": "resp_1", "model": "openai/gpt-5", "status": "in_progress" }
+        })),
+        responses_event(json!({
+            "type": "response.output_text.delta",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "Hello "
+        })),
+        responses_event(json!({
+            "type": "response.output_text.delta",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "world"
+        })),
+        responses_event(json!({
+            "type": "response.completed",
+            "response": {
+                "id": "resp_1",
+                "model": "openai/gpt-5",
+                "status": "completed",
+                "usage": { "input_tokens": 3, "output_tokens": 2, "total_tokens": 5 }
+            }
+        })),
+    ];
+
+    let response = stream::iter(events)
+        .collect_response()
+        .await
+        .expect("stream should collect");
+    assert_eq!(response.id.as_deref(), Some("resp_1"));
+    assert_eq!(response.status.as_deref(), Some("completed"));
+    assert_eq!(response.output_text().as_deref(), Some("Hello world"));
+    assert!(response.usage.is_some());
+
+    let (forwarded, collector) = stream::iter(vec![responses_event(json!({
+        "type": "response.output_text.delta",
+        "output_index": 0,
+        "content_index": 0,
+        "delta": "tee"
+    }))])
+    .tee_response();
+    assert_eq!(forwarded.count().await, 1);
+    let response = collector.await.expect("collector should resolve");
+    assert_eq!(response.output_text().as_deref(), Some("tee"));
+}
+
+#[tokio::test]
+async fn test_collect_message_ignores_indices_far_past_the_end() {
+    let events = vec![
+        messages_event(json!({
+            "type": "content_block_start",
+            "index": 0,
+            "content_block": { "type": "text", "text": "kept" }
+        })),
+        messages_event(json!({
+            "type": "content_block_delta",
+            "index": 4_000_000_000_u64,
+            "delta": { "type": "text_delta", "text": "dropped" }
+        })),
+    ];
+    let message = stream::iter(events)
+        .collect_message()
+        .await
+        .expect("stream should collect");
+    assert_eq!(message.content.len(), 1);
+}
+
+fn messages_event(data: Value) -> Result<AnthropicMessagesSseEvent, OpenRouterError> {
+    let event = data["type"].clone();
+    Ok(
+        serde_json::from_value(json!({ "event": event, "data": data }))
+            .expect("messages event should deserialize"),
+    )
+}
+
+fn messages_events() -> Vec<Result<AnthropicMessagesSseEvent, OpenRouterError>> {
+    vec![
+        messages_event(json!({
+            "type": "message_start",
+            "message": {
+                "id": "msg_1",
+                "type": "message",
+                "role": "assistant",
+                "content": [],
+                "model": "anthropic/claude-sonnet-4",
+                "usage": { "input_tokens": 12, "output_tokens": 1 }
+            }
+        })),
+        messages_event(json!({
+            "type": "content_block_start",
+            "index": 0,
+            "content_block": { "type": "thinking", "thinking": "", "signature": "" }
+        })),
+        messages_event(json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": { "type": "thinking_delta", "thinking": "Check the weather." }
+        })),
+        messages_event(json!({
+            "type": "content_block_delta",
+            "index": 0,
+            "delta": { "type": "signature_delta", "signature": "sig_1" }
+        })),
+        messages_event(json!({ "type": "content_block_stop", "index": 0 })),
+        messages_event(json!({
+            "type": "content_block_start",
+            "index": 1,
+            "content_block": { "type": "text", "text": "" }
+        })),
+        messages_event(json!({
+            "type": "content_block_delta",
+            "index": 1,
+            "delta": { "type": "text_delta", "text": "Let me " }
+        })),
+        messages_event(json!({
+            "type": "content_block_delta",
+            "index": 1,
+            "delta": { "type": "text_delta", "text": "check." }
+        })),
+        messages_event(json!({ "type": "content_block_stop", "index": 1 })),
+        messages_event(json!({
+            "type": "content_block_start",
+            "index": 2,
+            "content_block": { "type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": {} }
+        })),
+        messages_event(json!({
+            "type": "content_block_delta",
+            "index": 2,
+            "delta": { "type": "input_json_delta", "partial_json": "{\"city\":" }
+        })),
+        messages_event(json!({
+            "type": "content_block_delta",
+            "index": 2,
+            "delta": { "type": "input_json_delta", "partial_json": "\"SF\"}" }
+        })),
+        messages_event(json!({ "type": "content_block_stop", "index": 2 })),
+        messages_event(json!({
+            "type": "message_delta",
+            "delta": { "stop_reason": "tool_use", "stop_sequence": null },
+            "usage": { "output_tokens": 30 }
+        })),
+        messages_event(json!({ "type": "message_stop" })),
+    ]
+}
+
+#[tokio::test]
+async fn test_collect_message_merges_content_blocks_and_usage() {
+    let message = stream::iter(messages_events())
+        .collect_message()
+        .await
+        .expect("stream should collect");
+
+    assert_eq!(message.id.as_deref(), Some("msg_1"));
+    assert_eq!(message.stop_reason.as_deref(), Some("tool_use"));
+    let usage = message.usage.as_ref().expect("usage");
+    assert_eq!(usage.input_tokens, Some(12));
+    assert_eq!(usage.output_tokens, Some(30));
+
+    assert_eq!(message.content.len(), 3);
+    match &message.content[0] {
+        AnthropicContentPart::Thinking {
+            thinking,
+            signature,
+        } => {
+            assert_eq!(thinking, "Check the weather.");
+            assert_eq!(signature, "sig_1");
+        }
+        other => panic!("expected thinking block, got {other:?}"),
+    }
+    match &message.content[1] {
+        AnthropicContentPart::Text { text, .. } => assert_eq!(text, "Let me check."),
+        other => panic!("expected text block, got {other:?}"),
+    }
+    match &message.content[2] {
+        AnthropicContentPart::ToolUse { id, input, .. } => {
+            assert_eq!(id, "toolu_1");
+            assert_eq!(input.as_ref(), Some(&json!({ "city": "SF" })));
+        }
+        other => panic!("expected tool_use block, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_tee_message_forwards_events_while_collecting() {
+    let (forwarded, collector) = stream::iter(messages_events()).tee_message();
+    assert_eq!(forwarded.count().await, 15);
+
+    let message = collector.await.expect("collector should resolve");
+    assert_eq!(message.content.len(), 3);
+    assert_eq!(message.stop_reason.as_deref(), Some("tool_use"));
+}
@@ -0,0 +1,247 @@
+use openrouter_rs::{
+    OpenRouterClient,
+    api::{chat::ChatCompletionRequest, chat::Message, responses::ResponsesRequest},
+    error::OpenRouterError,
+    types::{ResponseFormat, Role},
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+#[derive(Debug, Deserialize, JsonSchema, PartialEq)]
+struct CityAnswer {
+    city: String,
+    population: u64,
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct Itinerary {
+    destination: CityAnswer,
+    note: Option<String>,
+    layover: Option<CityAnswer>,
+    stops: Vec<CityAnswer>,
+}
+
+/// Assert every object schema in `schema` is closed and requires all of its
+/// properties, as strict mode demands.
+fn assert_strict(schema: &Value) {
+    match schema {
+        Value::Object(object) => {
+            if let Some(properties) = object.get("properties").and_then(Value::as_object) {
+                assert_eq!(object["additionalProperties"], false, "{schema}");
+                let mut required: Vec<&str> = object["required"]
+                    .as_array()
+                    .expect("required should be listed")
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect();
+                required.sort_unstable();
+                let mut names: Vec<&str> = properties.keys().map(String::as_str).collect();
+                names.sort_unstable();
+                assert_eq!(required, names, "{schema}");
+            }
+            object.values().for_each(assert_strict);
+        }
+        Value::Array(values) => values.iter().for_each(assert_strict),
+        _ => {}
+    }
+}
+
+fn chat_response(content: &str) -> Scripted {
+    Scripted::json(
+        200,
+        &json!({
+            "id": "gen-1",
+            "choices": [{
+                "finish_reason": "stop",
+                "index": 0,
+                "message": {"role": "assistant", "content": content}
+            }],
+            "created": 1700000000,
+            "model": "openai/gpt-4.1-mini",
+            "object": "chat.completion"
+        }),
+    )
+}
+
+fn responses_response(text: &str) -> Scripted {
+    Scripted::json(
+        200,
+        &json!({
+            "id": "resp-1",
+            "object": "response",
+            "created_at": 1700000000,
+            "model": "openai/gpt-4.1-mini",
+            "status": "completed",
+            "output": [{
+                "type": "message",
+                "role": "assistant",
+                "content": [{"type": "output_text", "text": text}]
+            }]
+        }),
+    )
+}
+
+fn build_client(base_url: String) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .build()
+        .expect("client should build")
+}
+
+#[test]
+fn test_json_schema_for_generates_strict_named_schema() {
+    let value = serde_json::to_value(ResponseFormat::json_schema_for::<CityAnswer>())
+        .expect("format should serialize");
+    assert_eq!(value["type"], "json_schema");
+    assert_eq!(value["json_schema"]["name"], "CityAnswer");
+    assert_eq!(value["json_schema"]["strict"], true);
+    assert_eq!(
+        value["json_schema"]["schema"]["properties"]["population"]["type"],
+        "integer"
+    );
+    assert_strict(&value["json_schema"]["schema"]);
+
+    let value = serde_json::to_value(ResponseFormat::json_schema_for::<Itinerary>())
+        .expect("format should serialize");
+    let schema = &value["json_schema"]["schema"];
+    assert_eq!(value["json_schema"]["strict"], true);
+    assert_strict(schema);
+    assert_eq!(
+        schema["properties"]["note"]["type"],
+        json!(["string", "null"])
+    );
+    assert!(
+        schema["properties"]["layover"]["anyOf"]
+            .as_array()
+            .expect("optional struct should be a union")
+            .contains(&json!({"type": "null"}))
+    );
+    assert_eq!(schema["$defs"]["CityAnswer"]["additionalProperties"], false);
+}
+
+#[tokio::test]
+async fn test_chat_create_structured_parses_fenced_reply() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![chat_response(
+        "```json\n{\"city\":\"Paris\",\"population\":2100000}\n```",
+    )]);
+    let request = ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![Message::new(Role::User, "Largest city in France?")])
+        .build()
+        .expect("chat request should build");
+
+    let answer: CityAnswer = build_client(base_url)
+        .chat()
+        .create_structured(&request)
+        .await
+        .expect("structured reply should parse");
+    assert_eq!(
+        answer,
+        CityAnswer {
+            city: "Paris".to_string(),
+            population: 2_100_000
+        }
+    );
+
+    let body = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("request should be captured");
+    assert_eq!(body["response_format"]["type"], "json_schema");
+    assert_eq!(body["response_format"]["json_schema"]["name"], "CityAnswer");
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_chat_create_structured_repairs_then_reports_raw_text() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        chat_response("Paris, about two million people"),
+        chat_response("{\"city\":\"Paris\"}"),
+    ]);
+    let request = ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![Message::new(Role::User, "Largest city in France?")])
+        .build()
+        .expect("chat request should build");
+
+    let error = build_client(base_url)
+        .chat()
+        .create_structured_with_repair::<CityAnswer>(&request, 1)
+        .await
+        .expect_err("both replies are invalid");
+    match error {
+        OpenRouterError::StructuredOutput(error) => {
+            assert_eq!(error.raw(), "{\"city\":\"Paris\"}");
+            assert_eq!(error.attempts(), 2);
+            assert!(error.message().contains("population"));
+        }
+        other => panic!("expected structured output error, got {other:?}"),
+    }
+
+    let _ = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("first request should be captured");
+    let repair = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("repair request should be captured");
+    let messages = repair["messages"].as_array().expect("messages array");
+    assert_eq!(messages.len(), 3);
+    assert_eq!(messages[1]["role"], "assistant");
+    assert_eq!(messages[1]["content"], "Paris, about two million people");
+    assert_eq!(messages[2]["role"], "user");
+    assert!(
+        messages[2]["content"]
+            .as_str()
+            .expect("repair prompt should be text")
+            .contains("could not be parsed")
+    );
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_responses_create_structured_sets_text_format_and_repairs() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        responses_response("not json"),
+        responses_response("{\"city\":\"Rome\",\"population\":2800000}"),
+    ]);
+    let request = ResponsesRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .input(json!("Largest city in Italy?").into())
+        .text(json!({"verbosity": "low"}))
+        .build()
+        .expect("responses request should build");
+
+    let answer: CityAnswer = build_client(base_url)
+        .responses()
+        .create_structured_with_repair(&request, 2)
+        .await
+        .expect("repaired reply should parse");
+    assert_eq!(answer.city, "Rome");
+
+    let first = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("first request should be captured");
+    assert_eq!(first["text"]["verbosity"], "low");
+    assert_eq!(first["text"]["format"]["type"], "json_schema");
+    assert_eq!(first["text"]["format"]["name"], "CityAnswer");
+    assert_eq!(first["text"]["format"]["strict"], true);
+
+    let repair = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("repair request should be captured");
+    let input = repair["input"].as_array().expect("input should be a list");
+    assert_eq!(input.len(), 3);
+    assert_eq!(input[0]["content"], "Largest city in Italy?");
+    assert_eq!(input[1]["role"], "assistant");
+    assert_eq!(input[1]["content"], "not json");
+    server.join().expect("server thread should finish");
+}
@@ -0,0 +1,183 @@
+//! Local HTTP servers shared by tests: one that scripts a sequence of
+//! responses, and a keep-alive server for connection reuse checks. Also
+//! included by the `connection_reuse` bench.
+
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+/// One scripted HTTP response.
+pub struct Scripted {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Scripted {
+    pub fn new(status: u16, content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type".to_string(), content_type.to_string())],
+            body: body.into(),
+        }
+    }
+
+    pub fn json(status: u16, body: &Value) -> Self {
+        Self::new(status, "application/json", body.to_string())
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request received by the scripted server.
+pub struct Captured {
+    pub request_line: String,
+    /// Request headers, lowercased.
+    pub headers: String,
+    pub body: Vec<u8>,
+}
+
+impl Captured {
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).to_string()
+    }
+
+    pub fn json(&self) -> Value {
+        serde_json::from_slice(&self.body).expect("request body should be JSON")
+    }
+}
+
+/// Read one request, or `None` if the client hung up before sending it.
+fn read_request(stream: &mut TcpStream) -> Option<Captured> {
+    let mut request_bytes = Vec::new();
+    let mut chunk = [0_u8; 4096];
+    let header_end = loop {
+        if let Some(position) = request_bytes
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+        {
+            break position + 4;
+        }
+        let read = stream.read(&mut chunk).ok().filter(|read| *read > 0)?;
+        request_bytes.extend_from_slice(&chunk[..read]);
+    };
+    let head = String::from_utf8_lossy(&request_bytes[..header_end]).to_string();
+    let headers = head.to_lowercase();
+    let content_length = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .unwrap_or(0);
+    while request_bytes.len() < header_end + content_length {
+        let read = stream.read(&mut chunk).expect("server should read body");
+        if read == 0 {
+            break;
+        }
+        request_bytes.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(Captured {
+        request_line: head.lines().next().unwrap_or_default().to_string(),
+        headers,
+        body: request_bytes[header_end..].to_vec(),
+    })
+}
+
+/// Serve one scripted response per connection, in order, and report each
+/// request received. Requests are still served after the receiver is
+/// dropped.
+pub fn spawn_scripted_server(
+    responses: Vec<Scripted>,
+) -> (String, mpsc::Receiver<Captured>, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    let (tx, rx) = mpsc::channel::<Captured>();
+
+    let server = thread::spawn(move || {
+        for scripted in responses {
+            let (mut stream, _) = listener.accept().expect("server should accept connection");
+            let Some(request) = read_request(&mut stream) else {
+                continue;
+            };
+            let _ = tx.send(request);
+
+            let mut head = format!("HTTP/1.1 {} Scripted\r\n", scripted.status);
+            for (name, value) in &scripted.headers {
+                head.push_str(&format!("{name}: {value}\r\n"));
+            }
+            head.push_str(&format!(
+                "Content-Length: {}\r\nConnection: close\r\n\r\n",
+                scripted.body.len()
+            ));
+            stream
+                .write_all(head.as_bytes())
+                .and_then(|()| stream.write_all(&scripted.body))
+                .expect("server should write response");
+        }
+    });
+
+    (format!("http://{addr}/api/v1"), rx, server)
+}
+
+/// Keep-alive server that answers every request with a credits payload and
+/// counts accepted connections.
+pub async fn spawn_keep_alive_server() -> (String, Arc<AtomicUsize>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    let connections = Arc::new(AtomicUsize::new(0));
+    let accepted = connections.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((mut stream, _)) = listener.accept().await else {
+                return;
+            };
+            accepted.fetch_add(1, Ordering::SeqCst);
+            tokio::spawn(async move {
+                let body = r#"{"data":{"total_credits":10.0,"total_usage":2.5}}"#;
+                let mut request = Vec::new();
+                let mut chunk = [0_u8; 1024];
+                loop {
+                    let read = match stream.read(&mut chunk).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(read) => read,
+                    };
+                    request.extend_from_slice(&chunk[..read]);
+                    while let Some(end) =
+                        request.windows(4).position(|window| window == b"\r\n\r\n")
+                    {
+                        request.drain(..end + 4);
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                            body.len()
+                        );
+                        if stream.write_all(response.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    (format!("http://{addr}/api/v1"), connections)
+}
@@ -0,0 +1,316 @@
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::chat::{ChatCompletionRequest, Message},
+    types::Role,
+};
+use serde_json::json;
+
+/// Serve one event stream with an `x-request-id` header, then stop.
+fn spawn_stream_server() -> (String, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("server should accept connection");
+        let mut request_bytes = Vec::new();
+        let mut chunk = [0_u8; 4096];
+        let header_end = loop {
+            if let Some(position) = request_bytes
+                .windows(4)
+                .position(|window| window == b"\r\n\r\n")
+            {
+                break position + 4;
+            }
+            let read = stream.read(&mut chunk).expect("server should read request");
+            request_bytes.extend_from_slice(&chunk[..read]);
+        };
+        let headers = String::from_utf8_lossy(&request_bytes[..header_end]).to_lowercase();
+        let content_length = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length:"))
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        while request_bytes.len() < header_end + content_length {
+            let read = stream.read(&mut chunk).expect("server should read body");
+            request_bytes.extend_from_slice(&chunk[..read]);
+        }
+
+        let chunk = |delta: serde_json::Value, finish_reason: Option<&str>| {
+            json!({
+                "id": "gen-telemetry",
+                "provider": "OpenAI",
+                "choices": [{"index": 0, "finish_reason": finish_reason, "delta": delta}],
+                "created": 1700000000,
+                "model": "openai/gpt-4.1-mini",
+                "object": "chat.completion.chunk"
+            })
+        };
+        let usage = json!({
+            "id": "gen-telemetry",
+            "provider": "OpenAI",
+            "choices": [],
+            "created": 1700000000,
+            "model": "openai/gpt-4.1-mini",
+            "object": "chat.completion.chunk",
+            "usage": {"prompt_tokens": 12, "completion_tokens": 5, "total_tokens": 17, "cost": 0.002}
+        });
+        let body = format!(
+            "data: {}\n\ndata: {}\n\ndata: {usage}\n\ndata: [DONE]\n\n",
+            chunk(json!({"role": "assistant", "content": "hi"}), None),
+            chunk(json!({}), Some("stop")),
+        );
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nx-request-id: req-telemetry\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream
+            .write_all(response.as_bytes())
+            .expect("server should write response");
+    });
+
+    (format!("http://{addr}/api/v1"), server)
+}
+
+fn request() -> ChatCompletionRequest {
+    ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![Message::new(Role::User, "Say hi.")])
+        .max_tokens(50)
+        .temperature(0.0)
+        .build()
+        .expect("request should build")
+}
+
+fn client(base_url: String) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("test-api-key")
+        .build()
+        .expect("client should build")
+}
+
+#[cfg(feature = "tracing")]
+mod spans {
+    use std::{
+        collections::BTreeMap,
+        fmt,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+    };
+    use tracing_subscriber::{
+        layer::{Context, Layer, SubscriberExt},
+        registry::LookupSpan,
+        util::SubscriberInitExt,
+    };
+
+    use super::*;
+    use crate::support::{Scripted, spawn_scripted_server};
+
+    #[derive(Default)]
+    struct Fields(BTreeMap<String, String>);
+
+    impl Visit for Fields {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    /// Layer that keeps the fields of every closed span.
+    #[derive(Clone, Default)]
+    struct CaptureLayer {
+        closed: Arc<Mutex<Vec<BTreeMap<String, String>>>>,
+    }
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for CaptureLayer {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let mut fields = Fields::default();
+            attrs.record(&mut fields);
+            fields
+                .0
+                .insert("name".to_string(), attrs.metadata().name().to_string());
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(fields);
+            }
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+            if let Some(span) = ctx.span(id) {
+                if let Some(fields) = span.extensions_mut().get_mut::<Fields>() {
+                    values.record(fields);
+                }
+            }
+        }
+
+        fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+            if let Some(span) = ctx.span(&id) {
+                if let Some(fields) = span.extensions_mut().remove::<Fields>() {
+                    self.closed.lock().unwrap().push(fields.0);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_span_covers_stream_and_records_gen_ai_fields() {
+        let layer = CaptureLayer::default();
+        let _guard = tracing_subscriber::registry()
+            .with(layer.clone())
+            .set_default();
+        let (base_url, server) = spawn_stream_server();
+        let client = client(base_url);
+
+        let stream = client
+            .chat()
+            .stream(&request())
+            .await
+            .expect("stream should start");
+        assert!(
+            layer.closed.lock().unwrap().is_empty(),
+            "span should stay open while the stream is unread"
+        );
+
+        let chunks = stream.collect::<Vec<_>>().await;
+        assert!(chunks.iter().all(Result::is_ok));
+        server.join().expect("server should finish");
+
+        let closed = layer.closed.lock().unwrap();
+        assert_eq!(closed.len(), 1);
+        let span = &closed[0];
+        assert_eq!(span["name"], "openrouter.request");
+        assert_eq!(span["otel.name"], "chat openai/gpt-4.1-mini");
+        assert_eq!(span["gen_ai.operation.name"], "chat");
+        assert_eq!(span["gen_ai.provider.name"], "openrouter");
+        assert_eq!(span["gen_ai.request.model"], "openai/gpt-4.1-mini");
+        assert_eq!(span["gen_ai.request.max_tokens"], "50");
+        assert_eq!(span["gen_ai.response.model"], "openai/gpt-4.1-mini");
+        assert_eq!(span["gen_ai.response.id"], "gen-telemetry");
+        assert_eq!(span["gen_ai.response.finish_reasons"], "stop");
+        assert_eq!(span["gen_ai.usage.input_tokens"], "12");
+        assert_eq!(span["gen_ai.usage.output_tokens"], "5");
+        assert_eq!(span["openrouter.provider"], "OpenAI");
+        assert_eq!(span["openrouter.cost"], "0.002");
+        assert_eq!(span["openrouter.request_id"], "req-telemetry");
+        assert_eq!(span["openrouter.stream"], "true");
+        assert_eq!(span["http.response.status_code"], "200");
+        assert_eq!(span["otel.status_code"], "OK");
+        assert!(span.contains_key("gen_ai.server.time_to_first_token"));
+    }
+
+    #[tokio::test]
+    async fn test_binary_download_streams_without_being_observed() {
+        let layer = CaptureLayer::default();
+        let _guard = tracing_subscriber::registry()
+            .with(layer.clone())
+            .set_default();
+        // A large body that would parse as JSON if it were buffered.
+        let mut body = br#"{"model":"leaked","pad":""#.to_vec();
+        body.resize(body.len() + 32 * 1024 * 1024, b'x');
+        body.extend_from_slice(br#""}"#);
+        let size = body.len() as u64;
+        let (base_url, _requests, server) =
+            spawn_scripted_server(vec![Scripted::new(200, "application/octet-stream", body)]);
+        let client = client(base_url);
+
+        let written = client
+            .files()
+            .download_to_writer("file_123", None, &mut tokio::io::sink())
+            .await
+            .expect("download should complete");
+        server.join().expect("server should finish");
+
+        assert_eq!(written, size);
+        let closed = layer.closed.lock().unwrap();
+        assert_eq!(closed.len(), 1);
+        let span = &closed[0];
+        assert_eq!(span["url.path"], "/api/v1/files/file_123/content");
+        assert_eq!(span["otel.status_code"], "OK");
+        assert!(!span.contains_key("gen_ai.response.model"));
+    }
+}
+
+#[cfg(feature = "metrics")]
+mod counters {
+    use metrics_util::{
+        MetricKind,
+        debugging::{DebugValue, DebuggingRecorder},
+    };
+    use openrouter_rs::telemetry::{REQUESTS, TIME_TO_FIRST_TOKEN, TOKEN_USAGE, TOKENS};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stream_records_request_token_and_latency_metrics() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+        let (base_url, server) = spawn_stream_server();
+        let client = client(base_url);
+
+        let stream = client
+            .chat()
+            .stream(&request())
+            .await
+            .expect("stream should start");
+        let chunks = stream.collect::<Vec<_>>().await;
+        assert!(chunks.iter().all(Result::is_ok));
+        server.join().expect("server should finish");
+
+        let metrics = snapshotter.snapshot().into_vec();
+        let find = |kind: MetricKind, name: &str, token_type: Option<&str>| {
+            metrics
+                .iter()
+                .find(|(key, ..)| {
+                    let labels: Vec<_> = key.key().labels().collect();
+                    key.kind() == kind
+                        && key.key().name() == name
+                        && labels.iter().any(|label| {
+                            label.key() == "gen_ai.request.model"
+                                && label.value() == "openai/gpt-4.1-mini"
+                        })
+                        && token_type.is_none_or(|token_type| {
+                            labels.iter().any(|label| {
+                                label.key() == "gen_ai.token.type" && label.value() == token_type
+                            })
+                        })
+                })
+                .map(|(.., value)| value)
+                .unwrap_or_else(|| panic!("missing {name} metric"))
+        };
+
+        assert_eq!(
+            find(MetricKind::Counter, REQUESTS, None),
+            &DebugValue::Counter(1)
+        );
+        assert_eq!(
+            find(MetricKind::Counter, TOKENS, Some("output")),
+            &DebugValue::Counter(5)
+        );
+        match find(MetricKind::Histogram, TOKEN_USAGE, Some("input")) {
+            DebugValue::Histogram(values) => assert_eq!(values.len(), 1),
+            other => panic!("expected histogram, got {other:?}"),
+        }
+        match find(MetricKind::Histogram, TIME_TO_FIRST_TOKEN, None) {
+            DebugValue::Histogram(values) => assert_eq!(values.len(), 1),
+            other => panic!("expected histogram, got {other:?}"),
+        }
+    }
+}
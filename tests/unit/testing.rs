@@ -0,0 +1,344 @@
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures_util::StreamExt;
+use http::StatusCode;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::{
+        chat,
+        images::{ImageGenerationRequest, ImageStreamEvent},
+    },
+    client::RetryPolicy,
+    error::OpenRouterError,
+    testing::{Cassette, CassetteMode, Matcher, MockResponse, MockTransport},
+    types::Role,
+};
+use serde_json::json;
+
+const CHAT_CHUNK: &str = "data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"hi\"}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n";
+
+fn chat_completion_json(content: &str) -> serde_json::Value {
+    json!({
+        "id": "gen-1",
+        "object": "chat.completion",
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "choices": [{
+            "index": 0,
+            "message": {"role": "assistant", "content": content},
+            "finish_reason": "stop"
+        }]
+    })
+}
+
+fn build_chat_request(prompt: &str) -> chat::ChatCompletionRequest {
+    chat::ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![chat::Message::new(Role::User, prompt)])
+        .build()
+        .expect("chat request should build")
+}
+
+fn mock_client(mock: &MockTransport) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .api_key("api-key")
+        .transport(mock.clone())
+        .build()
+        .expect("client should build")
+}
+
+fn unreachable_base_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    drop(listener);
+    format!("http://{addr}/api/v1")
+}
+
+fn cassette_path(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock should be after epoch")
+        .as_nanos();
+    std::env::temp_dir().join(format!(
+        "openrouter-rs-{name}-{}-{nanos}.json",
+        std::process::id()
+    ))
+}
+
+fn read_request(stream: &mut TcpStream) {
+    let mut request_bytes = Vec::new();
+    let mut chunk = [0_u8; 1024];
+    let header_end = loop {
+        let read = stream.read(&mut chunk).expect("server should read request");
+        if read == 0 {
+            return;
+        }
+        request_bytes.extend_from_slice(&chunk[..read]);
+        if let Some(pos) = request_bytes
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+        {
+            break pos + 4;
+        }
+    };
+
+    let header_text = String::from_utf8_lossy(&request_bytes[..header_end]).to_ascii_lowercase();
+    let content_length = header_text
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:")?.trim().parse().ok())
+        .unwrap_or(0);
+    let mut body_len = request_bytes.len() - header_end;
+    while body_len < content_length {
+        let read = stream
+            .read(&mut chunk)
+            .expect("server should read request body");
+        if read == 0 {
+            break;
+        }
+        body_len += read;
+    }
+}
+
+fn spawn_sse_server(body: &'static str) -> (String, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener
+            .accept()
+            .expect("server should accept one connection");
+        read_request(&mut stream);
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nSet-Cookie: session=secret\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream
+            .write_all(response.as_bytes())
+            .expect("server should write response");
+    });
+
+    (format!("http://{addr}/api/v1"), server)
+}
+
+#[tokio::test]
+async fn test_mock_transport_serves_json_and_records_requests() {
+    let mock = MockTransport::new();
+    mock.on(
+        Matcher::post("/chat/completions")
+            .header("authorization", "Bearer api-key")
+            .body_partial_json(json!({"model": "openai/gpt-4.1-mini"})),
+        MockResponse::json(chat_completion_json("mocked")),
+    );
+    let client = mock_client(&mock);
+
+    let response = client
+        .chat()
+        .create(&build_chat_request("hello"))
+        .await
+        .expect("mocked request should succeed");
+    assert_eq!(response.choices[0].content(), Some("mocked"));
+
+    let requests = mock.received_requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, http::Method::POST);
+    assert!(requests[0].url.ends_with("/api/v1/chat/completions"));
+    assert!(!requests[0].streaming);
+    let body = requests[0].body.as_ref().expect("body should be recorded");
+    assert_eq!(body["messages"][0]["content"], "hello");
+}
+
+#[tokio::test]
+async fn test_mock_transport_streams_chat_and_image_sse() {
+    let mock = MockTransport::new();
+    mock.on(
+        Matcher::post("/chat/completions").streaming(true),
+        MockResponse::sse_raw([CHAT_CHUNK, "data: [DONE]\n\n"]),
+    );
+    mock.on(
+        Matcher::post("/images").streaming(true),
+        MockResponse::sse([json!({
+            "data": {
+                "type": "image_generation.partial_image",
+                "partial_image_index": 0,
+                "b64_json": "cGFydGlhbA=="
+            }
+        })]),
+    );
+    let client = mock_client(&mock);
+
+    let chunks: Vec<_> = client
+        .chat()
+        .stream(&build_chat_request("hello"))
+        .await
+        .expect("chat stream should open")
+        .collect()
+        .await;
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(
+        chunks[0].as_ref().expect("chunk should parse").choices[0].content(),
+        Some("hi")
+    );
+
+    let image_request = ImageGenerationRequest::builder()
+        .model("openai/gpt-image-1")
+        .prompt("stream an image")
+        .build()
+        .expect("image request should build");
+    let mut images = client
+        .images()
+        .stream(&image_request)
+        .await
+        .expect("image stream should open");
+    let event = images
+        .next()
+        .await
+        .expect("image stream should emit one event")
+        .expect("event should deserialize");
+    assert!(matches!(event.data, ImageStreamEvent::PartialImage(_)));
+    assert!(images.next().await.is_none());
+}
+
+#[tokio::test]
+async fn test_mock_transport_once_errors_and_unmatched_requests() {
+    let mock = MockTransport::new();
+    mock.on_once(
+        Matcher::post("/chat/completions"),
+        MockResponse::error(StatusCode::TOO_MANY_REQUESTS, "slow down"),
+    )
+    .on(
+        Matcher::post("/chat/completions"),
+        MockResponse::json(chat_completion_json("second")),
+    );
+    let client = mock_client(&mock);
+
+    match client.chat().create(&build_chat_request("hello")).await {
+        Err(OpenRouterError::Api(error)) => {
+            assert_eq!(error.status, StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(error.message, "slow down");
+        }
+        other => panic!("expected API error, got {other:?}"),
+    }
+
+    let response = client
+        .chat()
+        .create(&build_chat_request("hello"))
+        .await
+        .expect("second mock should respond");
+    assert_eq!(response.choices[0].content(), Some("second"));
+
+    let error = client
+        .management()
+        .get_credits()
+        .await
+        .expect_err("unmatched request should fail");
+    assert!(matches!(error, OpenRouterError::ConfigError(message) if message.contains("/credits")));
+    assert_eq!(mock.received_requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_mock_transport_runs_behind_retry_policy() {
+    let mock = MockTransport::new();
+    mock.on_once(
+        Matcher::post("/chat/completions"),
+        MockResponse::error(StatusCode::SERVICE_UNAVAILABLE, "overloaded"),
+    )
+    .on(
+        Matcher::post("/chat/completions"),
+        MockResponse::json(chat_completion_json("retried")),
+    );
+    let client = OpenRouterClient::builder()
+        .api_key("api-key")
+        .transport(mock.clone())
+        .retry_policy(
+            RetryPolicy::new(2)
+                .initial_backoff(Duration::from_millis(1))
+                .jitter(false),
+        )
+        .build()
+        .expect("client should build");
+
+    let response = client
+        .chat()
+        .create(&build_chat_request("hello"))
+        .await
+        .expect("retry should reach the second mock");
+    assert_eq!(response.choices[0].content(), Some("retried"));
+    assert_eq!(mock.received_requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_cassette_records_streaming_traffic_and_replays_offline() {
+    let path = cassette_path("chat-stream");
+    let (base_url, server) = spawn_sse_server(concat!(
+        "data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"hi\"}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+        "data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\" there\"}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+        "data: [DONE]\n\n"
+    ));
+
+    let recorder = Cassette::record_or_replay(&path).expect("cassette should open");
+    assert_eq!(recorder.mode(), CassetteMode::Record);
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("secret-api-key")
+        .transport(recorder)
+        .build()
+        .expect("client should build");
+    let recorded: Vec<_> = client
+        .chat()
+        .stream(&build_chat_request("hello"))
+        .await
+        .expect("recorded stream should open")
+        .collect()
+        .await;
+    assert_eq!(recorded.len(), 2);
+    server.join().expect("server thread should finish");
+
+    let contents = std::fs::read_to_string(&path).expect("cassette should be written");
+    assert!(!contents.contains("secret-api-key"));
+    assert!(!contents.to_ascii_lowercase().contains("set-cookie"));
+
+    let player = Cassette::record_or_replay(&path).expect("cassette should load");
+    assert_eq!(player.mode(), CassetteMode::Replay);
+    let client = OpenRouterClient::builder()
+        .base_url(unreachable_base_url())
+        .api_key("other-key")
+        .transport(player)
+        .build()
+        .expect("client should build");
+    let replayed: Vec<_> = client
+        .chat()
+        .stream(&build_chat_request("hello"))
+        .await
+        .expect("replayed stream should open")
+        .collect()
+        .await;
+    let text: String = replayed
+        .iter()
+        .map(|chunk| {
+            chunk.as_ref().expect("chunk should parse").choices[0]
+                .content()
+                .unwrap_or_default()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(text, "hi there");
+
+    match client.chat().stream(&build_chat_request("hello")).await {
+        Err(OpenRouterError::ConfigError(_)) => {}
+        Err(other) => panic!("expected cassette miss, got {other:?}"),
+        Ok(_) => panic!("each interaction should replay once"),
+    }
+
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,256 @@
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    thread,
+    time::Duration,
+};
+
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::chat,
+    client::Timeouts,
+    error::{OpenRouterError, TimeoutKind},
+    types::Role,
+};
+
+fn read_request_headers(stream: &mut TcpStream) {
+    let mut request_bytes = Vec::new();
+    let mut chunk = [0_u8; 1024];
+    loop {
+        let read = stream.read(&mut chunk).expect("server should read request");
+        if read == 0 {
+            return;
+        }
+        request_bytes.extend_from_slice(&chunk[..read]);
+        if request_bytes.windows(4).any(|window| window == b"\r\n\r\n") {
+            return;
+        }
+    }
+}
+
+fn spawn_stalled_json_server(delay: Duration) -> (String, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener
+            .accept()
+            .expect("server should accept one connection");
+        read_request_headers(&mut stream);
+        thread::sleep(delay);
+        let body = r#"{"data":{"total_credits":1.0,"total_usage":0.0}}"#;
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let _ = stream.write_all(response.as_bytes());
+    });
+
+    (format!("http://{addr}/api/v1"), server)
+}
+
+/// Server that sends response headers, then stalls before the body.
+fn spawn_stalled_body_server(delay: Duration) -> (String, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener
+            .accept()
+            .expect("server should accept one connection");
+        read_request_headers(&mut stream);
+        let body = r#"{"data":{"total_credits":1.0,"total_usage":0.0}}"#;
+        let head = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        stream
+            .write_all(head.as_bytes())
+            .expect("server should write headers");
+        stream.flush().expect("server should flush headers");
+        thread::sleep(delay);
+        let _ = stream.write_all(body.as_bytes());
+    });
+
+    (format!("http://{addr}/api/v1"), server)
+}
+
+fn spawn_stalling_sse_server(
+    first_chunk: &str,
+    stall: Duration,
+) -> (String, thread::JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    let first_chunk = first_chunk.to_string();
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener
+            .accept()
+            .expect("server should accept one connection");
+        read_request_headers(&mut stream);
+        let head =
+            "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nConnection: close\r\n\r\n";
+        stream
+            .write_all(head.as_bytes())
+            .expect("server should write headers");
+        stream
+            .write_all(first_chunk.as_bytes())
+            .expect("server should write first chunk");
+        stream.flush().expect("server should flush first chunk");
+        thread::sleep(stall);
+        let _ = stream.write_all(b"data: [DONE]\n\n");
+    });
+
+    (format!("http://{addr}/api/v1"), server)
+}
+
+fn build_chat_request() -> chat::ChatCompletionRequest {
+    chat::ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![chat::Message::new(Role::User, "hello")])
+        .build()
+        .expect("chat request should build")
+}
+
+fn assert_timeout(error: OpenRouterError, expected: TimeoutKind, duration: Duration) {
+    match error {
+        OpenRouterError::Timeout(timeout) => {
+            assert_eq!(timeout.kind(), expected);
+            assert_eq!(timeout.duration(), Some(duration));
+        }
+        other => panic!("expected {expected:?} timeout, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_request_timeout_surfaces_timeout_variant() {
+    let (base_url, server) = spawn_stalled_json_server(Duration::from_millis(500));
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .timeouts(Timeouts::new().request(Duration::from_millis(100)))
+        .build()
+        .expect("client should build");
+
+    let error = client
+        .management()
+        .get_credits()
+        .await
+        .expect_err("stalled request should time out");
+
+    assert_timeout(error, TimeoutKind::Request, Duration::from_millis(100));
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_per_call_timeout_override_applies_to_one_call() {
+    let (base_url, server) = spawn_stalled_json_server(Duration::from_millis(500));
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .build()
+        .expect("client should build");
+
+    let error = client
+        .with_timeouts(Timeouts::new().request(Duration::from_millis(100)))
+        .management()
+        .get_credits()
+        .await
+        .expect_err("override should bound the call");
+
+    assert_timeout(error, TimeoutKind::Request, Duration::from_millis(100));
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_request_timeout_while_reading_body_reports_duration() {
+    let (base_url, server) = spawn_stalled_body_server(Duration::from_millis(500));
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .timeouts(Timeouts::new().request(Duration::from_millis(100)))
+        .build()
+        .expect("client should build");
+
+    let error = client
+        .management()
+        .get_credits()
+        .await
+        .expect_err("stalled body should time out");
+
+    assert_timeout(error, TimeoutKind::Request, Duration::from_millis(100));
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_stream_idle_timeout_fails_stalled_stream() {
+    let first_chunk = "data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"hi\"}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n";
+    let (base_url, server) = spawn_stalling_sse_server(first_chunk, Duration::from_millis(500));
+    let client = OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .timeouts(
+            Timeouts::new()
+                .request(Duration::from_millis(100))
+                .stream_idle(Duration::from_millis(150)),
+        )
+        .build()
+        .expect("client should build");
+
+    let mut stream = client
+        .chat()
+        .stream(&build_chat_request())
+        .await
+        .expect("stream should open before the request timeout");
+
+    let first = stream
+        .next()
+        .await
+        .expect("first chunk should arrive")
+        .expect("first chunk should parse");
+    assert_eq!(first.choices[0].content(), Some("hi"));
+
+    let error = stream
+        .next()
+        .await
+        .expect("stream should yield the idle timeout")
+        .expect_err("stalled stream should time out");
+    assert_timeout(error, TimeoutKind::StreamIdle, Duration::from_millis(150));
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_connection_refused_is_not_a_timeout() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    drop(listener);
+
+    let client = OpenRouterClient::builder()
+        .base_url(format!("http://{addr}/api/v1"))
+        .api_key("api-key")
+        .timeouts(Timeouts::new().connect(Duration::from_secs(2)))
+        .build()
+        .expect("client should build");
+
+    let error = client
+        .management()
+        .get_credits()
+        .await
+        .expect_err("closed port should refuse the connection");
+
+    match error {
+        OpenRouterError::HttpRequest(http_error) => assert!(http_error.is_connect()),
+        other => panic!("expected connection error, got {other:?}"),
+    }
+}
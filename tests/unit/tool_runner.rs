@@ -0,0 +1,381 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::{
+        chat::{ChatCompletionRequest, Message},
+        messages::{AnthropicContentPart, AnthropicMessage, AnthropicMessagesRequest},
+    },
+    tool_runner::{ToolRunEvent, ToolRunStop, ToolRunner},
+    types::{Role, stream::StreamEvent, typed_tool::TypedTool},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Barrier;
+
+use crate::support::{Scripted, spawn_scripted_server};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct WeatherParams {
+    location: String,
+}
+
+impl TypedTool for WeatherParams {
+    fn name() -> &'static str {
+        "get_weather"
+    }
+
+    fn description() -> &'static str {
+        "Get the weather for a location"
+    }
+}
+
+fn tool_calls_response(calls: &[(&str, &str, &str)]) -> Scripted {
+    let tool_calls: Vec<Value> = calls
+        .iter()
+        .map(|(id, name, arguments)| {
+            serde_json::json!({
+                "id": id,
+                "type": "function",
+                "function": {"name": name, "arguments": arguments}
+            })
+        })
+        .collect();
+    let body = serde_json::json!({
+        "id": "gen-1",
+        "choices": [{
+            "finish_reason": "tool_calls",
+            "index": 0,
+            "message": {"role": "assistant", "content": "", "tool_calls": tool_calls}
+        }],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion"
+    });
+    Scripted::json(200, &body)
+}
+
+fn final_response(content: &str) -> Scripted {
+    let body = serde_json::json!({
+        "id": "gen-2",
+        "choices": [{
+            "finish_reason": "stop",
+            "index": 0,
+            "message": {"role": "assistant", "content": content}
+        }],
+        "created": 1700000000,
+        "model": "openai/gpt-4.1-mini",
+        "object": "chat.completion"
+    });
+    Scripted::json(200, &body)
+}
+
+fn build_client(base_url: String) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("api-key")
+        .build()
+        .expect("client should build")
+}
+
+fn build_request() -> ChatCompletionRequest {
+    ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![Message::new(Role::User, "Weather in Paris and Rome?")])
+        .build()
+        .expect("chat request should build")
+}
+
+fn weather_runner() -> ToolRunner {
+    ToolRunner::new().tool(|params: WeatherParams| async move {
+        Ok::<_, String>(format!("Sunny in {}", params.location))
+    })
+}
+
+#[tokio::test]
+async fn test_tool_runner_executes_parallel_calls_and_feeds_results_back() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        tool_calls_response(&[
+            ("call_1", "get_weather", r#"{"location":"Paris"}"#),
+            ("call_2", "get_weather", r#"{"location":"Rome"}"#),
+        ]),
+        final_response("Sunny in both."),
+    ]);
+
+    let barrier = Arc::new(Barrier::new(2));
+    let runner = ToolRunner::new().tool(move |params: WeatherParams| {
+        let barrier = barrier.clone();
+        async move {
+            // Both calls of the turn must be in flight at once to pass the barrier.
+            tokio::time::timeout(Duration::from_secs(2), barrier.wait())
+                .await
+                .map_err(|_| "tool calls were not run concurrently")?;
+            Ok::<_, &str>(serde_json::json!({"location": params.location, "sky": "clear"}))
+        }
+    });
+
+    let outcome = runner
+        .run(&build_client(base_url), &build_request())
+        .await
+        .expect("tool loop should succeed");
+
+    assert_eq!(outcome.stop, ToolRunStop::FinalAnswer);
+    assert_eq!(outcome.iterations, 2);
+    assert_eq!(
+        outcome.response.choices[0].content(),
+        Some("Sunny in both.")
+    );
+    assert_eq!(outcome.messages.len(), 5);
+    assert_eq!(outcome.messages[4].role, Role::Assistant);
+
+    let first = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("first request should be captured");
+    assert_eq!(first["tools"][0]["function"]["name"], "get_weather");
+
+    let second = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("second request should be captured");
+    let messages = second["messages"].as_array().expect("messages array");
+    assert_eq!(messages.len(), 4);
+    assert_eq!(messages[1]["tool_calls"][1]["id"], "call_2");
+    assert_eq!(messages[2]["role"], "tool");
+    assert_eq!(messages[2]["tool_call_id"], "call_1");
+    assert_eq!(
+        messages[2]["content"],
+        r#"{"location":"Paris","sky":"clear"}"#
+    );
+    assert_eq!(messages[3]["tool_call_id"], "call_2");
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_tool_runner_reports_invalid_arguments_and_unknown_tools_to_model() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        tool_calls_response(&[
+            ("call_1", "get_weather", r#"{"city":"Paris"}"#),
+            ("call_2", "get_time", "{}"),
+        ]),
+        final_response("Sorry."),
+    ]);
+
+    let outcome = weather_runner()
+        .run(&build_client(base_url), &build_request())
+        .await
+        .expect("tool loop should succeed");
+    assert_eq!(outcome.stop, ToolRunStop::FinalAnswer);
+
+    let _ = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("first request should be captured");
+    let second = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("second request should be captured");
+    let invalid = second["messages"][2]["content"]
+        .as_str()
+        .expect("tool content should be text");
+    assert!(invalid.starts_with("Invalid arguments for get_weather"));
+    assert_eq!(second["messages"][3]["content"], "Unknown tool: get_time");
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_tool_runner_stops_at_max_iterations_without_executing() {
+    let (base_url, _rx, server) = spawn_scripted_server(vec![tool_calls_response(&[(
+        "call_1",
+        "get_weather",
+        r#"{"location":"Paris"}"#,
+    )])]);
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    let runner = ToolRunner::new()
+        .tool(move |_: WeatherParams| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok::<_, String>("unused") }
+        })
+        .max_iterations(1);
+
+    let outcome = runner
+        .run(&build_client(base_url), &build_request())
+        .await
+        .expect("tool loop should stop cleanly");
+
+    assert_eq!(outcome.stop, ToolRunStop::MaxIterations);
+    assert_eq!(outcome.iterations, 1);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        outcome.response.choices[0]
+            .tool_calls()
+            .expect("pending tool calls")
+            .len(),
+        1
+    );
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_tool_runner_streaming_mode_runs_tools_between_turns() {
+    let tool_turn = concat!(
+        "data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"tool_calls\":[{\"index\":0,\"id\":\"call_1\",\"type\":\"function\",\"function\":{\"name\":\"get_weather\",\"arguments\":\"{\\\"location\\\":\"}}]}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+        "data: {\"id\":\"gen-1\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\"\\\"Paris\\\"}\"}}]},\"finish_reason\":\"tool_calls\"}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+        "data: [DONE]\n\n"
+    );
+    let answer_turn = concat!(
+        "data: {\"id\":\"gen-2\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"Sunny \"}}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+        "data: {\"id\":\"gen-2\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"in Paris.\"},\"finish_reason\":\"stop\"}],\"created\":1700000000,\"model\":\"openai/gpt-4.1-mini\",\"object\":\"chat.completion.chunk\"}\n\n",
+        "data: [DONE]\n\n"
+    );
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::new(200, "text/event-stream", tool_turn.to_string()),
+        Scripted::new(200, "text/event-stream", answer_turn.to_string()),
+    ]);
+
+    let events: Vec<_> = weather_runner()
+        .run_streaming(&build_client(base_url), &build_request())
+        .collect()
+        .await;
+    let events: Vec<ToolRunEvent> = events
+        .into_iter()
+        .map(|event| event.expect("stream event should succeed"))
+        .collect();
+
+    let mut text = String::new();
+    let mut tool_results = Vec::new();
+    for event in &events {
+        match event {
+            ToolRunEvent::Model(StreamEvent::ContentDelta(delta)) => text.push_str(delta),
+            ToolRunEvent::ToolResult { call, output } => {
+                tool_results.push((call.id.clone(), output.content.clone()))
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(text, "Sunny in Paris.");
+    assert_eq!(
+        tool_results,
+        [("call_1".to_string(), "Sunny in Paris".to_string())]
+    );
+    match events.last() {
+        Some(ToolRunEvent::Finished {
+            messages,
+            iterations,
+            stop,
+        }) => {
+            assert_eq!(*stop, ToolRunStop::FinalAnswer);
+            assert_eq!(*iterations, 2);
+            assert_eq!(messages.len(), 4);
+        }
+        other => panic!("expected Finished event, got {other:?}"),
+    }
+
+    let first = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("first request should be captured");
+    assert_eq!(first["stream"], true);
+    let second = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("second request should be captured");
+    assert_eq!(second["messages"][2]["content"], "Sunny in Paris");
+
+    server.join().expect("server thread should finish");
+}
+
+#[tokio::test]
+async fn test_tool_runner_messages_loop_preserves_thinking_and_reports_errors() {
+    let tool_use_turn = serde_json::json!({
+        "id": "msg-1",
+        "type": "message",
+        "role": "assistant",
+        "model": "anthropic/claude-sonnet-4",
+        "stop_reason": "tool_use",
+        "content": [
+            {"type": "thinking", "thinking": "Need the weather.", "signature": "sig-1"},
+            {"type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": {"location": "Paris"}},
+            {"type": "tool_use", "id": "toolu_2", "name": "get_weather", "input": {}}
+        ]
+    });
+    let final_turn = serde_json::json!({
+        "id": "msg-2",
+        "type": "message",
+        "role": "assistant",
+        "model": "anthropic/claude-sonnet-4",
+        "stop_reason": "end_turn",
+        "content": [{"type": "text", "text": "Sunny in Paris."}]
+    });
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        Scripted::json(200, &tool_use_turn),
+        Scripted::json(200, &final_turn),
+    ]);
+
+    let request = AnthropicMessagesRequest::builder()
+        .model("anthropic/claude-sonnet-4")
+        .max_tokens(1024)
+        .messages(vec![AnthropicMessage::user("Weather in Paris?")])
+        .thinking_enabled(512)
+        .build()
+        .expect("messages request should build");
+    let outcome = weather_runner()
+        .run_messages(&build_client(base_url), &request)
+        .await
+        .expect("messages tool run should succeed");
+    server.join().expect("server thread should finish");
+
+    assert_eq!(outcome.stop, ToolRunStop::FinalAnswer);
+    assert_eq!(outcome.iterations, 2);
+    assert_eq!(outcome.messages.len(), 4);
+    assert!(matches!(
+        outcome.response.content.as_slice(),
+        [AnthropicContentPart::Text { text, .. }] if text == "Sunny in Paris."
+    ));
+
+    let first = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("first request should be captured");
+    assert_eq!(first["tools"][0]["name"], "get_weather");
+    assert_eq!(first["tools"][0]["type"], "custom");
+    assert_eq!(first["tools"][0]["input_schema"]["type"], "object");
+
+    let second = rx
+        .recv()
+        .map(|request| request.json())
+        .expect("second request should be captured");
+    let assistant = &second["messages"][1];
+    assert_eq!(assistant["role"], "assistant");
+    assert_eq!(assistant["content"][0]["type"], "thinking");
+    assert_eq!(assistant["content"][0]["signature"], "sig-1");
+    assert_eq!(assistant["content"][1]["type"], "tool_use");
+
+    let results = &second["messages"][2];
+    assert_eq!(results["role"], "user");
+    assert_eq!(results["content"][0]["type"], "tool_result");
+    assert_eq!(results["content"][0]["tool_use_id"], "toolu_1");
+    assert_eq!(results["content"][0]["content"], "Sunny in Paris");
+    assert!(results["content"][0].get("is_error").is_none());
+    assert_eq!(results["content"][1]["tool_use_id"], "toolu_2");
+    assert_eq!(results["content"][1]["is_error"], true);
+    assert!(
+        results["content"][1]["content"]
+            .as_str()
+            .expect("error content should be text")
+            .starts_with("Invalid arguments for get_weather")
+    );
+}
@@ -0,0 +1,146 @@
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use http::{HeaderMap, HeaderValue, StatusCode, header::CONTENT_TYPE};
+use openrouter_rs::{
+    OpenRouterClient,
+    api::chat,
+    client::{
+        Middleware, MiddlewareFuture, MiddlewareRequest, MiddlewareResponse, Network, Next,
+        RetryPolicy, Timeouts, Transport,
+    },
+    error::{OpenRouterError, TimeoutKind},
+    types::Role,
+};
+
+const CHAT_RESPONSE: &str = r#"{"id":"gen-1","choices":[{"index":0,"message":{"role":"assistant","content":"hi"},"finish_reason":"stop"}],"created":1700000000,"model":"openai/gpt-4.1-mini","object":"chat.completion"}"#;
+
+fn unreachable_base_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    drop(listener);
+    format!("http://{addr}/api/v1")
+}
+
+fn build_chat_request() -> chat::ChatCompletionRequest {
+    chat::ChatCompletionRequest::builder()
+        .model("openai/gpt-4.1-mini")
+        .messages(vec![chat::Message::new(Role::User, "hello")])
+        .build()
+        .expect("chat request should build")
+}
+
+/// Records each pass through the middleware chain.
+struct Counting(Arc<Mutex<usize>>);
+
+impl Middleware for Counting {
+    fn handle<'a>(&'a self, request: MiddlewareRequest, next: Next<'a>) -> MiddlewareFuture<'a> {
+        *self.0.lock().unwrap() += 1;
+        next.run(request)
+    }
+}
+
+/// Fails the first `failures` attempts with a 503, then serves `CHAT_RESPONSE`.
+struct Flaky {
+    failures: usize,
+    attempts: Arc<Mutex<usize>>,
+}
+
+impl Transport for Flaky {
+    fn send<'a>(
+        &'a self,
+        _request: MiddlewareRequest,
+        _network: Network<'a>,
+    ) -> MiddlewareFuture<'a> {
+        let attempt = {
+            let mut attempts = self.attempts.lock().unwrap();
+            *attempts += 1;
+            *attempts
+        };
+        Box::pin(async move {
+            if attempt <= self.failures {
+                return MiddlewareResponse::json(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    &serde_json::json!({"error": {"code": 503, "message": "overloaded"}}),
+                );
+            }
+            let mut headers = HeaderMap::new();
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            Ok(MiddlewareResponse::new(
+                StatusCode::OK,
+                headers,
+                CHAT_RESPONSE,
+            ))
+        })
+    }
+}
+
+struct Slow;
+
+impl Transport for Slow {
+    fn send<'a>(
+        &'a self,
+        request: MiddlewareRequest,
+        network: Network<'a>,
+    ) -> MiddlewareFuture<'a> {
+        Box::pin(async move {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            network.send(request).await
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_transport_runs_below_retries_and_middleware() {
+    let passes = Arc::new(Mutex::new(0));
+    let attempts = Arc::new(Mutex::new(0));
+    let client = OpenRouterClient::builder()
+        .base_url(unreachable_base_url())
+        .api_key("api-key")
+        .middleware(Counting(passes.clone()))
+        .transport(Flaky {
+            failures: 1,
+            attempts: attempts.clone(),
+        })
+        .retry_policy(
+            RetryPolicy::new(2)
+                .initial_backoff(Duration::from_millis(1))
+                .jitter(false),
+        )
+        .build()
+        .expect("client should build");
+
+    let response = client
+        .chat()
+        .create(&build_chat_request())
+        .await
+        .expect("retried request should succeed");
+
+    assert_eq!(response.choices[0].content(), Some("hi"));
+    assert_eq!(*attempts.lock().unwrap(), 2);
+    assert_eq!(*passes.lock().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_transport_is_bounded_by_request_timeout() {
+    let client = OpenRouterClient::builder()
+        .base_url(unreachable_base_url())
+        .api_key("api-key")
+        .transport(Slow)
+        .timeouts(Timeouts::new().request(Duration::from_millis(20)))
+        .build()
+        .expect("client should build");
+
+    match client.chat().create(&build_chat_request()).await {
+        Err(OpenRouterError::Timeout(timeout)) => {
+            assert_eq!(timeout.kind(), TimeoutKind::Request);
+            assert_eq!(timeout.duration(), Some(Duration::from_millis(20)));
+        }
+        other => panic!("expected request timeout, got {other:?}"),
+    }
+}
@@ -104,11 +104,25 @@ async fn test_unified_chat_stream_mixed_sequence() {
     let mut stream = adapt_chat_stream(stream::iter(chunks).boxed());
     let events: Vec<UnifiedStreamEvent> = stream.by_ref().collect().await;
 
-    assert_eq!(events.len(), 4);
+    assert_eq!(events.len(), 6);
     assert!(matches!(events[0], UnifiedStreamEvent::ContentDelta(_)));
     assert!(matches!(events[1], UnifiedStreamEvent::ReasoningDelta(_)));
-    assert!(matches!(events[2], UnifiedStreamEvent::ToolDelta(_)));
-    match &events[3] {
+    assert!(matches!(
+        events[2],
+        UnifiedStreamEvent::ToolCallStarted { index: 0, .. }
+    ));
+    assert!(matches!(
+        events[3],
+        UnifiedStreamEvent::ToolCallArgumentsDelta { index: 0, .. }
+    ));
+    match &events[4] {
+        UnifiedStreamEvent::ToolCallCompleted(call) => {
+            assert_eq!(call.id(), "call_1");
+            assert_eq!(call.arguments_json(), "{\"location\":\"SF\"}");
+        }
+        other => panic!("expected ToolCallCompleted event, got {other:?}"),
+    }
+    match &events[5] {
         UnifiedStreamEvent::Done {
             source,
             id,
@@ -120,7 +134,10 @@ async fn test_unified_chat_stream_mixed_sequence() {
             assert_eq!(id.as_deref(), Some("gen_1"));
             assert_eq!(model.as_deref(), Some("test-model"));
             assert_eq!(finish_reason.as_deref(), Some("stop"));
-            assert!(usage.is_some());
+            let usage = usage.as_ref().expect("usage should be present");
+            assert_eq!(usage.prompt_tokens, 5);
+            assert_eq!(usage.completion_tokens, 7);
+            assert_eq!(usage.total_tokens, 12);
         }
         other => panic!("expected Done event, got {other:?}"),
     }
@@ -197,13 +214,7 @@ async fn test_unified_responses_stream_mixed_sequence() {
             assert_eq!(id.as_deref(), Some("resp_1"));
             assert_eq!(model.as_deref(), Some("openai/gpt-5"));
             assert_eq!(finish_reason.as_deref(), Some("completed"));
-            assert_eq!(
-                usage
-                    .as_ref()
-                    .and_then(|u| u.get("total_tokens"))
-                    .and_then(|v| v.as_u64()),
-                Some(10)
-            );
+            assert_eq!(usage.as_ref().map(|usage| usage.total_tokens), Some(10));
         }
         other => panic!("expected Done event, got {other:?}"),
     }
@@ -331,7 +342,10 @@ async fn test_unified_messages_stream_mixed_sequence() {
     assert_eq!(unified.len(), 4);
     assert!(matches!(unified[0], UnifiedStreamEvent::ContentDelta(_)));
     assert!(matches!(unified[1], UnifiedStreamEvent::ReasoningDelta(_)));
-    assert!(matches!(unified[2], UnifiedStreamEvent::ToolDelta(_)));
+    assert!(matches!(
+        unified[2],
+        UnifiedStreamEvent::ToolCallStarted { index: 1, .. }
+    ));
     match &unified[3] {
         UnifiedStreamEvent::Done {
             source, id, model, ..
@@ -438,27 +452,12 @@ async fn test_unified_messages_tool_start_preserves_content_block_index() {
 
     assert_eq!(unified.len(), 2);
     match &unified[0] {
-        UnifiedStreamEvent::ToolDelta(payload) => {
-            assert_eq!(
-                payload.get("index").and_then(|value| value.as_u64()),
-                Some(3)
-            );
-            assert_eq!(
-                payload
-                    .get("content_block")
-                    .and_then(|block| block.get("id"))
-                    .and_then(|value| value.as_str()),
-                Some("toolu_2")
-            );
-            assert_eq!(
-                payload
-                    .get("content_block")
-                    .and_then(|block| block.get("name"))
-                    .and_then(|value| value.as_str()),
-                Some("get_weather")
-            );
+        UnifiedStreamEvent::ToolCallStarted { index, id, name } => {
+            assert_eq!(*index, 3);
+            assert_eq!(id, "toolu_2");
+            assert_eq!(name, "get_weather");
         }
-        other => panic!("expected ToolDelta event, got {other:?}"),
+        other => panic!("expected ToolCallStarted event, got {other:?}"),
     }
     assert!(matches!(
         unified[1],
@@ -468,3 +467,279 @@ async fn test_unified_messages_tool_start_preserves_content_block_index() {
         }
     ));
 }
+
+#[tokio::test]
+async fn test_unified_chat_stream_tool_call_split_across_chunks() {
+    let fragment = |value: serde_json::Value| -> PartialToolCall {
+        serde_json::from_value(value).expect("partial tool call should deserialize")
+    };
+    let chunks = vec![
+        Ok(chat_chunk(
+            "gen_3",
+            "test-model",
+            None,
+            None,
+            Some(fragment(json!({
+                "index": 0,
+                "id": "call_9",
+                "type": "function",
+                "function": { "name": "get_weather", "arguments": "" }
+            }))),
+            None,
+            None,
+        )),
+        Ok(chat_chunk(
+            "gen_3",
+            "test-model",
+            None,
+            None,
+            Some(fragment(json!({
+                "index": 0,
+                "function": { "arguments": "{\"city\":" }
+            }))),
+            None,
+            None,
+        )),
+        Ok(chat_chunk(
+            "gen_3",
+            "test-model",
+            None,
+            None,
+            Some(fragment(json!({
+                "index": 0,
+                "function": { "arguments": "\"SF\"}" }
+            }))),
+            None,
+            None,
+        )),
+    ];
+
+    let mut stream = adapt_chat_stream(stream::iter(chunks).boxed());
+    let events: Vec<UnifiedStreamEvent> = stream.by_ref().collect().await;
+
+    assert_eq!(events.len(), 5);
+    match &events[0] {
+        UnifiedStreamEvent::ToolCallStarted { index, id, name } => {
+            assert_eq!(*index, 0);
+            assert_eq!(id, "call_9");
+            assert_eq!(name, "get_weather");
+        }
+        other => panic!("expected ToolCallStarted event, got {other:?}"),
+    }
+    let deltas: Vec<&str> = events[1..3]
+        .iter()
+        .map(|event| match event {
+            UnifiedStreamEvent::ToolCallArgumentsDelta { delta, .. } => delta.as_str(),
+            other => panic!("expected ToolCallArgumentsDelta event, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(deltas, ["{\"city\":", "\"SF\"}"]);
+    match &events[3] {
+        UnifiedStreamEvent::ToolCallCompleted(call) => {
+            assert_eq!(call.name(), "get_weather");
+            assert_eq!(call.arguments_json(), "{\"city\":\"SF\"}");
+            assert_eq!(call.index, Some(0));
+        }
+        other => panic!("expected ToolCallCompleted event, got {other:?}"),
+    }
+    assert!(matches!(events[4], UnifiedStreamEvent::Done { .. }));
+}
+
+#[tokio::test]
+async fn test_unified_responses_stream_function_call_events() {
+    let events = vec![
+        Ok(responses_event(
+            "response.output_item.added",
+            json!({
+                "output_index": 1,
+                "item": {
+                    "type": "function_call",
+                    "id": "fc_1",
+                    "call_id": "call_1",
+                    "name": "get_weather",
+                    "arguments": ""
+                }
+            }),
+        )),
+        Ok(responses_event(
+            "response.function_call_arguments.delta",
+            json!({ "item_id": "fc_1", "output_index": 1, "delta": "{\"city\":\"SF\"}" }),
+        )),
+        Ok(responses_event(
+            "response.function_call_arguments.done",
+            json!({ "item_id": "fc_1", "output_index": 1, "arguments": "{\"city\":\"SF\"}" }),
+        )),
+        Ok(responses_event(
+            "response.output_item.done",
+            json!({
+                "output_index": 1,
+                "item": {
+                    "type": "function_call",
+                    "id": "fc_1",
+                    "call_id": "call_1",
+                    "name": "get_weather",
+                    "arguments": "{\"city\":\"SF\"}"
+                }
+            }),
+        )),
+        Ok(responses_event(
+            "response.completed",
+            json!({
+                "response": {
+                    "id": "resp_2",
+                    "status": "completed",
+                    "usage": {
+                        "input_tokens": 20,
+                        "output_tokens": 8,
+                        "total_tokens": 28,
+                        "input_tokens_details": { "cached_tokens": 16 },
+                        "output_tokens_details": { "reasoning_tokens": 4 }
+                    }
+                }
+            }),
+        )),
+    ];
+
+    let mut stream = adapt_responses_stream(stream::iter(events).boxed());
+    let unified: Vec<UnifiedStreamEvent> = stream.by_ref().collect().await;
+
+    assert_eq!(unified.len(), 4);
+    match &unified[0] {
+        UnifiedStreamEvent::ToolCallStarted { index, id, name } => {
+            assert_eq!(*index, 1);
+            assert_eq!(id, "call_1");
+            assert_eq!(name, "get_weather");
+        }
+        other => panic!("expected ToolCallStarted event, got {other:?}"),
+    }
+    assert!(matches!(
+        &unified[1],
+        UnifiedStreamEvent::ToolCallArgumentsDelta { index: 1, delta } if delta == "{\"city\":\"SF\"}"
+    ));
+    match &unified[2] {
+        UnifiedStreamEvent::ToolCallCompleted(call) => {
+            assert_eq!(call.id(), "call_1");
+            assert_eq!(call.arguments_json(), "{\"city\":\"SF\"}");
+        }
+        other => panic!("expected ToolCallCompleted event, got {other:?}"),
+    }
+    match &unified[3] {
+        UnifiedStreamEvent::Done { usage, .. } => {
+            let usage = usage.as_ref().expect("usage should be present");
+            assert_eq!(usage.prompt_tokens, 20);
+            assert_eq!(usage.completion_tokens, 8);
+            assert_eq!(usage.total_tokens, 28);
+            assert_eq!(usage.cached_tokens, Some(16));
+            assert_eq!(usage.reasoning_tokens, Some(4));
+        }
+        other => panic!("expected Done event, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_unified_messages_stream_input_json_deltas_complete_tool_call() {
+    let events = vec![
+        Ok(messages_event(
+            "message_start",
+            json!({
+                "type": "message_start",
+                "message": {
+                    "id": "msg_2",
+                    "type": "message",
+                    "role": "assistant",
+                    "content": [],
+                    "model": "anthropic/claude-sonnet-4",
+                    "usage": {
+                        "input_tokens": 10,
+                        "cache_read_input_tokens": 30,
+                        "output_tokens": 1
+                    }
+                }
+            }),
+        )),
+        Ok(messages_event(
+            "content_block_start",
+            json!({
+                "type": "content_block_start",
+                "index": 0,
+                "content_block": {
+                    "type": "tool_use",
+                    "id": "toolu_3",
+                    "name": "get_weather",
+                    "input": {}
+                }
+            }),
+        )),
+        Ok(messages_event(
+            "content_block_delta",
+            json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "input_json_delta", "partial_json": "{\"city\":" }
+            }),
+        )),
+        Ok(messages_event(
+            "content_block_delta",
+            json!({
+                "type": "content_block_delta",
+                "index": 0,
+                "delta": { "type": "input_json_delta", "partial_json": "\"SF\"}" }
+            }),
+        )),
+        Ok(messages_event(
+            "content_block_stop",
+            json!({ "type": "content_block_stop", "index": 0 }),
+        )),
+        Ok(messages_event(
+            "message_delta",
+            json!({
+                "type": "message_delta",
+                "delta": { "stop_reason": "tool_use" },
+                "usage": { "output_tokens": 12 }
+            }),
+        )),
+        Ok(messages_event(
+            "message_stop",
+            json!({ "type": "message_stop" }),
+        )),
+    ];
+
+    let mut stream = adapt_messages_stream(stream::iter(events).boxed());
+    let unified: Vec<UnifiedStreamEvent> = stream.by_ref().collect().await;
+
+    assert_eq!(unified.len(), 5);
+    assert!(matches!(
+        unified[0],
+        UnifiedStreamEvent::ToolCallStarted { index: 0, .. }
+    ));
+    assert!(matches!(
+        unified[1],
+        UnifiedStreamEvent::ToolCallArgumentsDelta { index: 0, .. }
+    ));
+    assert!(matches!(
+        unified[2],
+        UnifiedStreamEvent::ToolCallArgumentsDelta { index: 0, .. }
+    ));
+    match &unified[3] {
+        UnifiedStreamEvent::ToolCallCompleted(call) => {
+            assert_eq!(call.id(), "toolu_3");
+            assert_eq!(call.arguments_json(), "{\"city\":\"SF\"}");
+        }
+        other => panic!("expected ToolCallCompleted event, got {other:?}"),
+    }
+    match &unified[4] {
+        UnifiedStreamEvent::Done {
+            finish_reason,
+            usage,
+            ..
+        } => {
+            assert_eq!(finish_reason.as_deref(), Some("tool_use"));
+            let usage = usage.as_ref().expect("usage should be present");
+            assert_eq!(usage.prompt_tokens, 40);
+            assert_eq!(usage.completion_tokens, 12);
+            assert_eq!(usage.total_tokens, 52);
+            assert_eq!(usage.cached_tokens, Some(30));
+        }
+        other => panic!("expected Done event, got {other:?}"),
+    }
+}
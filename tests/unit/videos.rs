@@ -4,14 +4,22 @@ use std::{
     net::TcpListener,
     sync::mpsc,
     thread,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-use openrouter_rs::api::videos::{
-    self, VideoFrameImage, VideoGenerationRequest, VideoGenerationResponse, VideoInputReference,
-    VideoModel, VideoProviderOptions,
+use futures_util::StreamExt;
+use openrouter_rs::{
+    OpenRouterClient,
+    api::videos::{
+        self, VideoFrameImage, VideoGenerationRequest, VideoGenerationResponse,
+        VideoInputReference, VideoModel, VideoProviderOptions,
+    },
+    error::{OpenRouterError, TimeoutKind},
+    types::{CancellationToken, PollOptions},
 };
 
+use crate::support::{Scripted, spawn_scripted_server};
+
 #[test]
 fn test_video_generation_request_serialization() {
     let mut provider_options = HashMap::new();
@@ -430,3 +438,272 @@ async fn test_get_video_content_path_query_and_auth_header() {
 
     server.join().expect("server thread should finish");
 }
+
+fn status_body(status: &str, error: Option<&str>) -> Scripted {
+    let body = serde_json::json!({
+        "id": "job_123",
+        "polling_url": "https://openrouter.ai/api/v1/videos/job_123",
+        "status": status,
+        "error": error,
+    });
+    Scripted::json(200, &body)
+}
+
+fn fast_polling() -> PollOptions {
+    PollOptions::new()
+        .interval(Duration::from_millis(1))
+        .max_interval(Duration::from_millis(5))
+}
+
+fn client(base_url: String) -> OpenRouterClient {
+    OpenRouterClient::builder()
+        .base_url(base_url)
+        .api_key("test-api-key")
+        .build()
+        .expect("client should build")
+}
+
+#[tokio::test]
+async fn test_video_status_updates_yield_transitions_until_final_status() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        status_body("pending", None),
+        status_body("pending", None),
+        status_body("in_progress", None),
+        status_body("in_progress", None),
+        status_body("completed", None),
+    ]);
+    let client = client(base_url);
+
+    let statuses: Vec<String> = client
+        .videos()
+        .status_updates("job_123", fast_polling())
+        .expect("api key is configured")
+        .map(|update| update.expect("poll should succeed").status)
+        .collect()
+        .await;
+    server.join().expect("server thread should finish");
+
+    assert_eq!(statuses, vec!["pending", "in_progress", "completed"]);
+    let request_lines: Vec<String> = rx.try_iter().map(|request| request.request_line).collect();
+    assert_eq!(request_lines.len(), 5);
+    assert!(
+        request_lines
+            .iter()
+            .all(|line| line == "GET /api/v1/videos/job_123 HTTP/1.1")
+    );
+}
+
+#[tokio::test]
+async fn test_wait_for_completion_reports_failed_job() {
+    let (base_url, _rx, server) = spawn_scripted_server(vec![
+        status_body("in_progress", None),
+        status_body("failed", Some("content policy violation")),
+    ]);
+    let client = client(base_url);
+
+    let error = client
+        .videos()
+        .wait_for_completion("job_123", fast_polling())
+        .await
+        .expect_err("failed job should be an error");
+    server.join().expect("server thread should finish");
+
+    match error {
+        OpenRouterError::JobFailed(job) => {
+            assert_eq!(job.job_id(), "job_123");
+            assert_eq!(job.status(), "failed");
+            assert_eq!(job.message(), Some("content policy violation"));
+        }
+        other => panic!("expected job failure, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_completion_honors_cancellation_and_deadline() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let (base_url, rx, _server) = spawn_scripted_server(Vec::new());
+    let error = client(base_url)
+        .videos()
+        .wait_for_completion("job_123", fast_polling().cancellation_token(token))
+        .await
+        .expect_err("cancelled wait should fail");
+    assert!(matches!(error, OpenRouterError::Cancelled));
+    assert!(rx.try_recv().is_err(), "no request after cancellation");
+
+    let (base_url, _rx, _server) =
+        spawn_scripted_server((0..100).map(|_| status_body("pending", None)).collect());
+    let error = client(base_url)
+        .videos()
+        .wait_for_completion(
+            "job_123",
+            fast_polling().deadline(Duration::from_millis(30)),
+        )
+        .await
+        .expect_err("wait should time out");
+    match error {
+        OpenRouterError::Timeout(timeout) => {
+            assert_eq!(timeout.kind(), TimeoutKind::Poll);
+            assert_eq!(timeout.duration(), Some(Duration::from_millis(30)));
+        }
+        other => panic!("expected poll timeout, got {other:?}"),
+    }
+}
+
+/// Server that accepts requests and never answers them.
+fn spawn_silent_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    thread::spawn(move || {
+        let held: Vec<_> = listener.incoming().collect();
+        drop(held);
+    });
+    format!("http://{addr}/api/v1")
+}
+
+#[tokio::test]
+async fn test_wait_for_completion_interrupts_a_hung_status_request() {
+    let started = Instant::now();
+    let error = client(spawn_silent_server())
+        .videos()
+        .wait_for_completion(
+            "job_123",
+            fast_polling().deadline(Duration::from_millis(50)),
+        )
+        .await
+        .expect_err("hung request should hit the deadline");
+    assert!(
+        matches!(error, OpenRouterError::Timeout(ref timeout) if timeout.kind() == TimeoutKind::Poll)
+    );
+    assert!(started.elapsed() < Duration::from_secs(2));
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        canceller.cancel();
+    });
+    let started = Instant::now();
+    let error = client(spawn_silent_server())
+        .videos()
+        .wait_for_completion("job_123", fast_polling().cancellation_token(token))
+        .await
+        .expect_err("hung request should be cancelled");
+    assert!(matches!(error, OpenRouterError::Cancelled));
+    assert!(started.elapsed() < Duration::from_secs(2));
+}
+
+#[tokio::test]
+async fn test_wait_and_save_streams_video_to_disk() {
+    let (base_url, rx, server) = spawn_scripted_server(vec![
+        status_body("completed", None),
+        Scripted::new(200, "video/mp4", b"fake mp4 bytes".to_vec()),
+    ]);
+    let path = std::env::temp_dir().join(format!("openrouter-rs-video-{}.mp4", std::process::id()));
+
+    let response = client(base_url)
+        .videos()
+        .wait_and_save("job_123", fast_polling(), &path)
+        .await
+        .expect("wait and save should succeed");
+    server.join().expect("server thread should finish");
+
+    assert!(response.is_completed());
+    let saved = std::fs::read(&path).expect("video should be saved");
+    std::fs::remove_file(&path).ok();
+    assert_eq!(saved, b"fake mp4 bytes");
+    assert!(!path.with_extension("mp4.part").exists());
+    let request_lines: Vec<String> = rx.try_iter().map(|request| request.request_line).collect();
+    assert_eq!(
+        request_lines[1],
+        "GET /api/v1/videos/job_123/content HTTP/1.1"
+    );
+}
+
+/// Server that reports the job completed, then starts the content download
+/// and stalls partway through it.
+fn spawn_stalling_download_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("listener should bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener should have local addr");
+    let status = serde_json::json!({
+        "id": "job_123",
+        "polling_url": "https://openrouter.ai/api/v1/videos/job_123",
+        "status": "completed",
+    })
+    .to_string();
+    thread::spawn(move || {
+        let responses = [
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{status}",
+                status.len()
+            ),
+            "HTTP/1.1 200 OK\r\nContent-Type: video/mp4\r\nContent-Length: 1000\r\n\r\npartial"
+                .to_string(),
+        ];
+        for response in responses {
+            let Ok((mut stream, _)) = listener.accept() else {
+                return;
+            };
+            let mut request = Vec::new();
+            let mut chunk = [0_u8; 1024];
+            while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                match stream.read(&mut chunk) {
+                    Ok(0) | Err(_) => return,
+                    Ok(read) => request.extend_from_slice(&chunk[..read]),
+                }
+            }
+            stream
+                .write_all(response.as_bytes())
+                .expect("server should write response");
+            // Hold the stalled download open until the client hangs up.
+            let _ = stream.read(&mut chunk);
+        }
+    });
+    format!("http://{addr}/api/v1")
+}
+
+#[tokio::test]
+async fn test_wait_and_save_bounds_download_and_keeps_existing_file() {
+    let path = std::env::temp_dir().join(format!(
+        "openrouter-rs-video-stalled-{}.mp4",
+        std::process::id()
+    ));
+    let partial = path.with_extension("mp4.part");
+    std::fs::write(&path, b"previous video").expect("existing file should be written");
+
+    let error = client(spawn_stalling_download_server())
+        .videos()
+        .wait_and_save(
+            "job_123",
+            fast_polling().deadline(Duration::from_millis(200)),
+            &path,
+        )
+        .await
+        .expect_err("stalled download should hit the deadline");
+    assert!(
+        matches!(error, OpenRouterError::Timeout(ref timeout) if timeout.kind() == TimeoutKind::Poll)
+    );
+    assert_eq!(std::fs::read(&path).unwrap(), b"previous video");
+    assert!(!partial.exists(), "partial download should be removed");
+
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        canceller.cancel();
+    });
+    let error = client(spawn_stalling_download_server())
+        .videos()
+        .wait_and_save("job_123", fast_polling().cancellation_token(token), &path)
+        .await
+        .expect_err("stalled download should be cancelled");
+    assert!(matches!(error, OpenRouterError::Cancelled));
+    let saved = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(saved, b"previous video");
+    assert!(!partial.exists(), "partial download should be removed");
+}